@@ -1,66 +1,280 @@
+use crate::break_reminder::BreakReminder;
 use crate::data::StudyData;
 use crate::debug::DebugTools;
+use crate::deck_sync::DeckSync;
+use crate::discord_presence::DiscordPresence;
+use crate::error;
 use crate::file_drop_handler::FileDropHandler;
+use crate::fonts;
+use crate::hooks::{self, HookEvent};
 use crate::keyboard_handler::KeyboardHandler;
-use crate::settings::{AppSettings, NavigationLayout};
+use crate::lan_share::LanShare;
+use crate::reminder_checker::ReminderChecker;
+use crate::settings::{AppSettings, ColorTheme, NavigationLayout};
 use crate::split_view_ui::SplitViewUI;
+use crate::tab_badges::TabBadges;
 use crate::tab_manager::{SplitDirection, TabManager};
 use crate::tab_selector_ui::TabSelectorUI;
+use crate::task_sync::TaskSync;
 use crate::terminal::TerminalEmulator;
+use crate::time_export::TimeExport;
 use crate::timer::Timer;
+use crate::timer_recovery::TimerRecovery;
 use crate::ui;
 use crate::ui::flashcard_ui::{DeckManagerUI, FlashcardReviewer};
+use crate::ui::startup_summary::StartupSummary;
 use crate::weather::WeatherWidget;
 
+use chrono::{Local, Timelike};
 use eframe::{egui, CreationContext};
 use serde::{Deserialize, Serialize};
-use std::time::Instant;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Tab {
+    Home,
     Timer,
     Stats,
     Record,
     Graph,
     Todo,
+    Planner,
     Calculator,
     Markdown,
     Reminder,
+    Journal,
+    Assignments,
+    ImageViewer,
+    EpubReader,
+    Whiteboard,
+    Recorder,
+    Video,
     Terminal,
     Settings,
     Flashcards,
 }
 
-pub struct StatusMessage {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+struct Toast {
     message: String,
-    time: Option<Instant>,
+    details: Option<String>,
+    severity: Severity,
+    shown_at: Option<Instant>, // Set once the toast is actually on screen
+    expanded: bool,
+    prompt: Option<(String, Vec<String>)>, // Key and button labels of a prompt
+}
+
+impl Toast {
+    fn expired(&self) -> bool {
+        // Errors and prompts stay until they are dismissed or answered
+        if self.prompt.is_some() {
+            return false;
+        }
+        let lifetime = match self.severity {
+            Severity::Info => Duration::from_secs(5),
+            Severity::Warning => Duration::from_secs(8),
+            Severity::Error => return false,
+        };
+        self.shown_at
+            .map_or(false, |shown_at| shown_at.elapsed() >= lifetime)
+    }
+}
+
+const MAX_VISIBLE_TOASTS: usize = 4;
+
+// Queue of toast notifications drawn in the bottom-right corner of the window
+pub struct StatusMessage {
+    toasts: VecDeque<Toast>,
+    answers: Vec<(String, usize)>, // Prompt key and the index of the button clicked
 }
 
 impl StatusMessage {
     pub fn new() -> Self {
         Self {
-            message: String::new(),
-            time: None,
+            toasts: VecDeque::new(),
+            answers: Vec::new(),
         }
     }
 
     pub fn show(&mut self, message: &str) {
-        self.message = message.to_string();
-        self.time = Some(Instant::now());
+        self.push(Severity::Info, message, None);
     }
 
-    pub fn render(&mut self, ui: &mut egui::Ui) {
-        if let Some(status_time) = self.time {
-            if status_time.elapsed().as_secs() < 5 && !self.message.is_empty() {
-                ui.add_space(20.0);
-                ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
-                    ui.label(egui::RichText::new(&self.message).italics());
-                });
-            } else {
-                self.message.clear();
-                self.time = None;
+    pub fn warn(&mut self, message: &str) {
+        self.push(Severity::Warning, message, None);
+    }
+
+    pub fn error(&mut self, message: &str) {
+        self.push(Severity::Error, message, None);
+    }
+
+    pub fn error_with_details(&mut self, message: &str, details: &str) {
+        self.push(Severity::Error, message, Some(details.to_string()));
+    }
+
+    // A toast with buttons that stays until one is clicked; read the choice with take_answer
+    pub fn prompt(&mut self, key: &str, message: &str, actions: &[String]) {
+        if self.is_prompting(key) {
+            return;
+        }
+        self.toasts.push_back(Toast {
+            message: message.to_string(),
+            details: None,
+            severity: Severity::Warning,
+            shown_at: None,
+            expanded: false,
+            prompt: Some((key.to_string(), actions.to_vec())),
+        });
+    }
+
+    // Takes back a prompt that no longer applies
+    pub fn withdraw(&mut self, key: &str) {
+        self.toasts
+            .retain(|t| t.prompt.as_ref().is_none_or(|(k, _)| k != key));
+    }
+
+    pub fn is_prompting(&self, key: &str) -> bool {
+        self.toasts
+            .iter()
+            .any(|t| t.prompt.as_ref().is_some_and(|(k, _)| *k == key))
+    }
+
+    // Index of the button clicked on the prompt with this key, once
+    pub fn take_answer(&mut self, key: &str) -> Option<usize> {
+        let index = self.answers.iter().position(|(k, _)| *k == key)?;
+        Some(self.answers.remove(index).1)
+    }
+
+    fn push(&mut self, severity: Severity, message: &str, details: Option<String>) {
+        if message.is_empty() {
+            return;
+        }
+
+        // Some callers report the same thing every frame, so restart the existing toast instead
+        if let Some(existing) = self
+            .toasts
+            .iter_mut()
+            .find(|t| t.message == message && t.severity == severity)
+        {
+            if existing.shown_at.is_some() {
+                existing.shown_at = Some(Instant::now());
             }
+            return;
+        }
+
+        self.toasts.push_back(Toast {
+            message: message.to_string(),
+            details,
+            severity,
+            shown_at: None,
+            expanded: false,
+            prompt: None,
+        });
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, colors: &ColorTheme) {
+        self.toasts.retain(|t| !t.expired());
+        if self.toasts.is_empty() {
+            return;
+        }
+
+        let mut dismissed = None;
+        let mut answered = None;
+
+        egui::Area::new("status_toasts")
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-12.0, -12.0])
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.set_max_width(360.0);
+
+                for (index, toast) in self.toasts.iter_mut().take(MAX_VISIBLE_TOASTS).enumerate() {
+                    toast.shown_at.get_or_insert_with(Instant::now);
+
+                    let (icon, color) = match toast.severity {
+                        Severity::Info => ("ℹ", colors.accent_color32()),
+                        Severity::Warning => ("⚠", egui::Color32::from_rgb(255, 193, 7)),
+                        Severity::Error => ("✖", egui::Color32::from_rgb(220, 53, 69)),
+                    };
+
+                    egui::Frame::popup(ui.style())
+                        .fill(colors.panel_background_color32())
+                        .stroke(egui::Stroke::new(1.5, color))
+                        .show(ui, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(icon).color(color).strong());
+                                ui.add(
+                                    egui::Label::new(
+                                        egui::RichText::new(&toast.message)
+                                            .color(colors.text_primary_color32()),
+                                    )
+                                    .wrap(true),
+                                );
+                                if ui.small_button("✖").on_hover_text("Dismiss").clicked() {
+                                    dismissed = Some(index);
+                                }
+                            });
+
+                            if let Some((key, actions)) = &toast.prompt {
+                                ui.horizontal(|ui| {
+                                    for (action, label) in actions.iter().enumerate() {
+                                        if ui.button(label).clicked() {
+                                            answered = Some((index, key.clone(), action));
+                                        }
+                                    }
+                                });
+                            }
+
+                            if let Some(details) = &toast.details {
+                                let label = if toast.expanded {
+                                    "▾ Hide details"
+                                } else {
+                                    "▸ Show details"
+                                };
+                                if ui.small_button(label).clicked() {
+                                    toast.expanded = !toast.expanded;
+                                }
+                                if toast.expanded {
+                                    ui.add(
+                                        egui::Label::new(
+                                            egui::RichText::new(details)
+                                                .monospace()
+                                                .small()
+                                                .color(colors.text_secondary_color32()),
+                                        )
+                                        .wrap(true),
+                                    );
+                                }
+                            }
+                        });
+                    ui.add_space(6.0);
+                }
+
+                let queued = self.toasts.len().saturating_sub(MAX_VISIBLE_TOASTS);
+                if queued > 0 {
+                    ui.label(
+                        egui::RichText::new(format!("+{} more", queued))
+                            .small()
+                            .color(colors.text_secondary_color32()),
+                    );
+                }
+            });
+
+        if let Some((index, key, action)) = answered {
+            self.toasts.remove(index);
+            self.answers.push((key, action));
+        } else if let Some(index) = dismissed {
+            self.toasts.remove(index);
         }
+
+        // Keep ticking so toasts expire without other input
+        ctx.request_repaint_after(Duration::from_millis(250));
     }
 }
 
@@ -82,16 +296,70 @@ pub struct StudyTimerApp {
     pub last_used_split_pane: bool,
     pub flashcard_reviewer: FlashcardReviewer,
     pub deck_manager_ui: DeckManagerUI,
+    pub pane_content: crate::pane_content::PaneContent,
+    pub zen_tab_id: Option<String>, // The Markdown tab shown on its own in zen mode
+    window_fullscreen: bool,        // Set by fullscreen card review
+    close_prompt: Option<String>,   // Tab with unsaved edits waiting on save or discard
+    pending_heading: Option<(String, String)>, // Tab id and heading to show once the note loads
     pub weather_widget: WeatherWidget,
+    pub goal_reached_date: Option<String>,
+    pub discord_presence: DiscordPresence,
+    pub task_sync: TaskSync,
+    pub time_export: TimeExport,
+    pub deck_sync: DeckSync,
+    pub show_deck_sync: bool,
+    pub lan_share: LanShare,
+    pub show_lan_share: bool,
+    pub tab_badges: TabBadges,
+    pub startup_summary: Option<StartupSummary>,
+    pub reminder_checker: ReminderChecker,
+    pub break_reminder: BreakReminder,
+    pub timer_recovery: TimerRecovery,
+    pub show_trash: bool,
+    pub show_archive: bool,
+    last_trash_batch: u64, // Newest trash batch already announced
+    pub show_profile_picker: bool,
 }
 
 impl StudyTimerApp {
-    pub fn new(_cc: &CreationContext<'_>) -> Self {
-        let study_data = StudyData::load().unwrap_or_default();
-        let settings = AppSettings::load().unwrap_or_default();
+    pub fn new(cc: &CreationContext<'_>, ask_for_profile: bool) -> Self {
+        let study_data = StudyData::load().unwrap_or_else(|e| {
+            error::report("Could not load study data, starting empty", e);
+            StudyData::default()
+        });
+        let settings = AppSettings::load().unwrap_or_else(|e| {
+            error::report("Could not load settings, using defaults", e);
+            AppSettings::default()
+        });
         let current_tab = settings.get_first_enabled_tab();
+        if let Err(e) = fonts::apply(&cc.egui_ctx, &settings) {
+            error::report("Could not load custom fonts", e);
+        }
         let tab_manager = TabManager::new(&settings);
         let weather_widget = WeatherWidget::load().unwrap_or_default();
+        let mut lan_share = LanShare::new();
+        if settings.share_nearby {
+            if let Err(e) = lan_share.start() {
+                error::report("Could not start Share Nearby", e);
+            }
+        }
+
+        // Don't announce a goal that was already reached before launch
+        let goal_reached_date = if settings.daily_goal_minutes > 0
+            && study_data.get_today_minutes() >= settings.daily_goal_minutes as f64
+        {
+            Some(Local::now().date_naive().format("%Y-%m-%d").to_string())
+        } else {
+            None
+        };
+
+        let last_trash_batch = study_data.last_trash_batch();
+
+        let startup_summary = if settings.show_startup_summary {
+            Some(StartupSummary::new(&study_data))
+        } else {
+            None
+        };
 
         Self {
             timer: Timer::new(),
@@ -111,7 +379,357 @@ impl StudyTimerApp {
             last_used_split_pane: false,
             flashcard_reviewer: FlashcardReviewer::new(),
             deck_manager_ui: DeckManagerUI::new(),
+            pane_content: crate::pane_content::PaneContent::default(),
+            zen_tab_id: None,
+            window_fullscreen: false,
+            close_prompt: None,
+            pending_heading: None,
             weather_widget,
+            goal_reached_date,
+            discord_presence: DiscordPresence::new(),
+            task_sync: TaskSync::new(),
+            time_export: TimeExport::new(),
+            deck_sync: DeckSync::new(),
+            show_deck_sync: false,
+            lan_share,
+            show_lan_share: false,
+            tab_badges: TabBadges::new(),
+            startup_summary,
+            reminder_checker: ReminderChecker::new(),
+            break_reminder: BreakReminder::new(),
+            timer_recovery: TimerRecovery::new(),
+            show_trash: false,
+            show_archive: false,
+            last_trash_batch,
+            show_profile_picker: ask_for_profile,
+        }
+    }
+
+    // Focuses a tab of the given type, opening one if none exists yet
+    pub fn open_tab(&mut self, tab_type: Tab) {
+        if self.tab_manager.tabs.is_empty() {
+            self.current_tab = tab_type;
+            return;
+        }
+
+        let existing_id = self
+            .tab_manager
+            .tabs
+            .iter()
+            .find(|tab| tab.tab_type == tab_type)
+            .map(|tab| tab.id.clone());
+        let tab_id = existing_id.unwrap_or_else(|| self.tab_manager.add_tab(tab_type));
+
+        if self.tab_manager.is_split_active() {
+            self.tab_manager
+                .set_split_active_tab(&tab_id, self.last_used_split_pane);
+        } else {
+            self.tab_manager.set_active_tab(&tab_id);
+        }
+    }
+
+    // Opens a note in a Markdown tab of its own, which loads it when first drawn
+    pub fn open_note(&mut self, path: &std::path::Path) {
+        if path.is_file() {
+            self.tab_manager
+                .add_file_tab(Tab::Markdown, path.to_string_lossy().to_string());
+        } else {
+            self.status
+                .error(&format!("Error opening {}: file not found", path.display()));
+        }
+    }
+
+    // Like open_note, then moves to a heading in the note once its tab loads it
+    pub fn open_note_at(&mut self, path: &std::path::Path, heading: Option<&str>) {
+        self.open_note(path);
+        if let Some(heading) = heading.filter(|_| path.is_file()) {
+            self.pending_heading =
+                Some((self.tab_manager.active_tab_id.clone(), heading.to_string()));
+        }
+    }
+
+    // Each Markdown tab has its own editor, so split panes can show different notes
+    pub fn show_markdown_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, tab_id: &str) {
+        self.activate_markdown_tab(tab_id);
+        if self.focused_tab_id() == tab_id {
+            self.open_find_bar(ctx);
+        }
+        ui::markdown_tab_ui::display(ui, self, ctx);
+        self.sync_markdown_tab(tab_id);
+    }
+
+    // Ctrl+F finds in the open note, Ctrl+H also shows the replace row
+    fn open_find_bar(&mut self, ctx: &egui::Context) {
+        let (find, replace) = ctx.input(|i| {
+            let plain = i.modifiers.command && !i.modifiers.shift;
+            (
+                plain && i.key_pressed(egui::Key::F),
+                plain && i.key_pressed(egui::Key::H),
+            )
+        });
+        if !find && !replace {
+            return;
+        }
+        if let Some(editor) = self.markdown_editor.as_mut() {
+            match editor.find.as_mut() {
+                Some(bar) => bar.reopen(replace),
+                None => editor.find = Some(crate::ui::find_bar::FindBar::new(replace)),
+            }
+        }
+    }
+
+    fn activate_markdown_tab(&mut self, tab_id: &str) {
+        if self
+            .pane_content
+            .swap_editor(tab_id, &mut self.markdown_editor)
+        {
+            let file = self
+                .tab_manager
+                .get_tab(tab_id)
+                .and_then(|t| t.file_path.clone());
+            let mut editor = crate::ui::markdown_editor::MarkdownEditor::default();
+            if let Some(file) = file {
+                if let Err(e) = editor.open_file(&std::path::PathBuf::from(&file)) {
+                    self.status.error(&format!("Error opening {}: {}", file, e));
+                }
+            }
+            let for_this_tab = self
+                .pending_heading
+                .as_ref()
+                .map_or(false, |(pending_tab, _)| pending_tab == tab_id);
+            let pending = if for_this_tab {
+                self.pending_heading.take()
+            } else {
+                None
+            };
+            if let Some((_, heading)) = pending {
+                if !editor.go_to_heading(&heading) {
+                    self.status.show(&format!(
+                        "Heading \"{}\" is no longer in this note",
+                        heading
+                    ));
+                }
+            }
+            self.markdown_editor = Some(editor);
+        }
+    }
+
+    fn sync_markdown_tab(&mut self, tab_id: &str) {
+        let Some(editor) = self.markdown_editor.as_mut() else {
+            return;
+        };
+        // Link notes edited while the timer runs to the session
+        if let Some(path) = editor.take_edited_file() {
+            if self.timer.is_running {
+                ui::timer_tab::note_edited(path.to_string_lossy().to_string());
+            }
+        }
+        if let Some(path) = editor.take_opened_file() {
+            if self.settings.note_opened(&path.to_string_lossy()) {
+                if let Err(e) = self.settings.save() {
+                    self.status
+                        .error(&format!("Failed to save recent notes: {}", e));
+                }
+            }
+        }
+        // The tab follows the note open in it, so it reopens that note after a restart
+        let current = editor
+            .current_file
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string());
+        if let (Some(tab), Some(current)) = (self.tab_manager.get_tab_mut(tab_id), current) {
+            if tab.file_path.as_ref() != Some(&current) {
+                tab.title = std::path::Path::new(&current)
+                    .file_name()
+                    .map_or(current.clone(), |n| n.to_string_lossy().to_string());
+                tab.file_path = Some(current);
+                self.tab_manager.save_state();
+            }
+        }
+    }
+
+    // Likewise each Flashcards tab has its own reviewer and deck selection
+    pub fn show_flashcards_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, tab_id: &str) {
+        self.pane_content.swap_flashcards(
+            tab_id,
+            &mut self.flashcard_reviewer,
+            &mut self.deck_manager_ui,
+        );
+        ui::flashcard_tab_ui::display(ui, ctx, self);
+    }
+
+    // Zen mode shows the focused Markdown tab, or the first one, and nothing else
+    // The active tab, or the last used pane's tab in a split
+    fn focused_tab_id(&self) -> String {
+        match self.tab_manager.get_split_pane() {
+            Some(split) if self.last_used_split_pane => split.right_tab_id.clone(),
+            Some(split) => split.left_tab_id.clone(),
+            None => self.tab_manager.active_tab_id.clone(),
+        }
+    }
+
+    fn toggle_zen_mode(&mut self) {
+        if self.zen_tab_id.take().is_some() {
+            if let Some(editor) = self.markdown_editor.as_mut() {
+                if editor.current_file.is_some() {
+                    if let Err(e) = editor.save_file() {
+                        self.status.error(&format!("Error saving file: {}", e));
+                    }
+                }
+            }
+            return;
+        }
+
+        let focused = self.focused_tab_id();
+        let is_markdown = |tab: &&crate::tab_manager::TabInstance| tab.tab_type == Tab::Markdown;
+        let target = self
+            .tab_manager
+            .get_tab(&focused)
+            .filter(is_markdown)
+            .or_else(|| self.tab_manager.tabs.iter().find(is_markdown))
+            .map(|tab| tab.id.clone());
+        match target {
+            Some(tab_id) => self.zen_tab_id = Some(tab_id),
+            None => self.status.show("Open a Markdown tab to write in zen mode"),
+        }
+    }
+
+    fn render_zen(&mut self, ui: &mut egui::Ui, tab_id: &str) {
+        self.activate_markdown_tab(tab_id);
+        ui::markdown_tab_ui::display_zen(ui, self);
+        self.sync_markdown_tab(tab_id);
+    }
+
+    // Fullscreen card review takes over the OS window too when the setting is on
+    fn sync_window_fullscreen(&mut self, ctx: &egui::Context) {
+        let wanted =
+            self.flashcard_reviewer.is_fullscreen && self.settings.review_window_fullscreen;
+        if wanted != self.window_fullscreen {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Fullscreen(wanted));
+            self.window_fullscreen = wanted;
+        }
+    }
+
+    // Amber over everything, windows and popups included, without taking any input.
+    // The 30 second repaint picks up the start and end of the window.
+    fn paint_night_light(&self, ctx: &egui::Context) {
+        if !self.settings.in_night_light(Local::now().hour()) {
+            return;
+        }
+        let alpha = (self.settings.night_light_strength.min(100) as f32 / 100.0 * 180.0) as u8;
+        let layer = egui::LayerId::new(egui::Order::Debug, egui::Id::new("night_light"));
+        ctx.layer_painter(layer).rect_filled(
+            ctx.screen_rect(),
+            0.0,
+            egui::Color32::from_rgba_unmultiplied(255, 138, 18, alpha),
+        );
+    }
+
+    fn check_daily_goal(&mut self) {
+        let goal = self.settings.daily_goal_minutes;
+        if goal == 0 {
+            return;
+        }
+
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        if self.goal_reached_date.as_deref() == Some(today.as_str()) {
+            return;
+        }
+
+        let today_minutes = self.study_data.get_today_minutes();
+        if today_minutes >= goal as f64 {
+            self.goal_reached_date = Some(today);
+            hooks::emit(
+                HookEvent::GoalReached,
+                &[
+                    ("goal_minutes", goal.to_string()),
+                    ("minutes", format!("{:.1}", today_minutes)),
+                ],
+            );
+            self.status
+                .show(&format!("🎯 Daily goal of {} minutes reached!", goal));
+        }
+    }
+
+    // A note with unsaved edits asks first instead of closing straight away
+    fn request_close_tab(&mut self, tab_id: &str) -> bool {
+        let closable = self
+            .tab_manager
+            .get_tab(tab_id)
+            .is_some_and(|t| t.can_close);
+        if closable
+            && self
+                .pane_content
+                .has_unsaved_edits(tab_id, &self.markdown_editor)
+        {
+            self.close_prompt = Some(tab_id.to_string());
+            return true;
+        }
+        self.tab_manager.close_tab(tab_id)
+    }
+
+    fn render_close_prompt(&mut self, ctx: &egui::Context) {
+        let Some(tab_id) = self.close_prompt.clone() else {
+            return;
+        };
+        let Some(title) = self.tab_manager.get_tab(&tab_id).map(|t| t.title.clone()) else {
+            self.close_prompt = None;
+            return;
+        };
+
+        let untitled = self
+            .pane_content
+            .is_untitled(&tab_id, &self.markdown_editor);
+        let save_label = if untitled {
+            "💾 Save As…"
+        } else {
+            "💾 Save"
+        };
+        let (mut save, mut discard, mut cancel) = (false, false, false);
+        egui::Window::new("Unsaved Changes")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                if untitled {
+                    ui.label(format!("{} hasn't been saved to a note yet.", title));
+                } else {
+                    ui.label(format!("{} has changes that aren't saved.", title));
+                }
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    save = ui.button(save_label).clicked();
+                    discard = ui.button("Discard").clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+
+        let mut save_as = None;
+        if save && untitled {
+            // Cancelling the file dialog keeps the prompt open
+            save_as = rfd::FileDialog::new()
+                .add_filter("Markdown", &["md"])
+                .set_directory(ui::markdown_editor::FILES_DIR)
+                .set_file_name("Untitled.md")
+                .save_file();
+            save = save_as.is_some();
+        }
+        if save {
+            let saved = self
+                .pane_content
+                .save_editor(&tab_id, &mut self.markdown_editor, save_as);
+            if let Err(e) = saved {
+                self.status
+                    .error(&format!("Could not save {}: {}", title, e));
+                self.close_prompt = None;
+                return;
+            }
+        }
+        if save || discard {
+            self.tab_manager.close_tab(&tab_id);
+        }
+        if save || discard || cancel {
+            self.close_prompt = None;
         }
     }
 
@@ -122,8 +740,8 @@ impl StudyTimerApp {
 
         if self.keyboard_handler.close_tab_requested {
             let active_tab_id = self.tab_manager.active_tab_id.clone();
-            if !self.tab_manager.close_tab(&active_tab_id) {
-                self.status.show("Cannot close this tab");
+            if !self.request_close_tab(&active_tab_id) {
+                self.status.warn("Cannot close this tab");
             }
         }
 
@@ -180,6 +798,51 @@ impl StudyTimerApp {
                 self.status.show("No previous tab to switch to");
             }
         }
+
+        if self.keyboard_handler.undo_delete_requested {
+            match self.study_data.undo_last_delete() {
+                Ok(0) => self.status.show("Nothing to undo"),
+                Ok(count) => self.status.show(&format!("Restored {} item(s)", count)),
+                Err(e) => self.status.error(&format!("Could not undo delete: {}", e)),
+            }
+            self.last_trash_batch = self.study_data.last_trash_batch();
+            self.tab_badges.refresh(&self.study_data);
+        }
+
+        if self.keyboard_handler.zen_mode_requested {
+            self.toggle_zen_mode();
+        }
+
+        if self.keyboard_handler.replace_in_notes_requested {
+            ui::project_replace::open();
+        }
+    }
+
+    // Moves decks and cards deleted in the flashcard tab to the trash and offers an undo
+    // for anything deleted this frame
+    fn track_deletions(&mut self) {
+        let trashed = std::mem::take(&mut self.deck_manager_ui.trashed);
+        if !trashed.is_empty() {
+            self.study_data.move_to_trash(trashed);
+            if let Err(e) = self.study_data.save() {
+                self.status.error(&format!("Error saving: {}", e));
+            }
+        }
+
+        let batch = self.study_data.last_trash_batch();
+        if batch > self.last_trash_batch {
+            let count = self
+                .study_data
+                .trash
+                .iter()
+                .filter(|e| e.batch == batch)
+                .count();
+            self.status.show(&format!(
+                "Moved {} item(s) to the trash. Press Ctrl+Z to undo",
+                count
+            ));
+        }
+        self.last_trash_batch = batch;
     }
 
     fn render_tab_bar(&mut self, ui: &mut egui::Ui) {
@@ -240,13 +903,8 @@ impl StudyTimerApp {
                                             ui.add_space(4.0);
 
                                             // Allocate space for the new tab button with exact same dimensions as tabs
-                                            let tab_width = 90.0;
-                                            let tab_height = 50.0;
-                                            let button_rect = ui
-                                                .allocate_space(egui::Vec2::new(
-                                                    tab_width, tab_height,
-                                                ))
-                                                .1;
+                                            let metrics = self.settings.ui_density.tab_metrics();
+                                            let button_rect = ui.allocate_space(metrics.size).1;
 
                                             // Draw button background with rounded corners (same as tabs)
                                             ui.painter().rect_filled(
@@ -263,26 +921,26 @@ impl StudyTimerApp {
                                             );
 
                                             // Draw "+" icon at the top center
-                                            let icon_y = button_rect.min.y + 12.0;
+                                            let icon_y = button_rect.min.y + metrics.icon_inset;
                                             ui.painter().text(
                                                 egui::Pos2::new(button_rect.center().x, icon_y),
                                                 egui::Align2::CENTER_TOP,
                                                 "+",
                                                 egui::FontId::new(
-                                                    16.0,
+                                                    metrics.icon_size,
                                                     egui::FontFamily::Proportional,
                                                 ),
                                                 colors.text_primary_color32(),
                                             );
 
                                             // Draw "New" text at the bottom center
-                                            let text_y = button_rect.max.y - 8.0;
+                                            let text_y = button_rect.max.y - metrics.label_inset;
                                             ui.painter().text(
                                                 egui::Pos2::new(button_rect.center().x, text_y),
                                                 egui::Align2::CENTER_BOTTOM,
                                                 "New",
                                                 egui::FontId::new(
-                                                    10.0,
+                                                    metrics.label_size,
                                                     egui::FontFamily::Proportional,
                                                 ),
                                                 colors.text_primary_color32(),
@@ -294,6 +952,13 @@ impl StudyTimerApp {
                                                 egui::Id::new("new_tab_button_interact"),
                                                 egui::Sense::click(),
                                             );
+                                            button_response.widget_info(|| {
+                                                egui::WidgetInfo::labeled(
+                                                    egui::WidgetType::Button,
+                                                    "New tab",
+                                                )
+                                            });
+                                            paint_focus_ring(ui, button_rect, &button_response);
 
                                             if button_response.clicked() {
                                                 self.tab_selector.show();
@@ -325,6 +990,39 @@ impl StudyTimerApp {
                                     self.render_weather_widget_compact(ui);
                                 });
 
+                                ui.push_id("world_clock_section", |ui| {
+                                    ui::world_clock::render(ui, &self.settings.world_clocks);
+                                });
+
+                                if self.settings.agenda_widget {
+                                    let clicked = ui
+                                        .push_id("agenda_widget_section", |ui| {
+                                            ui::agenda_widget::render(ui, &self.study_data)
+                                        })
+                                        .inner;
+                                    if let Some(tab) = clicked {
+                                        self.open_tab(tab);
+                                    }
+                                }
+
+                                let trash_count = self.study_data.trash.len();
+                                if ui
+                                    .button("🗑")
+                                    .on_hover_text(format!("Trash ({} items)", trash_count))
+                                    .clicked()
+                                {
+                                    self.show_trash = true;
+                                }
+
+                                let archive_count = self.study_data.archive.len();
+                                if ui
+                                    .button("📦")
+                                    .on_hover_text(format!("Archive ({} items)", archive_count))
+                                    .clicked()
+                                {
+                                    self.show_archive = true;
+                                }
+
                                 // Split controls after weather (only if not in split mode)
                                 if !self.tab_manager.is_split_active() {
                                     ui.push_id("split_controls_section", |ui| {
@@ -383,8 +1081,7 @@ impl StudyTimerApp {
         // Create a vertical layout for the tab content with unique ID
         ui.push_id(format!("enhanced_tab_{}", tab.id), |ui| {
             // Wider tab dimensions with spacing
-            let tab_width = 90.0;
-            let tab_height = 50.0;
+            let metrics = self.settings.ui_density.tab_metrics();
 
             let (button_color, text_color, stroke_width) = if is_active {
                 (
@@ -401,14 +1098,23 @@ impl StudyTimerApp {
             };
 
             let tab_icon = match tab.tab_type {
+                Tab::Home => "🏠",
                 Tab::Timer => "⏱",
                 Tab::Stats => "📊",
                 Tab::Record => "📝",
                 Tab::Graph => "📈",
                 Tab::Todo => "✅",
+                Tab::Planner => "🗂",
                 Tab::Calculator => "=",
                 Tab::Markdown => "📄",
                 Tab::Reminder => "🔔",
+                Tab::Journal => "📔",
+                Tab::Assignments => "🎓",
+                Tab::ImageViewer => "🖼",
+                Tab::EpubReader => "📖",
+                Tab::Whiteboard => "🎨",
+                Tab::Recorder => "🎙",
+                Tab::Video => "🎬",
                 Tab::Terminal => "💻",
                 Tab::Flashcards => "🃏",
                 Tab::Settings => "⚙",
@@ -422,7 +1128,7 @@ impl StudyTimerApp {
             };
 
             // Allocate space for the entire tab
-            let tab_rect = ui.allocate_space(egui::Vec2::new(tab_width, tab_height)).1;
+            let tab_rect = ui.allocate_space(metrics.size).1;
 
             // Draw tab background with rounded corners
             ui.painter()
@@ -436,26 +1142,66 @@ impl StudyTimerApp {
             );
 
             // Draw icon at the top center (moved down to be more visible)
-            let icon_y = tab_rect.min.y + 12.0;
+            let icon_y = tab_rect.min.y + metrics.icon_inset;
             ui.painter().text(
                 egui::Pos2::new(tab_rect.center().x, icon_y),
                 egui::Align2::CENTER_TOP,
                 tab_icon,
-                egui::FontId::new(16.0, egui::FontFamily::Proportional),
+                egui::FontId::new(metrics.icon_size, egui::FontFamily::Proportional),
                 text_color,
             );
 
             // Draw text at the bottom center (moved up to be more visible)
-            let text_y = tab_rect.max.y - 8.0;
+            let text_y = tab_rect.max.y - metrics.label_inset;
             ui.painter().text(
                 egui::Pos2::new(tab_rect.center().x, text_y),
                 egui::Align2::CENTER_BOTTOM,
                 display_name,
-                egui::FontId::new(10.0, egui::FontFamily::Proportional),
+                egui::FontId::new(metrics.label_size, egui::FontFamily::Proportional),
                 text_color,
             );
 
-            // Handle close button first (if it exists)
+            self.tab_badges
+                .paint(ui.painter(), tab_rect, &tab.tab_type, &colors);
+
+            // Handle main tab area (excluding close button area)
+            let main_tab_rect = if tab.can_close {
+                // Exclude the close button area from the clickable tab area
+                egui::Rect::from_min_max(
+                    tab_rect.min,
+                    egui::Pos2::new(tab_rect.max.x - 20.0, tab_rect.max.y),
+                )
+            } else {
+                tab_rect
+            };
+
+            let tab_response = ui.interact(
+                main_tab_rect,
+                egui::Id::new(format!("tab_main_{}", tab.id)),
+                egui::Sense::click_and_drag(),
+            );
+
+            // Painted tabs have no text of their own, so describe them for screen readers.
+            // The tab is registered before its close button to keep keyboard focus in
+            // reading order
+            let badge_count = self.tab_badges.count(&tab.tab_type);
+            let mut accessible_name = format!("{} tab", tab.title);
+            if tab.is_modified {
+                accessible_name.push_str(", unsaved changes");
+            }
+            if badge_count > 0 {
+                accessible_name.push_str(&format!(", {} pending", badge_count));
+            }
+            tab_response.widget_info(|| {
+                egui::WidgetInfo::selected(
+                    egui::WidgetType::SelectableLabel,
+                    is_active,
+                    accessible_name.clone(),
+                )
+            });
+            paint_focus_ring(ui, tab_rect, &tab_response);
+
+            // Handle close button (if it exists)
             let close_button_clicked = if tab.can_close {
                 let close_rect = egui::Rect::from_min_size(
                     egui::Pos2::new(tab_rect.max.x - 18.0, tab_rect.min.y + 8.0),
@@ -467,6 +1213,13 @@ impl StudyTimerApp {
                     egui::Id::new(format!("close_btn_{}", tab.id)),
                     egui::Sense::click(),
                 );
+                close_response.widget_info(|| {
+                    egui::WidgetInfo::labeled(
+                        egui::WidgetType::Button,
+                        format!("Close {}", tab.title),
+                    )
+                });
+                paint_focus_ring(ui, close_rect, &close_response);
 
                 // Draw close button "×" without any hover effects
                 ui.painter().text(
@@ -486,26 +1239,9 @@ impl StudyTimerApp {
                 false
             };
 
-            // Handle main tab area (excluding close button area)
-            let main_tab_rect = if tab.can_close {
-                // Exclude the close button area from the clickable tab area
-                egui::Rect::from_min_max(
-                    tab_rect.min,
-                    egui::Pos2::new(tab_rect.max.x - 20.0, tab_rect.max.y),
-                )
-            } else {
-                tab_rect
-            };
-
-            let tab_response = ui.interact(
-                main_tab_rect,
-                egui::Id::new(format!("tab_main_{}", tab.id)),
-                egui::Sense::click_and_drag(),
-            );
-
             // Process close button click
             if close_button_clicked {
-                self.tab_manager.close_tab(&tab.id);
+                self.request_close_tab(&tab.id);
             }
             // Process tab click (only if close button wasn't clicked)
             else if tab_response.clicked() {
@@ -609,9 +1345,8 @@ impl StudyTimerApp {
 
             ui.push_id("settings_button", |ui| {
                 // Use same dimensions as regular tabs
-                let tab_width = 90.0;
-                let tab_height = 50.0;
-                let button_rect = ui.allocate_space(egui::Vec2::new(tab_width, tab_height)).1;
+                let metrics = self.settings.ui_density.tab_metrics();
+                let button_rect = ui.allocate_space(metrics.size).1;
 
                 // Draw button background with rounded corners (same as tabs)
                 ui.painter()
@@ -625,22 +1360,22 @@ impl StudyTimerApp {
                 );
 
                 // Draw settings icon at the top center
-                let icon_y = button_rect.min.y + 12.0;
+                let icon_y = button_rect.min.y + metrics.icon_inset;
                 ui.painter().text(
                     egui::Pos2::new(button_rect.center().x, icon_y),
                     egui::Align2::CENTER_TOP,
                     "⚙", // Use gear symbol instead of emoji
-                    egui::FontId::new(16.0, egui::FontFamily::Proportional),
+                    egui::FontId::new(metrics.icon_size, egui::FontFamily::Proportional),
                     text_color,
                 );
 
                 // Draw "Settings" text at the bottom center
-                let text_y = button_rect.max.y - 8.0;
+                let text_y = button_rect.max.y - metrics.label_inset;
                 ui.painter().text(
                     egui::Pos2::new(button_rect.center().x, text_y),
                     egui::Align2::CENTER_BOTTOM,
                     "Settings",
-                    egui::FontId::new(10.0, egui::FontFamily::Proportional),
+                    egui::FontId::new(metrics.label_size, egui::FontFamily::Proportional),
                     text_color,
                 );
 
@@ -650,6 +1385,14 @@ impl StudyTimerApp {
                     egui::Id::new("settings_button_interact"),
                     egui::Sense::click(),
                 );
+                button_response.widget_info(|| {
+                    egui::WidgetInfo::selected(
+                        egui::WidgetType::SelectableLabel,
+                        is_active,
+                        "Settings tab",
+                    )
+                });
+                paint_focus_ring(ui, button_rect, &button_response);
 
                 if button_response.clicked() {
                     if self.tab_manager.is_split_active() {
@@ -691,8 +1434,14 @@ impl StudyTimerApp {
                         .inner_margin(egui::Margin::symmetric(12.0, 8.0))
                         .rounding(egui::Rounding::same(8.0));
 
+                    let nav_layout = if self.settings.rtl_layout {
+                        egui::Layout::right_to_left(egui::Align::Center)
+                    } else {
+                        egui::Layout::left_to_right(egui::Align::Center)
+                    };
+
                     nav_frame.show(ui, |ui| {
-                        ui.horizontal(|ui| {
+                        ui.with_layout(nav_layout, |ui| {
                             for (i, config) in enabled_tabs.iter().enumerate() {
                                 let is_current = self.current_tab == config.tab_type;
                                 let display_name = config.get_display_name();
@@ -721,7 +1470,14 @@ impl StudyTimerApp {
                                 .rounding(egui::Rounding::same(8.0))
                                 .min_size(egui::Vec2::new(80.0, 36.0));
 
-                                if ui.add(button).clicked() {
+                                let response = ui.add(button);
+                                self.tab_badges.paint(
+                                    ui.painter(),
+                                    response.rect,
+                                    &config.tab_type,
+                                    &colors,
+                                );
+                                if response.clicked() {
                                     self.current_tab = config.tab_type.clone();
                                 }
 
@@ -735,7 +1491,13 @@ impl StudyTimerApp {
                 }
                 NavigationLayout::Vertical => {
                     // Enhanced vertical navigation
-                    egui::SidePanel::left("navigation_panel")
+                    // Right-to-left layouts keep navigation on the right edge
+                    let side_panel = if self.settings.rtl_layout {
+                        egui::SidePanel::right("navigation_panel")
+                    } else {
+                        egui::SidePanel::left("navigation_panel")
+                    };
+                    side_panel
                         .resizable(true)
                         .default_width(160.0)
                         .width_range(140.0..=280.0)
@@ -781,14 +1543,23 @@ impl StudyTimerApp {
 
                                     // Tab icon
                                     let tab_icon = match config.tab_type {
+                                        Tab::Home => "🏠",
                                         Tab::Timer => "⏱️",
                                         Tab::Stats => "📊",
                                         Tab::Record => "📝",
                                         Tab::Graph => "📈",
                                         Tab::Todo => "✅",
+                                        Tab::Planner => "🗂",
                                         Tab::Calculator => "🧮",
                                         Tab::Markdown => "📄",
                                         Tab::Reminder => "🔔",
+                                        Tab::Journal => "📔",
+                                        Tab::Assignments => "🎓",
+                                        Tab::ImageViewer => "🖼",
+                                        Tab::EpubReader => "📖",
+                                        Tab::Whiteboard => "🎨",
+                                        Tab::Recorder => "🎙",
+                                        Tab::Video => "🎬",
                                         Tab::Terminal => "💻",
                                         Tab::Flashcards => "🃏",
                                         Tab::Settings => "⚙️",
@@ -809,8 +1580,15 @@ impl StudyTimerApp {
                                     ))
                                     .rounding(egui::Rounding::same(8.0));
 
-                                    if ui.add_sized([ui.available_width(), 36.0], button).clicked()
-                                    {
+                                    let response =
+                                        ui.add_sized([ui.available_width(), 36.0], button);
+                                    self.tab_badges.paint(
+                                        ui.painter(),
+                                        response.rect,
+                                        &config.tab_type,
+                                        &colors,
+                                    );
+                                    if response.clicked() {
                                         self.current_tab = config.tab_type.clone();
                                     }
                                     ui.add_space(4.0);
@@ -824,6 +1602,7 @@ impl StudyTimerApp {
 
     pub fn save_on_exit(&mut self) {
         self.tab_manager.save_state();
+        self.discord_presence.disconnect();
     }
 
     fn render_main_content(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
@@ -853,27 +1632,111 @@ impl StudyTimerApp {
     }
 
     fn render_tab_content(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, tab_type: &Tab) {
+        let started = Instant::now();
         match tab_type {
+            Tab::Home => ui::home_tab::display(ui, self),
             Tab::Timer => ui::timer_tab::display(
                 ui,
                 &mut self.timer,
                 &mut self.study_data,
                 &mut self.debug_tools,
                 &mut self.status,
+                &self.settings,
+            ),
+            Tab::Stats => ui::stats_tab::display(
+                ui,
+                &mut self.study_data,
+                &mut self.status,
+                &mut self.time_export,
+                &self.settings,
             ),
-            Tab::Stats => ui::stats_tab::display(ui, &mut self.study_data, &mut self.status),
             Tab::Record => {
                 ui::record_tab::display(ui, &mut self.study_data, &mut self.status, &self.timer)
             }
-            Tab::Flashcards => ui::flashcard_tab_ui::display(ui, ctx, self),
-            Tab::Graph => ui::graph_tab::display(ui, &self.study_data, &mut self.status),
-            Tab::Todo => {
-                ui::todo_tab::display(ui, &mut self.study_data, &mut self.status, &self.settings)
+            Tab::Flashcards => {
+                let active_id = self.tab_manager.active_tab_id.clone();
+                self.show_flashcards_tab(ui, ctx, &active_id);
+            }
+            Tab::Graph => {
+                ui::graph_tab::display(ui, &self.study_data, &mut self.status, &self.settings)
+            }
+            Tab::Todo => ui::todo_tab::display(
+                ui,
+                &mut self.study_data,
+                &mut self.status,
+                &self.settings,
+                &mut self.task_sync,
+            ),
+            Tab::Planner => {
+                let timer_started = ui::planner_tab::display(
+                    ui,
+                    &mut self.study_data,
+                    &mut self.status,
+                    &self.settings,
+                    &mut self.timer,
+                );
+                if timer_started {
+                    self.open_tab(Tab::Timer);
+                }
+            }
+            Tab::Reminder => ui::reminder_tab::display(
+                ui,
+                &mut self.study_data,
+                &mut self.status,
+                &self.settings,
+            ),
+            Tab::Journal => ui::journal_tab::display(ui, &mut self.study_data, &mut self.status),
+            Tab::Assignments => {
+                ui::assignments_tab::display(ui, &mut self.study_data, &mut self.status)
+            }
+            Tab::ImageViewer => {
+                let active_id = self.tab_manager.active_tab_id.clone();
+                ui::image_viewer_tab::display(
+                    ui,
+                    self.tab_manager.get_tab_mut(&active_id),
+                    &mut self.study_data,
+                    &mut self.status,
+                )
+            }
+            Tab::EpubReader => {
+                let active_id = self.tab_manager.active_tab_id.clone();
+                ui::epub_reader_tab::display(
+                    ui,
+                    self.tab_manager.get_tab_mut(&active_id),
+                    &mut self.study_data,
+                    &mut self.status,
+                )
+            }
+            Tab::Whiteboard => ui::whiteboard_tab::display(
+                ui,
+                &mut self.study_data,
+                &mut self.status,
+                self.markdown_editor.as_mut(),
+            ),
+            Tab::Recorder => ui::recorder_tab::display(ui, &mut self.status),
+            Tab::Video => {
+                let active_id = self.tab_manager.active_tab_id.clone();
+                let note = ui::video_tab::display(
+                    ui,
+                    self.tab_manager.get_tab_mut(&active_id),
+                    &mut self.status,
+                    self.markdown_editor.as_mut(),
+                );
+                if let Some(path) = note {
+                    self.open_note(&path);
+                }
             }
-            Tab::Reminder => ui::reminder_tab::display(ui, &mut self.study_data, &mut self.status),
             Tab::Calculator => ui::calculator_tab::display(ui, &mut self.status),
-            Tab::Markdown => ui::markdown_tab_ui::display(ui, self, ctx),
-            Tab::Terminal => ui::terminal_tab_ui::display(ui, &mut self.terminal, &mut self.status),
+            Tab::Markdown => {
+                let active_id = self.tab_manager.active_tab_id.clone();
+                self.show_markdown_tab(ui, ctx, &active_id);
+            }
+            Tab::Terminal => ui::terminal_tab_ui::display(
+                ui,
+                &mut self.terminal,
+                &mut self.status,
+                self.settings.editor_font_size,
+            ),
             Tab::Settings => ui::settings_tab_ui::display(
                 ui,
                 &mut self.settings,
@@ -881,18 +1744,73 @@ impl StudyTimerApp {
                 &mut self.current_tab,
             ),
         }
+        self.debug_tools.record_tab(tab_type, started.elapsed());
+    }
+}
+
+// Outline keyboard focus on the hand-painted tab buttons, which egui does not draw for us
+fn paint_focus_ring(ui: &egui::Ui, rect: egui::Rect, response: &egui::Response) {
+    if response.has_focus() {
+        ui.painter().rect_stroke(
+            rect.expand(2.0),
+            egui::Rounding::same(7.0),
+            ui.visuals().selection.stroke,
+        );
     }
 }
 
 impl eframe::App for StudyTimerApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let frame_started = Instant::now();
         self.settings.apply_theme(ctx);
 
         self.keyboard_handler.handle_input(ctx);
         self.handle_keyboard_shortcuts();
+        ui::snippets::install(&self.settings.snippets);
 
         // Update weather widget
-        self.weather_widget.update();
+        self.weather_widget.update(self.settings.battery_saver);
+
+        let tab_manager = &self.tab_manager;
+        self.pane_content.prune(
+            |id| tab_manager.get_tab(id).is_some(),
+            &mut self.markdown_editor,
+            &mut self.flashcard_reviewer,
+            &mut self.deck_manager_ui,
+        );
+        if let Some(tab_id) = &self.zen_tab_id {
+            if self.tab_manager.get_tab(tab_id).is_none() {
+                self.zen_tab_id = None;
+            }
+        }
+
+        ui::timer_tab::update_intervals(
+            ctx,
+            &mut self.timer,
+            &mut self.study_data,
+            &mut self.status,
+            &self.settings,
+        );
+
+        self.timer_recovery.update(
+            &self.timer,
+            &ui::timer_tab::current_description(),
+            ui::timer_tab::edited_notes(),
+            ui::timer_tab::laps(),
+        );
+        ui::timer_recovery_ui::display(
+            ctx,
+            &mut self.timer_recovery,
+            &mut self.timer,
+            &mut self.study_data,
+            &mut self.status,
+        );
+
+        self.discord_presence.update(
+            &self.settings,
+            &self.timer,
+            &ui::timer_tab::current_description(),
+        );
 
         let dropped_files = self
             .file_drop_handler
@@ -904,11 +1822,14 @@ impl eframe::App for StudyTimerApp {
             }
         }
 
-        if let Some(selected_tab) = self
+        if let Some((selected_tab, file)) = self
             .tab_selector
             .display(ctx, &self.settings, &mut self.status)
         {
-            let new_tab_id = self.tab_manager.add_tab(selected_tab);
+            let new_tab_id = match file {
+                Some(file) => self.tab_manager.add_file_tab(selected_tab, file),
+                None => self.tab_manager.add_tab(selected_tab),
+            };
 
             if self.tab_manager.is_split_active() {
                 self.tab_manager
@@ -916,10 +1837,28 @@ impl eframe::App for StudyTimerApp {
             }
         }
 
+        self.render_close_prompt(ctx);
+
+        let jump_to = self
+            .startup_summary
+            .as_mut()
+            .and_then(|summary| summary.display(ctx));
+        if let Some(tab_type) = jump_to {
+            self.open_tab(tab_type);
+        }
+
         if self.timer.is_running {
-            ctx.request_repaint();
+            self.settings.request_clock_repaint(ctx);
         }
 
+        self.tab_badges.update(&self.study_data);
+        self.reminder_checker
+            .update(&mut self.study_data, &self.settings, &mut self.status);
+        self.break_reminder
+            .update(ctx, &self.settings, &mut self.status);
+        // Wake up for the next badge refresh even when idle
+        ctx.request_repaint_after(std::time::Duration::from_secs(30));
+
         let colors = self.settings.get_current_colors();
 
         let main_frame = egui::Frame::default()
@@ -929,6 +1868,15 @@ impl eframe::App for StudyTimerApp {
         egui::CentralPanel::default()
             .frame(main_frame)
             .show(ctx, |ui| {
+                if let Some(tab_id) = self.zen_tab_id.clone() {
+                    self.render_zen(ui, &tab_id);
+                    return;
+                }
+                if self.flashcard_reviewer.is_fullscreen {
+                    ui::flashcard_tab_ui::display_fullscreen(ui, self);
+                    return;
+                }
+
                 if !self.tab_manager.tabs.is_empty() {
                     self.render_tab_bar(ui);
                     ui.separator();
@@ -944,9 +1892,114 @@ impl eframe::App for StudyTimerApp {
 
                 self.render_main_content(ui, ctx);
             });
+
+        if let Some(message) = self.task_sync.update(&mut self.study_data) {
+            self.status.show(&message);
+        }
+        if let Some(message) = self.deck_sync.update(&mut self.study_data) {
+            self.status.show(&message);
+        }
+        if let Some(message) = self.time_export.update(&self.study_data.sessions) {
+            self.status.show(&message);
+        }
+        if self.task_sync.is_syncing()
+            || self.deck_sync.is_syncing()
+            || self.time_export.is_pushing()
+        {
+            // Keep polling the sync workers while they run
+            ctx.request_repaint_after(std::time::Duration::from_millis(250));
+        }
+
+        if let Some(message) = self.lan_share.update() {
+            self.status.show(&message);
+        }
+        if self.lan_share.is_running() {
+            // Peers and offers arrive from other threads
+            ctx.request_repaint_after(std::time::Duration::from_millis(500));
+        }
+        ui::lan_share_ui::incoming_prompt(
+            ctx,
+            &mut self.lan_share,
+            &mut self.study_data,
+            &mut self.status,
+        );
+        if self.show_lan_share {
+            ui::lan_share_ui::display(
+                ctx,
+                &mut self.lan_share,
+                &self.study_data,
+                &mut self.settings,
+                &mut self.show_lan_share,
+                &mut self.status,
+            );
+        }
+
+        ui::card_suggestions::display(ctx, &mut self.study_data, &mut self.status);
+        ui::references_ui::display(
+            ctx,
+            &mut self.study_data,
+            &mut self.status,
+            self.markdown_editor.as_mut(),
+        );
+        if ui::project_replace::is_open() {
+            let mut editors: Vec<_> = self
+                .markdown_editor
+                .iter_mut()
+                .chain(self.pane_content.editors_mut())
+                .collect();
+            ui::project_replace::display(ctx, &mut self.status, &mut editors);
+        }
+
+        if self.show_deck_sync {
+            ui::deck_sync_ui::display(
+                ctx,
+                &mut self.deck_sync,
+                &mut self.study_data,
+                &mut self.show_deck_sync,
+                &mut self.status,
+            );
+        }
+
+        if self.show_trash {
+            ui::trash_ui::display(
+                ctx,
+                &mut self.study_data,
+                &mut self.show_trash,
+                &mut self.status,
+            );
+        }
+        if self.show_archive {
+            ui::archive_ui::display(
+                ctx,
+                &mut self.study_data,
+                &mut self.show_archive,
+                &mut self.status,
+            );
+        }
+        self.track_deletions();
+
+        if self.show_profile_picker {
+            ui::profile_ui::picker(ctx, &mut self.show_profile_picker, &mut self.status);
+        }
+
+        // Run hooks for anything that happened this frame
+        self.check_daily_goal();
+        for error in hooks::dispatch_pending(&self.settings.hooks) {
+            self.status.error(&error);
+        }
+        for reported in error::drain_reported() {
+            self.status
+                .error_with_details(&reported.summary, &reported.details);
+        }
+
+        if self.zen_tab_id.is_none() && !self.flashcard_reviewer.is_fullscreen {
+            self.status.render(ctx, &colors);
+        }
+        self.sync_window_fullscreen(ctx);
+        self.paint_night_light(ctx);
+        self.debug_tools.end_frame(ctx, frame_started.elapsed());
     }
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
         self.save_on_exit();
     }
 }
-
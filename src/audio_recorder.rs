@@ -0,0 +1,341 @@
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use rodio::{OutputStream, OutputStreamHandle, Sink, Source};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub const RECORDINGS_DIR: &str = "files/recordings";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Marker {
+    pub seconds: f64,
+    #[serde(default)]
+    pub label: String,
+}
+
+// State the audio callback shares with the UI thread
+#[derive(Default)]
+struct Shared {
+    paused: AtomicBool,
+    frames: AtomicU64, // Frames written, so pauses don't count towards the length
+    level: AtomicU32,  // Peak of the last buffer, as f32 bits
+    error: Mutex<Option<String>>,
+}
+
+type WavWriter = Arc<Mutex<Option<hound::WavWriter<BufWriter<File>>>>>;
+
+pub struct Recorder {
+    stream: cpal::Stream,
+    writer: WavWriter,
+    shared: Arc<Shared>,
+    sample_rate: u32,
+    pub path: PathBuf,
+}
+
+impl Recorder {
+    pub fn start(path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
+        let device = cpal::default_host()
+            .default_input_device()
+            .ok_or("No microphone found")?;
+        let supported = device.default_input_config()?;
+        let sample_format = supported.sample_format();
+        let config: cpal::StreamConfig = supported.into();
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let spec = hound::WavSpec {
+            channels: config.channels,
+            sample_rate: config.sample_rate.0,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let writer = Arc::new(Mutex::new(Some(hound::WavWriter::create(&path, spec)?)));
+        let shared = Arc::new(Shared::default());
+
+        let stream = match sample_format {
+            cpal::SampleFormat::I16 => {
+                input_stream::<i16>(&device, &config, writer.clone(), shared.clone())
+            }
+            cpal::SampleFormat::U16 => {
+                input_stream::<u16>(&device, &config, writer.clone(), shared.clone())
+            }
+            cpal::SampleFormat::F32 => {
+                input_stream::<f32>(&device, &config, writer.clone(), shared.clone())
+            }
+        }?;
+        stream.play()?;
+
+        Ok(Self {
+            stream,
+            writer,
+            shared,
+            sample_rate: config.sample_rate.0,
+            path,
+        })
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        self.shared.paused.store(paused, Ordering::Relaxed);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.shared.paused.load(Ordering::Relaxed)
+    }
+
+    // Length of the recording so far
+    pub fn elapsed(&self) -> Duration {
+        let frames = self.shared.frames.load(Ordering::Relaxed);
+        Duration::from_secs_f64(frames as f64 / self.sample_rate as f64)
+    }
+
+    pub fn level(&self) -> f32 {
+        f32::from_bits(self.shared.level.load(Ordering::Relaxed))
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.shared.error.lock().ok().and_then(|e| e.clone())
+    }
+
+    pub fn stop(self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        drop(self.stream);
+        let writer = self
+            .writer
+            .lock()
+            .map_err(|_| "Recorder lock poisoned")?
+            .take();
+        if let Some(writer) = writer {
+            writer.finalize()?;
+        }
+        Ok(self.path)
+    }
+}
+
+fn input_stream<T: cpal::Sample>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    writer: WavWriter,
+    shared: Arc<Shared>,
+) -> Result<cpal::Stream, cpal::BuildStreamError> {
+    let channels = config.channels.max(1) as u64;
+    let errors = shared.clone();
+    device.build_input_stream(
+        config,
+        move |data: &[T], _: &cpal::InputCallbackInfo| {
+            if shared.paused.load(Ordering::Relaxed) {
+                shared.level.store(0, Ordering::Relaxed);
+                return;
+            }
+            let mut peak = 0.0f32;
+            if let Ok(mut guard) = writer.lock() {
+                if let Some(writer) = guard.as_mut() {
+                    for sample in data {
+                        peak = peak.max(sample.to_f32().abs());
+                        if let Err(e) = writer.write_sample(sample.to_i16()) {
+                            if let Ok(mut error) = shared.error.lock() {
+                                *error = Some(e.to_string());
+                            }
+                            return;
+                        }
+                    }
+                }
+            }
+            shared
+                .frames
+                .fetch_add(data.len() as u64 / channels, Ordering::Relaxed);
+            shared.level.store(peak.to_bits(), Ordering::Relaxed);
+        },
+        move |e| {
+            if let Ok(mut error) = errors.error.lock() {
+                *error = Some(e.to_string());
+            }
+        },
+    )
+}
+
+// Recordings, newest first
+pub fn list_recordings() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(RECORDINGS_DIR)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| {
+                    path.extension()
+                        .map_or(false, |ext| ext.eq_ignore_ascii_case("wav"))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort_by_key(|path| std::cmp::Reverse(fs::metadata(path).and_then(|m| m.modified()).ok()));
+    files
+}
+
+// Markers live next to the recording, e.g. lecture.wav and lecture.markers.json
+fn markers_path(recording: &Path) -> PathBuf {
+    recording.with_extension("markers.json")
+}
+
+pub fn load_markers(recording: &Path) -> Vec<Marker> {
+    fs::read_to_string(markers_path(recording))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_markers(
+    recording: &Path,
+    markers: &[Marker],
+) -> Result<(), Box<dyn std::error::Error>> {
+    fs::write(
+        markers_path(recording),
+        serde_json::to_string_pretty(markers)?,
+    )?;
+    Ok(())
+}
+
+pub fn format_time(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    if total >= 3600 {
+        format!("{}:{:02}:{:02}", total / 3600, total / 60 % 60, total % 60)
+    } else {
+        format!("{:02}:{:02}", total / 60, total % 60)
+    }
+}
+
+// Feeds a WAV file to rodio; hound can seek, so jumps don't decode the skipped part
+struct WavSource {
+    samples: hound::WavIntoSamples<BufReader<File>, i16>,
+    channels: u16,
+    sample_rate: u32,
+}
+
+impl Iterator for WavSource {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        self.samples.next().and_then(|sample| sample.ok())
+    }
+}
+
+impl Source for WavSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+pub struct Player {
+    _stream: OutputStream, // Playback stops when this is dropped
+    handle: OutputStreamHandle,
+    sink: Option<Sink>,
+    pub path: PathBuf,
+    pub length: Duration,
+    offset: Duration,         // Where the current sink started
+    started: Option<Instant>, // None while paused or stopped
+}
+
+impl Player {
+    pub fn open(path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
+        let reader = hound::WavReader::open(&path)?;
+        let spec = reader.spec();
+        let length = Duration::from_secs_f64(reader.duration() as f64 / spec.sample_rate as f64);
+        let (stream, handle) = OutputStream::try_default()?;
+        Ok(Self {
+            _stream: stream,
+            handle,
+            sink: None,
+            path,
+            length,
+            offset: Duration::ZERO,
+            started: None,
+        })
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.started.is_some()
+    }
+
+    pub fn position(&self) -> Duration {
+        let played = self.started.map_or(Duration::ZERO, |start| start.elapsed());
+        (self.offset + played).min(self.length)
+    }
+
+    pub fn play_from(&mut self, position: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        let position = position.min(self.length);
+        let mut reader = hound::WavReader::open(&self.path)?;
+        let spec = reader.spec();
+        reader.seek((position.as_secs_f64() * spec.sample_rate as f64) as u32)?;
+        let sink = Sink::try_new(&self.handle)?;
+        sink.append(WavSource {
+            samples: reader.into_samples(),
+            channels: spec.channels,
+            sample_rate: spec.sample_rate,
+        });
+        // Replacing the sink stops the old one
+        self.sink = Some(sink);
+        self.offset = position;
+        self.started = Some(Instant::now());
+        Ok(())
+    }
+
+    pub fn pause(&mut self) {
+        self.offset = self.position();
+        self.started = None;
+        if let Some(sink) = &self.sink {
+            sink.pause();
+        }
+    }
+
+    pub fn resume(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        match &self.sink {
+            Some(sink) if !sink.empty() => {
+                sink.play();
+                self.started = Some(Instant::now());
+                Ok(())
+            }
+            // Starting over once the end was reached
+            _ if self.offset >= self.length => self.play_from(Duration::ZERO),
+            _ => self.play_from(self.offset),
+        }
+    }
+
+    // Keeps playing from the new spot, or stays paused there
+    pub fn seek(&mut self, position: Duration) -> Result<(), Box<dyn std::error::Error>> {
+        if self.is_playing() {
+            return self.play_from(position);
+        }
+        self.sink = None;
+        self.offset = position.min(self.length);
+        Ok(())
+    }
+
+    pub fn stop(&mut self) {
+        self.sink = None;
+        self.offset = Duration::ZERO;
+        self.started = None;
+    }
+
+    // Call each frame, so the position stops at the end of the file
+    pub fn update(&mut self) {
+        if self.started.is_some() && self.sink.as_ref().map_or(true, |sink| sink.empty()) {
+            self.sink = None;
+            self.offset = self.length;
+            self.started = None;
+        }
+    }
+}
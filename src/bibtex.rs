@@ -0,0 +1,175 @@
+use crate::data::Reference;
+
+// Entries that aren't references
+const SKIPPED_TYPES: [&str; 3] = ["comment", "string", "preamble"];
+
+pub fn parse(text: &str) -> Result<Vec<Reference>, Box<dyn std::error::Error>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut references = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = chars[pos..].iter().position(|&c| c == '@') {
+        pos += offset + 1;
+        let entry_type = read_while(&chars, &mut pos, |c| c.is_alphanumeric() || c == '_');
+        skip_whitespace(&chars, &mut pos);
+        if !matches!(chars.get(pos), Some('{') | Some('(')) {
+            continue;
+        }
+        pos += 1;
+        let entry_type = entry_type.to_lowercase();
+        if SKIPPED_TYPES.contains(&entry_type.as_str()) {
+            continue;
+        }
+
+        skip_whitespace(&chars, &mut pos);
+        let key = read_while(&chars, &mut pos, |c| c != ',' && c != '}' && c != ')');
+        let mut reference = Reference {
+            key: key.trim().to_string(),
+            entry_type,
+            ..Default::default()
+        };
+
+        loop {
+            skip_whitespace(&chars, &mut pos);
+            match chars.get(pos) {
+                Some(',') => {
+                    pos += 1;
+                    continue;
+                }
+                Some('}') | Some(')') => {
+                    pos += 1;
+                    break;
+                }
+                None => return Err(format!("Unterminated entry \"{}\"", reference.key).into()),
+                _ => {}
+            }
+
+            let name = read_while(&chars, &mut pos, |c| c != '=' && c != ',' && c != '}');
+            if chars.get(pos) != Some(&'=') {
+                continue; // Not a field, e.g. a stray word
+            }
+            pos += 1;
+            skip_whitespace(&chars, &mut pos);
+            let value = read_value(&chars, &mut pos)?;
+            set_field(&mut reference, name.trim(), clean(&value));
+        }
+
+        references.push(reference);
+    }
+
+    if references.is_empty() {
+        return Err("No BibTeX entries found".into());
+    }
+    Ok(references)
+}
+
+pub fn export(references: &[Reference]) -> String {
+    let mut out = String::new();
+    for reference in references {
+        let entry_type = if reference.entry_type.is_empty() {
+            "misc"
+        } else {
+            &reference.entry_type
+        };
+        out.push_str(&format!("@{}{{{},\n", entry_type, reference.key));
+        let fields = [
+            ("title", &reference.title),
+            ("author", &reference.authors),
+            ("year", &reference.year),
+            ("doi", &reference.doi),
+            ("url", &reference.url),
+            ("note", &reference.notes),
+        ];
+        for (name, value) in fields
+            .into_iter()
+            .chain(reference.extra.iter().map(|(n, v)| (n.as_str(), v)))
+        {
+            if !value.trim().is_empty() {
+                out.push_str(&format!("  {} = {{{}}},\n", name, value.trim()));
+            }
+        }
+        out.push_str("}\n\n");
+    }
+    out
+}
+
+fn set_field(reference: &mut Reference, name: &str, value: String) {
+    match name.to_lowercase().as_str() {
+        "title" => reference.title = value,
+        "author" => reference.authors = value,
+        "year" => reference.year = value,
+        "doi" => reference.doi = value,
+        "url" => reference.url = value,
+        "note" | "annote" => reference.notes = value,
+        other => reference.extra.push((other.to_string(), value)),
+    }
+}
+
+// A braced or quoted value, or a bare number or macro name
+fn read_value(chars: &[char], pos: &mut usize) -> Result<String, Box<dyn std::error::Error>> {
+    match chars.get(*pos) {
+        Some('{') => {
+            let start = *pos + 1;
+            let mut depth = 0;
+            while let Some(&c) = chars.get(*pos) {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            *pos += 1;
+                            return Ok(chars[start..*pos - 1].iter().collect());
+                        }
+                    }
+                    _ => {}
+                }
+                *pos += 1;
+            }
+            Err("Unbalanced braces in a field value".into())
+        }
+        Some('"') => {
+            *pos += 1;
+            let start = *pos;
+            let mut depth = 0;
+            while let Some(&c) = chars.get(*pos) {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    '"' if depth == 0 && chars.get(*pos - 1) != Some(&'\\') => {
+                        *pos += 1;
+                        return Ok(chars[start..*pos - 1].iter().collect());
+                    }
+                    _ => {}
+                }
+                *pos += 1;
+            }
+            Err("Unterminated quoted field value".into())
+        }
+        _ => Ok(read_while(chars, pos, |c| {
+            c != ',' && c != '}' && c != ')' && !c.is_whitespace()
+        })),
+    }
+}
+
+// Drops the braces BibTeX uses to protect capitals and folds line breaks
+fn clean(value: &str) -> String {
+    value
+        .chars()
+        .filter(|&c| c != '{' && c != '}')
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn read_while(chars: &[char], pos: &mut usize, keep: impl Fn(char) -> bool) -> String {
+    let start = *pos;
+    while chars.get(*pos).is_some_and(|&c| keep(c)) {
+        *pos += 1;
+    }
+    chars[start..*pos].iter().collect()
+}
+
+fn skip_whitespace(chars: &[char], pos: &mut usize) {
+    read_while(chars, pos, char::is_whitespace);
+}
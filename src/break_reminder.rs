@@ -0,0 +1,125 @@
+use crate::app::StatusMessage;
+use crate::settings::AppSettings;
+use eframe::egui;
+use std::time::{Duration, Instant};
+
+// Key of the reminder's prompt toast
+const PROMPT: &str = "break_reminder";
+
+// This long without input or focus counts as having taken a break already
+const IDLE_RESET: Duration = Duration::from_secs(5 * 60);
+
+// The 20-20-20 rule: every 20 minutes, look 20 feet away for 20 seconds
+const EYE_BREAK: Duration = Duration::from_secs(20);
+
+const TAKE_BREAK: usize = 0;
+const SNOOZE: usize = 1;
+
+// Runs from the main update loop and counts continuous use of the window, whatever the timer does
+pub struct BreakReminder {
+    using_since: Option<Instant>,
+    last_activity: Option<Instant>,
+    snoozed_until: Option<Instant>,
+    break_started: Option<Instant>,
+    prompting: bool,
+}
+
+impl BreakReminder {
+    pub fn new() -> Self {
+        Self {
+            using_since: None,
+            last_activity: None,
+            snoozed_until: None,
+            break_started: None,
+            prompting: false,
+        }
+    }
+
+    fn restart(&mut self, now: Instant) {
+        self.using_since = Some(now);
+        self.snoozed_until = None;
+    }
+
+    pub fn update(
+        &mut self,
+        ctx: &egui::Context,
+        settings: &AppSettings,
+        status: &mut StatusMessage,
+    ) {
+        if !settings.break_reminders {
+            self.using_since = None;
+            return;
+        }
+
+        let now = Instant::now();
+        let active = ctx.input(|i| i.raw.focused && !i.raw.events.is_empty());
+        if active {
+            let idle = self
+                .last_activity
+                .is_none_or(|last| now - last >= IDLE_RESET);
+            if idle || self.using_since.is_none() {
+                self.restart(now);
+            }
+            self.last_activity = Some(now);
+        }
+
+        if self.prompting {
+            match status.take_answer(PROMPT) {
+                Some(TAKE_BREAK) => {
+                    self.prompting = false;
+                    self.break_started = Some(now);
+                    status.show("Look at something about 20 feet (6 m) away for 20 seconds");
+                }
+                Some(SNOOZE) => {
+                    self.prompting = false;
+                    self.snoozed_until =
+                        Some(now + Duration::from_secs(settings.break_snooze_minutes as u64 * 60));
+                }
+                // Skipped, or closed with ✖
+                Some(_) => {
+                    self.prompting = false;
+                    self.restart(now);
+                }
+                None if !status.is_prompting(PROMPT) => {
+                    self.prompting = false;
+                    self.restart(now);
+                }
+                None => {}
+            }
+        }
+
+        if let Some(started) = self.break_started {
+            if now - started >= EYE_BREAK {
+                self.break_started = None;
+                self.restart(now);
+                status.show("Break over, your eyes thank you");
+            } else {
+                ctx.request_repaint_after(EYE_BREAK - (now - started));
+            }
+            return;
+        }
+
+        let Some(since) = self.using_since else {
+            return;
+        };
+        let interval = Duration::from_secs(settings.break_reminder_minutes.max(1) as u64 * 60);
+        let snoozed = self.snoozed_until.is_some_and(|until| now < until);
+        if self.prompting || snoozed || now - since < interval {
+            return;
+        }
+
+        self.prompting = true;
+        status.prompt(
+            PROMPT,
+            &format!(
+                "You've been at the screen for {} minutes. Rest your eyes: look about 20 feet (6 m) away for 20 seconds.",
+                (now - since).as_secs() / 60
+            ),
+            &[
+                "👀 Take a break".to_string(),
+                format!("💤 Snooze {} min", settings.break_snooze_minutes),
+                "Skip".to_string(),
+            ],
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use crate::task_sync::curl_config_line;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+// Suggests flashcards for a piece of text using a language model the user points
+// us at. Nothing is sent anywhere unless it is enabled in Settings.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GeneratorBackend {
+    Ollama,
+    OpenAiCompatible,
+    Command, // Any program that reads the prompt on stdin and prints the reply
+}
+
+impl GeneratorBackend {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GeneratorBackend::Ollama => "Ollama (local)",
+            GeneratorBackend::OpenAiCompatible => "OpenAI-compatible API",
+            GeneratorBackend::Command => "Custom command",
+        }
+    }
+
+    pub fn all() -> Vec<GeneratorBackend> {
+        vec![
+            GeneratorBackend::Ollama,
+            GeneratorBackend::OpenAiCompatible,
+            GeneratorBackend::Command,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardGeneratorConfig {
+    pub enabled: bool,
+    pub backend: GeneratorBackend,
+    pub endpoint: String, // Ollama server or API base URL, e.g. https://api.openai.com/v1
+    pub model: String,
+    pub api_key: String,
+    pub command: String,
+    pub max_cards: usize,
+}
+
+impl Default for CardGeneratorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: GeneratorBackend::Ollama,
+            endpoint: "http://localhost:11434".to_string(),
+            model: "llama3".to_string(),
+            api_key: String::new(),
+            command: String::new(),
+            max_cards: 10,
+        }
+    }
+}
+
+impl CardGeneratorConfig {
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut path = crate::profile::config_dir().ok_or("Could not find config directory")?;
+    path.push("card_generator.json");
+    Ok(path)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SuggestedCard {
+    pub front: String,
+    pub back: String,
+}
+
+// Blocks until the model answers, so call it from a background thread
+pub fn suggest_cards(
+    config: &CardGeneratorConfig,
+    text: &str,
+) -> Result<Vec<SuggestedCard>, Box<dyn std::error::Error>> {
+    let prompt = build_prompt(text, config.max_cards);
+    let reply = match config.backend {
+        GeneratorBackend::Ollama => {
+            let body = serde_json::json!({
+                "model": config.model,
+                "prompt": prompt,
+                "stream": false,
+            });
+            let url = format!("{}/api/generate", config.endpoint.trim_end_matches('/'));
+            let response: serde_json::Value = serde_json::from_str(&post_json(&url, &body, None)?)?;
+            response["response"]
+                .as_str()
+                .ok_or("Ollama returned no response")?
+                .to_string()
+        }
+        GeneratorBackend::OpenAiCompatible => {
+            let body = serde_json::json!({
+                "model": config.model,
+                "messages": [{ "role": "user", "content": prompt }],
+            });
+            let url = format!("{}/chat/completions", config.endpoint.trim_end_matches('/'));
+            let response: serde_json::Value =
+                serde_json::from_str(&post_json(&url, &body, Some(&config.api_key))?)?;
+            response["choices"][0]["message"]["content"]
+                .as_str()
+                .ok_or("The API returned no message")?
+                .to_string()
+        }
+        GeneratorBackend::Command => run_command(&config.command, &prompt)?,
+    };
+
+    let cards = parse_suggestions(&reply);
+    if cards.is_empty() {
+        return Err("The model didn't suggest any cards".into());
+    }
+    Ok(cards)
+}
+
+fn build_prompt(text: &str, max_cards: usize) -> String {
+    format!(
+        "Write up to {} flashcards that test the key facts in the study notes below. \
+         Reply with only a JSON array of objects with \"front\" (a question) and \"back\" \
+         (a short answer) fields.\n\nNotes:\n{}",
+        max_cards.max(1),
+        text
+    )
+}
+
+// Models often wrap the JSON in prose or code fences, so look for the array itself and
+// fall back to "Q: ... A: ..." lines
+fn parse_suggestions(reply: &str) -> Vec<SuggestedCard> {
+    if let (Some(start), Some(end)) = (reply.find('['), reply.rfind(']')) {
+        if start < end {
+            if let Ok(cards) = serde_json::from_str::<Vec<SuggestedCard>>(&reply[start..=end]) {
+                return cards
+                    .into_iter()
+                    .filter(|c| !c.front.trim().is_empty() && !c.back.trim().is_empty())
+                    .collect();
+            }
+        }
+    }
+
+    let mut cards = Vec::new();
+    let mut question: Option<String> = None;
+    for line in reply.lines().map(str::trim) {
+        if let Some(q) = line.strip_prefix("Q:") {
+            question = Some(q.trim().to_string());
+        } else if let (Some(a), Some(q)) = (line.strip_prefix("A:"), question.take()) {
+            cards.push(SuggestedCard {
+                front: q,
+                back: a.trim().to_string(),
+            });
+        }
+    }
+    cards
+}
+
+// The body and the key go to curl as a config file on stdin, so long notes don't hit
+// argument length limits and the key never shows up in `ps`
+fn post_json(
+    url: &str,
+    body: &serde_json::Value,
+    api_key: Option<&str>,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut config = curl_config_line("data-binary", &body.to_string());
+    if let Some(key) = api_key.filter(|k| !k.trim().is_empty()) {
+        config.push_str(&curl_config_line(
+            "header",
+            &format!("Authorization: Bearer {}", key.trim()),
+        ));
+    }
+
+    let mut command = Command::new("curl");
+    command
+        .arg("-sS")
+        .arg("-f")
+        .arg("-X")
+        .arg("POST")
+        .arg("-H")
+        .arg("Content-Type: application/json")
+        .arg("-K")
+        .arg("-")
+        .arg(url);
+    pipe(command, &config)
+}
+
+fn run_command(command_line: &str, prompt: &str) -> Result<String, Box<dyn std::error::Error>> {
+    if command_line.trim().is_empty() {
+        return Err("No command is set up for card generation".into());
+    }
+    let mut command = if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.arg("/C");
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.arg("-c");
+        c
+    };
+    command.arg(command_line);
+    pipe(command, prompt)
+}
+
+fn pipe(mut command: Command, input: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("request failed: {}", stderr.trim()).into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
@@ -0,0 +1,153 @@
+use crate::data::StudyData;
+use crate::timer::Timer;
+use chrono::Local;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: study_timer [--profile <name>] [COMMAND]
+
+Run without a command to open the app. --profile picks whose data to use.
+
+Commands:
+  add-todo <text>              Add a todo to the list
+  start-timer [--subject <s>]  Run a study timer in the terminal, press Enter to stop and save
+  stats [--today|--week]       Print study time statistics
+  help                         Show this message";
+
+// Returns None when no subcommand was given and the GUI should start
+pub fn run_from_args(args: &[String]) -> Option<i32> {
+    if args.is_empty() {
+        return None;
+    }
+
+    match execute(args) {
+        Ok(()) => Some(0),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            Some(1)
+        }
+    }
+}
+
+fn execute(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let rest = &args[1..];
+    match args[0].as_str() {
+        "add-todo" => add_todo(rest),
+        "start-timer" => start_timer(rest),
+        "stats" => stats(rest),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        other => Err(format!("Unknown command '{}'\n\n{}", other, USAGE).into()),
+    }
+}
+
+fn add_todo(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let text = args.join(" ").trim().to_string();
+    if text.is_empty() {
+        return Err("add-todo needs the todo text, e.g. study_timer add-todo \"Read chapter 3\"".into());
+    }
+
+    let mut study_data = StudyData::load()?;
+    study_data.add_todo(text.clone())?;
+    println!("Added todo: {}", text);
+    Ok(())
+}
+
+fn start_timer(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let subject = match args.first().map(|s| s.as_str()) {
+        Some("--subject") => match args.get(1) {
+            Some(subject) if !subject.trim().is_empty() => Some(subject.trim().to_string()),
+            _ => return Err("--subject needs a value".into()),
+        },
+        Some(other) => return Err(format!("Unknown option '{}' for start-timer", other).into()),
+        None => None,
+    };
+
+    // Load up front so a broken data file is reported before the session starts
+    let mut study_data = StudyData::load()?;
+
+    let mut timer = Timer::new();
+    timer.start();
+    match &subject {
+        Some(subject) => println!("Studying {}. Press Enter to stop and save.", subject),
+        None => println!("Timer started. Press Enter to stop and save."),
+    }
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let ticker_stop = stop.clone();
+    let started = std::time::Instant::now();
+    let ticker = thread::spawn(move || {
+        while !ticker_stop.load(Ordering::Relaxed) {
+            let secs = started.elapsed().as_secs();
+            print!(
+                "\r{:02}:{:02}:{:02}",
+                secs / 3600,
+                (secs % 3600) / 60,
+                secs % 60
+            );
+            let _ = io::stdout().flush();
+            thread::sleep(Duration::from_millis(500));
+        }
+    });
+
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    stop.store(true, Ordering::Relaxed);
+    let _ = ticker.join();
+
+    timer.pause();
+    let minutes = timer.get_elapsed_minutes();
+    study_data.add_timed_session(minutes, subject, Vec::new(), Vec::new())?;
+    println!("\nSaved {:.1} minutes.", minutes);
+    Ok(())
+}
+
+fn stats(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let study_data = StudyData::load()?;
+
+    match args.first().map(|s| s.as_str()) {
+        Some("--today") => {
+            let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+            println!("Today: {}", format_minutes(study_data.get_today_minutes()));
+            for session in study_data.sessions.iter().filter(|s| s.date == today) {
+                println!(
+                    "  {} - {}",
+                    session.description.as_deref().unwrap_or("Study"),
+                    format_minutes(session.minutes)
+                );
+            }
+        }
+        Some("--week") => {
+            println!(
+                "Last 7 days: {}",
+                format_minutes(study_data.get_last_n_days_minutes(7))
+            );
+        }
+        None => {
+            println!("Today: {}", format_minutes(study_data.get_today_minutes()));
+            println!(
+                "Last 7 days: {}",
+                format_minutes(study_data.get_last_n_days_minutes(7))
+            );
+            println!("Total: {}", format_minutes(study_data.get_total_minutes()));
+        }
+        Some(other) => return Err(format!("Unknown option '{}' for stats", other).into()),
+    }
+    Ok(())
+}
+
+fn format_minutes(minutes: f64) -> String {
+    let total = minutes.round() as u64;
+    let hours = total / 60;
+    let mins = total % 60;
+    if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
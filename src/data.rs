@@ -1,8 +1,8 @@
 use crate::image_handler::ImageManager;
-use crate::ui::flashcard::Deck;
-use chrono::{Duration, Local, NaiveDate};
+use crate::ui::flashcard::{percentile, Card, Deck, ReviewLogEntry};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Write};
 use std::path::Path;
@@ -12,6 +12,204 @@ pub struct StudySession {
     pub date: String,
     pub minutes: f64,
     pub description: Option<String>,
+    #[serde(default)]
+    pub spans: Vec<TimeSpan>, // When the timed parts of this record happened; empty for manual entries
+    #[serde(default)]
+    pub notes: Vec<String>, // Note files edited while the timer ran
+    #[serde(default)]
+    pub laps: Vec<Lap>,
+}
+
+// A labeled part of a timed session, e.g. "reading" then "exercises"
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Lap {
+    pub label: String,
+    pub minutes: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimeSpan {
+    pub start: String, // HH:MM
+    pub minutes: f64,
+}
+
+impl TimeSpan {
+    // A span of the given length ending now, cut off at midnight if it started yesterday
+    pub fn ending_now(minutes: f64) -> Self {
+        let now = Local::now();
+        let since_midnight = now.hour() as f64 * 60.0 + now.minute() as f64;
+        let minutes = minutes.min(since_midnight);
+        let start = now - Duration::seconds((minutes * 60.0) as i64);
+        Self {
+            start: start.format("%H:%M").to_string(),
+            minutes,
+        }
+    }
+
+    // A span starting at the given time, cut off at midnight
+    pub fn starting_at(start: NaiveTime, minutes: f64) -> Self {
+        let since_midnight = start.hour() as f64 * 60.0 + start.minute() as f64;
+        Self {
+            start: start.format("%H:%M").to_string(),
+            minutes: minutes.min(24.0 * 60.0 - since_midnight),
+        }
+    }
+
+    // Minutes after midnight this span starts at
+    pub fn start_minute(&self) -> Option<f64> {
+        minute_of_day(&self.start)
+    }
+}
+
+// Minutes after midnight for an HH:MM time
+pub fn minute_of_day(time: &str) -> Option<f64> {
+    let time = NaiveTime::parse_from_str(time.trim(), "%H:%M").ok()?;
+    Some(time.hour() as f64 * 60.0 + time.minute() as f64)
+}
+
+// A planned stretch of study on the Planner tab's day view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeBlock {
+    pub id: u64,
+    pub date: String,  // YYYY-MM-DD
+    pub start: String, // HH:MM
+    pub end: String,   // HH:MM
+    pub subject: String,
+}
+
+impl TimeBlock {
+    // Parses "9:00-10:30 Linear Algebra" (an en dash works too) into a block on the given date
+    pub fn parse(date: &str, text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (range, subject) = text.split_once(char::is_whitespace)?;
+        let (start, end) = range.split_once(['-', '–'])?;
+        let block = Self {
+            id: 0,
+            date: date.to_string(),
+            start: start.trim().to_string(),
+            end: end.trim().to_string(),
+            subject: subject.trim().to_string(),
+        };
+        let valid = !block.subject.is_empty() && block.planned_minutes() > 0.0;
+        valid.then_some(block)
+    }
+
+    pub fn start_minute(&self) -> Option<f64> {
+        minute_of_day(&self.start)
+    }
+
+    pub fn end_minute(&self) -> Option<f64> {
+        minute_of_day(&self.end)
+    }
+
+    pub fn planned_minutes(&self) -> f64 {
+        match (self.start_minute(), self.end_minute()) {
+            (Some(start), Some(end)) if end > start => end - start,
+            _ => 0.0,
+        }
+    }
+}
+
+// A bulk edit applied to the selected session records in the Record tab
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionEdit {
+    Delete,
+    SetSubject(Option<String>),
+    Merge, // Selected records on the same day collapse into one
+}
+
+// Seconds from showing a card's question to revealing its answer, for one deck
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeckAnswerTimes {
+    pub deck_name: String,
+    pub answers: usize,
+    pub average: f32,
+    pub median: f32,
+    pub p90: f32,
+}
+
+// Aggregate stats for a set of sessions, used to preview what a bulk edit changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionTotals {
+    pub records: usize,
+    pub minutes: f64,
+    pub days: usize,
+    pub by_subject: BTreeMap<String, f64>,
+}
+
+impl SessionTotals {
+    pub fn of(sessions: &[StudySession]) -> Self {
+        let mut days = HashSet::new();
+        let mut by_subject = BTreeMap::new();
+        for session in sessions {
+            days.insert(session.date.as_str());
+            let subject = session
+                .description
+                .clone()
+                .unwrap_or_else(|| "(none)".to_string());
+            *by_subject.entry(subject).or_insert(0.0) += session.minutes;
+        }
+        Self {
+            records: sessions.len(),
+            minutes: sessions.iter().map(|s| s.minutes).sum(),
+            days: days.len(),
+            by_subject,
+        }
+    }
+}
+
+// Returns the sessions as they would be after the edit, leaving the input untouched
+pub fn edit_sessions(
+    sessions: &[StudySession],
+    selected: &HashSet<usize>,
+    edit: &SessionEdit,
+) -> Vec<StudySession> {
+    match edit {
+        SessionEdit::Delete => sessions
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !selected.contains(i))
+            .map(|(_, s)| s.clone())
+            .collect(),
+        SessionEdit::SetSubject(subject) => sessions
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let mut session = s.clone();
+                if selected.contains(&i) {
+                    session.description = subject.clone();
+                }
+                session
+            })
+            .collect(),
+        SessionEdit::Merge => {
+            let mut result: Vec<StudySession> = Vec::new();
+            // Position in result of the first selected record of each day
+            let mut targets: HashMap<&str, usize> = HashMap::new();
+            for (i, session) in sessions.iter().enumerate() {
+                if selected.contains(&i) {
+                    if let Some(&target) = targets.get(session.date.as_str()) {
+                        let target = &mut result[target];
+                        target.minutes += session.minutes;
+                        target.spans.extend(session.spans.iter().cloned());
+                        for note in &session.notes {
+                            if !target.notes.contains(note) {
+                                target.notes.push(note.clone());
+                            }
+                        }
+                        target.laps.extend(session.laps.iter().cloned());
+                        if target.description.is_none() {
+                            target.description = session.description.clone();
+                        }
+                        continue;
+                    }
+                    targets.insert(&session.date, result.len());
+                }
+                result.push(session.clone());
+            }
+            result
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +218,105 @@ pub struct Todo {
     pub text: String,
     pub completed: bool,
     pub created_at: String,
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>, // RFC 3339, used to resolve sync conflicts
+    #[serde(default)]
+    pub remote_id: Option<String>, // Id of the linked task in the sync provider
+    #[serde(default)]
+    pub board_column: BoardColumn, // Column on the planner board
+    #[serde(default)]
+    pub priority: TodoPriority,
+    #[serde(default)]
+    pub due_date: Option<String>, // YYYY-MM-DD
+    #[serde(default)]
+    pub due_time: Option<String>, // HH:MM
+    #[serde(default)]
+    pub urgent: Option<bool>, // Set by dragging in the Eisenhower matrix, overrides the due date
+}
+
+// Todos due within this many days count as urgent
+const URGENT_WITHIN_DAYS: i64 = 2;
+
+impl Todo {
+    pub fn is_important(&self) -> bool {
+        self.priority == TodoPriority::High
+    }
+
+    // Marking a todo not urgent by hand stops counting once it's overdue
+    pub fn is_urgent(&self) -> bool {
+        match self.days_until_due() {
+            Some(days) if days < 0 => true,
+            days => self
+                .urgent
+                .unwrap_or_else(|| days.is_some_and(|d| d <= URGENT_WITHIN_DAYS)),
+        }
+    }
+
+    fn days_until_due(&self) -> Option<i64> {
+        let today = Local::now().date_naive();
+        self.due_date
+            .as_ref()
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .map(|due| (due - today).num_days())
+    }
+
+    // Completed todos always sit in Done, whatever column they were last moved to
+    pub fn column(&self) -> BoardColumn {
+        if self.completed {
+            BoardColumn::Done
+        } else if self.board_column == BoardColumn::Done {
+            BoardColumn::Today
+        } else {
+            self.board_column
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TodoPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl TodoPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TodoPriority::Low => "Low",
+            TodoPriority::Normal => "Normal",
+            TodoPriority::High => "High",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum BoardColumn {
+    #[default]
+    Backlog,
+    ThisWeek,
+    Today,
+    Done,
+}
+
+impl BoardColumn {
+    pub const ALL: [BoardColumn; 4] = [
+        BoardColumn::Backlog,
+        BoardColumn::ThisWeek,
+        BoardColumn::Today,
+        BoardColumn::Done,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            BoardColumn::Backlog => "Backlog",
+            BoardColumn::ThisWeek => "This Week",
+            BoardColumn::Today => "Today",
+            BoardColumn::Done => "Done",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +327,15 @@ pub struct Habit {
     pub created_at: String,
     pub completion_dates: HashSet<String>, // Store dates as "YYYY-MM-DD" strings
     pub target_frequency: HabitFrequency,
+    #[serde(default)]
+    pub pauses: Vec<HabitPause>, // Sorted by start
+}
+
+// Days off (illness, holidays) that neither break the streak nor count against the success rate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HabitPause {
+    pub start: String, // YYYY-MM-DD, inclusive
+    pub end: String,   // YYYY-MM-DD, inclusive
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +346,79 @@ pub enum HabitFrequency {
 }
 
 impl Habit {
+    pub fn is_paused_on(&self, date: &str) -> bool {
+        self.pauses
+            .iter()
+            .any(|p| p.start.as_str() <= date && date <= p.end.as_str())
+    }
+
+    pub fn current_pause(&self) -> Option<&HabitPause> {
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        self.pauses
+            .iter()
+            .find(|p| p.start <= today && today <= p.end)
+    }
+
+    // Paused days without a completion between the two dates, inclusive
+    pub fn paused_days_between(&self, start: NaiveDate, end: NaiveDate) -> usize {
+        start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .filter(|date| self.is_paused_on(date) && !self.completion_dates.contains(date))
+            .count()
+    }
+
+    // Longest run of completed days ever; paused days neither extend nor break it
+    pub fn best_streak(&self) -> u32 {
+        let parse = |date: &String| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok();
+        let Some(first) = self.completion_dates.iter().filter_map(parse).min() else {
+            return 0;
+        };
+        let today = Local::now().date_naive();
+
+        let mut best = 0;
+        let mut run = 0;
+        for date in first.iter_days().take_while(|date| *date <= today) {
+            let date_str = date.format("%Y-%m-%d").to_string();
+            if self.completion_dates.contains(&date_str) {
+                run += 1;
+                best = best.max(run);
+            } else if !self.is_paused_on(&date_str) && date != today {
+                run = 0;
+            }
+        }
+        best
+    }
+
+    pub fn weekday_completions(&self) -> [usize; 7] {
+        let mut counts = [0; 7];
+        for date in &self.completion_dates {
+            if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                counts[date.weekday().num_days_from_monday() as usize] += 1;
+            }
+        }
+        counts
+    }
+
+    // Completions in each of the last `weeks` Monday-to-Sunday weeks, oldest first
+    pub fn weekly_completions(&self, weeks: usize) -> Vec<usize> {
+        let today = Local::now().date_naive();
+        let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let first_monday = this_monday - Duration::weeks(weeks as i64 - 1);
+
+        let mut counts = vec![0; weeks];
+        for date in &self.completion_dates {
+            if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                let week = (date - first_monday).num_days().div_euclid(7);
+                if (0..weeks as i64).contains(&week) {
+                    counts[week as usize] += 1;
+                }
+            }
+        }
+        counts
+    }
+
     pub fn calculate_current_streak(&self) -> u32 {
         let today = Local::now().date_naive();
         let mut streak = 0;
@@ -54,6 +433,8 @@ impl Habit {
             if self.completion_dates.contains(&date_str) {
                 streak += 1;
                 checking_today = false;
+            } else if self.is_paused_on(&date_str) {
+                // Paused days are skipped over rather than ending the streak
             } else if !checking_today {
                 // If we've moved past today and hit a gap, break the streak
                 break;
@@ -78,16 +459,23 @@ impl Habit {
     pub fn get_completion_rate_last_n_days(&self, days: u32) -> f32 {
         let today = Local::now().date_naive();
         let mut completed_days = 0;
+        let mut counted_days = 0;
 
         for i in 0..days {
             let date = today - Duration::days(i as i64);
             let date_str = date.format("%Y-%m-%d").to_string();
             if self.completion_dates.contains(&date_str) {
                 completed_days += 1;
+            } else if self.is_paused_on(&date_str) {
+                continue;
             }
+            counted_days += 1;
         }
 
-        completed_days as f32 / days as f32
+        if counted_days == 0 {
+            return 0.0;
+        }
+        completed_days as f32 / counted_days as f32
     }
 }
 
@@ -97,9 +485,45 @@ pub struct Reminder {
     pub title: String,
     pub description: Option<String>,
     pub due_date: String,
+    #[serde(default)]
+    pub due_time: Option<String>, // HH:MM
     pub created_at: String,
     pub notification_periods: Vec<NotificationPeriod>,
     pub is_completed: bool,
+    #[serde(default)]
+    pub snoozed_until: Option<String>, // YYYY-MM-DD HH:MM; no notifications before then
+    #[serde(default)]
+    pub escalation: OverdueEscalation,
+    #[serde(default)]
+    pub overdue_notified: bool, // Set once the single overdue notice of a Once reminder is shown
+}
+
+impl Reminder {
+    pub fn is_snoozed(&self) -> bool {
+        self.snoozed_until
+            .as_ref()
+            .and_then(|until| NaiveDateTime::parse_from_str(until, "%Y-%m-%d %H:%M").ok())
+            .is_some_and(|until| Local::now().naive_local() < until)
+    }
+}
+
+// How often a reminder keeps notifying once it is overdue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum OverdueEscalation {
+    #[default]
+    Daily,
+    Once,
+}
+
+impl OverdueEscalation {
+    pub const ALL: [OverdueEscalation; 2] = [OverdueEscalation::Daily, OverdueEscalation::Once];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OverdueEscalation::Daily => "Every day while overdue",
+            OverdueEscalation::Once => "Only once when overdue",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -110,6 +534,316 @@ pub enum NotificationPeriod {
     Custom(u32),
 }
 
+// Graded coursework on the Assignments tab
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Assignment {
+    pub id: u64,
+    pub course: String,
+    pub title: String,
+    pub due_date: String,         // YYYY-MM-DD
+    pub weight: f64,              // Percent of the course grade
+    pub grade: Option<f64>,       // Percent received, None until graded
+    pub reminder_id: Option<u64>, // Reminder created for the due date
+}
+
+// Where a course stands from its graded assignments so far
+pub struct CourseGrade {
+    pub course: String,
+    pub graded_weight: f64, // Weight of the assignments graded so far
+    pub total_weight: f64,
+    pub earned: f64, // Points of the final grade already secured
+}
+
+impl CourseGrade {
+    // Average over what has been graded, None before the first grade
+    pub fn running_grade(&self) -> Option<f64> {
+        (self.graded_weight > 0.0).then(|| self.earned / self.graded_weight * 100.0)
+    }
+}
+
+// An entry in the references collection, cited in notes as [@key]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Reference {
+    pub id: u64,
+    pub key: String,        // Citation key, e.g. "darwin1859origin"
+    pub entry_type: String, // BibTeX entry type, e.g. "article"
+    pub title: String,
+    pub authors: String, // "Last, First and Last, First", as BibTeX writes them
+    #[serde(default)]
+    pub year: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub doi: String,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub extra: Vec<(String, String)>, // Other BibTeX fields, kept for export
+}
+
+impl Reference {
+    // Last name of the first author, the year and the first long title word
+    pub fn suggested_key(&self) -> String {
+        let first_author = self.authors.split(" and ").next().unwrap_or("");
+        let last_name = match first_author.split_once(',') {
+            Some((last, _)) => last,
+            None => first_author.split_whitespace().last().unwrap_or(""),
+        };
+        let title_word = self
+            .title
+            .split_whitespace()
+            .find(|word| word.chars().filter(|c| c.is_alphanumeric()).count() > 3)
+            .unwrap_or("");
+        let key: String = format!("{}{}{}", last_name, self.year, title_word)
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        if key.is_empty() {
+            "ref".to_string()
+        } else {
+            key
+        }
+    }
+}
+
+// A note, or a section of one, queued for incremental reading
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingItem {
+    pub id: u64,
+    pub path: String,            // Path of the note, e.g. "files/biology/cells.md"
+    pub section: Option<String>, // Heading text, None for the whole note
+    pub added_at: String,
+    pub due_date: String, // YYYY-MM-DD format
+    pub interval: u32,    // Days until the item comes back
+    pub times_read: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReadingRating {
+    Sooner,
+    Good,
+    Later,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuizResult {
+    pub date: String, // YYYY-MM-DD format
+    pub deck_id: u64,
+    pub deck_name: String,
+    pub correct: u32,
+    pub total: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Mood {
+    Great,
+    Good,
+    Okay,
+    Low,
+    Bad,
+}
+
+impl Mood {
+    pub const ALL: [Mood; 5] = [Mood::Great, Mood::Good, Mood::Okay, Mood::Low, Mood::Bad];
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Mood::Great => "😄",
+            Mood::Good => "🙂",
+            Mood::Okay => "😐",
+            Mood::Low => "😕",
+            Mood::Bad => "😞",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Mood::Great => "Great",
+            Mood::Good => "Good",
+            Mood::Okay => "Okay",
+            Mood::Low => "Low",
+            Mood::Bad => "Bad",
+        }
+    }
+}
+
+// Offered when writing a journal entry; answers keep their prompt so the list can change
+pub const JOURNAL_PROMPTS: [&str; 5] = [
+    "What did you learn today?",
+    "What was hard, and what helped?",
+    "What are you proud of?",
+    "What will you focus on tomorrow?",
+    "What are you grateful for?",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalAnswer {
+    pub prompt: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub date: String, // YYYY-MM-DD, one entry per day
+    #[serde(default)]
+    pub mood: Option<Mood>,
+    #[serde(default)]
+    pub answers: Vec<JournalAnswer>,
+    #[serde(default)]
+    pub text: String,
+}
+
+impl JournalEntry {
+    pub fn new(date: String) -> Self {
+        Self {
+            date,
+            mood: None,
+            answers: Vec::new(),
+            text: String::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mood.is_none()
+            && self.text.trim().is_empty()
+            && self.answers.iter().all(|a| a.answer.trim().is_empty())
+    }
+
+    // Case-insensitive match against the text and the answers
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.text.to_lowercase().contains(&query)
+            || self.answers.iter().any(|a| {
+                a.answer.to_lowercase().contains(&query) || a.prompt.to_lowercase().contains(&query)
+            })
+    }
+}
+
+// Deleted records are kept this long so they can be restored
+const TRASH_RETENTION_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TrashedItem {
+    Todo(Todo),
+    Habit(Habit),
+    Reminder(Reminder),
+    Assignment(Assignment),
+    Reference(Reference),
+    Deck(Deck),
+    Session(StudySession),
+    JournalEntry(JournalEntry),
+    Card {
+        deck_id: u64,
+        deck_name: String,
+        card: Card,
+    },
+}
+
+impl TrashedItem {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TrashedItem::Todo(_) => "Todo",
+            TrashedItem::Habit(_) => "Habit",
+            TrashedItem::Reminder(_) => "Reminder",
+            TrashedItem::Assignment(_) => "Assignment",
+            TrashedItem::Reference(_) => "Reference",
+            TrashedItem::Deck(_) => "Deck",
+            TrashedItem::Session(_) => "Session",
+            TrashedItem::JournalEntry(_) => "Journal entry",
+            TrashedItem::Card { .. } => "Card",
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            TrashedItem::Todo(todo) => todo.text.clone(),
+            TrashedItem::Habit(habit) => habit.name.clone(),
+            TrashedItem::Reminder(reminder) => reminder.title.clone(),
+            TrashedItem::Assignment(assignment) => {
+                format!("{}: {}", assignment.course, assignment.title)
+            }
+            TrashedItem::Reference(reference) => {
+                format!("{} ({})", reference.title, reference.key)
+            }
+            TrashedItem::Deck(deck) => format!("{} ({} cards)", deck.name, deck.cards.len()),
+            TrashedItem::Session(session) => format!(
+                "{} · {:.0} min{}",
+                session.date,
+                session.minutes,
+                session
+                    .description
+                    .as_ref()
+                    .map(|d| format!(" · {}", d))
+                    .unwrap_or_default()
+            ),
+            TrashedItem::JournalEntry(entry) => format!("Journal for {}", entry.date),
+            TrashedItem::Card {
+                deck_name, card, ..
+            } => format!("{} (from {})", card.front, deck_name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub id: u64,
+    pub batch: u64, // Items deleted by the same action share a batch and undo together
+    pub deleted_at: String, // YYYY-MM-DD HH:MM:SS
+    pub item: TrashedItem,
+}
+
+// Finished records kept out of the active lists; unlike the trash they never expire
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ArchivedItem {
+    Todo(Todo),
+    Habit(Habit),
+    Reminder(Reminder),
+}
+
+impl ArchivedItem {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ArchivedItem::Todo(_) => "Todo",
+            ArchivedItem::Habit(_) => "Habit",
+            ArchivedItem::Reminder(_) => "Reminder",
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            ArchivedItem::Todo(todo) => todo.text.clone(),
+            ArchivedItem::Habit(habit) => {
+                format!(
+                    "{} ({} completions)",
+                    habit.name,
+                    habit.completion_dates.len()
+                )
+            }
+            ArchivedItem::Reminder(reminder) => {
+                format!("{} (due {})", reminder.title, reminder.due_date)
+            }
+        }
+    }
+}
+
+impl From<ArchivedItem> for TrashedItem {
+    fn from(item: ArchivedItem) -> Self {
+        match item {
+            ArchivedItem::Todo(todo) => TrashedItem::Todo(todo),
+            ArchivedItem::Habit(habit) => TrashedItem::Habit(habit),
+            ArchivedItem::Reminder(reminder) => TrashedItem::Reminder(reminder),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveEntry {
+    pub id: u64,
+    pub archived_at: String, // YYYY-MM-DD HH:MM:SS
+    pub item: ArchivedItem,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct StudyData {
     pub sessions: Vec<StudySession>,
@@ -119,13 +853,38 @@ pub struct StudyData {
     pub decks: Vec<Deck>,
     pub next_deck_id: u64,
     pub image_manager: ImageManager,
+    #[serde(default)]
+    pub reading_queue: Vec<ReadingItem>,
+    #[serde(default)]
+    pub quiz_results: Vec<QuizResult>,
+    #[serde(default)]
+    pub trash: Vec<TrashEntry>,
+    #[serde(default)]
+    pub time_blocks: Vec<TimeBlock>,
+    #[serde(default)]
+    pub assignments: Vec<Assignment>,
+    #[serde(default)]
+    pub references: Vec<Reference>,
+    #[serde(default)]
+    pub journal: Vec<JournalEntry>, // Sorted by date
+    #[serde(default)]
+    pub archive: Vec<ArchiveEntry>,
+    #[serde(default)]
+    pub collapsed_habit_categories: Vec<String>,
+    #[serde(default)]
+    pub review_log: Vec<ReviewLogEntry>, // Oldest first
 }
 
 impl StudyData {
     pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::load_repaired().map(|(data, _)| data)
+    }
+
+    // Also returns the id repairs made while loading, for the headless migrate task
+    pub fn load_repaired() -> Result<(Self, Vec<String>), Box<dyn std::error::Error>> {
         let data_path = Path::new("study_data.json");
         if !data_path.exists() {
-            return Ok(StudyData {
+            let data = StudyData {
                 sessions: Vec::new(),
                 todos: Vec::new(),
                 habits: Vec::new(),
@@ -133,14 +892,106 @@ impl StudyData {
                 decks: Vec::new(),
                 image_manager: ImageManager::new(),
                 next_deck_id: 1,
-            });
+                reading_queue: Vec::new(),
+                quiz_results: Vec::new(),
+                trash: Vec::new(),
+                time_blocks: Vec::new(),
+                assignments: Vec::new(),
+                references: Vec::new(),
+                journal: Vec::new(),
+                archive: Vec::new(),
+                collapsed_habit_categories: Vec::new(),
+                review_log: Vec::new(),
+            };
+            return Ok((data, Vec::new()));
         }
 
         let mut file = File::open(data_path)?;
         let mut contents = String::new();
         file.read_to_string(&mut contents)?;
-        let data: StudyData = serde_json::from_str(&contents)?;
-        Ok(data)
+        let mut data: StudyData = serde_json::from_str(&contents)?;
+        data.purge_expired_trash();
+        let repairs = data.repair_ids();
+        data.backfill_review_log();
+        Ok((data, repairs))
+    }
+
+    // Older versions pushed decks and cards without assigning an id, and everything keyed
+    // by card id (selection, duplicates, review history) needs them unique per deck
+    fn repair_ids(&mut self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        let mut deck_ids = HashSet::new();
+        let mut next_deck_id = self.decks.iter().map(|d| d.id).max().unwrap_or(0) + 1;
+        for deck in &mut self.decks {
+            if deck.id == 0 || !deck_ids.insert(deck.id) {
+                deck.id = next_deck_id;
+                deck_ids.insert(deck.id);
+                next_deck_id += 1;
+                changes.push(format!("Gave deck \"{}\" a new id", deck.name));
+            }
+
+            let mut card_ids = HashSet::new();
+            let mut next_card_id = deck.cards.iter().map(|c| c.id).max().unwrap_or(0) + 1;
+            let mut reassigned = 0;
+            for card in &mut deck.cards {
+                if card.id == 0 || !card_ids.insert(card.id) {
+                    card.id = next_card_id;
+                    card_ids.insert(card.id);
+                    next_card_id += 1;
+                    reassigned += 1;
+                }
+                card.deck_id = deck.id;
+            }
+            if reassigned > 0 {
+                changes.push(format!(
+                    "Gave {} card(s) in \"{}\" new ids",
+                    reassigned, deck.name
+                ));
+            }
+        }
+
+        if self.next_deck_id < next_deck_id {
+            self.next_deck_id = next_deck_id;
+            changes.push(format!("Moved the next deck id to {}", next_deck_id));
+        }
+        changes
+    }
+
+    // Reviews graded before the log existed only have a date, so they go in at midnight
+    fn backfill_review_log(&mut self) {
+        if !self.review_log.is_empty() {
+            return;
+        }
+        for deck in &self.decks {
+            for card in &deck.cards {
+                let forward = card.reviews.iter().map(|review| (review, false));
+                let reverse = card
+                    .reverse_schedule
+                    .iter()
+                    .flat_map(|schedule| schedule.reviews.iter().map(|review| (review, true)));
+                for (review, reversed) in forward.chain(reverse) {
+                    self.review_log.push(ReviewLogEntry {
+                        timestamp: format!("{} 00:00:00", review.date),
+                        deck_id: deck.id,
+                        card_id: card.id,
+                        grade: review.grade.clone(),
+                        reversed,
+                        answer_seconds: review.answer_seconds,
+                    });
+                }
+            }
+        }
+        self.review_log
+            .sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    }
+
+    pub fn reviews_per_day(&self) -> HashMap<NaiveDate, usize> {
+        let mut days = HashMap::new();
+        for date in self.review_log.iter().filter_map(|entry| entry.date()) {
+            *days.entry(date).or_insert(0) += 1;
+        }
+        days
     }
 
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -154,50 +1005,108 @@ impl StudyData {
         Ok(())
     }
 
-    pub fn add_session(
+    // Records time from the timer, which just finished, so it shows up on the timeline
+    pub fn add_timed_session(
+        &mut self,
+        minutes: f64,
+        description: Option<String>,
+        notes: Vec<String>,
+        laps: Vec<Lap>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        let span = TimeSpan::ending_now(minutes);
+        self.add_session_with_span(today, minutes, description, Some(span), notes, laps)
+    }
+
+    // Books the time on the day and at the time it started, e.g. for a session
+    // recovered after the app closed in the middle of it
+    pub fn add_timed_session_from(
+        &mut self,
+        start: NaiveDateTime,
+        minutes: f64,
+        description: Option<String>,
+        notes: Vec<String>,
+        laps: Vec<Lap>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let date = start.date().format("%Y-%m-%d").to_string();
+        let span = TimeSpan::starting_at(start.time(), minutes);
+        self.add_session_with_span(date, minutes, description, Some(span), notes, laps)
+    }
+
+    pub fn add_session_with_span(
         &mut self,
         date: String,
         minutes: f64,
         description: Option<String>,
+        span: Option<TimeSpan>,
+        notes: Vec<String>,
+        laps: Vec<Lap>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         if minutes <= 0.0 {
             return Ok(());
         }
+        let spans: Vec<TimeSpan> = span.into_iter().collect();
 
-        if let Some(description) = &description {
-            if let Some(session) = self
-                .sessions
-                .iter_mut()
-                .find(|s| s.date == date && s.description.as_ref() == Some(description))
-            {
-                session.minutes += minutes;
-            } else {
-                self.sessions.push(StudySession {
-                    date,
-                    minutes,
-                    description: Some(description.clone()),
-                });
+        if let Some(session) = self
+            .sessions
+            .iter_mut()
+            .find(|s| s.date == date && s.description == description)
+        {
+            session.minutes += minutes;
+            session.spans.extend(spans);
+            for note in notes {
+                if !session.notes.contains(&note) {
+                    session.notes.push(note);
+                }
             }
+            session.laps.extend(laps);
         } else {
-            if let Some(session) = self
-                .sessions
-                .iter_mut()
-                .find(|s| s.date == date && s.description.is_none())
-            {
-                session.minutes += minutes;
-            } else {
-                self.sessions.push(StudySession {
-                    date,
-                    minutes,
-                    description: None,
-                });
-            }
+            self.sessions.push(StudySession {
+                date,
+                minutes,
+                description,
+                spans,
+                notes,
+                laps,
+            });
         }
 
         self.save()?;
         Ok(())
     }
 
+    // Records the note was edited in, newest first
+    pub fn sessions_for_note(&self, path: &str) -> Vec<&StudySession> {
+        let mut sessions: Vec<&StudySession> = self
+            .sessions
+            .iter()
+            .filter(|s| s.notes.iter().any(|note| note == path))
+            .collect();
+        sessions.sort_by(|a, b| b.date.cmp(&a.date));
+        sessions
+    }
+
+    // Deleted records go to the trash so the edit can be undone
+    pub fn apply_session_edit(
+        &mut self,
+        selected: &HashSet<usize>,
+        edit: &SessionEdit,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if *edit == SessionEdit::Delete {
+            let removed = self
+                .sessions
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| selected.contains(i))
+                .map(|(_, s)| TrashedItem::Session(s.clone()))
+                .collect();
+            self.move_to_trash(removed);
+        }
+        self.sessions = edit_sessions(&self.sessions, selected, edit);
+        self.save()?;
+        Ok(())
+    }
+
     pub fn get_today_minutes(&self) -> f64 {
         let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
         self.sessions
@@ -207,33 +1116,338 @@ impl StudyData {
             .sum()
     }
 
-    pub fn get_total_minutes(&self) -> f64 {
-        self.sessions.iter().map(|s| s.minutes).sum()
+    // Days in a row with at least one session, counting yesterday's run while today is empty
+    pub fn study_streak(&self) -> u32 {
+        let days: HashSet<&str> = self.sessions.iter().map(|s| s.date.as_str()).collect();
+        let mut date = Local::now().date_naive();
+        if !days.contains(date.format("%Y-%m-%d").to_string().as_str()) {
+            date -= Duration::days(1);
+        }
+        let mut streak = 0;
+        while days.contains(date.format("%Y-%m-%d").to_string().as_str()) {
+            streak += 1;
+            date -= Duration::days(1);
+        }
+        streak
+    }
+
+    // Answer times from the review log for decks that still exist, slowest first
+    pub fn answer_times(&self) -> Vec<DeckAnswerTimes> {
+        let mut times: HashMap<u64, Vec<f32>> = HashMap::new();
+        for entry in &self.review_log {
+            if let Some(seconds) = entry.answer_seconds {
+                times.entry(entry.deck_id).or_default().push(seconds);
+            }
+        }
+
+        let mut decks: Vec<DeckAnswerTimes> = self
+            .decks
+            .iter()
+            .filter_map(|deck| {
+                let mut seconds = times.remove(&deck.id)?;
+                seconds.sort_by(|a, b| a.total_cmp(b));
+                Some(DeckAnswerTimes {
+                    deck_name: deck.name.clone(),
+                    answers: seconds.len(),
+                    average: seconds.iter().sum::<f32>() / seconds.len() as f32,
+                    median: percentile(&seconds, 0.5),
+                    p90: percentile(&seconds, 0.9),
+                })
+            })
+            .collect();
+        decks.sort_by(|a, b| b.average.total_cmp(&a.average));
+        decks
+    }
+
+    // Lap minutes per label across all sessions, largest first
+    pub fn lap_totals(&self) -> Vec<(String, f64)> {
+        let mut totals: HashMap<String, f64> = HashMap::new();
+        for lap in self.sessions.iter().flat_map(|s| &s.laps) {
+            *totals.entry(lap.label.clone()).or_insert(0.0) += lap.minutes;
+        }
+        let mut totals: Vec<(String, f64)> = totals.into_iter().collect();
+        totals.sort_by(|a, b| b.1.total_cmp(&a.1));
+        totals
+    }
+
+    pub fn get_total_minutes(&self) -> f64 {
+        self.sessions.iter().map(|s| s.minutes).sum()
+    }
+
+    pub fn get_last_n_days_minutes(&self, days: i64) -> f64 {
+        let today = Local::now().date_naive();
+        self.sessions
+            .iter()
+            .filter_map(|s| {
+                if let Ok(date) = NaiveDate::parse_from_str(&s.date, "%Y-%m-%d") {
+                    if (today - date).num_days() < days {
+                        return Some(s.minutes);
+                    }
+                }
+                None
+            })
+            .sum()
+    }
+
+    // Time block methods
+    pub fn add_time_block(
+        &mut self,
+        mut block: TimeBlock,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        block.id = self.time_blocks.iter().map(|b| b.id).max().unwrap_or(0) + 1;
+        self.time_blocks.push(block);
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn delete_time_block(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        self.time_blocks.retain(|b| b.id != id);
+        self.save()?;
+        Ok(())
+    }
+
+    // Timed study on the block's day that falls inside the block, whatever the subject
+    pub fn logged_minutes_in_block(&self, block: &TimeBlock) -> f64 {
+        let (block_start, block_end) = match (block.start_minute(), block.end_minute()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return 0.0,
+        };
+        self.sessions
+            .iter()
+            .filter(|s| s.date == block.date)
+            .flat_map(|s| &s.spans)
+            .filter_map(|span| {
+                let start = span.start_minute()?;
+                let overlap = (start + span.minutes).min(block_end) - start.max(block_start);
+                Some(overlap.max(0.0))
+            })
+            .sum()
+    }
+
+    // Also adds a reminder for the due date
+    pub fn add_assignment(
+        &mut self,
+        mut assignment: Assignment,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        assignment.id = self.assignments.iter().map(|a| a.id).max().unwrap_or(0) + 1;
+        let reminder_id = self.get_next_reminder_id();
+        self.add_reminder(
+            format!("{}: {}", assignment.course, assignment.title),
+            Some(format!("Assignment worth {:.0}% of the grade", assignment.weight)),
+            assignment.due_date.clone(),
+            None,
+            vec![NotificationPeriod::OneDay, NotificationPeriod::ThreeDays],
+            OverdueEscalation::default(),
+        )?;
+        assignment.reminder_id = Some(reminder_id);
+        self.assignments.push(assignment);
+        self.save()?;
+        Ok(())
+    }
+
+    // Grading an assignment completes its reminder
+    pub fn set_assignment_grade(
+        &mut self,
+        id: u64,
+        grade: Option<f64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(assignment) = self.assignments.iter_mut().find(|a| a.id == id) {
+            assignment.grade = grade;
+            if let Some(reminder) = self
+                .reminders
+                .iter_mut()
+                .find(|r| Some(r.id) == assignment.reminder_id)
+            {
+                reminder.is_completed = grade.is_some();
+            }
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    // Trashes the assignment together with its reminder, so undo brings back both
+    pub fn delete_assignment(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(index) = self.assignments.iter().position(|a| a.id == id) {
+            let assignment = self.assignments.remove(index);
+            let mut items = Vec::new();
+            if let Some(index) = self
+                .reminders
+                .iter()
+                .position(|r| Some(r.id) == assignment.reminder_id)
+            {
+                items.push(TrashedItem::Reminder(self.reminders.remove(index)));
+            }
+            items.push(TrashedItem::Assignment(assignment));
+            self.move_to_trash(items);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    // Sorted by course name
+    pub fn course_grades(&self) -> Vec<CourseGrade> {
+        let mut courses: BTreeMap<&str, CourseGrade> = BTreeMap::new();
+        for assignment in &self.assignments {
+            let course = courses
+                .entry(assignment.course.as_str())
+                .or_insert_with(|| CourseGrade {
+                    course: assignment.course.clone(),
+                    graded_weight: 0.0,
+                    total_weight: 0.0,
+                    earned: 0.0,
+                });
+            course.total_weight += assignment.weight;
+            if let Some(grade) = assignment.grade {
+                course.graded_weight += assignment.weight;
+                course.earned += assignment.weight * grade / 100.0;
+            }
+        }
+        courses.into_values().collect()
+    }
+
+    // Adds "a", "b", ... when the key is already taken
+    pub fn unique_reference_key(&self, key: &str) -> String {
+        let taken = |candidate: &str| self.references.iter().any(|r| r.key == candidate);
+        if !taken(key) {
+            return key.to_string();
+        }
+        ('a'..='z')
+            .map(|suffix| format!("{}{}", key, suffix))
+            .find(|candidate| !taken(candidate))
+            .unwrap_or_else(|| format!("{}{}", key, self.references.len()))
+    }
+
+    // Fills in a key when there is none and makes it unique; returns the key used
+    pub fn add_reference(
+        &mut self,
+        mut reference: Reference,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        reference.id = self.references.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+        let key = if reference.key.trim().is_empty() {
+            reference.suggested_key()
+        } else {
+            reference.key.trim().to_string()
+        };
+        reference.key = self.unique_reference_key(&key);
+        let key = reference.key.clone();
+        self.references.push(reference);
+        self.save()?;
+        Ok(key)
+    }
+
+    pub fn update_reference(
+        &mut self,
+        reference: Reference,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(existing) = self.references.iter_mut().find(|r| r.id == reference.id) {
+            *existing = reference;
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    pub fn delete_reference(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(index) = self.references.iter().position(|r| r.id == id) {
+            let reference = self.references.remove(index);
+            self.move_to_trash(vec![TrashedItem::Reference(reference)]);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn journal_entry(&self, date: &str) -> Option<&JournalEntry> {
+        self.journal.iter().find(|e| e.date == date)
+    }
+
+    // Replaces the entry for its date, or drops it when it was cleared
+    pub fn save_journal_entry(
+        &mut self,
+        entry: JournalEntry,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.journal.retain(|e| e.date != entry.date);
+        if !entry.is_empty() {
+            let index = self.journal.partition_point(|e| e.date < entry.date);
+            self.journal.insert(index, entry);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn delete_journal_entry(&mut self, date: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(index) = self.journal.iter().position(|e| e.date == date) {
+            let entry = self.journal.remove(index);
+            self.move_to_trash(vec![TrashedItem::JournalEntry(entry)]);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    // Skips entries already in the collection (same DOI, or same key and title)
+    pub fn import_references(
+        &mut self,
+        references: Vec<Reference>,
+    ) -> Result<usize, Box<dyn std::error::Error>> {
+        let mut added = 0;
+        for mut reference in references {
+            let duplicate = self.references.iter().any(|r| {
+                (!reference.doi.is_empty() && r.doi.eq_ignore_ascii_case(&reference.doi))
+                    || (r.key == reference.key && r.title == reference.title)
+            });
+            if duplicate {
+                continue;
+            }
+            reference.id = self.references.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+            let key = if reference.key.trim().is_empty() {
+                reference.suggested_key()
+            } else {
+                reference.key.trim().to_string()
+            };
+            reference.key = self.unique_reference_key(&key);
+            self.references.push(reference);
+            added += 1;
+        }
+        if added > 0 {
+            self.save()?;
+        }
+        Ok(added)
+    }
+
+    // Todo methods
+    pub fn add_todo(&mut self, text: String) -> Result<(), Box<dyn std::error::Error>> {
+        self.add_todo_to_project(text, None)
     }
 
-    pub fn get_last_n_days_minutes(&self, days: i64) -> f64 {
-        let today = Local::now().date_naive();
-        self.sessions
-            .iter()
-            .filter_map(|s| {
-                if let Ok(date) = NaiveDate::parse_from_str(&s.date, "%Y-%m-%d") {
-                    if (today - date).num_days() < days {
-                        return Some(s.minutes);
-                    }
-                }
-                None
-            })
-            .sum()
+    pub fn add_todo_to_project(
+        &mut self,
+        text: String,
+        project: Option<String>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.add_todo_with_details(text, project, TodoPriority::default(), None, None)
     }
 
-    // Todo methods
-    pub fn add_todo(&mut self, text: String) -> Result<(), Box<dyn std::error::Error>> {
+    // Used by the quick-add input, which can set everything at once
+    pub fn add_todo_with_details(
+        &mut self,
+        text: String,
+        project: Option<String>,
+        priority: TodoPriority,
+        due_date: Option<String>,
+        due_time: Option<String>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         let now = Local::now();
         let todo = Todo {
             id: self.get_next_todo_id(),
             text,
             completed: false,
             created_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+            project,
+            updated_at: Some(Utc::now().to_rfc3339()),
+            remote_id: None,
+            board_column: BoardColumn::default(),
+            priority,
+            due_date,
+            due_time,
+            urgent: None,
         };
 
         self.todos.push(todo);
@@ -241,16 +1455,86 @@ impl StudyData {
         Ok(())
     }
 
+    // Moving into or out of Done completes or reopens the todo
+    pub fn move_todo_to_column(
+        &mut self,
+        id: u64,
+        column: BoardColumn,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(todo) = self.todos.iter_mut().find(|t| t.id == id) {
+            todo.board_column = column;
+            todo.completed = column == BoardColumn::Done;
+            todo.updated_at = Some(Utc::now().to_rfc3339());
+        }
+        self.save()?;
+        Ok(())
+    }
+
     pub fn toggle_todo(&mut self, id: u64) -> Result<bool, Box<dyn std::error::Error>> {
         let mut completed = false;
         if let Some(todo) = self.todos.iter_mut().find(|t| t.id == id) {
             todo.completed = !todo.completed;
+            todo.updated_at = Some(Utc::now().to_rfc3339());
             completed = todo.completed;
         }
         self.save()?;
         Ok(completed)
     }
 
+    // Places a todo in an Eisenhower quadrant. Importance is the priority; urgency follows
+    // the due date unless the todo was dropped across the urgent line
+    pub fn classify_todo(
+        &mut self,
+        id: u64,
+        urgent: bool,
+        important: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(todo) = self.todos.iter_mut().find(|t| t.id == id) {
+            if important {
+                todo.priority = TodoPriority::High;
+            } else if todo.priority == TodoPriority::High {
+                todo.priority = TodoPriority::Normal;
+            }
+            if todo.is_urgent() != urgent {
+                todo.urgent = Some(urgent);
+            }
+            todo.updated_at = Some(Utc::now().to_rfc3339());
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn set_todo_priority(
+        &mut self,
+        id: u64,
+        priority: TodoPriority,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(todo) = self.todos.iter_mut().find(|t| t.id == id) {
+            todo.priority = priority;
+            todo.updated_at = Some(Utc::now().to_rfc3339());
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    // A new due date replaces any urgency set by hand
+    pub fn set_todo_due_date(
+        &mut self,
+        id: u64,
+        due_date: Option<String>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(todo) = self.todos.iter_mut().find(|t| t.id == id) {
+            if due_date.is_none() {
+                todo.due_time = None;
+            }
+            todo.due_date = due_date;
+            todo.urgent = None;
+            todo.updated_at = Some(Utc::now().to_rfc3339());
+        }
+        self.save()?;
+        Ok(())
+    }
+
     pub fn update_todo_text(
         &mut self,
         id: u64,
@@ -258,30 +1542,39 @@ impl StudyData {
     ) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(todo) = self.todos.iter_mut().find(|t| t.id == id) {
             todo.text = text;
+            todo.updated_at = Some(Utc::now().to_rfc3339());
             self.save()?;
         }
         Ok(())
     }
 
     pub fn delete_todo(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
-        self.todos.retain(|t| t.id != id);
+        if let Some(index) = self.todos.iter().position(|t| t.id == id) {
+            let todo = self.todos.remove(index);
+            self.move_to_trash(vec![TrashedItem::Todo(todo)]);
+        }
         self.save()?;
         Ok(())
     }
 
     pub fn clear_todos(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        self.todos.clear();
+        let todos = std::mem::take(&mut self.todos);
+        self.move_to_trash(todos.into_iter().map(TrashedItem::Todo).collect());
         self.save()?;
         Ok(())
     }
 
     pub fn clear_completed_todos(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        self.todos.retain(|t| !t.completed);
+        let (completed, open): (Vec<Todo>, Vec<Todo>) = std::mem::take(&mut self.todos)
+            .into_iter()
+            .partition(|t| t.completed);
+        self.todos = open;
+        self.move_to_trash(completed.into_iter().map(TrashedItem::Todo).collect());
         self.save()?;
         Ok(())
     }
 
-    fn get_next_todo_id(&self) -> u64 {
+    pub fn get_next_todo_id(&self) -> u64 {
         if let Some(max_id) = self.todos.iter().map(|t| t.id).max() {
             max_id + 1
         } else {
@@ -303,6 +1596,7 @@ impl StudyData {
             created_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
             completion_dates: HashSet::new(),
             target_frequency: HabitFrequency::Daily,
+            pauses: Vec::new(),
         };
 
         self.habits.push(habit);
@@ -320,6 +1614,19 @@ impl StudyData {
         Ok(())
     }
 
+    // One save for the whole evening review
+    pub fn mark_habits_complete_today(
+        &mut self,
+        ids: &[u64],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        for habit in self.habits.iter_mut().filter(|h| ids.contains(&h.id)) {
+            habit.completion_dates.insert(today.clone());
+        }
+        self.save()?;
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub fn unmark_habit_complete(
         &mut self,
@@ -333,15 +1640,86 @@ impl StudyData {
         Ok(())
     }
 
+    pub fn add_habit_pause(
+        &mut self,
+        id: u64,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if end < start {
+            return Err("The pause has to end on or after its first day".into());
+        }
+        if let Some(habit) = self.habits.iter_mut().find(|h| h.id == id) {
+            habit.pauses.push(HabitPause {
+                start: start.format("%Y-%m-%d").to_string(),
+                end: end.format("%Y-%m-%d").to_string(),
+            });
+            habit.pauses.sort_by(|a, b| a.start.cmp(&b.start));
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn remove_habit_pause(
+        &mut self,
+        id: u64,
+        pause: &HabitPause,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(habit) = self.habits.iter_mut().find(|h| h.id == id) {
+            habit.pauses.retain(|p| p != pause);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    // The dragged habit takes the target's place, and its category when dropped into another group
+    pub fn move_habit(&mut self, id: u64, target: u64) -> Result<(), Box<dyn std::error::Error>> {
+        let from = self.habits.iter().position(|h| h.id == id);
+        let to = self.habits.iter().position(|h| h.id == target);
+        if let (Some(from), Some(to)) = (from, to) {
+            let category = self.habits[to].category.clone();
+            let mut habit = self.habits.remove(from);
+            habit.category = category;
+            self.habits.insert(to, habit);
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    pub fn is_habit_category_collapsed(&self, category: &str) -> bool {
+        self.collapsed_habit_categories
+            .iter()
+            .any(|c| c == category)
+    }
+
+    pub fn toggle_habit_category(
+        &mut self,
+        category: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.is_habit_category_collapsed(category) {
+            self.collapsed_habit_categories.retain(|c| c != category);
+        } else {
+            self.collapsed_habit_categories.push(category.to_string());
+        }
+        self.save()
+    }
+
     pub fn delete_habit(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
-        self.habits.retain(|h| h.id != id);
+        if let Some(index) = self.habits.iter().position(|h| h.id == id) {
+            let habit = self.habits.remove(index);
+            self.move_to_trash(vec![TrashedItem::Habit(habit)]);
+        }
         self.save()?;
         Ok(())
     }
 
     pub fn clear_completed_habits(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
-        self.habits.retain(|h| !h.completion_dates.contains(&today));
+        let (completed, remaining): (Vec<Habit>, Vec<Habit>) = std::mem::take(&mut self.habits)
+            .into_iter()
+            .partition(|h| h.completion_dates.contains(&today));
+        self.habits = remaining;
+        self.move_to_trash(completed.into_iter().map(TrashedItem::Habit).collect());
         self.save()?;
         Ok(())
     }
@@ -366,7 +1744,6 @@ impl StudyData {
             .collect()
     }
 
-    #[allow(dead_code)]
     pub fn get_habit_stats(&self, id: u64) -> Option<HabitStats> {
         if let Some(habit) = self.habits.iter().find(|h| h.id == id) {
             let current_streak = habit.calculate_current_streak();
@@ -376,9 +1753,12 @@ impl StudyData {
 
             Some(HabitStats {
                 current_streak,
+                best_streak: habit.best_streak(),
                 total_completions,
                 completion_rate_7_days,
                 completion_rate_30_days,
+                weekday_completions: habit.weekday_completions(),
+                weekly_trend: habit.weekly_completions(HABIT_TREND_WEEKS),
             })
         } else {
             None
@@ -397,7 +1777,9 @@ impl StudyData {
         title: String,
         description: Option<String>,
         due_date: String,
+        due_time: Option<String>,
         notification_periods: Vec<NotificationPeriod>,
+        escalation: OverdueEscalation,
     ) -> Result<(), Box<dyn std::error::Error>> {
         let now = Local::now();
         let reminder = Reminder {
@@ -405,9 +1787,13 @@ impl StudyData {
             title,
             description,
             due_date,
+            due_time,
             created_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
             notification_periods,
             is_completed: false,
+            snoozed_until: None,
+            escalation,
+            overdue_notified: false,
         };
 
         self.reminders.push(reminder);
@@ -422,12 +1808,18 @@ impl StudyData {
         description: Option<String>,
         due_date: String,
         notification_periods: Vec<NotificationPeriod>,
+        escalation: OverdueEscalation,
     ) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(reminder) = self.reminders.iter_mut().find(|r| r.id == id) {
+            if reminder.due_date != due_date {
+                reminder.overdue_notified = false;
+            }
             reminder.title = title;
             reminder.description = description;
             reminder.due_date = due_date;
             reminder.notification_periods = notification_periods;
+            reminder.escalation = escalation;
+            reminder.snoozed_until = None;
             self.save()?;
         }
         Ok(())
@@ -443,20 +1835,58 @@ impl StudyData {
         Ok(completed)
     }
 
+    // Marks done from a notification, where toggling could reopen it
+    pub fn complete_reminder(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(reminder) = self.reminders.iter_mut().find(|r| r.id == id) {
+            reminder.is_completed = true;
+            reminder.snoozed_until = None;
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn mark_overdue_notified(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(reminder) = self.reminders.iter_mut().find(|r| r.id == id) {
+            reminder.overdue_notified = true;
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn snooze_reminder(
+        &mut self,
+        id: u64,
+        until: NaiveDateTime,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(reminder) = self.reminders.iter_mut().find(|r| r.id == id) {
+            reminder.snoozed_until = Some(until.format("%Y-%m-%d %H:%M").to_string());
+        }
+        self.save()?;
+        Ok(())
+    }
+
     pub fn delete_reminder(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
-        self.reminders.retain(|r| r.id != id);
+        if let Some(index) = self.reminders.iter().position(|r| r.id == id) {
+            let reminder = self.reminders.remove(index);
+            self.move_to_trash(vec![TrashedItem::Reminder(reminder)]);
+        }
         self.save()?;
         Ok(())
     }
 
     pub fn clear_reminders(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        self.reminders.clear();
+        let reminders = std::mem::take(&mut self.reminders);
+        self.move_to_trash(reminders.into_iter().map(TrashedItem::Reminder).collect());
         self.save()?;
         Ok(())
     }
 
     pub fn clear_completed_reminders(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        self.reminders.retain(|r| !r.is_completed);
+        let (completed, open): (Vec<Reminder>, Vec<Reminder>) = std::mem::take(&mut self.reminders)
+            .into_iter()
+            .partition(|r| r.is_completed);
+        self.reminders = open;
+        self.move_to_trash(completed.into_iter().map(TrashedItem::Reminder).collect());
         self.save()?;
         Ok(())
     }
@@ -469,20 +1899,350 @@ impl StudyData {
         }
     }
 
+    pub fn add_reading_item(
+        &mut self,
+        path: String,
+        section: Option<String>,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        if self
+            .reading_queue
+            .iter()
+            .any(|item| item.path == path && item.section == section)
+        {
+            return Ok(false);
+        }
+
+        let today = Local::now().date_naive();
+        let id = self.reading_queue.iter().map(|i| i.id).max().unwrap_or(0) + 1;
+        self.reading_queue.push(ReadingItem {
+            id,
+            path,
+            section,
+            added_at: today.format("%Y-%m-%d").to_string(),
+            due_date: (today + Duration::days(1)).format("%Y-%m-%d").to_string(),
+            interval: 1,
+            times_read: 0,
+        });
+        self.save()?;
+        Ok(true)
+    }
+
+    pub fn get_due_reading_items(&self) -> Vec<&ReadingItem> {
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let mut due: Vec<&ReadingItem> = self
+            .reading_queue
+            .iter()
+            .filter(|item| item.due_date <= today)
+            .collect();
+        due.sort_by(|a, b| a.due_date.cmp(&b.due_date));
+        due
+    }
+
+    // Intervals grow with each read so notes resurface less often over time
+    pub fn review_reading_item(
+        &mut self,
+        id: u64,
+        rating: ReadingRating,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(item) = self.reading_queue.iter_mut().find(|i| i.id == id) {
+            let interval = item.interval.max(1) as f32;
+            item.interval = match rating {
+                ReadingRating::Sooner => (interval / 2.0).round().max(1.0) as u32,
+                ReadingRating::Good => (interval * 1.5).round().max(interval + 1.0) as u32,
+                ReadingRating::Later => (interval * 2.5).round() as u32,
+            };
+            item.due_date = (Local::now().date_naive() + Duration::days(item.interval as i64))
+                .format("%Y-%m-%d")
+                .to_string();
+            item.times_read += 1;
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    pub fn remove_reading_item(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        self.reading_queue.retain(|item| item.id != id);
+        self.save()?;
+        Ok(())
+    }
+
     pub fn get_due_cards_count(&self) -> usize {
         self.decks
             .iter()
-            .flat_map(|deck| deck.get_due_cards(true))
-            .count()
+            .map(|deck| deck.get_due_cards(true).len() + deck.get_due_reverse_cards(true).len())
+            .sum()
+    }
+
+    // Trash methods
+    pub fn move_to_trash(&mut self, items: Vec<TrashedItem>) {
+        if items.is_empty() {
+            return;
+        }
+        let batch = self.last_trash_batch() + 1;
+        let mut next_id = self.trash.iter().map(|e| e.id).max().unwrap_or(0) + 1;
+        let deleted_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        for item in items {
+            self.trash.push(TrashEntry {
+                id: next_id,
+                batch,
+                deleted_at: deleted_at.clone(),
+                item,
+            });
+            next_id += 1;
+        }
+    }
+
+    pub fn last_trash_batch(&self) -> u64 {
+        self.trash.iter().map(|e| e.batch).max().unwrap_or(0)
+    }
+
+    pub fn restore_from_trash(&mut self, entry_id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        let index = self
+            .trash
+            .iter()
+            .position(|e| e.id == entry_id)
+            .ok_or("Item is no longer in the trash")?;
+        self.restore_item(self.trash[index].item.clone())?;
+        self.trash.remove(index);
+        self.save()?;
+        Ok(())
+    }
+
+    // Restores everything removed by the most recent delete; returns how many items came back
+    pub fn undo_last_delete(&mut self) -> Result<usize, Box<dyn std::error::Error>> {
+        let batch = self.last_trash_batch();
+        let (mut entries, rest): (Vec<TrashEntry>, Vec<TrashEntry>) =
+            std::mem::take(&mut self.trash)
+                .into_iter()
+                .partition(|e| e.batch == batch);
+        self.trash = rest;
+        // Decks first, so cards deleted along with their deck find it again
+        entries.sort_by_key(|e| !matches!(e.item, TrashedItem::Deck(_)));
+
+        let mut restored = 0;
+        let mut errors = Vec::new();
+        for entry in entries {
+            match self.restore_item(entry.item.clone()) {
+                Ok(()) => restored += 1,
+                Err(e) => {
+                    errors.push(e.to_string());
+                    self.trash.push(entry);
+                }
+            }
+        }
+        self.save()?;
+
+        if errors.is_empty() {
+            Ok(restored)
+        } else {
+            Err(errors.join("; ").into())
+        }
+    }
+
+    // Puts an item back, giving it a new id if something else took its old one meanwhile
+    fn restore_item(&mut self, item: TrashedItem) -> Result<(), Box<dyn std::error::Error>> {
+        match item {
+            TrashedItem::Todo(mut todo) => {
+                if self.todos.iter().any(|t| t.id == todo.id) {
+                    todo.id = self.get_next_todo_id();
+                }
+                self.todos.push(todo);
+            }
+            TrashedItem::Habit(mut habit) => {
+                if self.habits.iter().any(|h| h.id == habit.id) {
+                    habit.id = self.get_next_habit_id();
+                }
+                self.habits.push(habit);
+            }
+            TrashedItem::Reminder(mut reminder) => {
+                if self.reminders.iter().any(|r| r.id == reminder.id) {
+                    reminder.id = self.get_next_reminder_id();
+                }
+                self.reminders.push(reminder);
+            }
+            TrashedItem::Assignment(mut assignment) => {
+                if self.assignments.iter().any(|a| a.id == assignment.id) {
+                    assignment.id = self.assignments.iter().map(|a| a.id).max().unwrap_or(0) + 1;
+                }
+                self.assignments.push(assignment);
+            }
+            TrashedItem::Reference(mut reference) => {
+                if self.references.iter().any(|r| r.id == reference.id) {
+                    reference.id = self.references.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+                }
+                if self.references.iter().any(|r| r.key == reference.key) {
+                    reference.key = self.unique_reference_key(&reference.key);
+                }
+                self.references.push(reference);
+            }
+            TrashedItem::Deck(mut deck) => {
+                if self.decks.iter().any(|d| d.id == deck.id) {
+                    deck.id = self.decks.iter().map(|d| d.id).max().unwrap_or(0) + 1;
+                    for card in deck.cards.iter_mut() {
+                        card.deck_id = deck.id;
+                    }
+                }
+                self.next_deck_id = self.next_deck_id.max(deck.id + 1);
+                self.decks.push(deck);
+            }
+            TrashedItem::Session(session) => self.sessions.push(session),
+            TrashedItem::JournalEntry(entry) => {
+                if self.journal.iter().any(|e| e.date == entry.date) {
+                    return Err(format!(
+                        "There is already a journal entry for {}, delete it first",
+                        entry.date
+                    )
+                    .into());
+                }
+                let index = self.journal.partition_point(|e| e.date < entry.date);
+                self.journal.insert(index, entry);
+            }
+            TrashedItem::Card {
+                deck_id,
+                deck_name,
+                card,
+            } => {
+                let deck = self
+                    .decks
+                    .iter_mut()
+                    .find(|d| d.id == deck_id)
+                    .ok_or_else(|| {
+                        format!("Deck \"{}\" no longer exists, restore it first", deck_name)
+                    })?;
+                if deck.cards.iter().any(|c| c.id == card.id) {
+                    deck.add_card(card);
+                } else {
+                    deck.cards.push(card);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn delete_from_trash(&mut self, entry_id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        self.trash.retain(|e| e.id != entry_id);
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn empty_trash(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.trash.clear();
+        self.save()?;
+        Ok(())
+    }
+
+    // Archive methods
+    fn move_to_archive(&mut self, items: Vec<ArchivedItem>) {
+        let mut next_id = self.archive.iter().map(|e| e.id).max().unwrap_or(0) + 1;
+        let archived_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        for item in items {
+            self.archive.push(ArchiveEntry {
+                id: next_id,
+                archived_at: archived_at.clone(),
+                item,
+            });
+            next_id += 1;
+        }
+    }
+
+    // Returns how many todos were archived
+    pub fn archive_completed_todos(&mut self) -> Result<usize, Box<dyn std::error::Error>> {
+        let (completed, open): (Vec<Todo>, Vec<Todo>) = std::mem::take(&mut self.todos)
+            .into_iter()
+            .partition(|t| t.completed);
+        self.todos = open;
+        let count = completed.len();
+        self.move_to_archive(completed.into_iter().map(ArchivedItem::Todo).collect());
+        self.save()?;
+        Ok(count)
+    }
+
+    pub fn archive_completed_reminders(&mut self) -> Result<usize, Box<dyn std::error::Error>> {
+        let (completed, open): (Vec<Reminder>, Vec<Reminder>) = std::mem::take(&mut self.reminders)
+            .into_iter()
+            .partition(|r| r.is_completed);
+        self.reminders = open;
+        let count = completed.len();
+        self.move_to_archive(completed.into_iter().map(ArchivedItem::Reminder).collect());
+        self.save()?;
+        Ok(count)
+    }
+
+    // For habits that are finished for good; their completions still count in stats
+    pub fn archive_habit(&mut self, id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(index) = self.habits.iter().position(|h| h.id == id) {
+            let habit = self.habits.remove(index);
+            self.move_to_archive(vec![ArchivedItem::Habit(habit)]);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    pub fn restore_from_archive(
+        &mut self,
+        entry_id: u64,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let index = self
+            .archive
+            .iter()
+            .position(|e| e.id == entry_id)
+            .ok_or("Item is no longer in the archive")?;
+        self.restore_item(self.archive[index].item.clone().into())?;
+        self.archive.remove(index);
+        self.save()?;
+        Ok(())
+    }
+
+    // Deleting from the archive goes through the trash like any other delete
+    pub fn delete_from_archive(&mut self, entry_id: u64) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(index) = self.archive.iter().position(|e| e.id == entry_id) {
+            let entry = self.archive.remove(index);
+            self.move_to_trash(vec![entry.item.into()]);
+        }
+        self.save()?;
+        Ok(())
+    }
+
+    // Active and archived habits, for stats that cover the past
+    pub fn habit_history(&self) -> impl Iterator<Item = &Habit> {
+        self.habits
+            .iter()
+            .chain(self.archive.iter().filter_map(|e| match &e.item {
+                ArchivedItem::Habit(habit) => Some(habit),
+                _ => None,
+            }))
+    }
+
+    pub fn todo_history(&self) -> impl Iterator<Item = &Todo> {
+        self.todos
+            .iter()
+            .chain(self.archive.iter().filter_map(|e| match &e.item {
+                ArchivedItem::Todo(todo) => Some(todo),
+                _ => None,
+            }))
+    }
+
+    // Drops items deleted more than TRASH_RETENTION_DAYS ago; saved with the next change
+    pub fn purge_expired_trash(&mut self) {
+        let cutoff = Local::now().naive_local() - Duration::days(TRASH_RETENTION_DAYS);
+        self.trash.retain(|e| {
+            chrono::NaiveDateTime::parse_from_str(&e.deleted_at, "%Y-%m-%d %H:%M:%S")
+                .map_or(true, |deleted| deleted > cutoff)
+        });
     }
 }
 
-#[allow(dead_code)]
+// Weeks shown in the habit trend sparkline
+const HABIT_TREND_WEEKS: usize = 12;
+
 #[derive(Debug, Clone)]
 pub struct HabitStats {
     pub current_streak: u32,
+    pub best_streak: u32,
     pub total_completions: usize,
     pub completion_rate_7_days: f32,
     pub completion_rate_30_days: f32,
+    pub weekday_completions: [usize; 7], // Monday first
+    pub weekly_trend: Vec<usize>,        // Completions per week, oldest first, ending this week
 }
 
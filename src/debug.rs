@@ -1,10 +1,28 @@
+use crate::app::Tab;
 use crate::timer::Timer;
 use eframe::egui;
 use eframe::egui::Ui;
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Duration;
+
+const FRAME_HISTORY: usize = 120;
+
+// Smoothed and worst render time for one tab type
+#[derive(Default)]
+pub struct TabTiming {
+    pub average_ms: f32,
+    pub max_ms: f32,
+    pub samples: u64,
+}
 
 pub struct DebugTools {
     pub enabled: bool,
     pub time_to_add: f64, // Minutes to add
+    pub show_overlay: bool,
+    pub continuous_repaint: bool,
+    frame_times: VecDeque<f32>,     // CPU time spent in update, in ms
+    frame_intervals: VecDeque<f32>, // Time between frames, in ms
+    tab_timings: BTreeMap<String, TabTiming>,
 }
 
 impl DebugTools {
@@ -12,9 +30,152 @@ impl DebugTools {
         Self {
             enabled: false,
             time_to_add: 5.0, // Default 5 minutes
+            show_overlay: false,
+            continuous_repaint: false,
+            frame_times: VecDeque::with_capacity(FRAME_HISTORY),
+            frame_intervals: VecDeque::with_capacity(FRAME_HISTORY),
+            tab_timings: BTreeMap::new(),
+        }
+    }
+
+    fn profiling(&self) -> bool {
+        self.enabled && self.show_overlay
+    }
+
+    pub fn record_tab(&mut self, tab: &Tab, elapsed: Duration) {
+        if !self.profiling() {
+            return;
+        }
+        let ms = elapsed.as_secs_f32() * 1000.0;
+        let timing = self.tab_timings.entry(format!("{:?}", tab)).or_default();
+        timing.samples += 1;
+        // Exponential moving average so one slow frame doesn't hide the trend
+        timing.average_ms = if timing.samples == 1 {
+            ms
+        } else {
+            timing.average_ms * 0.9 + ms * 0.1
+        };
+        timing.max_ms = timing.max_ms.max(ms);
+    }
+
+    // Called at the end of App::update with the time the frame took to build
+    pub fn end_frame(&mut self, ctx: &egui::Context, elapsed: Duration) {
+        if !self.profiling() {
+            return;
+        }
+
+        push_sample(&mut self.frame_times, elapsed.as_secs_f32() * 1000.0);
+        push_sample(
+            &mut self.frame_intervals,
+            ctx.input(|i| i.unstable_dt) * 1000.0,
+        );
+
+        self.overlay(ctx);
+
+        if self.continuous_repaint {
+            ctx.request_repaint();
         }
     }
 
+    fn overlay(&self, ctx: &egui::Context) {
+        let (average, worst) = summarize(&self.frame_times);
+        let (interval, _) = summarize(&self.frame_intervals);
+        let fps = if interval > 0.0 {
+            1000.0 / interval
+        } else {
+            0.0
+        };
+
+        // Bottom left, so it stays clear of the toasts in the bottom right
+        egui::Area::new("debug_frame_overlay")
+            .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(10.0, -10.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{:.0} FPS  |  frame {:.2} ms avg, {:.2} ms max",
+                            fps, average, worst
+                        ))
+                        .monospace(),
+                    );
+
+                    // Frame-time graph, scaled so 16.7 ms (60 FPS) sits halfway up
+                    let (rect, _) =
+                        ui.allocate_exact_size(egui::vec2(240.0, 40.0), egui::Sense::hover());
+                    let painter = ui.painter_at(rect);
+                    let scale = rect.height() / 33.3;
+                    let budget_y = rect.bottom() - 16.7 * scale;
+                    painter.hline(
+                        rect.x_range(),
+                        budget_y,
+                        egui::Stroke::new(1.0, egui::Color32::from_gray(90)),
+                    );
+                    let bar_width = rect.width() / FRAME_HISTORY as f32;
+                    for (i, ms) in self.frame_times.iter().enumerate() {
+                        let x = rect.left() + i as f32 * bar_width;
+                        let top = (rect.bottom() - ms * scale).max(rect.top());
+                        let color = if *ms > 16.7 {
+                            egui::Color32::from_rgb(230, 159, 0)
+                        } else {
+                            egui::Color32::from_rgb(86, 180, 233)
+                        };
+                        painter.rect_filled(
+                            egui::Rect::from_min_max(
+                                egui::pos2(x, top),
+                                egui::pos2(x + bar_width, rect.bottom()),
+                            ),
+                            0.0,
+                            color,
+                        );
+                    }
+                });
+            });
+    }
+
+    fn profiler_ui(&mut self, ui: &mut Ui) {
+        ui.heading("Profiler");
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.show_overlay, "Frame-time overlay");
+            ui.checkbox(&mut self.continuous_repaint, "Repaint every frame")
+                .on_hover_text("Keep rendering while idle so the numbers stay live");
+            if ui.button("Reset").clicked() {
+                self.frame_times.clear();
+                self.frame_intervals.clear();
+                self.tab_timings.clear();
+            }
+        });
+
+        if !self.show_overlay {
+            ui.label("Turn on the overlay to collect frame and tab timings.");
+        } else if self.tab_timings.is_empty() {
+            ui.label("No tab timings yet.");
+        } else {
+            egui::Grid::new("debug_tab_timings")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Tab");
+                    ui.strong("Avg ms");
+                    ui.strong("Max ms");
+                    ui.strong("Frames");
+                    ui.end_row();
+
+                    for (name, timing) in &self.tab_timings {
+                        ui.label(name);
+                        ui.label(format!("{:.2}", timing.average_ms));
+                        ui.label(format!("{:.2}", timing.max_ms));
+                        ui.label(timing.samples.to_string());
+                        ui.end_row();
+                    }
+                });
+        }
+
+        ui.add_space(5.0);
+        texture_report(ui);
+    }
+
     pub fn ui(&mut self, ui: &mut Ui, timer: &mut Timer) -> Option<String> {
         let mut message = None;
 
@@ -74,7 +235,61 @@ impl DebugTools {
             hours, minutes, seconds
         ));
 
+        ui.separator();
+        self.profiler_ui(ui);
+
         message
     }
 }
 
+fn push_sample(samples: &mut VecDeque<f32>, value: f32) {
+    if samples.len() == FRAME_HISTORY {
+        samples.pop_front();
+    }
+    samples.push_back(value);
+}
+
+// (average, max) of the samples, 0 when empty
+fn summarize(samples: &VecDeque<f32>) -> (f32, f32) {
+    if samples.is_empty() {
+        return (0.0, 0.0);
+    }
+    let sum: f32 = samples.iter().sum();
+    let max = samples.iter().cloned().fold(0.0, f32::max);
+    (sum / samples.len() as f32, max)
+}
+
+// Lists GPU textures egui has allocated, largest first, to spot leaked or oversized images
+fn texture_report(ui: &mut Ui) {
+    let mut textures: Vec<(String, [usize; 2], usize)> = ui
+        .ctx()
+        .tex_manager()
+        .read()
+        .allocated()
+        .map(|(_, meta)| (meta.name.clone(), meta.size, meta.bytes_used()))
+        .collect();
+    textures.sort_by_key(|t| std::cmp::Reverse(t.2));
+    let total: usize = textures.iter().map(|t| t.2).sum();
+
+    egui::CollapsingHeader::new(format!(
+        "Textures: {} allocated, {:.1} MB",
+        textures.len(),
+        total as f64 / (1024.0 * 1024.0)
+    ))
+    .id_source("debug_texture_report")
+    .show(ui, |ui| {
+        egui::ScrollArea::vertical()
+            .max_height(200.0)
+            .show(ui, |ui| {
+                for (name, size, bytes) in &textures {
+                    ui.label(format!(
+                        "{}  {}x{}  {:.1} KB",
+                        name,
+                        size[0],
+                        size[1],
+                        *bytes as f64 / 1024.0
+                    ));
+                }
+            });
+    });
+}
@@ -0,0 +1,491 @@
+use crate::data::{StudyData, TrashedItem};
+use crate::error;
+use crate::image_handler::CardImage;
+use crate::task_sync::{curl, curl_config_line};
+use crate::ui::flashcard::{Card, Deck};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+// Shared decks live on a small JSON server: GET /decks/<code> returns the latest copy
+// and PUT /decks/<code> replaces it when sent with the maintainer's token. Only card
+// content travels; reviews and scheduling never leave this computer.
+
+const AUTO_PULL_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedCard {
+    pub uid: String,
+    pub front: String,
+    pub back: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub front_image: Option<CardImage>,
+    #[serde(default)]
+    pub back_image: Option<CardImage>,
+    #[serde(default)]
+    pub reverse: bool,
+    #[serde(default)]
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedDeck {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub version: u64, // Bumped by every publish
+    #[serde(default)]
+    pub updated_at: String,
+    pub cards: Vec<SharedCard>,
+}
+
+impl SharedDeck {
+    // Content of a local deck ready to publish; every card must already have a uid
+    fn from_deck(deck: &Deck, version: u64) -> Self {
+        let mut cards: Vec<SharedCard> = deck
+            .cards
+            .iter()
+            .filter_map(|card| {
+                Some(SharedCard {
+                    uid: card.shared_uid.clone()?,
+                    front: card.front.clone(),
+                    back: card.back.clone(),
+                    tags: card.tags.iter().cloned().collect(),
+                    front_image: card.front_image.clone(),
+                    back_image: card.back_image.clone(),
+                    reverse: card.reverse,
+                    notes: card.notes.clone(),
+                })
+            })
+            .collect();
+        cards.iter_mut().for_each(|card| card.tags.sort());
+
+        Self {
+            name: deck.name.clone(),
+            description: deck.description.clone(),
+            version,
+            updated_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            cards,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Subscription {
+    pub code: String,             // Shared deck name on the server
+    pub deck_id: Option<u64>,     // Local copy, created on the first pull
+    pub maintainer_token: String, // Empty for members, who only receive updates
+    pub version: u64,             // Last version pulled or published
+    pub last_sync: Option<String>,
+}
+
+impl Subscription {
+    pub fn is_maintainer(&self) -> bool {
+        !self.maintainer_token.trim().is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeckSyncConfig {
+    pub server_url: String,
+    pub auto_pull: bool,
+    pub subscriptions: Vec<Subscription>,
+}
+
+impl DeckSyncConfig {
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    pub fn subscribe(&mut self, code: &str, maintainer_token: &str, deck_id: Option<u64>) {
+        let code = code.trim();
+        if let Some(existing) = self.subscriptions.iter_mut().find(|s| s.code == code) {
+            existing.maintainer_token = maintainer_token.trim().to_string();
+            if deck_id.is_some() {
+                existing.deck_id = deck_id;
+            }
+            return;
+        }
+        self.subscriptions.push(Subscription {
+            code: code.to_string(),
+            deck_id,
+            maintainer_token: maintainer_token.trim().to_string(),
+            version: 0,
+            last_sync: None,
+        });
+    }
+}
+
+fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut path = crate::profile::config_dir().ok_or("Could not find config directory")?;
+    path.push("deck_sync.json");
+    Ok(path)
+}
+
+#[derive(Debug, Clone)]
+pub enum DeckSyncStatus {
+    Idle,
+    Syncing,
+    Synced(String),
+    Failed(String),
+}
+
+enum SyncResult {
+    Pulled(Vec<(String, SharedDeck)>),
+    Published(String, u64),
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct MergeCounts {
+    added: usize,
+    updated: usize,
+    removed: usize,
+}
+
+pub struct DeckSync {
+    pub config: DeckSyncConfig,
+    pub status: DeckSyncStatus,
+    receiver: Option<Receiver<Result<SyncResult, String>>>,
+    last_pull: Option<Instant>,
+}
+
+impl DeckSync {
+    pub fn new() -> Self {
+        Self {
+            config: DeckSyncConfig::load().unwrap_or_default(),
+            status: DeckSyncStatus::Idle,
+            receiver: None,
+            last_pull: None,
+        }
+    }
+
+    pub fn is_syncing(&self) -> bool {
+        self.receiver.is_some()
+    }
+
+    pub fn status_text(&self) -> String {
+        match &self.status {
+            DeckSyncStatus::Idle => "Not synced this session".to_string(),
+            DeckSyncStatus::Syncing => "⟳ Syncing...".to_string(),
+            DeckSyncStatus::Synced(summary) => summary.clone(),
+            DeckSyncStatus::Failed(error) => format!("⚠ {}", error),
+        }
+    }
+
+    // Fetches every shared deck this user only receives
+    pub fn pull(&mut self) {
+        if self.is_syncing() || self.config.server_url.trim().is_empty() {
+            return;
+        }
+
+        let server = self.config.server_url.clone();
+        let codes: Vec<String> = self
+            .config
+            .subscriptions
+            .iter()
+            .filter(|s| !s.is_maintainer())
+            .map(|s| s.code.clone())
+            .collect();
+        self.last_pull = Some(Instant::now());
+        if codes.is_empty() {
+            return;
+        }
+
+        self.spawn(move || {
+            let mut pulled: Vec<(String, SharedDeck)> = Vec::new();
+            for code in codes {
+                let body = curl(&[deck_url(&server, &code)], "")?;
+                pulled.push((code, serde_json::from_str(&body)?));
+            }
+            Ok(SyncResult::Pulled(pulled))
+        });
+    }
+
+    // Uploads the local copy of a deck this user maintains
+    pub fn publish(&mut self, study_data: &mut StudyData, code: &str) -> Result<(), String> {
+        if self.is_syncing() {
+            return Err("A sync is already running".to_string());
+        }
+        let subscription = self
+            .config
+            .subscriptions
+            .iter()
+            .find(|s| s.code == code && s.is_maintainer())
+            .ok_or("Only the deck's maintainer can publish it")?;
+        let deck = subscription
+            .deck_id
+            .and_then(|id| study_data.decks.iter_mut().find(|d| d.id == id))
+            .ok_or("The local deck for this share no longer exists")?;
+
+        // Cards need a stable uid before members can track them
+        let mut assigned = false;
+        for card in deck.cards.iter_mut().filter(|c| c.shared_uid.is_none()) {
+            card.shared_uid = Some(uuid::Uuid::new_v4().to_string());
+            assigned = true;
+        }
+        let shared = SharedDeck::from_deck(deck, subscription.version + 1);
+        if assigned {
+            study_data.save().map_err(|e| e.to_string())?;
+        }
+
+        let url = deck_url(&self.config.server_url, code);
+        let token = subscription.maintainer_token.clone();
+        let code = code.to_string();
+        self.spawn(move || {
+            let body = serde_json::to_string(&shared)?;
+            curl(
+                &[
+                    "-X".to_string(),
+                    "PUT".to_string(),
+                    "-H".to_string(),
+                    "Content-Type: application/json".to_string(),
+                    "--data-binary".to_string(),
+                    body,
+                    url,
+                ],
+                &curl_config_line("header", &format!("Authorization: Bearer {}", token)),
+            )?;
+            Ok(SyncResult::Published(code, shared.version))
+        });
+        Ok(())
+    }
+
+    fn spawn<F>(&mut self, job: F)
+    where
+        F: FnOnce() -> Result<SyncResult, Box<dyn std::error::Error>> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(job().map_err(|e| e.to_string()));
+        });
+        self.receiver = Some(receiver);
+        self.status = DeckSyncStatus::Syncing;
+    }
+
+    // Called every frame; pulls periodically and merges finished downloads
+    pub fn update(&mut self, study_data: &mut StudyData) -> Option<String> {
+        if self.config.auto_pull && !self.is_syncing() {
+            let due = self
+                .last_pull
+                .map_or(true, |last| last.elapsed() >= AUTO_PULL_INTERVAL);
+            if due {
+                self.pull();
+            }
+        }
+
+        let result = match &self.receiver {
+            Some(receiver) => match receiver.try_recv() {
+                Ok(result) => result,
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => Err("sync worker stopped".to_string()),
+            },
+            None => return None,
+        };
+        self.receiver = None;
+
+        let now = Local::now().format("%Y-%m-%d %H:%M").to_string();
+        let summary = match result {
+            Ok(SyncResult::Published(code, version)) => {
+                if let Some(subscription) = self
+                    .config
+                    .subscriptions
+                    .iter_mut()
+                    .find(|s| s.code == code)
+                {
+                    subscription.version = version;
+                    subscription.last_sync = Some(now);
+                }
+                format!("Published {} (version {})", code, version)
+            }
+            Ok(SyncResult::Pulled(decks)) => {
+                let mut changed = Vec::new();
+                for (code, shared) in decks {
+                    let Some(subscription) = self
+                        .config
+                        .subscriptions
+                        .iter_mut()
+                        .find(|s| s.code == code)
+                    else {
+                        continue;
+                    };
+                    subscription.last_sync = Some(now.clone());
+                    let missing = subscription
+                        .deck_id
+                        .map_or(true, |id| !study_data.decks.iter().any(|d| d.id == id));
+                    if shared.version <= subscription.version && !missing {
+                        continue;
+                    }
+
+                    let counts = apply_shared_deck(study_data, subscription, &shared);
+                    subscription.version = shared.version;
+                    if counts != MergeCounts::default() {
+                        changed.push(format!(
+                            "{}: {} new, {} updated, {} removed",
+                            shared.name, counts.added, counts.updated, counts.removed
+                        ));
+                    }
+                }
+
+                if !changed.is_empty() {
+                    if let Err(e) = study_data.save() {
+                        self.status = DeckSyncStatus::Failed(e.to_string());
+                        return Some(format!("Error saving shared decks: {}", e));
+                    }
+                }
+                if changed.is_empty() {
+                    self.status = DeckSyncStatus::Synced(format!("Up to date as of {}", now));
+                    if let Err(e) = self.config.save() {
+                        error::report("Could not save shared deck state", e);
+                    }
+                    return None;
+                }
+                format!("Shared decks updated. {}", changed.join("; "))
+            }
+            Err(e) => {
+                self.status = DeckSyncStatus::Failed(e.clone());
+                return Some(format!("Shared deck sync failed: {}", e));
+            }
+        };
+
+        if let Err(e) = self.config.save() {
+            error::report("Could not save shared deck state", e);
+        }
+        self.status = DeckSyncStatus::Synced(summary.clone());
+        Some(summary)
+    }
+}
+
+// Merges a downloaded deck into the member's copy, creating it on the first pull
+fn apply_shared_deck(
+    study_data: &mut StudyData,
+    subscription: &mut Subscription,
+    shared: &SharedDeck,
+) -> MergeCounts {
+    let index = subscription
+        .deck_id
+        .and_then(|id| study_data.decks.iter().position(|d| d.id == id));
+    let index = match index {
+        Some(index) => index,
+        None => {
+            let mut deck = Deck::new(shared.name.clone(), shared.description.clone());
+            deck.id = study_data.decks.iter().map(|d| d.id).max().unwrap_or(0) + 1;
+            study_data.next_deck_id = study_data.next_deck_id.max(deck.id + 1);
+            subscription.deck_id = Some(deck.id);
+            study_data.decks.push(deck);
+            study_data.decks.len() - 1
+        }
+    };
+
+    let deck = &mut study_data.decks[index];
+    let (counts, removed) = merge_cards(deck, shared);
+    let trashed = removed
+        .into_iter()
+        .map(|card| TrashedItem::Card {
+            deck_id: deck.id,
+            deck_name: deck.name.clone(),
+            card,
+        })
+        .collect();
+    study_data.move_to_trash(trashed);
+    counts
+}
+
+// Updates card content in place so reviews and scheduling stay with the member.
+// Cards the member added themselves have no uid and are left alone.
+fn merge_cards(deck: &mut Deck, shared: &SharedDeck) -> (MergeCounts, Vec<Card>) {
+    let mut counts = MergeCounts::default();
+    deck.name = shared.name.clone();
+    deck.description = shared.description.clone();
+
+    for remote in &shared.cards {
+        let tags: HashSet<String> = remote.tags.iter().cloned().collect();
+        match deck
+            .cards
+            .iter_mut()
+            .find(|c| c.shared_uid.as_deref() == Some(remote.uid.as_str()))
+        {
+            Some(card) => {
+                let same_images = card.front_image.as_ref().map(|i| &i.id)
+                    == remote.front_image.as_ref().map(|i| &i.id)
+                    && card.back_image.as_ref().map(|i| &i.id)
+                        == remote.back_image.as_ref().map(|i| &i.id);
+                if card.front != remote.front
+                    || card.back != remote.back
+                    || card.tags != tags
+                    || card.reverse != remote.reverse
+                    || card.notes != remote.notes
+                    || !same_images
+                {
+                    card.front = remote.front.clone();
+                    card.back = remote.back.clone();
+                    card.tags = tags;
+                    card.reverse = remote.reverse;
+                    card.notes = remote.notes.clone();
+                    card.front_image = remote.front_image.clone();
+                    card.back_image = remote.back_image.clone();
+                    counts.updated += 1;
+                }
+            }
+            None => {
+                let mut card = Card::new(deck.id, remote.front.clone(), remote.back.clone());
+                card.tags = tags;
+                card.reverse = remote.reverse;
+                card.notes = remote.notes.clone();
+                card.front_image = remote.front_image.clone();
+                card.back_image = remote.back_image.clone();
+                card.shared_uid = Some(remote.uid.clone());
+                deck.add_card(card);
+                counts.added += 1;
+            }
+        }
+    }
+
+    let uids: HashSet<&str> = shared.cards.iter().map(|c| c.uid.as_str()).collect();
+    let (removed, kept): (Vec<Card>, Vec<Card>) =
+        std::mem::take(&mut deck.cards).into_iter().partition(|c| {
+            c.shared_uid
+                .as_deref()
+                .map_or(false, |uid| !uids.contains(uid))
+        });
+    deck.cards = kept;
+    counts.removed = removed.len();
+    (counts, removed)
+}
+
+fn deck_url(server: &str, code: &str) -> String {
+    let code: String = code
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}/decks/{}", server.trim().trim_end_matches('/'), code)
+}
@@ -0,0 +1,140 @@
+use crate::error;
+use crate::settings::AppSettings;
+use crate::timer::Timer;
+use discord_rich_presence::{activity, DiscordIpc, DiscordIpcClient};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// Discord rate-limits activity updates to one every 15 seconds
+const UPDATE_INTERVAL: Duration = Duration::from_secs(15);
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+pub struct DiscordPresence {
+    client: Option<DiscordIpcClient>,
+    connected_client_id: String,
+    last_update: Option<Instant>,
+    last_connect_attempt: Option<Instant>,
+    activity_set: bool,
+}
+
+impl DiscordPresence {
+    pub fn new() -> Self {
+        Self {
+            client: None,
+            connected_client_id: String::new(),
+            last_update: None,
+            last_connect_attempt: None,
+            activity_set: false,
+        }
+    }
+
+    pub fn update(&mut self, settings: &AppSettings, timer: &Timer, subject: &str) {
+        let client_id = settings.discord_client_id.trim();
+        if !settings.discord_presence_enabled || client_id.is_empty() {
+            self.disconnect();
+            return;
+        }
+
+        if self.client.is_some() && self.connected_client_id != client_id {
+            self.disconnect();
+        }
+
+        if !timer.is_running {
+            self.clear();
+            return;
+        }
+
+        if self.client.is_none() && !self.connect(client_id) {
+            return;
+        }
+
+        let details = if subject.trim().is_empty() {
+            "Studying".to_string()
+        } else {
+            format!("Studying {}", subject.trim())
+        };
+
+        let due = self
+            .last_update
+            .map_or(true, |last| last.elapsed() >= UPDATE_INTERVAL);
+        if !due {
+            return;
+        }
+
+        let elapsed = timer.get_elapsed_time();
+        let state = format!(
+            "{:02}:{:02} elapsed",
+            elapsed.as_secs() / 60,
+            elapsed.as_secs() % 60
+        );
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        let started_at = now - elapsed.as_secs() as i64;
+
+        let payload = activity::Activity::new()
+            .details(&details)
+            .state(&state)
+            .timestamps(activity::Timestamps::new().start(started_at));
+
+        self.last_update = Some(Instant::now());
+        let result = match self.client.as_mut() {
+            Some(client) => client.set_activity(payload),
+            None => return,
+        };
+
+        match result {
+            Ok(()) => self.activity_set = true,
+            Err(_) => {
+                // Discord was probably closed; try again later
+                self.client = None;
+                self.activity_set = false;
+            }
+        }
+    }
+
+    fn connect(&mut self, client_id: &str) -> bool {
+        if let Some(last) = self.last_connect_attempt {
+            if last.elapsed() < RECONNECT_INTERVAL {
+                return false;
+            }
+        }
+        self.last_connect_attempt = Some(Instant::now());
+
+        let mut client = match DiscordIpcClient::new(client_id) {
+            Ok(client) => client,
+            Err(e) => {
+                error::report("Could not start Discord Rich Presence", e);
+                return false;
+            }
+        };
+
+        if client.connect().is_err() {
+            // Discord isn't running, which is the common case
+            return false;
+        }
+
+        self.client = Some(client);
+        self.connected_client_id = client_id.to_string();
+        self.last_update = None;
+        true
+    }
+
+    fn clear(&mut self) {
+        if !self.activity_set {
+            return;
+        }
+        if let Some(client) = self.client.as_mut() {
+            let _ = client.clear_activity();
+        }
+        self.activity_set = false;
+        self.last_update = None;
+    }
+
+    pub fn disconnect(&mut self) {
+        self.clear();
+        if let Some(mut client) = self.client.take() {
+            let _ = client.close();
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("file error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid data: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("image error: {0}")]
+    Image(#[from] image::ImageError),
+    #[error("clipboard error: {0}")]
+    Clipboard(#[from] arboard::Error),
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<Box<dyn std::error::Error>> for AppError {
+    fn from(error: Box<dyn std::error::Error>) -> Self {
+        AppError::Other(error.to_string())
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        AppError::Other(message)
+    }
+}
+
+pub struct ReportedError {
+    pub summary: String,
+    pub details: String,
+}
+
+thread_local! {
+    static REPORTED: RefCell<Vec<ReportedError>> = RefCell::new(Vec::new());
+}
+
+// Queue an error for the app to show; for code that has no StatusMessage at hand
+pub fn report(summary: &str, error: impl Into<AppError>) {
+    let details = error.into().to_string();
+    REPORTED.with(|reported| {
+        reported.borrow_mut().push(ReportedError {
+            summary: summary.to_string(),
+            details,
+        });
+    });
+}
+
+pub fn drain_reported() -> Vec<ReportedError> {
+    REPORTED.with(|reported| reported.borrow_mut().drain(..).collect())
+}
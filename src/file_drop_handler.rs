@@ -78,6 +78,9 @@ impl FileDropHandler {
             "toml" => Some(Tab::Markdown),
             "ini" | "cfg" | "conf" => Some(Tab::Markdown),
             "log" => Some(Tab::Markdown),
+            "png" | "jpg" | "jpeg" | "gif" | "webp" | "bmp" => Some(Tab::ImageViewer),
+            "epub" => Some(Tab::EpubReader),
+            "mp4" | "mkv" | "webm" | "mov" | "avi" | "m4v" | "ogv" => Some(Tab::Video),
             _ => None, // Unsupported file type
         }
     }
@@ -0,0 +1,102 @@
+use crate::settings::AppSettings;
+use eframe::egui::{self, FontData, FontDefinitions, FontFamily, TextStyle};
+use std::path::Path;
+
+const UI_FONT: &str = "user_ui";
+const EDITOR_FONT: &str = "user_editor";
+const FALLBACK_FONT: &str = "user_fallback";
+
+// egui panics on font data it can't parse, so only accept files that look like fonts
+fn read_font(path: &Path) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let bytes = std::fs::read(path)?;
+    let is_font = bytes.len() > 4
+        && matches!(
+            &bytes[..4],
+            [0x00, 0x01, 0x00, 0x00] | b"OTTO" | b"true" | b"ttcf"
+        );
+    if !is_font {
+        return Err(format!("{} is not a TrueType or OpenType font", path.display()).into());
+    }
+    Ok(bytes)
+}
+
+fn configured(path: &Option<String>) -> Option<&Path> {
+    path.as_deref()
+        .filter(|p| !p.trim().is_empty())
+        .map(Path::new)
+}
+
+// Rebuilds egui's font table and text sizes from the settings. The UI and editor fonts
+// take priority in their families; the fallback goes last so it only fills in glyphs
+// the others lack (CJK, Arabic, Hebrew, ...)
+pub fn apply(
+    ctx: &egui::Context,
+    settings: &AppSettings,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut fonts = FontDefinitions::default();
+    let mut errors = Vec::new();
+
+    let user_fonts = [
+        (&settings.ui_font_path, UI_FONT, FontFamily::Proportional),
+        (
+            &settings.editor_font_path,
+            EDITOR_FONT,
+            FontFamily::Monospace,
+        ),
+    ];
+    for (path, name, family) in user_fonts {
+        if let Some(path) = configured(path) {
+            match read_font(path) {
+                Ok(bytes) => {
+                    fonts
+                        .font_data
+                        .insert(name.to_owned(), FontData::from_owned(bytes));
+                    fonts
+                        .families
+                        .entry(family)
+                        .or_default()
+                        .insert(0, name.to_owned());
+                }
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+    }
+
+    if let Some(path) = configured(&settings.fallback_font_path) {
+        match read_font(path) {
+            Ok(bytes) => {
+                fonts
+                    .font_data
+                    .insert(FALLBACK_FONT.to_owned(), FontData::from_owned(bytes));
+                for family in [FontFamily::Proportional, FontFamily::Monospace] {
+                    fonts
+                        .families
+                        .entry(family)
+                        .or_default()
+                        .push(FALLBACK_FONT.to_owned());
+                }
+            }
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    // Set the fonts even on error so a previously loaded font is dropped
+    ctx.set_fonts(fonts);
+
+    let ui_size = settings.ui_font_size * settings.ui_density.font_scale();
+    let mut style = (*ctx.style()).clone();
+    for (text_style, font_id) in style.text_styles.iter_mut() {
+        font_id.size = match text_style {
+            TextStyle::Small => ui_size * 0.75,
+            TextStyle::Heading => ui_size * 1.5,
+            _ => ui_size,
+        };
+    }
+    ctx.set_style(style);
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; ").into())
+    }
+}
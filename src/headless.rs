@@ -0,0 +1,145 @@
+use crate::data::StudyData;
+use crate::hooks;
+use crate::reminder_checker::ReminderChecker;
+use crate::settings::AppSettings;
+use crate::vault_export::{self, VaultFormat};
+use std::path::Path;
+
+const USAGE: &str = "Usage: study_timer --headless [--data-dir <dir>] <TASK>
+
+Runs maintenance on the data files without opening a window.
+
+Tasks:
+  migrate                        Repair ids and rewrite the data file in the current format
+  export-xlsx <file>             Export stats to an Excel workbook
+  export-report <file>           Export the weekly summary as a PDF
+  export-vault <dir> [--notion]  Export notes, todos and decks as an Obsidian (or Notion) vault
+  check-reminders [--run-hooks]  Print due reminders and cards, optionally running reminder hooks";
+
+// Returns None unless the first argument is --headless
+pub fn run_from_args() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(|a| a.as_str()) != Some("--headless") {
+        return None;
+    }
+
+    match execute(&args[1..]) {
+        Ok(()) => Some(0),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            Some(1)
+        }
+    }
+}
+
+fn execute(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = args;
+    // Data files are resolved from the working directory, so point it at the data first
+    if args.first().map(|a| a.as_str()) == Some("--data-dir") {
+        let dir = args.get(1).ok_or("--data-dir needs a directory")?;
+        std::env::set_current_dir(dir)
+            .map_err(|e| format!("Cannot use data directory {}: {}", dir, e))?;
+        args = &args[2..];
+    }
+
+    let task = match args.first() {
+        Some(task) => task.as_str(),
+        None => return Err(USAGE.into()),
+    };
+    let rest = &args[1..];
+    match task {
+        "migrate" => migrate(),
+        "export-xlsx" => {
+            let study_data = StudyData::load()?;
+            let path = required_path(rest, "export-xlsx")?;
+            crate::xlsx_export::export_stats(&study_data, path)?;
+            println!("Exported stats to {}", path.display());
+            Ok(())
+        }
+        "export-report" => {
+            let study_data = StudyData::load()?;
+            let path = required_path(rest, "export-report")?;
+            crate::pdf_export::export_weekly_report(&study_data, path)?;
+            println!("Exported weekly report to {}", path.display());
+            Ok(())
+        }
+        "export-vault" => {
+            let study_data = StudyData::load()?;
+            let path = required_path(rest, "export-vault")?;
+            let format = if rest.iter().any(|a| a == "--notion") {
+                VaultFormat::Notion
+            } else {
+                VaultFormat::Obsidian
+            };
+            let summary = vault_export::export_vault(&study_data, path, format)?;
+            println!(
+                "Exported {} notes, {} attachments, {} todos and {} decks to {}",
+                summary.notes,
+                summary.attachments,
+                summary.todos,
+                summary.decks,
+                path.display()
+            );
+            Ok(())
+        }
+        "check-reminders" => check_reminders(rest.iter().any(|a| a == "--run-hooks")),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        other => Err(format!("Unknown task '{}'\n\n{}", other, USAGE).into()),
+    }
+}
+
+fn required_path<'a>(
+    args: &'a [String],
+    task: &str,
+) -> Result<&'a Path, Box<dyn std::error::Error>> {
+    match args.first() {
+        Some(path) if !path.starts_with("--") => Ok(Path::new(path)),
+        _ => Err(format!("{} needs an output path", task).into()),
+    }
+}
+
+// Loading fills fields added since the file was written with their defaults and
+// repairs ids from versions that pushed decks and cards without assigning one
+fn migrate() -> Result<(), Box<dyn std::error::Error>> {
+    let (study_data, changes) = StudyData::load_repaired()?;
+    study_data.save()?;
+    if changes.is_empty() {
+        println!("Data file rewritten, no repairs needed.");
+    } else {
+        for change in &changes {
+            println!("{}", change);
+        }
+        println!("Data file rewritten with {} repair(s).", changes.len());
+    }
+    Ok(())
+}
+
+fn check_reminders(run_hooks: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let study_data = StudyData::load()?;
+
+    match ReminderChecker::new().summary(&study_data) {
+        Some(message) => println!("{}", message),
+        None => println!("No reminders due."),
+    }
+    println!("Flashcards due: {}", study_data.get_due_cards_count());
+    println!(
+        "Reading items due: {}",
+        study_data.get_due_reading_items().len()
+    );
+
+    // Each run starts fresh, so hooks fire on every run; leave them to the caller's schedule
+    if run_hooks {
+        let settings = AppSettings::load()?;
+        let errors = hooks::dispatch_pending(&settings.hooks);
+        for error in &errors {
+            eprintln!("{}", error);
+        }
+        if !errors.is_empty() {
+            return Err(format!("{} hook(s) failed", errors.len()).into());
+        }
+    }
+    Ok(())
+}
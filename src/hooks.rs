@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HookEvent {
+    SessionStarted,
+    SessionFinished,
+    ReminderDue,
+    GoalReached,
+}
+
+impl HookEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookEvent::SessionStarted => "Session started",
+            HookEvent::SessionFinished => "Session finished",
+            HookEvent::ReminderDue => "Reminder due",
+            HookEvent::GoalReached => "Daily goal reached",
+        }
+    }
+
+    // Stable identifier passed to scripts and webhooks
+    pub fn key(&self) -> &'static str {
+        match self {
+            HookEvent::SessionStarted => "session_started",
+            HookEvent::SessionFinished => "session_finished",
+            HookEvent::ReminderDue => "reminder_due",
+            HookEvent::GoalReached => "goal_reached",
+        }
+    }
+
+    pub fn all() -> Vec<HookEvent> {
+        vec![
+            HookEvent::SessionStarted,
+            HookEvent::SessionFinished,
+            HookEvent::ReminderDue,
+            HookEvent::GoalReached,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HookAction {
+    Command,
+    Webhook,
+}
+
+impl HookAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookAction::Command => "Shell command",
+            HookAction::Webhook => "Webhook (POST)",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventHook {
+    pub event: HookEvent,
+    pub action: HookAction,
+    pub target: String, // Command line or URL
+    pub enabled: bool,
+}
+
+struct PendingEvent {
+    event: HookEvent,
+    details: Vec<(String, String)>,
+}
+
+thread_local! {
+    static PENDING_EVENTS: RefCell<Vec<PendingEvent>> = RefCell::new(Vec::new());
+}
+
+// Queue an event; hooks are run by the app on the next call to dispatch_pending
+pub fn emit(event: HookEvent, details: &[(&str, String)]) {
+    let details = details
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect();
+
+    PENDING_EVENTS.with(|pending| {
+        pending.borrow_mut().push(PendingEvent { event, details });
+    });
+}
+
+// Runs the configured hooks for all queued events and returns any errors
+pub fn dispatch_pending(hooks: &[EventHook]) -> Vec<String> {
+    let events: Vec<PendingEvent> =
+        PENDING_EVENTS.with(|pending| pending.borrow_mut().drain(..).collect());
+
+    let mut errors = Vec::new();
+    for pending in events {
+        for hook in hooks
+            .iter()
+            .filter(|h| h.enabled && h.event == pending.event)
+        {
+            if let Err(e) = run_hook(hook, &pending.event, &pending.details) {
+                errors.push(format!("{} hook failed: {}", pending.event.name(), e));
+            }
+        }
+    }
+    errors
+}
+
+pub fn run_hook(
+    hook: &EventHook,
+    event: &HookEvent,
+    details: &[(String, String)],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let target = hook.target.trim();
+    if target.is_empty() {
+        return Err("no command or URL configured".into());
+    }
+
+    let mut command = match hook.action {
+        HookAction::Command => {
+            let mut command = if cfg!(target_os = "windows") {
+                let mut c = Command::new("cmd");
+                c.arg("/C");
+                c
+            } else {
+                let mut c = Command::new("sh");
+                c.arg("-c");
+                c
+            };
+            command.arg(target);
+            command.env("FOCUSPAD_EVENT", event.key());
+            for (key, value) in details {
+                command.env(format!("FOCUSPAD_{}", key.to_uppercase()), value);
+            }
+            command
+        }
+        HookAction::Webhook => {
+            let mut payload = serde_json::Map::new();
+            payload.insert("event".to_string(), event.key().into());
+            for (key, value) in details {
+                payload.insert(key.clone(), value.clone().into());
+            }
+
+            let mut command = Command::new("curl");
+            command
+                .arg("-s")
+                .arg("-X")
+                .arg("POST")
+                .arg("-H")
+                .arg("Content-Type: application/json")
+                .arg("-d")
+                .arg(serde_json::Value::Object(payload).to_string())
+                .arg(target);
+            command
+        }
+    };
+
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // Reap the process in the background so the UI never waits on a hook
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+
+    Ok(())
+}
@@ -8,6 +8,9 @@ pub struct KeyboardHandler {
     pub close_split_requested: bool,
     pub tab_number_requested: Option<usize>,
     pub switch_to_last_tab_requested: bool,
+    pub undo_delete_requested: bool,
+    pub zen_mode_requested: bool,
+    pub replace_in_notes_requested: bool,
 }
 
 impl KeyboardHandler {
@@ -20,6 +23,9 @@ impl KeyboardHandler {
             close_split_requested: false,
             tab_number_requested: None,
             switch_to_last_tab_requested: false,
+            undo_delete_requested: false,
+            zen_mode_requested: false,
+            replace_in_notes_requested: false,
         }
     }
 
@@ -32,6 +38,12 @@ impl KeyboardHandler {
         self.close_split_requested = false;
         self.tab_number_requested = None;
         self.switch_to_last_tab_requested = false;
+        self.undo_delete_requested = false;
+        self.zen_mode_requested = false;
+        self.replace_in_notes_requested = false;
+
+        // Leave Ctrl+Z to text fields while one is being edited
+        let text_focused = ctx.memory(|m| m.focus().is_some());
 
         ctx.input(|i| {
             // Use mac_cmd for macOS and ctrl for other platforms
@@ -66,6 +78,21 @@ impl KeyboardHandler {
                 self.close_split_requested = true;
             }
 
+            // Cmd/Ctrl + Z - Restore the last deleted items
+            if cmd_or_ctrl && !i.modifiers.shift && i.key_pressed(Key::Z) && !text_focused {
+                self.undo_delete_requested = true;
+            }
+
+            // F11 - Zen mode, just the note being written
+            if i.key_pressed(Key::F11) {
+                self.zen_mode_requested = true;
+            }
+
+            // Cmd/Ctrl + Shift + F - Find and replace across all notes
+            if cmd_or_ctrl && i.modifiers.shift && i.key_pressed(Key::F) {
+                self.replace_in_notes_requested = true;
+            }
+
             // Option/Alt + Tab - Switch to last used tab
             if i.modifiers.alt && i.key_pressed(Key::Tab) {
                 self.switch_to_last_tab_requested = true;
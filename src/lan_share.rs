@@ -0,0 +1,369 @@
+use crate::data::StudyData;
+use crate::ui::flashcard::{Card, Deck};
+use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::time::Duration;
+
+// Instances advertise themselves over mDNS and receive items over a plain TCP
+// connection: the sender writes one JSON line and waits for "accepted" or "rejected".
+const SERVICE_TYPE: &str = "_focuspad._tcp.local.";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REPLY_TIMEOUT: Duration = Duration::from_secs(120); // Time the other person has to answer
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SharedItem {
+    Deck(Deck),
+    Note { name: String, content: String },
+}
+
+impl SharedItem {
+    // Cards go out without the sender's review history
+    pub fn from_deck(deck: &Deck) -> Self {
+        let mut copy = Deck::new(deck.name.clone(), deck.description.clone());
+        copy.reverse_cards = deck.reverse_cards;
+        copy.settings = deck.settings.clone();
+        for card in &deck.cards {
+            let mut fresh = Card::new(0, card.front.clone(), card.back.clone());
+            fresh.tags = card.tags.clone();
+            fresh.front_image = card.front_image.clone();
+            fresh.back_image = card.back_image.clone();
+            fresh.reverse = card.reverse;
+            fresh.notes = card.notes.clone();
+            copy.add_card(fresh);
+        }
+        SharedItem::Deck(copy)
+    }
+
+    pub fn from_note(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let name = path
+            .file_name()
+            .ok_or("Not a note")?
+            .to_string_lossy()
+            .to_string();
+        Ok(SharedItem::Note {
+            name,
+            content: fs::read_to_string(path)?,
+        })
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            SharedItem::Deck(deck) => {
+                format!("the deck \"{}\" ({} cards)", deck.name, deck.cards.len())
+            }
+            SharedItem::Note { name, .. } => format!("the note \"{}\"", name),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Offer {
+    from: String,
+    item: SharedItem,
+}
+
+#[derive(Debug, Clone)]
+pub struct Peer {
+    pub name: String,
+    address: SocketAddr,
+}
+
+pub struct IncomingShare {
+    pub from: String,
+    pub item: SharedItem,
+    stream: TcpStream, // Kept open until the user answers
+}
+
+enum LanEvent {
+    Incoming(IncomingShare),
+    Answered(String, Result<bool, String>),
+}
+
+pub struct LanShare {
+    daemon: Option<ServiceDaemon>,
+    fullname: Option<String>,
+    browser: Option<mdns_sd::Receiver<ServiceEvent>>,
+    stop: Arc<AtomicBool>,
+    sender: Sender<LanEvent>,
+    receiver: Receiver<LanEvent>,
+    pub peers: BTreeMap<String, Peer>, // By mDNS instance name
+    pub incoming: VecDeque<IncomingShare>,
+    pub waiting_for: Option<String>, // Peer that hasn't answered our offer yet
+}
+
+impl LanShare {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            daemon: None,
+            fullname: None,
+            browser: None,
+            stop: Arc::new(AtomicBool::new(false)),
+            sender,
+            receiver,
+            peers: BTreeMap::new(),
+            incoming: VecDeque::new(),
+            waiting_for: None,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.daemon.is_some()
+    }
+
+    // Starts accepting offers and advertises this instance on the network
+    pub fn start(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.is_running() {
+            return Ok(());
+        }
+
+        let listener = TcpListener::bind("0.0.0.0:0")?;
+        listener.set_nonblocking(true)?;
+        let port = listener.local_addr()?.port();
+
+        let display_name = format!("{} on {}", crate::profile::active_name(), hostname());
+        let instance = format!("FocusPad-{}-{}", std::process::id(), port);
+        let host = format!("{}.local.", hostname());
+        let info = ServiceInfo::new(
+            SERVICE_TYPE,
+            &instance,
+            &host,
+            "",
+            port,
+            &[("name", display_name.as_str())][..],
+        )?
+        .enable_addr_auto();
+
+        let daemon = ServiceDaemon::new()?;
+        self.fullname = Some(info.get_fullname().to_string());
+        daemon.register(info)?;
+        self.browser = Some(daemon.browse(SERVICE_TYPE)?);
+        self.daemon = Some(daemon);
+
+        self.stop = Arc::new(AtomicBool::new(false));
+        let stop = self.stop.clone();
+        let sender = self.sender.clone();
+        std::thread::spawn(move || accept_offers(listener, stop, sender));
+        Ok(())
+    }
+
+    pub fn stop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(daemon) = self.daemon.take() {
+            if let Some(fullname) = self.fullname.take() {
+                let _ = daemon.unregister(&fullname);
+            }
+            let _ = daemon.shutdown();
+        }
+        self.browser = None;
+        self.peers.clear();
+    }
+
+    pub fn send(&mut self, peer_key: &str, item: SharedItem) {
+        let Some(peer) = self.peers.get(peer_key).cloned() else {
+            return;
+        };
+        let offer = Offer {
+            from: format!("{} on {}", crate::profile::active_name(), hostname()),
+            item,
+        };
+
+        self.waiting_for = Some(peer.name.clone());
+        let sender = self.sender.clone();
+        std::thread::spawn(move || {
+            let result = send_offer(peer.address, &offer).map_err(|e| e.to_string());
+            let _ = sender.send(LanEvent::Answered(peer.name, result));
+        });
+    }
+
+    // Answers the oldest offer, importing it when accepted
+    pub fn answer(
+        &mut self,
+        study_data: &mut StudyData,
+        accept: bool,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let Some(mut share) = self.incoming.pop_front() else {
+            return Ok(None);
+        };
+        let reply = if accept { "accepted\n" } else { "rejected\n" };
+        let _ = share.stream.write_all(reply.as_bytes());
+        if !accept {
+            return Ok(None);
+        }
+
+        match share.item {
+            SharedItem::Deck(mut deck) => {
+                deck.id = study_data.decks.iter().map(|d| d.id).max().unwrap_or(0) + 1;
+                study_data.next_deck_id = study_data.next_deck_id.max(deck.id + 1);
+                for card in &mut deck.cards {
+                    card.deck_id = deck.id;
+                }
+                let message = format!("Added the deck \"{}\" from {}", deck.name, share.from);
+                study_data.decks.push(deck);
+                study_data.save()?;
+                Ok(Some(message))
+            }
+            SharedItem::Note { name, content } => {
+                let path = free_note_path(&name);
+                fs::create_dir_all(crate::ui::markdown_editor::FILES_DIR)?;
+                fs::write(&path, content)?;
+                Ok(Some(format!(
+                    "Saved {} from {}",
+                    path.display(),
+                    share.from
+                )))
+            }
+        }
+    }
+
+    // Called every frame; tracks nearby instances and collects offers and answers
+    pub fn update(&mut self) -> Option<String> {
+        if let Some(browser) = &self.browser {
+            while let Ok(event) = browser.try_recv() {
+                match event {
+                    ServiceEvent::ServiceResolved(info) => {
+                        if Some(info.get_fullname()) == self.fullname.as_deref() {
+                            continue;
+                        }
+                        let Some(ip) = info.get_addresses().iter().next() else {
+                            continue;
+                        };
+                        let name = info
+                            .get_property_val_str("name")
+                            .unwrap_or(info.get_fullname())
+                            .to_string();
+                        self.peers.insert(
+                            info.get_fullname().to_string(),
+                            Peer {
+                                name,
+                                address: SocketAddr::new(*ip, info.get_port()),
+                            },
+                        );
+                    }
+                    ServiceEvent::ServiceRemoved(_, fullname) => {
+                        self.peers.remove(&fullname);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut message = None;
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                LanEvent::Incoming(share) => self.incoming.push_back(share),
+                LanEvent::Answered(peer, result) => {
+                    self.waiting_for = None;
+                    message = Some(match result {
+                        Ok(true) => format!("{} accepted the share", peer),
+                        Ok(false) => format!("{} declined the share", peer),
+                        Err(e) => format!("Could not share with {}: {}", peer, e),
+                    });
+                }
+            }
+        }
+        message
+    }
+}
+
+impl Drop for LanShare {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn accept_offers(listener: TcpListener, stop: Arc<AtomicBool>, sender: Sender<LanEvent>) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if let Ok(share) = read_offer(stream) {
+                    if sender.send(LanEvent::Incoming(share)).is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(Duration::from_millis(200));
+            }
+            Err(_) => return,
+        }
+    }
+}
+
+fn read_offer(stream: TcpStream) -> Result<IncomingShare, Box<dyn std::error::Error>> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(CONNECT_TIMEOUT))?;
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    let offer: Offer = serde_json::from_str(&line)?;
+    Ok(IncomingShare {
+        from: offer.from,
+        item: offer.item,
+        stream,
+    })
+}
+
+fn send_offer(address: SocketAddr, offer: &Offer) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut stream = TcpStream::connect_timeout(&address, CONNECT_TIMEOUT)?;
+    let mut json = serde_json::to_string(offer)?;
+    json.push('\n');
+    stream.write_all(json.as_bytes())?;
+
+    stream.set_read_timeout(Some(REPLY_TIMEOUT))?;
+    let mut reply = String::new();
+    BufReader::new(&stream).read_line(&mut reply)?;
+    match reply.trim() {
+        "accepted" => Ok(true),
+        "rejected" => Ok(false),
+        _ => Err("no answer".into()),
+    }
+}
+
+// Received notes never replace an existing file
+fn free_note_path(name: &str) -> PathBuf {
+    let dir = Path::new(crate::ui::markdown_editor::FILES_DIR);
+    let name = Path::new(name)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "shared.md".to_string());
+    let path = dir.join(&name);
+    if !path.exists() {
+        return path;
+    }
+
+    let stem = Path::new(&name)
+        .file_stem()
+        .map_or(name.clone(), |s| s.to_string_lossy().to_string());
+    let extension = Path::new(&name)
+        .extension()
+        .map_or("md".to_string(), |e| e.to_string_lossy().to_string());
+    (2..)
+        .map(|n| dir.join(format!("{} ({}).{}", stem, n, extension)))
+        .find(|p| !p.exists())
+        .unwrap_or(path)
+}
+
+fn hostname() -> String {
+    std::env::var("COMPUTERNAME")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .ok()
+        .or_else(|| {
+            let output = std::process::Command::new("hostname").output().ok()?;
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            name.chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                .collect()
+        })
+        .unwrap_or_else(|| "focuspad".to_string())
+}
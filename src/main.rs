@@ -1,21 +1,69 @@
 mod app;
+mod audio_recorder;
+mod bibtex;
+mod break_reminder;
+mod card_generator;
+mod cli;
 mod data;
 mod debug;
+mod deck_sync;
+mod discord_presence;
+mod error;
 mod file_drop_handler;
+mod fonts;
+#[cfg(feature = "headless")]
+mod headless;
+mod hooks;
 mod image_handler;
 mod keyboard_handler;
+mod lan_share;
+mod pane_content;
+mod pdf_export;
+mod power;
+mod profile;
+mod quick_add;
+mod quizlet_import;
+mod quotes;
+mod reminder_checker;
 mod settings;
+mod spell_check;
 mod split_view_ui;
+mod tab_badges;
 mod tab_manager;
 mod tab_selector_ui;
+mod task_sync;
 mod terminal;
+mod time_export;
 mod timer;
+mod timer_recovery;
 mod ui;
+mod vault_export;
+mod video_player;
 mod weather;
+mod web_clip;
+mod xlsx_export;
+mod year_review;
 
 use app::StudyTimerApp;
 
 fn main() -> Result<(), eframe::Error> {
+    #[cfg(feature = "headless")]
+    if let Some(code) = headless::run_from_args() {
+        std::process::exit(code);
+    }
+
+    // Everything below reads and writes the chosen profile's data
+    let (args, profile_given) = profile::activate_from_args();
+
+    // Subcommands run against the data files without opening a window
+    if let Some(code) = cli::run_from_args(&args) {
+        std::process::exit(code);
+    }
+
+    let profiles = profile::ProfileList::load();
+    let ask_for_profile =
+        !profile_given && profiles.ask_on_startup && !profiles.profiles.is_empty();
+
     let options = eframe::NativeOptions {
         viewport: eframe::egui::ViewportBuilder::default()
             .with_inner_size([800.0, 600.0]) // Increased default size for split view
@@ -27,7 +75,7 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         "Study Timer - Enhanced",
         options,
-        Box::new(|cc| Box::new(StudyTimerApp::new(cc))),
+        Box::new(move |cc| Box::new(StudyTimerApp::new(cc, ask_for_profile))),
     )
 }
 
@@ -0,0 +1,149 @@
+use crate::ui::flashcard_ui::{DeckManagerUI, FlashcardReviewer};
+use crate::ui::markdown_editor::MarkdownEditor;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+// The app keeps one live editor and one live reviewer, which the rest of the code
+// works with. Each Markdown or Flashcards tab swaps its own instances into those
+// slots while it's drawn, so two panes can show different notes or decks.
+#[derive(Default)]
+pub struct PaneContent {
+    editor_owner: Option<String>,
+    editors: HashMap<String, Option<MarkdownEditor>>,
+    flashcards_owner: Option<String>,
+    flashcards: HashMap<String, (FlashcardReviewer, DeckManagerUI)>,
+}
+
+impl PaneContent {
+    // Puts the tab's editor in the live slot. Returns true if the tab has none yet,
+    // so the caller can open the tab's file in it.
+    pub fn swap_editor(&mut self, tab_id: &str, live: &mut Option<MarkdownEditor>) -> bool {
+        match self.editor_owner.as_deref() {
+            Some(owner) if owner == tab_id => return live.is_none(),
+            Some(owner) => {
+                self.editors.insert(owner.to_string(), live.take());
+            }
+            // The first tab drawn takes over whatever is already open
+            None if !self.editors.contains_key(tab_id) => {
+                self.editor_owner = Some(tab_id.to_string());
+                return live.is_none();
+            }
+            None => {}
+        }
+        self.editor_owner = Some(tab_id.to_string());
+        *live = self.editors.remove(tab_id).flatten();
+        live.is_none()
+    }
+
+    pub fn swap_flashcards(
+        &mut self,
+        tab_id: &str,
+        reviewer: &mut FlashcardReviewer,
+        manager: &mut DeckManagerUI,
+    ) {
+        match self.flashcards_owner.as_deref() {
+            Some(owner) if owner == tab_id => return,
+            None if !self.flashcards.contains_key(tab_id) => {
+                self.flashcards_owner = Some(tab_id.to_string());
+                return;
+            }
+            _ => {}
+        }
+        let (mut next_reviewer, mut next_manager) = self
+            .flashcards
+            .remove(tab_id)
+            .unwrap_or_else(|| (FlashcardReviewer::new(), DeckManagerUI::new()));
+        // Deletions are picked up from the live manager, whichever tab made them
+        next_manager.trashed.append(&mut manager.trashed);
+        std::mem::swap(reviewer, &mut next_reviewer);
+        std::mem::swap(manager, &mut next_manager);
+        if let Some(owner) = self.flashcards_owner.replace(tab_id.to_string()) {
+            self.flashcards.insert(owner, (next_reviewer, next_manager));
+        }
+    }
+
+    // The editors of tabs not being drawn right now
+    pub fn editors_mut(&mut self) -> impl Iterator<Item = &mut MarkdownEditor> {
+        self.editors.values_mut().flatten()
+    }
+
+    // Checked before a tab closes, so typed text isn't dropped without asking
+    pub fn has_unsaved_edits(&self, tab_id: &str, live: &Option<MarkdownEditor>) -> bool {
+        let editor = if self.editor_owner.as_deref() == Some(tab_id) {
+            live.as_ref()
+        } else {
+            self.editors.get(tab_id).and_then(Option::as_ref)
+        };
+        editor.is_some_and(unsaved)
+    }
+
+    // Whether the tab's text was never saved to a note, so saving needs a file name
+    pub fn is_untitled(&self, tab_id: &str, live: &Option<MarkdownEditor>) -> bool {
+        let editor = if self.editor_owner.as_deref() == Some(tab_id) {
+            live.as_ref()
+        } else {
+            self.editors.get(tab_id).and_then(Option::as_ref)
+        };
+        editor.is_some_and(|editor| editor.current_file.is_none())
+    }
+
+    // Saves the tab's note, to `save_as` instead when given
+    pub fn save_editor(
+        &mut self,
+        tab_id: &str,
+        live: &mut Option<MarkdownEditor>,
+        save_as: Option<PathBuf>,
+    ) -> Result<(), std::io::Error> {
+        let editor = if self.editor_owner.as_deref() == Some(tab_id) {
+            live.as_mut()
+        } else {
+            self.editors.get_mut(tab_id).and_then(Option::as_mut)
+        };
+        let Some(editor) = editor else {
+            return Ok(());
+        };
+        if save_as.is_some() {
+            editor.current_file = save_as;
+        }
+        editor.save_file()
+    }
+
+    // Drops the instances of closed tabs. Unsaved edits were settled when the tab closed.
+    pub fn prune(
+        &mut self,
+        is_open: impl Fn(&str) -> bool,
+        editor: &mut Option<MarkdownEditor>,
+        reviewer: &mut FlashcardReviewer,
+        manager: &mut DeckManagerUI,
+    ) {
+        self.editors.retain(|id, _| is_open(id));
+        if self.editor_owner.as_deref().is_some_and(|id| !is_open(id)) {
+            *editor = None;
+            self.editor_owner = None;
+        }
+
+        self.flashcards.retain(|id, _| is_open(id));
+        if self
+            .flashcards_owner
+            .as_deref()
+            .is_some_and(|id| !is_open(id))
+        {
+            let trashed = std::mem::take(&mut manager.trashed);
+            *reviewer = FlashcardReviewer::new();
+            *manager = DeckManagerUI::new();
+            manager.trashed = trashed;
+            self.flashcards_owner = None;
+        }
+    }
+}
+
+// Whether what's typed differs from the note on disk, or was typed without a note
+pub fn unsaved(editor: &MarkdownEditor) -> bool {
+    match &editor.current_file {
+        Some(path) => {
+            fs::read_to_string(path).map_or(false, |saved| saved != editor.current_content)
+        }
+        None => !editor.current_content.trim().is_empty(),
+    }
+}
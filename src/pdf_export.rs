@@ -0,0 +1,538 @@
+use crate::data::StudyData;
+use crate::ui::flashcard::Deck;
+use crate::year_review::YearReview;
+use chrono::{Duration, Local, NaiveDate};
+use printpdf::{
+    BuiltinFont, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference,
+    PdfLayerReference, Point,
+};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+// A4 portrait
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+const PT_TO_MM: f32 = 0.3528;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeckLayout {
+    Table,
+    CardSheet,
+}
+
+impl DeckLayout {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeckLayout::Table => "PDF (front/back table)",
+            DeckLayout::CardSheet => "PDF (printable flashcards)",
+        }
+    }
+}
+
+struct PdfWriter {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    font: IndirectFontRef,
+    bold: IndirectFontRef,
+    y: f32, // Distance from the bottom of the page, in mm
+}
+
+impl PdfWriter {
+    fn new(title: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let (doc, page, layer) =
+            PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        let font = doc.add_builtin_font(BuiltinFont::Helvetica)?;
+        let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold)?;
+        let layer = doc.get_page(page).get_layer(layer);
+        Ok(Self {
+            doc,
+            layer,
+            font,
+            bold,
+            y: PAGE_HEIGHT - MARGIN,
+        })
+    }
+
+    fn new_page(&mut self) {
+        let (page, layer) = self
+            .doc
+            .add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn text_at(&self, text: &str, size: f32, bold: bool, x: f32, y: f32) {
+        let font = if bold { &self.bold } else { &self.font };
+        self.layer.use_text(text, size, Mm(x), Mm(y), font);
+    }
+
+    // Writes a line at the cursor and moves it down
+    fn line_of_text(&mut self, text: &str, size: f32, bold: bool) {
+        let height = line_height(size);
+        self.ensure_space(height);
+        self.y -= height;
+        self.text_at(text, size, bold, MARGIN, self.y);
+    }
+
+    fn rule(&self, x1: f32, y1: f32, x2: f32, y2: f32) {
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(x1), Mm(y1)), false),
+                (Point::new(Mm(x2), Mm(y2)), false),
+            ],
+            is_closed: false,
+        });
+    }
+
+    fn save(self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        self.doc.save(&mut BufWriter::new(File::create(path)?))?;
+        Ok(())
+    }
+}
+
+fn line_height(size: f32) -> f32 {
+    size * PT_TO_MM * 1.4
+}
+
+// Helvetica averages about half an em per character, which is close enough for wrapping
+fn wrap_text(text: &str, width: f32, size: f32) -> Vec<String> {
+    let max_chars = ((width / (size * PT_TO_MM * 0.52)) as usize).max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut current = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word = word.to_string();
+            while word.chars().count() > max_chars {
+                if !current.is_empty() {
+                    lines.push(std::mem::take(&mut current));
+                }
+                let head: String = word.chars().take(max_chars).collect();
+                word = word.chars().skip(max_chars).collect();
+                lines.push(head);
+            }
+
+            let needed = current.chars().count() + word.chars().count() + 1;
+            if !current.is_empty() && needed > max_chars {
+                lines.push(std::mem::take(&mut current));
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(&word);
+        }
+        lines.push(current);
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+pub fn export_deck(
+    deck: &Deck,
+    layout: DeckLayout,
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut pdf = PdfWriter::new(&deck.name)?;
+    match layout {
+        DeckLayout::Table => write_deck_table(&mut pdf, deck),
+        DeckLayout::CardSheet => write_card_sheet(&mut pdf, deck),
+    }
+    pdf.save(path)
+}
+
+fn write_deck_table(pdf: &mut PdfWriter, deck: &Deck) {
+    const SIZE: f32 = 10.0;
+    const PADDING: f32 = 2.0;
+    let column_width = (PAGE_WIDTH - 2.0 * MARGIN) / 2.0;
+    let back_x = MARGIN + column_width;
+
+    pdf.line_of_text(&deck.name, 18.0, true);
+    if let Some(description) = &deck.description {
+        pdf.line_of_text(description, 11.0, false);
+    }
+    pdf.line_of_text(&format!("{} cards", deck.cards.len()), 9.0, false);
+    pdf.y -= 4.0;
+
+    let draw_header = |pdf: &mut PdfWriter| {
+        pdf.y -= line_height(11.0);
+        pdf.text_at("Front", 11.0, true, MARGIN + PADDING, pdf.y);
+        pdf.text_at("Back", 11.0, true, back_x + PADDING, pdf.y);
+        pdf.y -= PADDING;
+        pdf.rule(MARGIN, pdf.y, PAGE_WIDTH - MARGIN, pdf.y);
+    };
+    draw_header(pdf);
+
+    for card in &deck.cards {
+        let mut front = wrap_text(&card.front, column_width - 2.0 * PADDING, SIZE);
+        let mut back = wrap_text(&card.back, column_width - 2.0 * PADDING, SIZE);
+        if card.front_image.is_some() {
+            front.push("[image]".to_string());
+        }
+        if card.back_image.is_some() {
+            back.push("[image]".to_string());
+        }
+        if !card.notes.trim().is_empty() {
+            let notes = format!("Note: {}", card.notes.trim());
+            back.extend(wrap_text(&notes, column_width - 2.0 * PADDING, SIZE));
+        }
+
+        let rows = front.len().max(back.len());
+        let row_height = rows as f32 * line_height(SIZE) + 2.0 * PADDING;
+        if pdf.y - row_height < MARGIN {
+            pdf.new_page();
+            draw_header(pdf);
+        }
+
+        let top = pdf.y;
+        for (i, line) in front.iter().enumerate() {
+            let y = top - PADDING - (i + 1) as f32 * line_height(SIZE);
+            pdf.text_at(line, SIZE, false, MARGIN + PADDING, y);
+        }
+        for (i, line) in back.iter().enumerate() {
+            let y = top - PADDING - (i + 1) as f32 * line_height(SIZE);
+            pdf.text_at(line, SIZE, false, back_x + PADDING, y);
+        }
+
+        pdf.y = top - row_height;
+        pdf.rule(MARGIN, pdf.y, PAGE_WIDTH - MARGIN, pdf.y);
+        pdf.rule(back_x, top, back_x, pdf.y);
+    }
+}
+
+// Fronts and backs go on alternating pages, with the back columns mirrored so
+// the sheet lines up when printed double-sided and flipped on the long edge
+fn write_card_sheet(pdf: &mut PdfWriter, deck: &Deck) {
+    const COLUMNS: usize = 2;
+    const ROWS: usize = 4;
+    const SIZE: f32 = 12.0;
+    const PADDING: f32 = 5.0;
+    let cell_width = (PAGE_WIDTH - 2.0 * MARGIN) / COLUMNS as f32;
+    let cell_height = (PAGE_HEIGHT - 2.0 * MARGIN) / ROWS as f32;
+    // Leave room for the deck label at the bottom of each card
+    let max_lines = ((cell_height - 3.0 * PADDING) / line_height(SIZE)) as usize;
+
+    for (page_index, chunk) in deck.cards.chunks(COLUMNS * ROWS).enumerate() {
+        for back_side in [false, true] {
+            if page_index > 0 || back_side {
+                pdf.new_page();
+            }
+
+            // Cut lines
+            for column in 0..=COLUMNS {
+                let x = MARGIN + column as f32 * cell_width;
+                pdf.rule(x, MARGIN, x, PAGE_HEIGHT - MARGIN);
+            }
+            for row in 0..=ROWS {
+                let y = MARGIN + row as f32 * cell_height;
+                pdf.rule(MARGIN, y, PAGE_WIDTH - MARGIN, y);
+            }
+
+            for (i, card) in chunk.iter().enumerate() {
+                let row = i / COLUMNS;
+                let mut column = i % COLUMNS;
+                if back_side {
+                    column = COLUMNS - 1 - column;
+                }
+
+                let (text, has_image) = if back_side {
+                    (&card.back, card.back_image.is_some())
+                } else {
+                    (&card.front, card.front_image.is_some())
+                };
+                let mut lines = wrap_text(text, cell_width - 2.0 * PADDING, SIZE);
+                if has_image {
+                    lines.push("[image]".to_string());
+                }
+                if lines.len() > max_lines {
+                    lines.truncate(max_lines.max(1));
+                    if let Some(last) = lines.last_mut() {
+                        last.push_str("...");
+                    }
+                }
+
+                let x = MARGIN + column as f32 * cell_width + PADDING;
+                let top = PAGE_HEIGHT - MARGIN - row as f32 * cell_height - PADDING;
+                for (line_index, line) in lines.iter().enumerate() {
+                    let y = top - (line_index + 1) as f32 * line_height(SIZE);
+                    pdf.text_at(line, SIZE, !back_side, x, y);
+                }
+
+                let label = if back_side { "A" } else { "Q" };
+                pdf.text_at(
+                    &format!("{} - {}", deck.name, label),
+                    7.0,
+                    false,
+                    x,
+                    top - cell_height + 2.0 * PADDING,
+                );
+            }
+        }
+    }
+}
+
+pub fn export_weekly_report(
+    study_data: &StudyData,
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let today = Local::now().date_naive();
+    let start = today - Duration::days(6);
+    let in_week = |date: &str| {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").map_or(false, |d| d >= start && d <= today)
+    };
+
+    let mut pdf = PdfWriter::new("Weekly Study Report")?;
+    pdf.line_of_text("Weekly Study Report", 20.0, true);
+    pdf.line_of_text(
+        &format!(
+            "{} to {}",
+            start.format("%A, %B %-d"),
+            today.format("%A, %B %-d, %Y")
+        ),
+        11.0,
+        false,
+    );
+    pdf.y -= 6.0;
+
+    let sessions: Vec<_> = study_data
+        .sessions
+        .iter()
+        .filter(|s| in_week(&s.date))
+        .collect();
+    let total_minutes: f64 = sessions.iter().map(|s| s.minutes).sum();
+    let reviews = study_data
+        .decks
+        .iter()
+        .flat_map(|d| d.cards.iter())
+        .flat_map(|c| c.reviews.iter())
+        .filter(|r| in_week(&r.date))
+        .count();
+
+    pdf.line_of_text("Summary", 14.0, true);
+    pdf.line_of_text(
+        &format!(
+            "Total study time: {:.1} hours ({:.0} minutes)",
+            total_minutes / 60.0,
+            total_minutes
+        ),
+        11.0,
+        false,
+    );
+    pdf.line_of_text(&format!("Sessions: {}", sessions.len()), 11.0, false);
+    pdf.line_of_text(
+        &format!("Daily average: {:.0} minutes", total_minutes / 7.0),
+        11.0,
+        false,
+    );
+    pdf.line_of_text(&format!("Flashcard reviews: {}", reviews), 11.0, false);
+    pdf.y -= 6.0;
+
+    // Daily bar chart drawn with thick lines
+    pdf.line_of_text("Study time per day", 14.0, true);
+    let daily: Vec<(NaiveDate, f64)> = (0..7)
+        .map(|i| {
+            let date = start + Duration::days(i);
+            let key = date.format("%Y-%m-%d").to_string();
+            let minutes = sessions
+                .iter()
+                .filter(|s| s.date == key)
+                .map(|s| s.minutes)
+                .sum();
+            (date, minutes)
+        })
+        .collect();
+    let max_minutes = daily.iter().map(|(_, m)| *m).fold(1.0, f64::max);
+    let bar_x = MARGIN + 30.0;
+    let bar_max = PAGE_WIDTH - 2.0 * MARGIN - 55.0;
+
+    pdf.layer.set_outline_thickness(8.0);
+    for (date, minutes) in &daily {
+        pdf.ensure_space(line_height(11.0) + 1.0);
+        pdf.y -= line_height(11.0) + 1.0;
+        pdf.text_at(&date.format("%a %d").to_string(), 10.0, false, MARGIN, pdf.y);
+        if *minutes > 0.0 {
+            let width = (minutes / max_minutes) as f32 * bar_max;
+            pdf.rule(bar_x, pdf.y + 1.2, bar_x + width, pdf.y + 1.2);
+        }
+        pdf.text_at(
+            &format!("{:.0} min", minutes),
+            10.0,
+            false,
+            bar_x + bar_max + 3.0,
+            pdf.y,
+        );
+    }
+    pdf.layer.set_outline_thickness(1.0);
+    pdf.y -= 6.0;
+
+    if !sessions.is_empty() {
+        pdf.line_of_text("Sessions", 14.0, true);
+        let mut sorted = sessions.clone();
+        sorted.sort_by(|a, b| a.date.cmp(&b.date));
+        for session in sorted {
+            let line = format!(
+                "{}   {:>5.0} min   {}",
+                session.date,
+                session.minutes,
+                session.description.as_deref().unwrap_or("")
+            );
+            for wrapped in wrap_text(&line, PAGE_WIDTH - 2.0 * MARGIN, 10.0) {
+                pdf.line_of_text(&wrapped, 10.0, false);
+            }
+        }
+        pdf.y -= 6.0;
+    }
+
+    if !study_data.habits.is_empty() {
+        pdf.line_of_text("Habits", 14.0, true);
+        for habit in &study_data.habits {
+            let done = habit
+                .completion_dates
+                .iter()
+                .filter(|d| in_week(d))
+                .count();
+            pdf.line_of_text(
+                &format!(
+                    "{}: {}/7 days, current streak {}",
+                    habit.name,
+                    done,
+                    habit.calculate_current_streak()
+                ),
+                10.0,
+                false,
+            );
+        }
+        pdf.y -= 6.0;
+    }
+
+    let entries: Vec<_> = study_data
+        .journal
+        .iter()
+        .filter(|e| in_week(&e.date))
+        .collect();
+    if !entries.is_empty() {
+        pdf.line_of_text("Journal", 14.0, true);
+        for entry in entries {
+            let mood = entry
+                .mood
+                .map(|m| format!(" (mood: {})", m.label()))
+                .unwrap_or_default();
+            pdf.line_of_text(&format!("{}{}", entry.date, mood), 11.0, true);
+            let answers = entry
+                .answers
+                .iter()
+                .filter(|a| !a.answer.trim().is_empty())
+                .map(|a| format!("{} {}", a.prompt, a.answer.trim()));
+            let text = Some(entry.text.trim().to_string()).filter(|t| !t.is_empty());
+            for paragraph in answers.chain(text) {
+                for wrapped in wrap_text(&paragraph, PAGE_WIDTH - 2.0 * MARGIN, 10.0) {
+                    pdf.line_of_text(&wrapped, 10.0, false);
+                }
+            }
+            pdf.y -= 2.0;
+        }
+    }
+
+    pdf.save(path)
+}
+
+pub fn export_year_review(
+    review: &YearReview,
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let title = format!("{} Year in Review", review.year);
+    let mut pdf = PdfWriter::new(&title)?;
+    pdf.line_of_text(&title, 20.0, true);
+    pdf.y -= 6.0;
+
+    pdf.line_of_text("Study time", 14.0, true);
+    pdf.line_of_text(
+        &format!(
+            "Total: {:.1} hours across {} sessions",
+            review.total_minutes / 60.0,
+            review.sessions
+        ),
+        11.0,
+        false,
+    );
+    pdf.line_of_text(&format!("Days studied: {}", review.study_days), 11.0, false);
+    pdf.line_of_text(
+        &format!("Longest streak: {} days", review.longest_streak),
+        11.0,
+        false,
+    );
+    if let Some(week) = review.busiest_week_label() {
+        pdf.line_of_text(&format!("Busiest week: {}", week), 11.0, false);
+    }
+    if let Some(day) = review.busiest_day_label() {
+        pdf.line_of_text(&format!("Busiest day: {}", day), 11.0, false);
+    }
+    pdf.y -= 6.0;
+
+    // Monthly bar chart, drawn the same way as the weekly report's
+    let months = review.active_months();
+    if !months.is_empty() {
+        pdf.line_of_text("Hours per month", 14.0, true);
+        let max_minutes = months.iter().map(|(_, m)| *m).fold(1.0, f64::max);
+        let bar_x = MARGIN + 30.0;
+        let bar_max = PAGE_WIDTH - 2.0 * MARGIN - 55.0;
+
+        pdf.layer.set_outline_thickness(8.0);
+        for (month, minutes) in &months {
+            pdf.ensure_space(line_height(11.0) + 1.0);
+            pdf.y -= line_height(11.0) + 1.0;
+            pdf.text_at(month, 10.0, false, MARGIN, pdf.y);
+            let width = (minutes / max_minutes) as f32 * bar_max;
+            pdf.rule(bar_x, pdf.y + 1.2, bar_x + width, pdf.y + 1.2);
+            pdf.text_at(
+                &format!("{:.1} h", minutes / 60.0),
+                10.0,
+                false,
+                bar_x + bar_max + 3.0,
+                pdf.y,
+            );
+        }
+        pdf.layer.set_outline_thickness(1.0);
+        pdf.y -= 6.0;
+    }
+
+    pdf.line_of_text("Flashcards", 14.0, true);
+    pdf.line_of_text(&format!("Reviews: {}", review.reviews), 11.0, false);
+    pdf.line_of_text(&format!("Cards added: {}", review.cards_added), 11.0, false);
+    if let Some((deck, count)) = &review.most_reviewed_deck {
+        pdf.line_of_text(
+            &format!("Most reviewed deck: {} ({} reviews)", deck, count),
+            11.0,
+            false,
+        );
+    }
+    pdf.y -= 6.0;
+
+    if !review.habits.is_empty() {
+        pdf.line_of_text("Habits", 14.0, true);
+        for habit in &review.habits {
+            let line = format!(
+                "{}: {} completions, {:.0}% success, longest streak {} days",
+                habit.name,
+                habit.completions,
+                habit.success_rate(),
+                habit.longest_streak
+            );
+            for wrapped in wrap_text(&line, PAGE_WIDTH - 2.0 * MARGIN, 10.0) {
+                pdf.line_of_text(&wrapped, 10.0, false);
+            }
+        }
+    }
+
+    pdf.save(path)
+}
@@ -0,0 +1,47 @@
+// Best-effort check for running on battery; false whenever it can't be determined
+#[cfg(target_os = "linux")]
+pub fn on_battery() -> bool {
+    let entries = match std::fs::read_dir("/sys/class/power_supply") {
+        Ok(entries) => entries,
+        Err(_) => return false,
+    };
+    entries.flatten().any(|entry| {
+        let path = entry.path();
+        let kind = std::fs::read_to_string(path.join("type")).unwrap_or_default();
+        let status = std::fs::read_to_string(path.join("status")).unwrap_or_default();
+        kind.trim() == "Battery" && status.trim() == "Discharging"
+    })
+}
+
+#[cfg(target_os = "macos")]
+pub fn on_battery() -> bool {
+    // First line reads "Now drawing from 'Battery Power'" when unplugged
+    match std::process::Command::new("pmset")
+        .arg("-g")
+        .arg("batt")
+        .output()
+    {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).contains("'Battery Power'"),
+        Err(_) => false,
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn on_battery() -> bool {
+    // BatteryStatus 1 means discharging
+    match std::process::Command::new("powershell")
+        .arg("-c")
+        .arg("(Get-CimInstance Win32_Battery).BatteryStatus")
+        .output()
+    {
+        Ok(output) => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .any(|line| line.trim() == "1"),
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+pub fn on_battery() -> bool {
+    false // Unsupported platform
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+// Profiles keep separate data on shared computers. The default profile uses the
+// directory the app starts in, as before profiles existed; the others live in
+// profiles/<name> below it and have their own folder in the config directory.
+const PROFILES_DIR: &str = "profiles";
+const PROFILES_FILE: &str = "profiles.json";
+pub const DEFAULT_PROFILE: &str = "Default";
+
+static BASE_DIR: OnceLock<PathBuf> = OnceLock::new();
+static ACTIVE: OnceLock<Option<String>> = OnceLock::new();
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfileList {
+    pub profiles: Vec<String>, // Besides the default profile
+    #[serde(default)]
+    pub last_used: Option<String>,
+    #[serde(default)]
+    pub ask_on_startup: bool,
+}
+
+impl ProfileList {
+    pub fn load() -> Self {
+        fs::read_to_string(base_dir().join(PROFILES_FILE))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(base_dir().join(PROFILES_FILE), json)?;
+        Ok(())
+    }
+
+    // Every profile name including the default one, for pickers
+    pub fn names(&self) -> Vec<String> {
+        std::iter::once(DEFAULT_PROFILE.to_string())
+            .chain(self.profiles.iter().cloned())
+            .collect()
+    }
+
+    pub fn create(&mut self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let name = name.trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == ' ' || c == '-' || c == '_')
+        {
+            return Err("Profile names may only use letters, numbers, spaces, - and _".into());
+        }
+        if self.names().iter().any(|p| p.eq_ignore_ascii_case(name)) {
+            return Err(format!("A profile named \"{}\" already exists", name).into());
+        }
+        fs::create_dir_all(base_dir().join(PROFILES_DIR).join(name))?;
+        self.profiles.push(name.to_string());
+        self.save()
+    }
+}
+
+// Removes `--profile <name>` from the arguments and switches into that profile, or into
+// the last used one when none is given. Returns the remaining arguments and whether a
+// profile was asked for explicitly.
+pub fn activate_from_args() -> (Vec<String>, bool) {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let requested = args.iter().position(|a| a == "--profile").map(|index| {
+        args.remove(index);
+        if index < args.len() {
+            args.remove(index)
+        } else {
+            String::new()
+        }
+    });
+
+    let explicit = requested.is_some();
+    let name = requested.or_else(|| ProfileList::load().last_used);
+    if let Err(e) = activate(name.as_deref()) {
+        crate::error::report("Could not open the profile, using the default one", e);
+        let _ = activate(None);
+    }
+    (args, explicit)
+}
+
+fn activate(name: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+    let name = name.filter(|n| !n.is_empty() && *n != DEFAULT_PROFILE);
+    let base = base_dir();
+    if let Some(name) = name {
+        let list = ProfileList::load();
+        if !list.profiles.iter().any(|p| p == name) {
+            return Err(format!("There is no profile named \"{}\"", name).into());
+        }
+        let dir = base.join(PROFILES_DIR).join(name);
+        fs::create_dir_all(&dir)?;
+        std::env::set_current_dir(&dir)?;
+    }
+    let _ = ACTIVE.set(name.map(str::to_string));
+
+    let mut list = ProfileList::load();
+    if list.last_used.as_deref() != name {
+        list.last_used = name.map(str::to_string);
+        list.save()?;
+    }
+    Ok(())
+}
+
+// Directory the app was started from, which holds the default profile's data
+fn base_dir() -> &'static PathBuf {
+    BASE_DIR.get_or_init(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")))
+}
+
+pub fn active_name() -> &'static str {
+    ACTIVE
+        .get()
+        .and_then(|name| name.as_deref())
+        .unwrap_or(DEFAULT_PROFILE)
+}
+
+// Per-profile folder for settings kept in the user's config directory
+pub fn config_dir() -> Option<PathBuf> {
+    let mut path = dirs::config_dir().or_else(dirs::home_dir)?;
+    path.push("study_timer");
+    if let Some(Some(name)) = ACTIVE.get() {
+        path.push(PROFILES_DIR);
+        path.push(name);
+    }
+    Some(path)
+}
+
+// Starts a new instance in another profile; the caller closes this one
+pub fn relaunch_as(name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let exe = std::env::current_exe()?;
+    std::process::Command::new(exe)
+        .arg("--profile")
+        .arg(name)
+        .current_dir(base_dir())
+        .spawn()?;
+    Ok(())
+}
@@ -0,0 +1,279 @@
+use crate::data::TodoPriority;
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveTime, Weekday};
+use eframe::egui;
+
+// What "submit lab report !high #physics fri 5pm" turns into
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QuickAdd {
+    pub title: String,
+    pub due_date: Option<NaiveDate>,
+    pub due_time: Option<NaiveTime>,
+    pub priority: Option<TodoPriority>,
+    pub project: Option<String>,
+}
+
+impl QuickAdd {
+    pub fn due_date_string(&self) -> Option<String> {
+        self.due_date.map(|d| d.format("%Y-%m-%d").to_string())
+    }
+
+    pub fn due_time_string(&self) -> Option<String> {
+        self.due_time.map(|t| t.format("%H:%M").to_string())
+    }
+
+    // Short labels for the preview row under the input
+    pub fn chips(&self) -> Vec<String> {
+        let mut chips = Vec::new();
+        if let Some(date) = self.due_date {
+            chips.push(format!("📅 {}", date.format("%a, %b %-d")));
+        }
+        if let Some(time) = self.due_time {
+            chips.push(format!("🕔 {}", time.format("%H:%M")));
+        }
+        if let Some(priority) = self.priority {
+            chips.push(format!("❗ {}", priority.as_str()));
+        }
+        if let Some(project) = &self.project {
+            chips.push(format!("#{}", project));
+        }
+        chips
+    }
+
+    // The chip row shown under an input while typing
+    pub fn preview(&self, ui: &mut egui::Ui, color: egui::Color32) {
+        let chips = self.chips();
+        if chips.is_empty() {
+            return;
+        }
+        ui.horizontal(|ui| {
+            for chip in chips {
+                egui::Frame::none()
+                    .stroke(egui::Stroke::new(1.0, color))
+                    .rounding(egui::Rounding::same(8.0))
+                    .inner_margin(egui::style::Margin::symmetric(6.0, 1.0))
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new(chip).small().color(color));
+                    });
+            }
+        });
+    }
+}
+
+// Todos understand everything; reminders only have a due date and time
+pub fn parse(input: &str) -> QuickAdd {
+    parse_at(input, Local::now().date_naive(), true)
+}
+
+pub fn parse_dates(input: &str) -> QuickAdd {
+    parse_at(input, Local::now().date_naive(), false)
+}
+
+fn parse_at(input: &str, today: NaiveDate, tags: bool) -> QuickAdd {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let mut result = QuickAdd::default();
+    let mut title = Vec::new();
+    let mut i = 0;
+
+    while i < words.len() {
+        let word = words[i];
+        let lower = word.to_lowercase();
+
+        if tags && result.priority.is_none() {
+            if let Some(priority) = lower.strip_prefix('!').and_then(parse_priority) {
+                result.priority = Some(priority);
+                i += 1;
+                continue;
+            }
+        }
+        if tags && result.project.is_none() && word.len() > 1 && word.starts_with('#') {
+            result.project = Some(word[1..].to_string());
+            i += 1;
+            continue;
+        }
+
+        // "on fri", "by tomorrow", "at 5pm": the filler word goes with the date
+        let filler = matches!(lower.as_str(), "on" | "by" | "at" | "due");
+        let start = if filler && i + 1 < words.len() {
+            i + 1
+        } else {
+            i
+        };
+
+        if result.due_date.is_none() {
+            if let Some((date, used)) = parse_date(&words[start..], today) {
+                result.due_date = Some(date);
+                i = start + used;
+                continue;
+            }
+        }
+        if result.due_time.is_none() {
+            if let Some(time) = parse_time(&words[start].to_lowercase()) {
+                result.due_time = Some(time);
+                i = start + 1;
+                continue;
+            }
+        }
+
+        title.push(word);
+        i += 1;
+    }
+
+    // A time alone means the next time the clock shows it
+    if let (None, Some(time)) = (result.due_date, result.due_time) {
+        let passed = time <= Local::now().time();
+        result.due_date = Some(if passed {
+            today + Duration::days(1)
+        } else {
+            today
+        });
+    }
+
+    result.title = title.join(" ");
+    result
+}
+
+fn parse_priority(word: &str) -> Option<TodoPriority> {
+    match word {
+        "high" | "h" | "urgent" | "1" | "!" => Some(TodoPriority::High),
+        "normal" | "n" | "medium" | "med" | "2" => Some(TodoPriority::Normal),
+        "low" | "l" | "3" => Some(TodoPriority::Low),
+        _ => None,
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_month(word: &str) -> Option<u32> {
+    let months = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let prefix = word.get(..3)?;
+    let index = months.iter().position(|m| *m == prefix)?;
+    // "march", "mar" and "sept" are fine, "mayday" is not
+    let full = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    full[index].starts_with(word).then_some(index as u32 + 1)
+}
+
+// On or after today
+fn upcoming(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let days = (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    today + Duration::days(days as i64)
+}
+
+// The date at the start of the words and how many words it used
+fn parse_date(words: &[&str], today: NaiveDate) -> Option<(NaiveDate, usize)> {
+    let first = words.first()?.to_lowercase();
+    let first = first.trim_end_matches(',');
+    let second = words.get(1).map(|w| w.to_lowercase());
+    let second = second.as_deref().map(|w| w.trim_end_matches(','));
+
+    match first {
+        "today" | "tod" | "tonight" => return Some((today, 1)),
+        "tomorrow" | "tmr" | "tmrw" => return Some((today + Duration::days(1), 1)),
+        _ => {}
+    }
+    if let Some(weekday) = parse_weekday(first) {
+        return Some((upcoming(today, weekday), 1));
+    }
+    if first == "next" {
+        match second {
+            Some("week") => {
+                let monday = upcoming(today + Duration::days(1), Weekday::Mon);
+                return Some((monday, 2));
+            }
+            // That day in the week starting next Monday
+            Some(word) => {
+                if let Some(weekday) = parse_weekday(word) {
+                    let monday = upcoming(today + Duration::days(1), Weekday::Mon);
+                    let date = monday + Duration::days(weekday.num_days_from_monday() as i64);
+                    return Some((date, 2));
+                }
+            }
+            None => {}
+        }
+    }
+    if first == "in" {
+        let count: i64 = second?.parse().ok()?;
+        let unit = words.get(2)?.to_lowercase();
+        let days = match unit.trim_end_matches(',') {
+            "day" | "days" | "d" => count,
+            "week" | "weeks" | "w" => count * 7,
+            _ => return None,
+        };
+        return Some((today + Duration::days(days), 3));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(first, "%Y-%m-%d") {
+        return Some((date, 1));
+    }
+
+    // "may 3" or "3 may", this year unless it already passed
+    let day_month = match (second, parse_month(first)) {
+        (Some(day), Some(month)) => day
+            .trim_end_matches(|c: char| c.is_alphabetic())
+            .parse::<u32>()
+            .ok()
+            .map(|day| (day, month)),
+        _ => None,
+    }
+    .or_else(|| {
+        let month = parse_month(second?)?;
+        let day = first
+            .trim_end_matches(|c: char| c.is_alphabetic())
+            .parse::<u32>()
+            .ok()?;
+        Some((day, month))
+    });
+    let (day, month) = day_month?;
+    let date = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if date < today {
+        return NaiveDate::from_ymd_opt(today.year() + 1, month, day).map(|d| (d, 2));
+    }
+    Some((date, 2))
+}
+
+// "5pm", "5:30pm", "17:00"
+fn parse_time(word: &str) -> Option<NaiveTime> {
+    let (clock, offset) = if let Some(clock) = word.strip_suffix("am") {
+        (clock, Some(0))
+    } else if let Some(clock) = word.strip_suffix("pm") {
+        (clock, Some(12))
+    } else {
+        (word, None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour.parse().ok()?, minute.parse().ok()?),
+        None if offset.is_some() => (clock.parse::<u32>().ok()?, 0),
+        _ => return None,
+    };
+    let hour = match offset {
+        Some(offset) if (1..=12).contains(&hour) => hour % 12 + offset,
+        Some(_) => return None,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
@@ -0,0 +1,173 @@
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TermSeparator {
+    Tab,
+    Comma,
+    Custom,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CardSeparator {
+    NewLine,
+    Semicolon,
+    Custom,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    pub term_separator: TermSeparator,
+    pub card_separator: CardSeparator,
+    pub custom_term_separator: String,
+    pub custom_card_separator: String,
+    // Put the definition on the front of the card instead of the term
+    pub definition_first: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        // Matches Quizlet's default export settings
+        Self {
+            term_separator: TermSeparator::Tab,
+            card_separator: CardSeparator::NewLine,
+            custom_term_separator: String::new(),
+            custom_card_separator: String::new(),
+            definition_first: false,
+        }
+    }
+}
+
+pub struct ParsedSet {
+    pub cards: Vec<(String, String)>, // (front, back)
+    pub skipped: usize,
+}
+
+pub fn parse_set(text: &str, options: &ImportOptions) -> Result<ParsedSet, Box<dyn std::error::Error>> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Err("Nothing to import".into());
+    }
+
+    let mut parsed = if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        parse_json(trimmed)?
+    } else {
+        parse_text(trimmed, options)?
+    };
+
+    if options.definition_first {
+        for (front, back) in parsed.cards.iter_mut() {
+            std::mem::swap(front, back);
+        }
+    }
+
+    if parsed.cards.is_empty() {
+        return Err("No terms found, check the separator settings".into());
+    }
+    Ok(parsed)
+}
+
+fn parse_text(text: &str, options: &ImportOptions) -> Result<ParsedSet, Box<dyn std::error::Error>> {
+    let term_separator = match &options.term_separator {
+        TermSeparator::Tab => "\t",
+        TermSeparator::Comma => ",",
+        TermSeparator::Custom if !options.custom_term_separator.is_empty() => {
+            options.custom_term_separator.as_str()
+        }
+        TermSeparator::Custom => return Err("Custom term separator is empty".into()),
+    };
+
+    let normalized = text.replace("\r\n", "\n");
+    let cards: Vec<&str> = match &options.card_separator {
+        CardSeparator::NewLine => normalized.split('\n').collect(),
+        CardSeparator::Semicolon => normalized.split(';').collect(),
+        CardSeparator::Custom if !options.custom_card_separator.is_empty() => normalized
+            .split(options.custom_card_separator.as_str())
+            .collect(),
+        CardSeparator::Custom => return Err("Custom card separator is empty".into()),
+    };
+
+    let mut parsed = ParsedSet {
+        cards: Vec::new(),
+        skipped: 0,
+    };
+    for card in cards {
+        if card.trim().is_empty() {
+            continue;
+        }
+        match card.split_once(term_separator) {
+            Some((term, definition)) if !term.trim().is_empty() => {
+                parsed
+                    .cards
+                    .push((term.trim().to_string(), definition.trim().to_string()));
+            }
+            _ => parsed.skipped += 1,
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_json(text: &str) -> Result<ParsedSet, Box<dyn std::error::Error>> {
+    let value: Value = serde_json::from_str(text)?;
+    let mut parsed = ParsedSet {
+        cards: Vec::new(),
+        skipped: 0,
+    };
+    collect_json_terms(&value, &mut parsed);
+    Ok(parsed)
+}
+
+// Quizlet's JSON comes in a few shapes (plain term lists, API responses with
+// nested "cardSides"), so look for term/definition pairs anywhere in the tree
+fn collect_json_terms(value: &Value, parsed: &mut ParsedSet) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                collect_json_terms(item, parsed);
+            }
+        }
+        Value::Object(map) => {
+            let term = ["term", "word", "front"]
+                .iter()
+                .find_map(|key| map.get(*key).and_then(|v| v.as_str()));
+            let definition = ["definition", "back"]
+                .iter()
+                .find_map(|key| map.get(*key).and_then(|v| v.as_str()));
+
+            if let (Some(term), Some(definition)) = (term, definition) {
+                if term.trim().is_empty() {
+                    parsed.skipped += 1;
+                } else {
+                    parsed
+                        .cards
+                        .push((term.trim().to_string(), definition.trim().to_string()));
+                }
+                return;
+            }
+
+            if let Some(Value::Array(sides)) = map.get("cardSides") {
+                let side_text = |label: &str| {
+                    sides
+                        .iter()
+                        .find(|side| side.get("label").and_then(|l| l.as_str()) == Some(label))
+                        .and_then(|side| side.get("media"))
+                        .and_then(|media| media.get(0))
+                        .and_then(|media| media.get("plainText"))
+                        .and_then(|text| text.as_str())
+                        .map(|text| text.trim().to_string())
+                };
+                match (side_text("word"), side_text("definition")) {
+                    (Some(term), Some(definition)) if !term.is_empty() => {
+                        parsed.cards.push((term, definition))
+                    }
+                    _ => parsed.skipped += 1,
+                }
+                return;
+            }
+
+            for child in map.values() {
+                collect_json_terms(child, parsed);
+            }
+        }
+        _ => {}
+    }
+}
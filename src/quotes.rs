@@ -0,0 +1,155 @@
+use chrono::{Datelike, Local};
+use std::cell::RefCell;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    pub text: String,
+    pub author: String, // Empty when the quote isn't attributed
+}
+
+impl Quote {
+    // How pinned quotes are stored in the settings
+    pub fn key(&self) -> String {
+        if self.author.is_empty() {
+            self.text.clone()
+        } else {
+            format!("{} — {}", self.text, self.author)
+        }
+    }
+}
+
+const BUNDLED: [(&str, &str); 20] = [
+    (
+        "The secret of getting ahead is getting started.",
+        "Mark Twain",
+    ),
+    (
+        "It always seems impossible until it's done.",
+        "Nelson Mandela",
+    ),
+    ("Well begun is half done.", "Aristotle"),
+    ("The expert in anything was once a beginner.", "Helen Hayes"),
+    ("Learning never exhausts the mind.", "Leonardo da Vinci"),
+    (
+        "Small deeds done are better than great deeds planned.",
+        "Peter Marshall",
+    ),
+    (
+        "You don't have to see the whole staircase, just take the first step.",
+        "Martin Luther King Jr.",
+    ),
+    (
+        "Success is the sum of small efforts, repeated day in and day out.",
+        "Robert Collier",
+    ),
+    (
+        "An investment in knowledge pays the best interest.",
+        "Benjamin Franklin",
+    ),
+    ("Quality is not an act, it is a habit.", "Will Durant"),
+    (
+        "The beautiful thing about learning is that nobody can take it away from you.",
+        "B. B. King",
+    ),
+    (
+        "Don't watch the clock; do what it does. Keep going.",
+        "Sam Levenson",
+    ),
+    (
+        "Tell me and I forget. Teach me and I remember. Involve me and I learn.",
+        "Benjamin Franklin",
+    ),
+    (
+        "A little progress each day adds up to big results.",
+        "Satya Nani",
+    ),
+    ("Focus on being productive instead of busy.", "Tim Ferriss"),
+    (
+        "What we learn with pleasure we never forget.",
+        "Alfred Mercier",
+    ),
+    (
+        "Start where you are. Use what you have. Do what you can.",
+        "Arthur Ashe",
+    ),
+    (
+        "Education is not the filling of a pail, but the lighting of a fire.",
+        "W. B. Yeats",
+    ),
+    ("Either you run the day or the day runs you.", "Jim Rohn"),
+    (
+        "Perseverance is not a long race; it is many short races one after the other.",
+        "Walter Elliot",
+    ),
+];
+
+pub fn bundled() -> Vec<Quote> {
+    BUNDLED
+        .iter()
+        .map(|(text, author)| Quote {
+            text: text.to_string(),
+            author: author.to_string(),
+        })
+        .collect()
+}
+
+// One quote per line, "Text — Author" or "Text - Author"; blank lines and "#" comments are skipped
+pub fn parse(contents: &str) -> Vec<Quote> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let split = line.rsplit_once(" — ").or_else(|| line.rsplit_once(" - "));
+            match split {
+                Some((text, author)) => Quote {
+                    text: text.trim().trim_matches('"').to_string(),
+                    author: author.trim().to_string(),
+                },
+                None => Quote {
+                    text: line.trim_matches('"').to_string(),
+                    author: String::new(),
+                },
+            }
+        })
+        .collect()
+}
+
+// A quotes file path and what was read from it
+type CachedFile = (String, Result<Vec<Quote>, String>);
+
+thread_local! {
+    // The user's quotes file, read once per path rather than every frame
+    static FILE_CACHE: RefCell<Option<CachedFile>> = RefCell::new(None);
+}
+
+pub fn load_file(path: &str) -> Result<Vec<Quote>, String> {
+    FILE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some((cached_path, quotes)) = cache.as_ref() {
+            if cached_path == path {
+                return quotes.clone();
+            }
+        }
+        let quotes = std::fs::read_to_string(Path::new(path))
+            .map(|contents| parse(&contents))
+            .map_err(|e| e.to_string());
+        *cache = Some((path.to_string(), quotes.clone()));
+        quotes
+    })
+}
+
+// Forgets the cached file, e.g. after a new one is chosen
+pub fn reload() {
+    FILE_CACHE.with(|cache| *cache.borrow_mut() = None);
+}
+
+// Changes once a day, so the quote stays put while it is shown
+pub fn of_the_day(quotes: &[Quote]) -> Option<&Quote> {
+    if quotes.is_empty() {
+        return None;
+    }
+    let day = Local::now().date_naive().num_days_from_ce() as usize;
+    quotes.get(day % quotes.len())
+}
@@ -0,0 +1,334 @@
+use crate::app::StatusMessage;
+use crate::data::{NotificationPeriod, OverdueEscalation, StudyData};
+use crate::hooks::{self, HookEvent};
+use crate::settings::AppSettings;
+use chrono::{Local, NaiveDate, NaiveDateTime, Timelike};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+// Hours at which "Tonight" and "Tomorrow" snoozes end
+const TONIGHT_HOUR: u32 = 20;
+const MORNING_HOUR: u32 = 9;
+
+#[derive(Debug, Clone, Copy)]
+enum ReminderAction {
+    SnoozeHour,
+    SnoozeTonight,
+    SnoozeTomorrow,
+    Done,
+}
+
+impl ReminderAction {
+    // "Tonight" is only offered while it is still ahead
+    fn available(now: NaiveDateTime) -> Vec<ReminderAction> {
+        let mut actions = vec![ReminderAction::SnoozeHour];
+        if now.hour() + 1 < TONIGHT_HOUR {
+            actions.push(ReminderAction::SnoozeTonight);
+        }
+        actions.push(ReminderAction::SnoozeTomorrow);
+        actions.push(ReminderAction::Done);
+        actions
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ReminderAction::SnoozeHour => "💤 1 hour",
+            ReminderAction::SnoozeTonight => "🌙 Tonight",
+            ReminderAction::SnoozeTomorrow => "📅 Tomorrow",
+            ReminderAction::Done => "✔ Mark done",
+        }
+    }
+
+    fn snooze_until(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        match self {
+            ReminderAction::SnoozeHour => Some(now + chrono::Duration::hours(1)),
+            ReminderAction::SnoozeTonight => now.date().and_hms_opt(TONIGHT_HOUR, 0, 0),
+            ReminderAction::SnoozeTomorrow => {
+                (now.date() + chrono::Duration::days(1)).and_hms_opt(MORNING_HOUR, 0, 0)
+            }
+            ReminderAction::Done => None,
+        }
+    }
+}
+
+// New notification texts for one reminder
+struct DueNotice {
+    reminder_id: u64,
+    messages: Vec<String>,
+    overdue: bool,
+}
+
+fn prompt_key(reminder_id: u64) -> String {
+    format!("reminder:{}", reminder_id)
+}
+
+// Runs from the main update loop so reminders fire whichever tab is open
+pub struct ReminderChecker {
+    last_check: Option<Instant>,
+    // (reminder id, date, notification text) already shown; entries are per day so
+    // "due today" comes back tomorrow as "overdue"
+    notified: HashSet<(u64, String, String)>,
+    // Reminders with a notification on screen and what each of its buttons does
+    prompts: HashMap<u64, Vec<ReminderAction>>,
+    // Held back during quiet hours
+    queued: Vec<DueNotice>,
+}
+
+impl ReminderChecker {
+    pub fn new() -> Self {
+        Self {
+            last_check: None,
+            notified: HashSet::new(),
+            prompts: HashMap::new(),
+            queued: Vec::new(),
+        }
+    }
+
+    fn check_due(&mut self) -> bool {
+        let due = self
+            .last_check
+            .map_or(true, |last| last.elapsed() >= CHECK_INTERVAL);
+        if due {
+            self.last_check = Some(Instant::now());
+        }
+        due
+    }
+
+    // Called every frame; each due reminder gets its own notification with snooze and done
+    // buttons, and stays quiet for the day once dismissed
+    pub fn update(
+        &mut self,
+        study_data: &mut StudyData,
+        settings: &AppSettings,
+        status: &mut StatusMessage,
+    ) {
+        self.handle_answers(study_data, settings, status);
+        if !self.check_due() {
+            return;
+        }
+
+        for notice in self.check(study_data) {
+            match self
+                .queued
+                .iter_mut()
+                .find(|q| q.reminder_id == notice.reminder_id)
+            {
+                Some(queued) => {
+                    queued.messages.extend(notice.messages);
+                    queued.overdue |= notice.overdue;
+                }
+                None => self.queued.push(notice),
+            }
+        }
+
+        let now = Local::now().naive_local();
+        if settings.in_quiet_hours(now.hour()) {
+            return;
+        }
+
+        for notice in std::mem::take(&mut self.queued) {
+            let escalation = match study_data
+                .reminders
+                .iter()
+                .find(|r| r.id == notice.reminder_id && !r.is_completed && !r.is_snoozed())
+            {
+                Some(reminder) => reminder.escalation,
+                // Done or snoozed while the notice was queued
+                None => continue,
+            };
+            if notice.overdue && escalation == OverdueEscalation::Once {
+                if let Err(e) = study_data.mark_overdue_notified(notice.reminder_id) {
+                    status.error(&format!("Error updating reminder: {}", e));
+                }
+            }
+
+            let actions = ReminderAction::available(now);
+            let labels: Vec<String> = actions.iter().map(|a| a.label().to_string()).collect();
+            status.prompt(
+                &prompt_key(notice.reminder_id),
+                &format!("REMINDER: {}", notice.messages.join(" | ")),
+                &labels,
+            );
+            self.prompts.insert(notice.reminder_id, actions);
+        }
+    }
+
+    fn handle_answers(
+        &mut self,
+        study_data: &mut StudyData,
+        settings: &AppSettings,
+        status: &mut StatusMessage,
+    ) {
+        let now = Local::now().naive_local();
+        let mut finished = Vec::new();
+
+        for (&reminder_id, actions) in &self.prompts {
+            let key = prompt_key(reminder_id);
+            let title = match study_data
+                .reminders
+                .iter()
+                .find(|r| r.id == reminder_id && !r.is_completed)
+            {
+                Some(reminder) => reminder.title.clone(),
+                // Completed or deleted from the Reminders tab meanwhile
+                None => {
+                    status.withdraw(&key);
+                    finished.push(reminder_id);
+                    continue;
+                }
+            };
+
+            let action = match status.take_answer(&key) {
+                Some(index) => actions.get(index).copied(),
+                // Dismissed with ✖
+                None if !status.is_prompting(&key) => None,
+                None => continue,
+            };
+            finished.push(reminder_id);
+
+            let Some(action) = action else {
+                continue;
+            };
+            let result = match action.snooze_until(now) {
+                Some(until) => {
+                    // Let it notify again once the snooze is over
+                    self.notified
+                        .retain(|(id, _, kind)| *id != reminder_id || kind == "hook");
+                    study_data.snooze_reminder(reminder_id, until).map(|()| {
+                        format!(
+                            "Snoozed \"{}\" until {} {}",
+                            title,
+                            until.format("%a"),
+                            settings.clock_format.format(until.time())
+                        )
+                    })
+                }
+                None => study_data
+                    .complete_reminder(reminder_id)
+                    .map(|()| format!("Marked \"{}\" as done", title)),
+            };
+            match result {
+                Ok(message) => status.show(&message),
+                Err(e) => status.error(&format!("Error updating reminder: {}", e)),
+            }
+        }
+
+        for reminder_id in finished {
+            self.prompts.remove(&reminder_id);
+        }
+    }
+
+    // Everything due as a single line, for the headless check-reminders task
+    #[cfg(feature = "headless")]
+    pub fn summary(&mut self, study_data: &StudyData) -> Option<String> {
+        let notifications: Vec<String> = self
+            .check(study_data)
+            .into_iter()
+            .flat_map(|notice| notice.messages)
+            .collect();
+        if notifications.is_empty() {
+            return None;
+        }
+
+        // Show up to 3 at once
+        let mut message = "REMINDER: ".to_string();
+        message.push_str(
+            &notifications
+                .iter()
+                .take(3)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" | "),
+        );
+        if notifications.len() > 3 {
+            message.push_str(" (and more...)");
+        }
+        Some(message)
+    }
+
+    fn check(&mut self, study_data: &StudyData) -> Vec<DueNotice> {
+        let today = Local::now().date_naive();
+        let today_str = today.format("%Y-%m-%d").to_string();
+        let mut notifications = Vec::new();
+
+        for reminder in &study_data.reminders {
+            if reminder.is_completed
+                || reminder.is_snoozed()
+                || self.prompts.contains_key(&reminder.id)
+            {
+                continue;
+            }
+
+            let due_date = match NaiveDate::parse_from_str(&reminder.due_date, "%Y-%m-%d") {
+                Ok(date) => date,
+                Err(_) => continue,
+            };
+            let days_until = (due_date - today).num_days();
+            let mut messages = Vec::new();
+
+            for period in &reminder.notification_periods {
+                match period {
+                    NotificationPeriod::OneDay if days_until == 1 => {
+                        messages.push(format!("\"{}\" is due tomorrow!", reminder.title));
+                    }
+                    NotificationPeriod::ThreeDays if days_until == 3 => {
+                        messages.push(format!("\"{}\" is due in 3 days!", reminder.title));
+                    }
+                    NotificationPeriod::OneWeek if days_until == 7 => {
+                        messages.push(format!("\"{}\" is due in a week!", reminder.title));
+                    }
+                    NotificationPeriod::Custom(custom_days)
+                        if days_until == *custom_days as i64 =>
+                    {
+                        messages.push(format!(
+                            "\"{}\" is due in {} days!",
+                            reminder.title, custom_days
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+
+            // Always notify if due today; overdue ones every day, or just the first time
+            let once_shown =
+                reminder.escalation == OverdueEscalation::Once && reminder.overdue_notified;
+            let overdue_message = (days_until < 0 && !once_shown)
+                .then(|| format!("\"{}\" is overdue by {} days!", reminder.title, -days_until));
+            if days_until == 0 {
+                messages.push(format!("\"{}\" is due today!", reminder.title));
+            }
+            messages.extend(overdue_message.clone());
+
+            if days_until <= 0 {
+                let key = (reminder.id, today_str.clone(), "hook".to_string());
+                if self.notified.insert(key) {
+                    hooks::emit(
+                        HookEvent::ReminderDue,
+                        &[
+                            ("title", reminder.title.clone()),
+                            ("due_date", reminder.due_date.clone()),
+                        ],
+                    );
+                }
+            }
+
+            let new_messages: Vec<String> = messages
+                .into_iter()
+                .filter(|message| {
+                    self.notified
+                        .insert((reminder.id, today_str.clone(), message.clone()))
+                })
+                .collect();
+            if !new_messages.is_empty() {
+                notifications.push(DueNotice {
+                    reminder_id: reminder.id,
+                    overdue: overdue_message.is_some_and(|m| new_messages.contains(&m)),
+                    messages: new_messages,
+                });
+            }
+        }
+        notifications
+    }
+}
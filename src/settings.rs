@@ -1,4 +1,10 @@
-use eframe::egui::Color32;
+use crate::hooks::EventHook;
+use crate::ui::home_tab::HomeWidgetConfig;
+use crate::ui::snippets::Snippet;
+use crate::ui::world_clock::WorldClockZone;
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use eframe::egui::style::Spacing;
+use eframe::egui::{vec2, Color32, Margin, Vec2};
 use serde::{Deserialize, Serialize};
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Write};
@@ -16,6 +22,240 @@ impl Default for NavigationLayout {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum WeekStart {
+    #[default]
+    Monday,
+    Sunday,
+}
+
+impl WeekStart {
+    pub const ALL: [WeekStart; 2] = [WeekStart::Monday, WeekStart::Sunday];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WeekStart::Monday => "Monday",
+            WeekStart::Sunday => "Sunday",
+        }
+    }
+
+    // Column of the date in a week that starts on this day, 0-6
+    pub fn offset(&self, date: NaiveDate) -> u32 {
+        match self {
+            WeekStart::Monday => date.weekday().num_days_from_monday(),
+            WeekStart::Sunday => date.weekday().num_days_from_sunday(),
+        }
+    }
+
+    pub fn week_of(&self, date: NaiveDate) -> NaiveDate {
+        date - chrono::Duration::days(self.offset(date) as i64)
+    }
+
+    pub fn day_names(&self) -> [&'static str; 7] {
+        match self {
+            WeekStart::Monday => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            WeekStart::Sunday => ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum DateFormat {
+    #[default]
+    Iso, // 2024-05-03
+    DayMonthYear, // 03/05/2024
+    MonthDayYear, // 05/03/2024
+    Long,         // May 3, 2024
+}
+
+impl DateFormat {
+    pub const ALL: [DateFormat; 4] = [
+        DateFormat::Iso,
+        DateFormat::DayMonthYear,
+        DateFormat::MonthDayYear,
+        DateFormat::Long,
+    ];
+
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            DateFormat::Iso => "%Y-%m-%d",
+            DateFormat::DayMonthYear => "%d/%m/%Y",
+            DateFormat::MonthDayYear => "%m/%d/%Y",
+            DateFormat::Long => "%b %-d, %Y",
+        }
+    }
+
+    pub fn format(&self, date: NaiveDate) -> String {
+        date.format(self.pattern()).to_string()
+    }
+
+    // Stored dates are always YYYY-MM-DD; anything else is shown as it is
+    pub fn format_str(&self, date: &str) -> String {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map(|d| self.format(d))
+            .unwrap_or_else(|_| date.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum ClockFormat {
+    #[default]
+    TwentyFourHour,
+    TwelveHour,
+}
+
+impl ClockFormat {
+    pub const ALL: [ClockFormat; 2] = [ClockFormat::TwentyFourHour, ClockFormat::TwelveHour];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ClockFormat::TwentyFourHour => "24-hour",
+            ClockFormat::TwelveHour => "12-hour",
+        }
+    }
+
+    pub fn format(&self, time: NaiveTime) -> String {
+        match self {
+            ClockFormat::TwentyFourHour => time.format("%H:%M").to_string(),
+            ClockFormat::TwelveHour => time.format("%-I:%M %p").to_string(),
+        }
+    }
+
+    // Stored times are always HH:MM
+    pub fn format_str(&self, time: &str) -> String {
+        NaiveTime::parse_from_str(time, "%H:%M")
+            .map(|t| self.format(t))
+            .unwrap_or_else(|_| time.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum NumberFormat {
+    #[default]
+    Plain, // 1234.5
+    Comma, // 1,234.5
+    Space, // 1 234,5
+    Dot,   // 1.234,5
+}
+
+impl NumberFormat {
+    pub const ALL: [NumberFormat; 4] = [
+        NumberFormat::Plain,
+        NumberFormat::Comma,
+        NumberFormat::Space,
+        NumberFormat::Dot,
+    ];
+
+    // Thousands and decimal separators
+    fn separators(&self) -> (Option<char>, char) {
+        match self {
+            NumberFormat::Plain => (None, '.'),
+            NumberFormat::Comma => (Some(','), '.'),
+            NumberFormat::Space => (Some(' '), ','),
+            NumberFormat::Dot => (Some('.'), ','),
+        }
+    }
+
+    pub fn format(&self, value: f64, decimals: usize) -> String {
+        let (thousands, decimal) = self.separators();
+        let text = format!("{:.*}", decimals, value.abs());
+        let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+
+        let mut result = String::new();
+        if value < 0.0 && text.chars().any(|c| c != '0' && c != '.') {
+            result.push('-');
+        }
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                if let Some(separator) = thousands {
+                    result.push(separator);
+                }
+            }
+            result.push(digit);
+        }
+        if !fraction.is_empty() {
+            result.push(decimal);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    pub fn minutes_and_hours(&self, minutes: f64) -> String {
+        format!(
+            "{} minutes ({} hours)",
+            self.format(minutes, 1),
+            self.format(minutes / 60.0, 1)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum UiDensity {
+    #[default]
+    Comfortable,
+    Compact, // Tighter spacing, smaller tabs and fonts for small laptop screens
+}
+
+// Painted tabs in the tab bar, with the icon and label placed from the top and bottom edges
+pub struct TabMetrics {
+    pub size: Vec2,
+    pub icon_inset: f32,
+    pub icon_size: f32,
+    pub label_inset: f32,
+    pub label_size: f32,
+}
+
+impl UiDensity {
+    pub const ALL: [UiDensity; 2] = [UiDensity::Comfortable, UiDensity::Compact];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            UiDensity::Comfortable => "Comfortable",
+            UiDensity::Compact => "Compact",
+        }
+    }
+
+    // Applied on top of the interface font size
+    pub fn font_scale(&self) -> f32 {
+        match self {
+            UiDensity::Comfortable => 1.0,
+            UiDensity::Compact => 0.9,
+        }
+    }
+
+    pub fn spacing(&self) -> Spacing {
+        let mut spacing = Spacing::default();
+        if *self == UiDensity::Compact {
+            spacing.item_spacing = vec2(6.0, 2.0);
+            spacing.button_padding = vec2(3.0, 0.0);
+            spacing.window_margin = Margin::same(4.0);
+            spacing.menu_margin = Margin::same(4.0);
+            spacing.indent = 14.0;
+            spacing.interact_size = vec2(36.0, 16.0);
+        }
+        spacing
+    }
+
+    pub fn tab_metrics(&self) -> TabMetrics {
+        match self {
+            UiDensity::Comfortable => TabMetrics {
+                size: vec2(90.0, 50.0),
+                icon_inset: 12.0,
+                icon_size: 16.0,
+                label_inset: 8.0,
+                label_size: 10.0,
+            },
+            UiDensity::Compact => TabMetrics {
+                size: vec2(72.0, 38.0),
+                icon_inset: 5.0,
+                icon_size: 14.0,
+                label_inset: 5.0,
+                label_size: 9.0,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PresetTheme {
     Default,
@@ -29,6 +269,8 @@ pub enum PresetTheme {
     Nord,
     Dracula,
     Monokai,
+    HighContrast,
+    ColorBlindSafe,
     Custom,
 }
 
@@ -136,6 +378,28 @@ impl PresetTheme {
                 accent: [174, 129, 255, 255],             // #ae81ff
                 panel_background: [73, 72, 62, 255],      // #49483e
             },
+            PresetTheme::HighContrast => ColorTheme {
+                background: [0, 0, 0, 255],
+                navigation_background: [0, 0, 0, 255],
+                active_tab: [0, 90, 200, 255],
+                inactive_tab: [90, 90, 90, 255],
+                text_primary: [255, 255, 255, 255],
+                text_secondary: [230, 230, 230, 255],
+                accent: [0, 150, 150, 255],
+                panel_background: [20, 20, 20, 255],
+            },
+            // Vermillion/blue from the Okabe-Ito palette, distinguishable with deuteranopia
+            // and protanopia
+            PresetTheme::ColorBlindSafe => ColorTheme {
+                background: [30, 32, 38, 255],
+                navigation_background: [40, 43, 50, 255],
+                active_tab: [213, 94, 0, 255], // #d55e00
+                inactive_tab: [100, 100, 110, 255],
+                text_primary: [245, 245, 245, 255],
+                text_secondary: [200, 200, 205, 255],
+                accent: [0, 114, 178, 255], // #0072b2
+                panel_background: [45, 48, 56, 255],
+            },
             PresetTheme::Custom => ColorTheme::default(), // Will use custom values
         }
     }
@@ -153,6 +417,8 @@ impl PresetTheme {
             PresetTheme::Nord => "Nord",
             PresetTheme::Dracula => "Dracula",
             PresetTheme::Monokai => "Monokai",
+            PresetTheme::HighContrast => "High Contrast",
+            PresetTheme::ColorBlindSafe => "Color Blind Safe",
             PresetTheme::Custom => "Custom",
         }
     }
@@ -170,6 +436,8 @@ impl PresetTheme {
             PresetTheme::Nord,
             PresetTheme::Dracula,
             PresetTheme::Monokai,
+            PresetTheme::HighContrast,
+            PresetTheme::ColorBlindSafe,
             PresetTheme::Custom,
         ]
     }
@@ -199,8 +467,12 @@ impl PresetTheme {
             PresetTheme::Monokai,
         ]);
 
-        // Third row for Custom (if you want to keep it separate)
-        rows.push(vec![PresetTheme::Custom]);
+        // Third row - accessibility themes and Custom
+        rows.push(vec![
+            PresetTheme::HighContrast,
+            PresetTheme::ColorBlindSafe,
+            PresetTheme::Custom,
+        ]);
 
         rows
     }
@@ -302,15 +574,24 @@ impl TabConfig {
 
     pub fn get_default_name(&self) -> String {
         match self.tab_type {
+            crate::app::Tab::Home => "Home".to_string(),
             crate::app::Tab::Timer => "Timer".to_string(),
             crate::app::Tab::Stats => "Statistics".to_string(),
             crate::app::Tab::Record => "Record".to_string(),
             crate::app::Tab::Graph => "Graph".to_string(),
             crate::app::Tab::Todo => "Todo and Habits".to_string(),
+            crate::app::Tab::Planner => "Planner".to_string(),
             crate::app::Tab::Flashcards => "Flashcards".to_string(),
             crate::app::Tab::Calculator => "Calculator".to_string(),
             crate::app::Tab::Markdown => "Markdown".to_string(),
             crate::app::Tab::Reminder => "Reminder".to_string(),
+            crate::app::Tab::Journal => "Journal".to_string(),
+            crate::app::Tab::Assignments => "Assignments".to_string(),
+            crate::app::Tab::ImageViewer => "Image Viewer".to_string(),
+            crate::app::Tab::EpubReader => "EPUB Reader".to_string(),
+            crate::app::Tab::Whiteboard => "Whiteboard".to_string(),
+            crate::app::Tab::Recorder => "Recorder".to_string(),
+            crate::app::Tab::Video => "Video Player".to_string(),
             crate::app::Tab::Terminal => "Terminal".to_string(),
             crate::app::Tab::Settings => "Settings".to_string(),
         }
@@ -323,18 +604,189 @@ pub struct AppSettings {
     pub tab_configs: Vec<TabConfig>,
     pub theme_preset: PresetTheme,
     pub custom_colors: ColorTheme,
+    #[serde(default)]
+    pub hooks: Vec<EventHook>,
+    #[serde(default)]
+    pub daily_goal_minutes: u32, // 0 disables the goal
+    #[serde(default)]
+    pub discord_presence_enabled: bool,
+    #[serde(default)]
+    pub discord_client_id: String,
+    #[serde(default)]
+    pub show_startup_summary: bool,
+    #[serde(default)]
+    pub rtl_layout: bool, // Mirror navigation and right-align rendered notes
+    #[serde(default)]
+    pub ui_density: UiDensity,
+    #[serde(default)]
+    pub fallback_font_path: Option<String>, // TTF/OTF used for glyphs the built-in fonts lack
+    #[serde(default)]
+    pub ui_font_path: Option<String>,
+    #[serde(default = "default_ui_font_size")]
+    pub ui_font_size: f32,
+    #[serde(default)]
+    pub editor_font_path: Option<String>, // Markdown editor and terminal
+    #[serde(default = "default_editor_font_size")]
+    pub editor_font_size: f32,
+    #[serde(default)]
+    pub battery_saver: bool, // No animations, 1 Hz clock repaints, no weather polling on battery
+    #[serde(default)]
+    pub share_nearby: bool, // Advertise on the local network so others can send decks and notes
+    #[serde(default)]
+    pub work_interval_minutes: u32, // Pomodoro length; 0 keeps the timer a plain stopwatch
+    #[serde(default = "default_break_minutes")]
+    pub break_minutes: u32,
+    #[serde(default)]
+    pub auto_start_breaks: bool,
+    #[serde(default = "default_auto_start_work")]
+    pub auto_start_work: bool,
+    #[serde(default)]
+    pub world_clocks: Vec<WorldClockZone>, // Up to three, shown next to the weather
+    #[serde(default = "default_agenda_widget")]
+    pub agenda_widget: bool, // Top todo, next reminder and habit progress in the tab bar
+    #[serde(default)]
+    pub week_start: WeekStart,
+    #[serde(default)]
+    pub date_format: DateFormat,
+    #[serde(default)]
+    pub clock_format: ClockFormat,
+    #[serde(default)]
+    pub number_format: NumberFormat,
+    #[serde(default = "default_zen_line_width")]
+    pub zen_line_width: f32, // Width of the editor column in zen mode, in points
+    #[serde(default)]
+    pub zen_focus_paragraph: bool, // Dim everything but the paragraph being written
+    #[serde(default = "default_review_window_fullscreen")]
+    pub review_window_fullscreen: bool, // Fullscreen card review also makes the OS window fullscreen
+    #[serde(default)]
+    pub typewriter_mode: bool, // Markdown editor toolbar toggles, shared by all notes
+    #[serde(default)]
+    pub highlight_line: bool,
+    #[serde(default)]
+    pub snippets: Vec<Snippet>, // Expanded in the markdown editor and card fields
+    #[serde(default)]
+    pub recent_notes: Vec<String>, // Most recently opened first, offered for new Markdown tabs
+    #[serde(default)]
+    pub favorite_notes: Vec<String>,
+    #[serde(default = "crate::ui::home_tab::default_widgets")]
+    pub home_widgets: Vec<HomeWidgetConfig>, // Shown on the Home tab, in this order
+    #[serde(default)]
+    pub quotes_file: Option<String>, // Replaces the built-in quotes when it has any
+    #[serde(default)]
+    pub pinned_quotes: Vec<String>,
+    #[serde(default)]
+    pub pinned_quotes_only: bool,
+    #[serde(default)]
+    pub break_reminders: bool, // 20-20-20 eye breaks after continuous use, separate from Pomodoro
+    #[serde(default = "default_break_reminder_minutes")]
+    pub break_reminder_minutes: u32,
+    #[serde(default = "default_break_snooze_minutes")]
+    pub break_snooze_minutes: u32,
+    #[serde(default)]
+    pub quiet_hours: bool, // Reminder notifications wait until the window ends
+    #[serde(default = "default_quiet_hours_start")]
+    pub quiet_hours_start: u32, // Hour of the day, 0-23
+    #[serde(default = "default_quiet_hours_end")]
+    pub quiet_hours_end: u32,
+    #[serde(default)]
+    pub night_light: bool, // Warm tint over the whole window during the hours below
+    #[serde(default = "default_night_light_start")]
+    pub night_light_start: u32, // Hour of the day, 0-23
+    #[serde(default = "default_night_light_end")]
+    pub night_light_end: u32,
+    #[serde(default = "default_night_light_strength")]
+    pub night_light_strength: u32, // Percent
+}
+
+const MAX_RECENT_NOTES: usize = 10;
+
+// The window may wrap past midnight, e.g. 22 to 7
+fn hour_in_window(hour: u32, start: u32, end: u32) -> bool {
+    if start == end {
+        return false;
+    }
+    if start < end {
+        (start..end).contains(&hour)
+    } else {
+        hour >= start || hour < end
+    }
+}
+
+fn default_ui_font_size() -> f32 {
+    12.5 // egui's own body size
+}
+
+fn default_editor_font_size() -> f32 {
+    14.0
+}
+
+fn default_break_minutes() -> u32 {
+    5
+}
+
+fn default_agenda_widget() -> bool {
+    true
+}
+
+fn default_review_window_fullscreen() -> bool {
+    true
+}
+
+fn default_auto_start_work() -> bool {
+    true // The timer always resumed on its own after a break
+}
+
+fn default_break_reminder_minutes() -> u32 {
+    20
+}
+
+fn default_break_snooze_minutes() -> u32 {
+    5
+}
+
+fn default_quiet_hours_start() -> u32 {
+    22
+}
+
+fn default_quiet_hours_end() -> u32 {
+    7
+}
+
+fn default_night_light_start() -> u32 {
+    21
+}
+
+fn default_night_light_end() -> u32 {
+    7
+}
+
+fn default_night_light_strength() -> u32 {
+    30
+}
+
+fn default_zen_line_width() -> f32 {
+    720.0
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         let default_tabs = vec![
+            TabConfig::new(crate::app::Tab::Home, true),
             TabConfig::new(crate::app::Tab::Timer, true),
             TabConfig::new(crate::app::Tab::Record, true),
             TabConfig::new(crate::app::Tab::Stats, true),
             TabConfig::new(crate::app::Tab::Graph, true),
             TabConfig::new(crate::app::Tab::Todo, true),
+            TabConfig::new(crate::app::Tab::Planner, true),
             TabConfig::new(crate::app::Tab::Flashcards, true),
             TabConfig::new(crate::app::Tab::Reminder, true),
+            TabConfig::new(crate::app::Tab::Journal, true),
+            TabConfig::new(crate::app::Tab::Assignments, true),
+            TabConfig::new(crate::app::Tab::ImageViewer, true),
+            TabConfig::new(crate::app::Tab::EpubReader, true),
+            TabConfig::new(crate::app::Tab::Whiteboard, true),
+            TabConfig::new(crate::app::Tab::Recorder, true),
+            TabConfig::new(crate::app::Tab::Video, true),
             TabConfig::new(crate::app::Tab::Calculator, true),
             TabConfig::new(crate::app::Tab::Markdown, true),
             TabConfig::new(crate::app::Tab::Terminal, true),
@@ -346,6 +798,52 @@ impl Default for AppSettings {
             tab_configs: default_tabs,
             theme_preset: PresetTheme::Default,
             custom_colors: ColorTheme::default(),
+            hooks: Vec::new(),
+            daily_goal_minutes: 0,
+            discord_presence_enabled: false,
+            discord_client_id: String::new(),
+            show_startup_summary: false,
+            rtl_layout: false,
+            ui_density: UiDensity::default(),
+            fallback_font_path: None,
+            ui_font_path: None,
+            ui_font_size: default_ui_font_size(),
+            editor_font_path: None,
+            editor_font_size: default_editor_font_size(),
+            battery_saver: false,
+            share_nearby: false,
+            work_interval_minutes: 0,
+            break_minutes: default_break_minutes(),
+            auto_start_breaks: false,
+            auto_start_work: default_auto_start_work(),
+            world_clocks: Vec::new(),
+            agenda_widget: default_agenda_widget(),
+            week_start: WeekStart::default(),
+            date_format: DateFormat::default(),
+            clock_format: ClockFormat::default(),
+            number_format: NumberFormat::default(),
+            zen_line_width: default_zen_line_width(),
+            zen_focus_paragraph: false,
+            review_window_fullscreen: default_review_window_fullscreen(),
+            typewriter_mode: false,
+            highlight_line: false,
+            snippets: Vec::new(),
+            recent_notes: Vec::new(),
+            favorite_notes: Vec::new(),
+            home_widgets: crate::ui::home_tab::default_widgets(),
+            quotes_file: None,
+            pinned_quotes: Vec::new(),
+            pinned_quotes_only: false,
+            break_reminders: false,
+            break_reminder_minutes: default_break_reminder_minutes(),
+            break_snooze_minutes: default_break_snooze_minutes(),
+            quiet_hours: false,
+            quiet_hours_start: default_quiet_hours_start(),
+            quiet_hours_end: default_quiet_hours_end(),
+            night_light: false,
+            night_light_start: default_night_light_start(),
+            night_light_end: default_night_light_end(),
+            night_light_strength: default_night_light_strength(),
         }
     }
 }
@@ -412,7 +910,56 @@ impl AppSettings {
         // Apply text colors through override_text_color
         visuals.override_text_color = Some(colors.text_primary_color32());
 
-        ctx.set_visuals(visuals);
+        let mut style = (*ctx.style()).clone();
+        style.visuals = visuals;
+        style.spacing = self.ui_density.spacing();
+        style.animation_time = if self.battery_saver {
+            0.0
+        } else {
+            eframe::egui::Style::default().animation_time
+        };
+        ctx.set_style(style);
+    }
+
+    // Keeps a running clock ticking; battery saver drops it from every frame to once a second
+    pub fn request_clock_repaint(&self, ctx: &eframe::egui::Context) {
+        if self.battery_saver {
+            ctx.request_repaint_after(std::time::Duration::from_secs(1));
+        } else {
+            ctx.request_repaint();
+        }
+    }
+
+    pub fn in_quiet_hours(&self, hour: u32) -> bool {
+        self.quiet_hours && hour_in_window(hour, self.quiet_hours_start, self.quiet_hours_end)
+    }
+
+    pub fn in_night_light(&self, hour: u32) -> bool {
+        self.night_light && hour_in_window(hour, self.night_light_start, self.night_light_end)
+    }
+
+    // Puts a note at the front of the recent list. Returns false if it already was,
+    // so opening the same note again doesn't rewrite the settings file.
+    pub fn note_opened(&mut self, path: &str) -> bool {
+        if self.recent_notes.first().map(String::as_str) == Some(path) {
+            return false;
+        }
+        self.recent_notes.retain(|recent| recent != path);
+        self.recent_notes.insert(0, path.to_string());
+        self.recent_notes.truncate(MAX_RECENT_NOTES);
+        true
+    }
+
+    pub fn is_favorite_note(&self, path: &str) -> bool {
+        self.favorite_notes.iter().any(|favorite| favorite == path)
+    }
+
+    pub fn toggle_favorite_note(&mut self, path: &str) {
+        if self.is_favorite_note(path) {
+            self.favorite_notes.retain(|favorite| favorite != path);
+        } else {
+            self.favorite_notes.push(path.to_string());
+        }
     }
 
     pub fn is_tab_enabled(&self, tab: &crate::app::Tab) -> bool {
@@ -463,6 +1010,13 @@ impl AppSettings {
         }
     }
 
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        if from < self.tab_configs.len() && to < self.tab_configs.len() {
+            let config = self.tab_configs.remove(from);
+            self.tab_configs.insert(to, config);
+        }
+    }
+
     pub fn reset_tab_name(&mut self, tab: &crate::app::Tab) {
         if let Some(config) = self.get_tab_config_mut(tab) {
             config.custom_name = None;
@@ -472,12 +1026,22 @@ impl AppSettings {
     pub fn reset_tab_order(&mut self) {
         // Reset to default order
         let default_order = vec![
+            crate::app::Tab::Home,
             crate::app::Tab::Timer,
             crate::app::Tab::Record,
             crate::app::Tab::Stats,
             crate::app::Tab::Graph,
             crate::app::Tab::Todo,
+            crate::app::Tab::Planner,
+            crate::app::Tab::Flashcards,
             crate::app::Tab::Reminder,
+            crate::app::Tab::Journal,
+            crate::app::Tab::Assignments,
+            crate::app::Tab::ImageViewer,
+            crate::app::Tab::EpubReader,
+            crate::app::Tab::Whiteboard,
+            crate::app::Tab::Recorder,
+            crate::app::Tab::Video,
             crate::app::Tab::Calculator,
             crate::app::Tab::Markdown,
             crate::app::Tab::Terminal,
@@ -501,14 +1065,115 @@ impl AppSettings {
         self.tab_configs = new_configs;
     }
 
+    pub fn export_to(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(&self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    // Reads settings exported on another machine and checks they are usable here
+    pub fn import_from(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = std::fs::read_to_string(path)?;
+        let mut settings: AppSettings = serde_json::from_str(&contents)
+            .map_err(|e| format!("Not a valid settings file: {}", e))?;
+
+        // Drop duplicate tab entries a hand-edited file might contain
+        let mut seen = Vec::new();
+        settings.tab_configs.retain(|config| {
+            if seen.contains(&config.tab_type) {
+                false
+            } else {
+                seen.push(config.tab_type.clone());
+                true
+            }
+        });
+        settings.ensure_all_tabs_present();
+
+        if settings.daily_goal_minutes > 1440 {
+            return Err("Daily goal must be at most 1440 minutes".into());
+        }
+        if settings
+            .hooks
+            .iter()
+            .any(|hook| hook.target.trim().is_empty())
+        {
+            return Err("Every event hook needs a command or URL".into());
+        }
+
+        Ok(settings)
+    }
+
+    // Human-readable list of what replacing these settings with `other` would change
+    pub fn describe_changes(&self, other: &AppSettings) -> Vec<String> {
+        let (current, incoming) = match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(serde_json::Value::Object(current)), Ok(serde_json::Value::Object(incoming))) => {
+                (current, incoming)
+            }
+            _ => return vec!["Unable to compare settings".to_string()],
+        };
+
+        let mut changes = Vec::new();
+        for (key, new_value) in &incoming {
+            let old_value = current.get(key).unwrap_or(&serde_json::Value::Null);
+            if old_value == new_value {
+                continue;
+            }
+
+            // Hooks run commands, so each new or edited one is shown in full
+            if key == "hooks" {
+                for hook in other.hooks.iter().filter(|hook| !self.hooks.contains(hook)) {
+                    changes.push(format!(
+                        "event hook: {} → {}: {}{}",
+                        hook.event.name(),
+                        hook.action.name(),
+                        hook.target,
+                        if hook.enabled { "" } else { " (disabled)" }
+                    ));
+                }
+                let removed = self
+                    .hooks
+                    .iter()
+                    .filter(|hook| !other.hooks.contains(hook))
+                    .count();
+                if removed > 0 {
+                    changes.push(format!("event hooks removed or replaced: {}", removed));
+                }
+                continue;
+            }
+
+            let name = key.replace('_', " ");
+            let change = match key.as_str() {
+                "tab_configs" => "tabs: order, visibility or names".to_string(),
+                "custom_colors" => "custom theme colors".to_string(),
+                _ => format!(
+                    "{}: {} → {}",
+                    name,
+                    short_value(old_value),
+                    short_value(new_value)
+                ),
+            };
+            changes.push(change);
+        }
+        changes
+    }
+
     fn ensure_all_tabs_present(&mut self) {
         let all_tabs = vec![
+            crate::app::Tab::Home,
             crate::app::Tab::Timer,
             crate::app::Tab::Record,
             crate::app::Tab::Stats,
             crate::app::Tab::Graph,
             crate::app::Tab::Todo,
+            crate::app::Tab::Planner,
             crate::app::Tab::Reminder,
+            crate::app::Tab::Journal,
+            crate::app::Tab::Assignments,
+            crate::app::Tab::ImageViewer,
+            crate::app::Tab::EpubReader,
+            crate::app::Tab::Whiteboard,
+            crate::app::Tab::Recorder,
+            crate::app::Tab::Video,
             crate::app::Tab::Flashcards,
             crate::app::Tab::Calculator,
             crate::app::Tab::Markdown,
@@ -523,3 +1188,16 @@ impl AppSettings {
         }
     }
 }
+
+fn short_value(value: &serde_json::Value) -> String {
+    let text = match value {
+        serde_json::Value::String(text) if text.is_empty() => "(empty)".to_string(),
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    if text.chars().count() > 40 {
+        format!("{}…", text.chars().take(40).collect::<String>())
+    } else {
+        text
+    }
+}
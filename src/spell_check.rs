@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+use spellbook::Dictionary;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::ops::Range;
+use std::path::PathBuf;
+
+// Checks spelling against Hunspell dictionaries (a <language>.aff and <language>.dic
+// pair), either from the system or dropped into our own dictionaries folder.
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpellCheckConfig {
+    pub enabled: bool,
+    pub language: String,
+    #[serde(default)]
+    pub ignored: BTreeSet<String>, // Lowercase words never flagged in this profile
+}
+
+impl Default for SpellCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            language: "en_US".to_string(),
+            ignored: BTreeSet::new(),
+        }
+    }
+}
+
+impl SpellCheckConfig {
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut path = crate::profile::config_dir().ok_or("Could not find config directory")?;
+    path.push("spell_check.json");
+    Ok(path)
+}
+
+// Shared by every profile, unlike the ignore list
+pub fn user_dictionary_dir() -> Option<PathBuf> {
+    let mut path = dirs::config_dir().or_else(dirs::home_dir)?;
+    path.push("study_timer");
+    path.push("dictionaries");
+    Some(path)
+}
+
+fn dictionary_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = user_dictionary_dir().into_iter().collect();
+    dirs.extend(
+        [
+            "/usr/share/hunspell",
+            "/usr/share/myspell",
+            "/usr/share/myspell/dicts",
+            "/Library/Spelling",
+        ]
+        .iter()
+        .map(PathBuf::from),
+    );
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join("Library/Spelling"));
+    }
+    dirs
+}
+
+// Languages with both dictionary files present, e.g. "en_US"
+pub fn available_languages() -> Vec<String> {
+    let mut languages = BTreeSet::new();
+    for dir in dictionary_dirs() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in entries.flatten().map(|e| e.path()) {
+            if path.extension().map_or(false, |ext| ext == "dic")
+                && path.with_extension("aff").exists()
+            {
+                if let Some(stem) = path.file_stem() {
+                    languages.insert(stem.to_string_lossy().to_string());
+                }
+            }
+        }
+    }
+    languages.into_iter().collect()
+}
+
+fn load_dictionary(language: &str) -> Result<Dictionary, Box<dyn std::error::Error>> {
+    for dir in dictionary_dirs() {
+        let dic = dir.join(format!("{}.dic", language));
+        let aff = dir.join(format!("{}.aff", language));
+        if dic.exists() && aff.exists() {
+            let dictionary = Dictionary::new(&fs::read_to_string(aff)?, &fs::read_to_string(dic)?)
+                .map_err(|e| format!("{:?}", e))?;
+            return Ok(dictionary);
+        }
+    }
+    Err(format!("No dictionary found for {}", language).into())
+}
+
+pub struct SpellChecker {
+    pub config: SpellCheckConfig,
+    dictionary: Option<Dictionary>,
+    loaded_language: Option<String>, // Tried already, even if loading failed
+    pub load_error: Option<String>,
+    known: HashMap<String, bool>, // Words are checked many times per second while typing
+}
+
+impl SpellChecker {
+    pub fn new() -> Self {
+        Self {
+            config: SpellCheckConfig::load().unwrap_or_default(),
+            dictionary: None,
+            loaded_language: None,
+            load_error: None,
+            known: HashMap::new(),
+        }
+    }
+
+    fn ensure_loaded(&mut self) {
+        if self.loaded_language.as_deref() == Some(self.config.language.as_str()) {
+            return;
+        }
+        self.known.clear();
+        self.loaded_language = Some(self.config.language.clone());
+        match load_dictionary(&self.config.language) {
+            Ok(dictionary) => {
+                self.dictionary = Some(dictionary);
+                self.load_error = None;
+            }
+            Err(e) => {
+                self.dictionary = None;
+                self.load_error = Some(e.to_string());
+            }
+        }
+    }
+
+    pub fn is_active(&mut self) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+        self.ensure_loaded();
+        self.dictionary.is_some()
+    }
+
+    pub fn is_correct(&mut self, word: &str) -> bool {
+        if self.config.ignored.contains(&word.to_lowercase()) {
+            return true;
+        }
+        if let Some(&correct) = self.known.get(word) {
+            return correct;
+        }
+        let correct = self.dictionary.as_ref().map_or(true, |d| d.check(word));
+        self.known.insert(word.to_string(), correct);
+        correct
+    }
+
+    // Byte ranges of misspelled words, empty when checking is off
+    pub fn misspelled(&mut self, text: &str) -> Vec<Range<usize>> {
+        if !self.is_active() {
+            return Vec::new();
+        }
+        words(text)
+            .filter(|range| !self.is_correct(&text[range.clone()]))
+            .collect()
+    }
+
+    pub fn suggestions(&self, word: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        if let Some(dictionary) = &self.dictionary {
+            dictionary.suggest(word, &mut out);
+        }
+        out.truncate(5);
+        out
+    }
+
+    pub fn ignore(&mut self, word: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.config.ignored.insert(word.to_lowercase());
+        self.config.save()
+    }
+}
+
+// Words worth checking: letters with inner apostrophes, skipping acronyms, single
+// letters and anything glued to digits, paths or URLs
+fn words(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let inner_apostrophe = (c == '\'' || c == '’')
+            && start.is_some()
+            && chars.peek().map_or(false, |(_, next)| next.is_alphabetic());
+        if c.is_alphabetic() || inner_apostrophe {
+            start.get_or_insert(index);
+        } else if let Some(begin) = start.take() {
+            if !c.is_alphanumeric() && c != '/' && c != '_' {
+                ranges.push(begin..index);
+            } else {
+                // Part of a longer token, skip the rest of it
+                while chars
+                    .peek()
+                    .map_or(false, |(_, next)| !next.is_whitespace())
+                {
+                    chars.next();
+                }
+            }
+        }
+    }
+    if let Some(begin) = start {
+        ranges.push(begin..text.len());
+    }
+
+    ranges.into_iter().filter(move |range| {
+        let word = &text[range.clone()];
+        let preceded = text[..range.start].chars().next_back().map_or(false, |c| {
+            c.is_alphanumeric() || c == '/' || c == '_' || c == '.'
+        });
+        word.chars().count() > 1 && !word.chars().all(|c| c.is_uppercase()) && !preceded
+    })
+}
@@ -1,12 +1,46 @@
 use crate::app::StudyTimerApp;
 use crate::tab_manager::{SplitDirection, SplitPane};
 use eframe::egui;
+use std::cell::RefCell;
 
 pub struct SplitViewUI;
 
+// Scroll positions shared by the two panes while scrolling is locked. Positions are
+// fractions of the scrollable height, so a short summary keeps pace with a long text.
+#[derive(Default)]
+struct ScrollLock {
+    enabled: bool,
+    drawing: Option<usize>, // Pane being drawn, 0 for left/top and 1 for right/bottom
+    driver: usize,          // The pane last used, which the other one follows
+    tracked: [bool; 2],     // Only the first scroll area of a pane takes part
+    position: [Option<f32>; 2],
+    max_offset: [f32; 2],
+    pending: [Option<f32>; 2],
+}
+
+impl ScrollLock {
+    // The pane whose scroll area is being shown, if it should follow or lead
+    fn pane(&self) -> Option<usize> {
+        self.drawing
+            .filter(|pane| self.enabled && !self.tracked[*pane])
+    }
+}
+
+thread_local! {
+    static SCROLL_LOCK: RefCell<ScrollLock> = RefCell::new(ScrollLock::default());
+}
+
 impl SplitViewUI {
     pub fn display(ui: &mut egui::Ui, app: &mut StudyTimerApp, ctx: &egui::Context) {
         if let Some(ref split_pane) = app.tab_manager.split_pane.clone() {
+            SCROLL_LOCK.with(|lock| {
+                let mut lock = lock.borrow_mut();
+                if lock.enabled != split_pane.scroll_lock {
+                    *lock = ScrollLock::default();
+                    lock.enabled = split_pane.scroll_lock;
+                }
+                lock.driver = app.last_used_split_pane as usize;
+            });
             match split_pane.direction {
                 SplitDirection::Horizontal => {
                     Self::render_horizontal_split(ui, app, ctx, split_pane);
@@ -15,6 +49,29 @@ impl SplitViewUI {
                     Self::render_vertical_split(ui, app, ctx, split_pane);
                 }
             }
+
+            // Actions outside the panes, like inserting a citation, go to the pane last used
+            let focused = if app.last_used_split_pane {
+                &split_pane.right_tab_id
+            } else {
+                &split_pane.left_tab_id
+            };
+            match app
+                .tab_manager
+                .get_tab(focused)
+                .map(|tab| tab.tab_type.clone())
+            {
+                Some(crate::app::Tab::Markdown) => {
+                    app.pane_content
+                        .swap_editor(focused, &mut app.markdown_editor);
+                }
+                Some(crate::app::Tab::Flashcards) => app.pane_content.swap_flashcards(
+                    focused,
+                    &mut app.flashcard_reviewer,
+                    &mut app.deck_manager_ui,
+                ),
+                _ => {}
+            }
         }
     }
 
@@ -52,36 +109,13 @@ impl SplitViewUI {
             egui::Vec2::new(available_rect.width(), 4.0),
         );
 
-        let splitter_response = ui.allocate_rect(splitter_rect, egui::Sense::drag());
-        ui.painter().rect_filled(
-            splitter_rect,
-            egui::Rounding::ZERO,
-            app.settings.get_current_colors().accent_color32(),
-        );
-
-        if splitter_response.dragged() {
-            let new_ratio = (splitter_response.interact_pointer_pos().unwrap().y
-                - available_rect.min.y)
-                / available_rect.height();
-            app.tab_manager.update_split_ratio(new_ratio);
-        }
-
-        // Change cursor when hovering over splitter
-        if splitter_response.hovered() {
-            ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::ResizeVertical);
-        }
-
-        // Handle tab drops on splitter for swapping
-        if let Some(_dragging_tab_id) = &app.dragging_tab_id {
-            if splitter_response.hovered() && ui.input(|i| i.pointer.any_released()) {
-                app.tab_manager.swap_split_tabs();
-                app.status.show("Split panes swapped");
-            }
-        }
+        Self::render_splitter(ui, app, available_rect, splitter_rect, false);
 
         // Render bottom pane
         let mut bottom_ui = ui.child_ui(bottom_rect, egui::Layout::top_down(egui::Align::LEFT));
         Self::render_split_pane_content(&mut bottom_ui, app, ctx, &split_pane.right_tab_id, true);
+
+        Self::render_scroll_lock_toggle(ui, app, splitter_rect.center());
     }
 
     fn render_vertical_split(
@@ -118,36 +152,138 @@ impl SplitViewUI {
             egui::Vec2::new(4.0, available_rect.height()),
         );
 
-        let splitter_response = ui.allocate_rect(splitter_rect, egui::Sense::drag());
-        ui.painter().rect_filled(
-            splitter_rect,
-            egui::Rounding::ZERO,
-            app.settings.get_current_colors().accent_color32(),
-        );
+        Self::render_splitter(ui, app, available_rect, splitter_rect, true);
+
+        // Render right pane
+        let mut right_ui = ui.child_ui(right_rect, egui::Layout::top_down(egui::Align::LEFT));
+        Self::render_split_pane_content(&mut right_ui, app, ctx, &split_pane.right_tab_id, true);
+
+        Self::render_scroll_lock_toggle(ui, app, splitter_rect.center());
+    }
 
-        if splitter_response.dragged() {
-            let new_ratio = (splitter_response.interact_pointer_pos().unwrap().x
-                - available_rect.min.x)
-                / available_rect.width();
-            app.tab_manager.update_split_ratio(new_ratio);
+    // The divider is drawn thin but grabbed from a wider strip. Dragging resizes the
+    // panes and double-clicking puts it back in the middle.
+    fn render_splitter(
+        ui: &mut egui::Ui,
+        app: &mut StudyTimerApp,
+        available_rect: egui::Rect,
+        splitter_rect: egui::Rect,
+        side_by_side: bool,
+    ) {
+        let grab_rect = if side_by_side {
+            splitter_rect.expand2(egui::vec2(4.0, 0.0))
+        } else {
+            splitter_rect.expand2(egui::vec2(0.0, 4.0))
+        };
+        let splitter_response = ui.interact(
+            grab_rect,
+            ui.id().with("split_divider"),
+            egui::Sense::click_and_drag(),
+        );
+        let accent = app.settings.get_current_colors().accent_color32();
+        let fill = if splitter_response.hovered() || splitter_response.dragged() {
+            accent
+        } else {
+            accent.gamma_multiply(0.7)
+        };
+        ui.painter()
+            .rect_filled(splitter_rect, egui::Rounding::ZERO, fill);
+
+        if splitter_response.double_clicked() {
+            app.tab_manager.reset_split_ratio();
+        } else if splitter_response.dragged() {
+            if let Some(pos) = splitter_response.interact_pointer_pos() {
+                let new_ratio = if side_by_side {
+                    (pos.x - available_rect.min.x) / available_rect.width()
+                } else {
+                    (pos.y - available_rect.min.y) / available_rect.height()
+                };
+                app.tab_manager.update_split_ratio(new_ratio);
+            }
+        }
+        // Saved once, rather than on every frame of the drag
+        if splitter_response.drag_released() {
+            app.tab_manager.save_state();
         }
 
         // Change cursor when hovering over splitter
-        if splitter_response.hovered() {
-            ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::ResizeHorizontal);
+        if splitter_response.hovered() || splitter_response.dragged() {
+            ui.output_mut(|o| {
+                o.cursor_icon = if side_by_side {
+                    egui::CursorIcon::ResizeHorizontal
+                } else {
+                    egui::CursorIcon::ResizeVertical
+                }
+            });
         }
+        splitter_response.on_hover_text("Drag to resize, double-click to reset");
 
         // Handle tab drops on splitter for swapping
-        if let Some(_dragging_tab_id) = &app.dragging_tab_id {
-            if splitter_response.hovered() && ui.input(|i| i.pointer.any_released()) {
-                app.tab_manager.swap_split_tabs();
-                app.status.show("Split panes swapped");
-            }
+        if app.dragging_tab_id.is_some()
+            && ui.rect_contains_pointer(grab_rect)
+            && ui.input(|i| i.pointer.any_released())
+        {
+            app.tab_manager.swap_split_tabs();
+            app.status.show("Split panes swapped");
         }
+    }
 
-        // Render right pane
-        let mut right_ui = ui.child_ui(right_rect, egui::Layout::top_down(egui::Align::LEFT));
-        Self::render_split_pane_content(&mut right_ui, app, ctx, &split_pane.right_tab_id, true);
+    // Drawn on the divider after both panes, so neither covers it
+    fn render_scroll_lock_toggle(ui: &mut egui::Ui, app: &mut StudyTimerApp, center: egui::Pos2) {
+        let locked = app
+            .tab_manager
+            .get_split_pane()
+            .is_some_and(|split| split.scroll_lock);
+        let rect = egui::Rect::from_center_size(center, egui::Vec2::splat(22.0));
+        let button = egui::Button::new(if locked { "🔒" } else { "🔓" })
+            .small()
+            .selected(locked);
+        let hint = if locked {
+            "Scrolling is locked between the panes. Click to unlock."
+        } else {
+            "Lock scrolling, so scrolling one pane scrolls the other"
+        };
+        if ui.put(rect, button).on_hover_text(hint).clicked() {
+            app.tab_manager.toggle_scroll_lock();
+        }
+    }
+
+    // Tabs pass their main scroll area through this before showing it, and its output
+    // through `track_scroll` after, to follow the other pane while scrolling is locked
+    pub fn lock_scroll(area: egui::ScrollArea) -> egui::ScrollArea {
+        SCROLL_LOCK.with(|lock| {
+            let mut lock = lock.borrow_mut();
+            let Some(pane) = lock.pane() else {
+                return area;
+            };
+            match lock.pending[pane].take() {
+                Some(fraction) => area.vertical_scroll_offset(fraction * lock.max_offset[pane]),
+                None => area,
+            }
+        })
+    }
+
+    pub fn track_scroll<R>(ui: &egui::Ui, output: &egui::scroll_area::ScrollAreaOutput<R>) {
+        SCROLL_LOCK.with(|lock| {
+            let mut lock = lock.borrow_mut();
+            let Some(pane) = lock.pane() else {
+                return;
+            };
+            lock.tracked[pane] = true;
+            let max_offset = (output.content_size.y - output.inner_rect.height()).max(0.0);
+            let fraction = if max_offset > 0.0 {
+                (output.state.offset.y / max_offset).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let moved = lock.position[pane].is_some_and(|last| (last - fraction).abs() > 0.0005);
+            if moved && pane == lock.driver {
+                lock.pending[1 - pane] = Some(fraction);
+                ui.ctx().request_repaint();
+            }
+            lock.position[pane] = Some(fraction);
+            lock.max_offset[pane] = max_offset;
+        });
     }
 
     fn render_split_pane_content(
@@ -163,10 +299,16 @@ impl SplitViewUI {
         }
 
         // Get the tab information first to avoid borrowing conflicts
-        let tab_info = app
-            .tab_manager
-            .get_tab(tab_id)
-            .map(|tab| (tab.get_display_title(), tab.tab_type.clone()));
+        let tab_info = app.tab_manager.get_tab(tab_id).map(|tab| {
+            (
+                format!(
+                    "{}{}",
+                    tab.get_display_title(),
+                    app.tab_badges.suffix(&tab.tab_type)
+                ),
+                tab.tab_type.clone(),
+            )
+        });
 
         if let Some((title, tab_type)) = tab_info {
             let colors = app.settings.get_current_colors();
@@ -239,53 +381,128 @@ impl SplitViewUI {
                     }
                 }
 
-                // Render tab content based on type
-                match tab_type {
+                // Render tab content based on type, under ids of its own so two
+                // tabs of the same kind don't share widget state
+                let started = std::time::Instant::now();
+                SCROLL_LOCK.with(|lock| {
+                    let mut lock = lock.borrow_mut();
+                    lock.drawing = Some(is_right_pane as usize);
+                    lock.tracked[is_right_pane as usize] = false;
+                });
+                ui.push_id(tab_id, |ui| match tab_type {
+                    crate::app::Tab::Home => crate::ui::home_tab::display(ui, app),
                     crate::app::Tab::Timer => crate::ui::timer_tab::display(
                         ui,
                         &mut app.timer,
                         &mut app.study_data,
                         &mut app.debug_tools,
                         &mut app.status,
+                        &app.settings,
+                    ),
+                    crate::app::Tab::Stats => crate::ui::stats_tab::display(
+                        ui,
+                        &mut app.study_data,
+                        &mut app.status,
+                        &mut app.time_export,
+                        &app.settings,
                     ),
-                    crate::app::Tab::Stats => {
-                        crate::ui::stats_tab::display(ui, &mut app.study_data, &mut app.status)
-                    }
                     crate::app::Tab::Record => crate::ui::record_tab::display(
                         ui,
                         &mut app.study_data,
                         &mut app.status,
                         &app.timer,
                     ),
-                    crate::app::Tab::Graph => {
-                        crate::ui::graph_tab::display(ui, &app.study_data, &mut app.status)
-                    }
+                    crate::app::Tab::Graph => crate::ui::graph_tab::display(
+                        ui,
+                        &app.study_data,
+                        &mut app.status,
+                        &app.settings,
+                    ),
                     crate::app::Tab::Todo => crate::ui::todo_tab::display(
                         ui,
                         &mut app.study_data,
                         &mut app.status,
                         &app.settings,
+                        &mut app.task_sync,
                     ),
-                    crate::app::Tab::Reminder => {
-                        crate::ui::reminder_tab::display(ui, &mut app.study_data, &mut app.status)
+                    crate::app::Tab::Planner => {
+                        let timer_started = crate::ui::planner_tab::display(
+                            ui,
+                            &mut app.study_data,
+                            &mut app.status,
+                            &app.settings,
+                            &mut app.timer,
+                        );
+                        if timer_started {
+                            app.open_tab(crate::app::Tab::Timer);
+                        }
                     }
-                    crate::app::Tab::Flashcards => {
-                        crate::ui::flashcard_tab_ui::display(ui, ctx, app)
+                    crate::app::Tab::Reminder => crate::ui::reminder_tab::display(
+                        ui,
+                        &mut app.study_data,
+                        &mut app.status,
+                        &app.settings,
+                    ),
+                    crate::app::Tab::Journal => {
+                        crate::ui::journal_tab::display(ui, &mut app.study_data, &mut app.status)
                     }
+                    crate::app::Tab::Assignments => crate::ui::assignments_tab::display(
+                        ui,
+                        &mut app.study_data,
+                        &mut app.status,
+                    ),
+                    crate::app::Tab::ImageViewer => crate::ui::image_viewer_tab::display(
+                        ui,
+                        app.tab_manager.get_tab_mut(tab_id),
+                        &mut app.study_data,
+                        &mut app.status,
+                    ),
+                    crate::app::Tab::EpubReader => crate::ui::epub_reader_tab::display(
+                        ui,
+                        app.tab_manager.get_tab_mut(tab_id),
+                        &mut app.study_data,
+                        &mut app.status,
+                    ),
+                    crate::app::Tab::Whiteboard => crate::ui::whiteboard_tab::display(
+                        ui,
+                        &mut app.study_data,
+                        &mut app.status,
+                        app.markdown_editor.as_mut(),
+                    ),
+                    crate::app::Tab::Recorder => {
+                        crate::ui::recorder_tab::display(ui, &mut app.status)
+                    }
+                    crate::app::Tab::Video => {
+                        let note = crate::ui::video_tab::display(
+                            ui,
+                            app.tab_manager.get_tab_mut(tab_id),
+                            &mut app.status,
+                            app.markdown_editor.as_mut(),
+                        );
+                        if let Some(path) = note {
+                            app.open_note(&path);
+                        }
+                    }
+                    crate::app::Tab::Flashcards => app.show_flashcards_tab(ui, ctx, tab_id),
                     crate::app::Tab::Calculator => {
                         crate::ui::calculator_tab::display(ui, &mut app.status)
                     }
-                    crate::app::Tab::Markdown => crate::ui::markdown_tab_ui::display(ui, app, ctx),
-                    crate::app::Tab::Terminal => {
-                        crate::ui::terminal_tab_ui::display(ui, &mut app.terminal, &mut app.status)
-                    }
+                    crate::app::Tab::Markdown => app.show_markdown_tab(ui, ctx, tab_id),
+                    crate::app::Tab::Terminal => crate::ui::terminal_tab_ui::display(
+                        ui,
+                        &mut app.terminal,
+                        &mut app.status,
+                        app.settings.editor_font_size,
+                    ),
                     crate::app::Tab::Settings => crate::ui::settings_tab_ui::display(
                         ui,
                         &mut app.settings,
                         &mut app.status,
                         &mut app.current_tab,
                     ),
-                }
+                });
+                SCROLL_LOCK.with(|lock| lock.borrow_mut().drawing = None);
+                app.debug_tools.record_tab(&tab_type, started.elapsed());
             });
         }
     }
@@ -313,7 +530,16 @@ impl SplitViewUI {
                 .tab_manager
                 .tabs
                 .iter()
-                .map(|tab| (tab.id.clone(), tab.get_display_title()))
+                .map(|tab| {
+                    (
+                        tab.id.clone(),
+                        format!(
+                            "{}{}",
+                            tab.get_display_title(),
+                            app.tab_badges.suffix(&tab.tab_type)
+                        ),
+                    )
+                })
                 .collect();
 
             egui::ComboBox::from_id_source(format!("split_tab_selector_{}", is_right_pane))
@@ -0,0 +1,119 @@
+use crate::app::Tab;
+use crate::data::StudyData;
+use crate::settings::ColorTheme;
+use chrono::Local;
+use eframe::egui;
+use std::time::{Duration, Instant};
+
+// Counting due items walks every deck and list, so only do it every few seconds
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+pub struct TabBadges {
+    due_cards: usize,
+    overdue_reminders: usize,
+    due_todos: usize,
+    last_refresh: Option<Instant>,
+}
+
+impl TabBadges {
+    pub fn new() -> Self {
+        Self {
+            due_cards: 0,
+            overdue_reminders: 0,
+            due_todos: 0,
+            last_refresh: None,
+        }
+    }
+
+    pub fn update(&mut self, study_data: &StudyData) {
+        let stale = self
+            .last_refresh
+            .map_or(true, |last| last.elapsed() >= REFRESH_INTERVAL);
+        if stale {
+            self.refresh(study_data);
+        }
+    }
+
+    pub fn refresh(&mut self, study_data: &StudyData) {
+        let today = Local::now().format("%Y-%m-%d").to_string();
+
+        self.due_cards = study_data.get_due_cards_count();
+        self.overdue_reminders = study_data
+            .reminders
+            .iter()
+            .filter(|r| !r.is_completed && r.due_date < today)
+            .count();
+
+        // Like cards, todos count from the day they are due
+        self.due_todos = study_data
+            .todos
+            .iter()
+            .filter(|t| !t.completed && t.due_date.as_ref().is_some_and(|d| *d <= today))
+            .count();
+        self.last_refresh = Some(Instant::now());
+    }
+
+    pub fn count(&self, tab: &Tab) -> usize {
+        match tab {
+            Tab::Flashcards => self.due_cards,
+            Tab::Reminder => self.overdue_reminders,
+            Tab::Todo => self.due_todos,
+            _ => 0,
+        }
+    }
+
+    pub fn paint(&self, painter: &egui::Painter, rect: egui::Rect, tab: &Tab, colors: &ColorTheme) {
+        // Overdue reminders are inverted so they stand out from the informational counts
+        let (fill, text) = match tab {
+            Tab::Reminder => (colors.text_primary_color32(), colors.background_color32()),
+            _ => (colors.accent_color32(), colors.text_primary_color32()),
+        };
+        paint_badge(painter, rect, self.count(tab), fill, text);
+    }
+
+    // Text suffix for places where a painted badge doesn't fit, like combo boxes
+    pub fn suffix(&self, tab: &Tab) -> String {
+        match self.count(tab) {
+            0 => String::new(),
+            count => format!(" ({})", badge_text(count)),
+        }
+    }
+}
+
+fn badge_text(count: usize) -> String {
+    if count > 99 {
+        "99+".to_string()
+    } else {
+        count.to_string()
+    }
+}
+
+// Draws a small pill with the count over the top-left corner of `rect`
+fn paint_badge(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    count: usize,
+    fill: egui::Color32,
+    text_color: egui::Color32,
+) {
+    if count == 0 {
+        return;
+    }
+
+    let text = badge_text(count);
+    let font = egui::FontId::new(9.0, egui::FontFamily::Proportional);
+    let width = 8.0 + 5.5 * text.len() as f32;
+    let badge_rect = egui::Rect::from_min_size(
+        egui::Pos2::new(rect.min.x + 3.0, rect.min.y + 3.0),
+        egui::Vec2::new(width.max(14.0), 14.0),
+    );
+
+    painter.rect_filled(badge_rect, egui::Rounding::same(7.0), fill);
+    painter.text(
+        badge_rect.center(),
+        egui::Align2::CENTER_CENTER,
+        text,
+        font,
+        text_color,
+    );
+}
@@ -1,4 +1,5 @@
 use crate::app::Tab;
+use crate::error;
 use crate::settings::AppSettings;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -19,15 +20,24 @@ pub struct TabInstance {
 impl TabInstance {
     pub fn new(tab_type: Tab) -> Self {
         let title = match tab_type {
+            Tab::Home => "Home".to_string(),
             Tab::Timer => "Timer".to_string(),
             Tab::Stats => "Statistics".to_string(),
             Tab::Record => "Record".to_string(),
             Tab::Graph => "Graph".to_string(),
             Tab::Todo => "Todo and Habits".to_string(),
+            Tab::Planner => "Planner".to_string(),
             Tab::Calculator => "Calculator".to_string(),
             Tab::Flashcards => "Flashcards".to_string(),
             Tab::Markdown => "New Markdown".to_string(),
             Tab::Reminder => "Reminder".to_string(),
+            Tab::Journal => "Journal".to_string(),
+            Tab::Assignments => "Assignments".to_string(),
+            Tab::ImageViewer => "Image Viewer".to_string(),
+            Tab::EpubReader => "EPUB Reader".to_string(),
+            Tab::Whiteboard => "Whiteboard".to_string(),
+            Tab::Recorder => "Recorder".to_string(),
+            Tab::Video => "Video Player".to_string(),
             Tab::Terminal => "Terminal".to_string(),
             Tab::Settings => "Settings".to_string(),
         };
@@ -79,6 +89,8 @@ pub struct SplitPane {
     pub right_tab_id: String,
     pub direction: SplitDirection,
     pub split_ratio: f32, // 0.0 to 1.0, position of the divider
+    #[serde(default)]
+    pub scroll_lock: bool, // Scrolling one pane scrolls the other
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,11 +99,17 @@ pub struct TabManagerState {
     pub active_tab_id: String,
     pub split_pane: Option<SplitPane>,
     pub last_active_tab_id: Option<String>,
+    #[serde(default = "default_split_ratio")]
+    pub split_ratio: f32, // Where the divider goes when a split is opened
+}
+
+fn default_split_ratio() -> f32 {
+    0.5
 }
 
 impl Default for TabManagerState {
     fn default() -> Self {
-        let default_tab = TabInstance::new(Tab::Timer);
+        let default_tab = TabInstance::new(Tab::Home);
         let active_tab_id = default_tab.id.clone();
 
         Self {
@@ -99,14 +117,14 @@ impl Default for TabManagerState {
             active_tab_id,
             split_pane: None,
             last_active_tab_id: None,
+            split_ratio: default_split_ratio(),
         }
     }
 }
 
 impl TabManagerState {
     fn get_save_path() -> PathBuf {
-        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("study_timer");
+        let mut path = crate::profile::config_dir().unwrap_or_else(|| PathBuf::from("."));
         path.push("tab_manager.json");
         path
     }
@@ -167,6 +185,7 @@ pub struct TabManager {
     pub active_tab_id: String,
     pub last_active_tab_id: Option<String>,
     pub split_pane: Option<SplitPane>,
+    pub split_ratio: f32, // Kept after a split is closed, for the next one
     pub tab_data: HashMap<String, Box<dyn std::any::Any>>, // Store tab-specific data
 }
 
@@ -195,6 +214,7 @@ impl TabManager {
                 active_tab_id,
                 split_pane: None,
                 last_active_tab_id: None,
+                split_ratio: default_split_ratio(),
             }
         });
 
@@ -203,6 +223,7 @@ impl TabManager {
             active_tab_id: state.active_tab_id,
             last_active_tab_id: state.last_active_tab_id,
             split_pane: state.split_pane,
+            split_ratio: state.split_ratio.clamp(0.1, 0.9),
             tab_data: HashMap::new(),
         }
     }
@@ -213,10 +234,11 @@ impl TabManager {
             active_tab_id: self.active_tab_id.clone(),
             split_pane: self.split_pane.clone(),
             last_active_tab_id: self.last_active_tab_id.clone(),
+            split_ratio: self.split_ratio,
         };
 
         if let Err(e) = state.save() {
-            eprintln!("Failed to save tab manager state: {}", e);
+            error::report("Could not save open tabs", e);
         }
     }
 
@@ -290,7 +312,7 @@ impl TabManager {
 
             // Ensure at least one tab exists
             if self.tabs.is_empty() {
-                let default_tab = TabInstance::new(Tab::Timer);
+                let default_tab = TabInstance::new(Tab::Home);
                 self.active_tab_id = default_tab.id.clone();
                 self.tabs.push(default_tab);
             }
@@ -422,7 +444,8 @@ impl TabManager {
                 left_tab_id,
                 right_tab_id,
                 direction,
-                split_ratio: 0.5,
+                split_ratio: self.split_ratio,
+                scroll_lock: false,
             });
 
             self.save_state();
@@ -438,13 +461,19 @@ impl TabManager {
         self.split_pane.is_some()
     }
 
+    // Called while the divider is dragged; the ratio is saved once the drag ends
     pub fn update_split_ratio(&mut self, ratio: f32) {
         if let Some(ref mut split) = self.split_pane {
             split.split_ratio = ratio.clamp(0.1, 0.9);
-            self.save_state();
+            self.split_ratio = split.split_ratio;
         }
     }
 
+    pub fn reset_split_ratio(&mut self) {
+        self.update_split_ratio(0.5);
+        self.save_state();
+    }
+
     #[allow(dead_code)]
     pub fn set_tab_modified(&mut self, tab_id: &str, modified: bool) {
         if let Some(tab) = self.get_tab_mut(tab_id) {
@@ -523,6 +552,13 @@ impl TabManager {
         }
     }
 
+    pub fn toggle_scroll_lock(&mut self) {
+        if let Some(ref mut split) = self.split_pane {
+            split.scroll_lock = !split.scroll_lock;
+            self.save_state();
+        }
+    }
+
     pub fn get_split_pane(&self) -> Option<&SplitPane> {
         self.split_pane.as_ref()
     }
@@ -539,4 +575,3 @@ impl TabManager {
         }
     }
 }
-
@@ -8,6 +8,7 @@ pub struct TabSelectorUI {
     pub is_open: bool,
     pub search_text: String,
     pub filtered_tabs: Vec<Tab>,
+    pub choosing_note: bool, // A Markdown tab was picked, now pick the note it opens
 }
 
 impl TabSelectorUI {
@@ -17,6 +18,7 @@ impl TabSelectorUI {
             is_open: false,
             search_text: String::new(),
             filtered_tabs: Vec::new(),
+            choosing_note: false,
         }
     }
 
@@ -25,6 +27,7 @@ impl TabSelectorUI {
         self.selected_tab = None;
         self.search_text.clear();
         self.filtered_tabs.clear();
+        self.choosing_note = false;
     }
 
     pub fn hide(&mut self) {
@@ -32,6 +35,7 @@ impl TabSelectorUI {
         self.selected_tab = None;
         self.search_text.clear();
         self.filtered_tabs.clear();
+        self.choosing_note = false;
     }
 
     fn update_filtered_tabs(&mut self, available_tabs: &[&crate::settings::TabConfig]) {
@@ -56,15 +60,19 @@ impl TabSelectorUI {
         }
     }
 
+    // The tab to create, and for Markdown tabs the note to open in it
     pub fn display(
         &mut self,
         ctx: &egui::Context,
         settings: &AppSettings,
         _status: &mut StatusMessage,
-    ) -> Option<Tab> {
+    ) -> Option<(Tab, Option<String>)> {
         if !self.is_open {
             return None;
         }
+        if self.choosing_note {
+            return self.display_note_picker(ctx, settings);
+        }
 
         let mut selected_tab = None;
 
@@ -270,21 +278,138 @@ impl TabSelectorUI {
                 });
             });
 
-        selected_tab
+        // Favorite and recent notes are offered before an empty editor
+        if selected_tab == Some(Tab::Markdown) && !quick_open_notes(settings).is_empty() {
+            self.show();
+            self.choosing_note = true;
+            return None;
+        }
+        selected_tab.map(|tab| (tab, None))
     }
+
+    fn display_note_picker(
+        &mut self,
+        ctx: &egui::Context,
+        settings: &AppSettings,
+    ) -> Option<(Tab, Option<String>)> {
+        let mut picked = None;
+        let search = self.search_text.to_lowercase();
+        let notes: Vec<(String, bool)> = quick_open_notes(settings)
+            .into_iter()
+            .filter(|(path, _)| note_name(path).to_lowercase().contains(&search))
+            .collect();
+
+        egui::Window::new("📄 New Markdown Tab")
+            .collapsible(false)
+            .resizable(false)
+            .default_width(400.0)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("🔍 Open a note:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.search_text)
+                            .hint_text("Type to filter")
+                            .desired_width(200.0),
+                    )
+                    .request_focus();
+                });
+                ui.add_space(10.0);
+
+                egui::ScrollArea::vertical()
+                    .max_height(240.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        if notes.is_empty() {
+                            ui.label("No matching notes");
+                        }
+                        let mut heading = None;
+                        for (index, (path, favorite)) in notes.iter().enumerate() {
+                            if heading != Some(*favorite) {
+                                heading = Some(*favorite);
+                                ui.label(if *favorite {
+                                    "★ Favorites"
+                                } else {
+                                    "🕘 Recent"
+                                });
+                            }
+                            // Enter opens the first one
+                            let response = ui
+                                .selectable_label(index == 0, note_name(path))
+                                .on_hover_text(path);
+                            if response.clicked() {
+                                picked = Some(Some(path.clone()));
+                            }
+                        }
+                    });
+
+                ui.add_space(10.0);
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("❌ Cancel").clicked() {
+                        self.hide();
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("📄 Empty Editor").clicked() {
+                            picked = Some(None);
+                        }
+                    });
+                });
+            });
+
+        if ctx.input(|i| i.key_pressed(egui::Key::Enter)) {
+            picked = Some(notes.first().map(|(path, _)| path.clone()));
+        }
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.hide();
+        }
+        let file = picked?;
+        self.hide();
+        Some((Tab::Markdown, file))
+    }
+}
+
+// Favorites first, then recently opened notes, skipping any that no longer exist
+fn quick_open_notes(settings: &AppSettings) -> Vec<(String, bool)> {
+    let favorites = settings.favorite_notes.iter().map(|path| (path, true));
+    let recent = settings
+        .recent_notes
+        .iter()
+        .filter(|path| !settings.is_favorite_note(path))
+        .map(|path| (path, false));
+    favorites
+        .chain(recent)
+        .filter(|(path, _)| std::path::Path::new(path).is_file())
+        .map(|(path, favorite)| (path.clone(), favorite))
+        .collect()
+}
+
+fn note_name(path: &str) -> String {
+    std::path::Path::new(path)
+        .file_name()
+        .map_or(path.to_string(), |name| name.to_string_lossy().to_string())
 }
 
 fn get_tab_icon(tab_type: &Tab) -> &'static str {
     match tab_type {
+        Tab::Home => "🏠",
         Tab::Timer => "⏰",
         Tab::Stats => "📊",
         Tab::Record => "📝",
         Tab::Graph => "📈",
         Tab::Todo => "✅",
+        Tab::Planner => "🗂",
         Tab::Calculator => "🔢",
         Tab::Flashcards => "🃏",
         Tab::Markdown => "📄",
         Tab::Reminder => "🔔",
+        Tab::Journal => "📔",
+        Tab::Assignments => "🎓",
+        Tab::ImageViewer => "🖼",
+        Tab::EpubReader => "📖",
+        Tab::Whiteboard => "🎨",
+        Tab::Recorder => "🎙",
+        Tab::Video => "🎬",
         Tab::Terminal => "💻",
         Tab::Settings => "⚙",
     }
@@ -292,15 +417,24 @@ fn get_tab_icon(tab_type: &Tab) -> &'static str {
 
 fn get_tab_description(tab_type: &Tab) -> &'static str {
     match tab_type {
+        Tab::Home => "Lifetime hours, streaks, due cards and this week at a glance",
         Tab::Timer => "Focus timer with pomodoro technique support",
         Tab::Stats => "View your study statistics and progress",
         Tab::Record => "Record and manage study sessions",
         Tab::Graph => "Visualize your study data with charts",
         Tab::Todo => "Manage tasks, to-do items and Habits",
+        Tab::Planner => "Plan the week on a kanban board of todos",
         Tab::Flashcards => "Anki like flashcards",
         Tab::Calculator => "Built-in calculator for quick calculations",
         Tab::Markdown => "Write and edit markdown documents",
         Tab::Reminder => "Set reminders and notifications",
+        Tab::Journal => "Dated entries with prompts and mood",
+        Tab::Assignments => "Track assignments, weights and grades per course",
+        Tab::ImageViewer => "Zoom, pan and annotate images, then turn them into flashcards",
+        Tab::EpubReader => "Read EPUB textbooks with bookmarks and turn passages into flashcards",
+        Tab::Whiteboard => "Sketch diagrams freehand and save them for notes and cards",
+        Tab::Recorder => "Record lectures with markers and play them back",
+        Tab::Video => "Watch lecture videos and take notes that jump back to the moment",
         Tab::Terminal => "Built-in terminal emulator",
         Tab::Settings => "Configure application settings",
     }
@@ -308,15 +442,24 @@ fn get_tab_description(tab_type: &Tab) -> &'static str {
 
 fn get_tab_display_name(tab_type: &Tab) -> &'static str {
     match tab_type {
+        Tab::Home => "Home",
         Tab::Timer => "Timer",
         Tab::Stats => "Statistics",
         Tab::Record => "Record",
         Tab::Graph => "Graph",
         Tab::Todo => "Todo and Habits",
+        Tab::Planner => "Planner",
         Tab::Calculator => "Calculator",
         Tab::Flashcards => "Flashcards",
         Tab::Markdown => "Markdown",
         Tab::Reminder => "Reminder",
+        Tab::Journal => "Journal",
+        Tab::Assignments => "Assignments",
+        Tab::ImageViewer => "Image Viewer",
+        Tab::EpubReader => "EPUB Reader",
+        Tab::Whiteboard => "Whiteboard",
+        Tab::Recorder => "Recorder",
+        Tab::Video => "Video Player",
         Tab::Terminal => "Terminal",
         Tab::Settings => "Settings",
     }
@@ -324,15 +467,24 @@ fn get_tab_display_name(tab_type: &Tab) -> &'static str {
 
 fn get_tab_search_name(tab_type: &Tab) -> &'static str {
     match tab_type {
+        Tab::Home => "home dashboard overview streak",
         Tab::Timer => "timer",
         Tab::Stats => "stats statistics",
         Tab::Record => "record",
         Tab::Graph => "graph",
         Tab::Todo => "todo task habits",
+        Tab::Planner => "planner kanban board week plan",
         Tab::Calculator => "calculator calc",
         Tab::Flashcards => "flashcards cards flash",
         Tab::Markdown => "markdown md text",
         Tab::Reminder => "reminder remind",
+        Tab::Journal => "journal diary mood reflection",
+        Tab::Assignments => "assignments homework grades courses",
+        Tab::ImageViewer => "image viewer picture photo annotate",
+        Tab::EpubReader => "epub reader book textbook ebook",
+        Tab::Whiteboard => "whiteboard canvas sketch draw diagram",
+        Tab::Recorder => "recorder audio lecture microphone record",
+        Tab::Video => "video player movie lecture watch timestamp",
         Tab::Terminal => "terminal term console",
         Tab::Settings => "settings config",
     }
@@ -0,0 +1,911 @@
+use crate::data::{BoardColumn, StudyData, Todo, TodoPriority};
+use crate::error;
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+// Todos without a project are synced through the mapping for this name
+pub const DEFAULT_PROJECT: &str = "Inbox";
+
+const AUTO_SYNC_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SyncProvider {
+    GoogleTasks,
+    CalDav,
+}
+
+impl SyncProvider {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SyncProvider::GoogleTasks => "Google Tasks",
+            SyncProvider::CalDav => "CalDAV",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConflictPolicy {
+    NewestWins,
+    PreferLocal,
+    PreferRemote,
+}
+
+impl ConflictPolicy {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConflictPolicy::NewestWins => "Newest change wins",
+            ConflictPolicy::PreferLocal => "Keep FocusPad version",
+            ConflictPolicy::PreferRemote => "Keep remote version",
+        }
+    }
+
+    pub fn all() -> Vec<ConflictPolicy> {
+        vec![
+            ConflictPolicy::NewestWins,
+            ConflictPolicy::PreferLocal,
+            ConflictPolicy::PreferRemote,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListMapping {
+    pub project: String,
+    pub remote_list: String, // Google task list id or CalDAV collection name
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskSyncConfig {
+    pub enabled: bool,
+    pub provider: SyncProvider,
+    pub google_access_token: String,
+    pub caldav_url: String, // Calendar home, e.g. https://host/dav/calendars/user/
+    pub caldav_username: String,
+    pub caldav_password: String,
+    pub mappings: Vec<ListMapping>,
+    pub conflict_policy: ConflictPolicy,
+    pub last_sync: Option<String>, // RFC 3339
+    // Remote ids present after the last sync, used to detect deletions on either side
+    pub known_remote_ids: HashSet<String>,
+}
+
+impl Default for TaskSyncConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: SyncProvider::GoogleTasks,
+            google_access_token: String::new(),
+            caldav_url: String::new(),
+            caldav_username: String::new(),
+            caldav_password: String::new(),
+            mappings: vec![ListMapping {
+                project: DEFAULT_PROJECT.to_string(),
+                remote_list: "@default".to_string(),
+            }],
+            conflict_policy: ConflictPolicy::NewestWins,
+            last_sync: None,
+            known_remote_ids: HashSet::new(),
+        }
+    }
+}
+
+impl TaskSyncConfig {
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut path = crate::profile::config_dir().ok_or("Could not find config directory")?;
+    path.push("task_sync.json");
+    Ok(path)
+}
+
+#[derive(Debug, Clone)]
+pub enum SyncStatus {
+    Idle,
+    Syncing,
+    Synced(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+struct RemoteTask {
+    id: String,
+    uid: String, // Same as id for Google Tasks
+    title: String,
+    completed: bool,
+    updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+enum LocalChange {
+    Link {
+        todo_id: u64,
+        remote_id: String,
+    },
+    Update {
+        todo_id: u64,
+        text: String,
+        completed: bool,
+    },
+    Create {
+        text: String,
+        completed: bool,
+        project: Option<String>,
+        remote_id: String,
+    },
+    Delete {
+        todo_id: u64,
+    },
+}
+
+struct SyncOutcome {
+    changes: Vec<LocalChange>,
+    known_remote_ids: HashSet<String>,
+    synced_at: String,
+    pushed: usize,
+    pulled: usize,
+    conflicts: usize,
+}
+
+pub struct TaskSync {
+    pub config: TaskSyncConfig,
+    pub status: SyncStatus,
+    receiver: Option<Receiver<Result<SyncOutcome, String>>>,
+    last_attempt: Option<Instant>,
+}
+
+impl TaskSync {
+    pub fn new() -> Self {
+        Self {
+            config: TaskSyncConfig::load().unwrap_or_default(),
+            status: SyncStatus::Idle,
+            receiver: None,
+            last_attempt: None,
+        }
+    }
+
+    pub fn is_syncing(&self) -> bool {
+        self.receiver.is_some()
+    }
+
+    pub fn status_text(&self) -> String {
+        match &self.status {
+            SyncStatus::Idle => match &self.config.last_sync {
+                Some(last) => format!("☁ Last synced {}", format_sync_time(last)),
+                None => "☁ Not synced yet".to_string(),
+            },
+            SyncStatus::Syncing => "⟳ Syncing...".to_string(),
+            SyncStatus::Synced(summary) => format!("☁ {}", summary),
+            SyncStatus::Failed(error) => format!("⚠ Sync failed: {}", error),
+        }
+    }
+
+    // Starts a sync in the background using a snapshot of the todos
+    pub fn start(&mut self, todos: &[Todo]) {
+        if self.is_syncing() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let config = self.config.clone();
+        let todos = todos.to_vec();
+
+        std::thread::spawn(move || {
+            let result = run_sync(&config, &todos).map_err(|e| e.to_string());
+            let _ = sender.send(result);
+        });
+
+        self.receiver = Some(receiver);
+        self.status = SyncStatus::Syncing;
+        self.last_attempt = Some(Instant::now());
+    }
+
+    // Called every frame; starts periodic syncs and applies finished ones
+    pub fn update(&mut self, study_data: &mut StudyData) -> Option<String> {
+        if self.config.enabled && !self.is_syncing() {
+            let due = self
+                .last_attempt
+                .map_or(true, |last| last.elapsed() >= AUTO_SYNC_INTERVAL);
+            if due {
+                self.start(&study_data.todos);
+            }
+        }
+
+        let result = match &self.receiver {
+            Some(receiver) => match receiver.try_recv() {
+                Ok(result) => result,
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => Err("sync worker stopped".to_string()),
+            },
+            None => return None,
+        };
+        self.receiver = None;
+
+        match result {
+            Ok(outcome) => {
+                if let Err(e) = apply_changes(study_data, &outcome) {
+                    self.status = SyncStatus::Failed(e.to_string());
+                    return Some(format!("Error saving synced todos: {}", e));
+                }
+
+                self.config.known_remote_ids = outcome.known_remote_ids;
+                self.config.last_sync = Some(outcome.synced_at.clone());
+                if let Err(e) = self.config.save() {
+                    error::report("Could not save sync state", e);
+                }
+
+                let mut summary = format!(
+                    "Synced: {} sent, {} received",
+                    outcome.pushed, outcome.pulled
+                );
+                if outcome.conflicts > 0 {
+                    summary.push_str(&format!(
+                        ", {} conflict(s) resolved ({})",
+                        outcome.conflicts,
+                        self.config.conflict_policy.name()
+                    ));
+                }
+                self.status = SyncStatus::Synced(summary.clone());
+                if outcome.pushed + outcome.pulled + outcome.conflicts > 0 {
+                    Some(summary)
+                } else {
+                    None
+                }
+            }
+            Err(e) => {
+                self.status = SyncStatus::Failed(e.clone());
+                Some(format!("Todo sync failed: {}", e))
+            }
+        }
+    }
+}
+
+fn format_sync_time(rfc3339: &str) -> String {
+    DateTime::parse_from_rfc3339(rfc3339)
+        .map(|t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|_| rfc3339.to_string())
+}
+
+fn apply_changes(
+    study_data: &mut StudyData,
+    outcome: &SyncOutcome,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if outcome.changes.is_empty() {
+        return Ok(());
+    }
+
+    for change in &outcome.changes {
+        match change {
+            LocalChange::Link { todo_id, remote_id } => {
+                if let Some(todo) = study_data.todos.iter_mut().find(|t| t.id == *todo_id) {
+                    todo.remote_id = Some(remote_id.clone());
+                    // Todos from before sync have no stamp and would count as changed forever
+                    todo.updated_at = Some(outcome.synced_at.clone());
+                }
+            }
+            LocalChange::Update {
+                todo_id,
+                text,
+                completed,
+            } => {
+                if let Some(todo) = study_data.todos.iter_mut().find(|t| t.id == *todo_id) {
+                    todo.text = text.clone();
+                    todo.completed = *completed;
+                    // Not a local edit, so don't let the next sync push it back
+                    todo.updated_at = Some(outcome.synced_at.clone());
+                }
+            }
+            LocalChange::Create {
+                text,
+                completed,
+                project,
+                remote_id,
+            } => {
+                let id = study_data.get_next_todo_id();
+                study_data.todos.push(Todo {
+                    id,
+                    text: text.clone(),
+                    completed: *completed,
+                    created_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                    project: project.clone(),
+                    updated_at: Some(outcome.synced_at.clone()),
+                    remote_id: Some(remote_id.clone()),
+                    board_column: BoardColumn::default(),
+                    priority: TodoPriority::default(),
+                    due_date: None,
+                    due_time: None,
+                    urgent: None,
+                });
+            }
+            LocalChange::Delete { todo_id } => {
+                study_data.todos.retain(|t| t.id != *todo_id);
+            }
+        }
+    }
+
+    study_data.save()
+}
+
+fn project_of(todo: &Todo) -> &str {
+    todo.project.as_deref().unwrap_or(DEFAULT_PROJECT)
+}
+
+fn parse_time(value: &Option<String>) -> Option<DateTime<Utc>> {
+    value
+        .as_ref()
+        .and_then(|v| DateTime::parse_from_rfc3339(v).ok())
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn run_sync(
+    config: &TaskSyncConfig,
+    todos: &[Todo],
+) -> Result<SyncOutcome, Box<dyn std::error::Error>> {
+    if config.mappings.is_empty() {
+        return Err("no project to list mappings configured".into());
+    }
+
+    let synced_at = Utc::now();
+    let last_sync = parse_time(&config.last_sync);
+    let changed_since_sync = |time: Option<DateTime<Utc>>| match (time, last_sync) {
+        (Some(time), Some(last)) => time > last,
+        _ => true,
+    };
+
+    let mut outcome = SyncOutcome {
+        changes: Vec::new(),
+        known_remote_ids: HashSet::new(),
+        synced_at: synced_at.to_rfc3339(),
+        pushed: 0,
+        pulled: 0,
+        conflicts: 0,
+    };
+
+    let linked_ids: HashSet<&str> = todos
+        .iter()
+        .filter_map(|t| t.remote_id.as_deref())
+        .collect();
+
+    for mapping in &config.mappings {
+        let list = mapping.remote_list.trim();
+        if list.is_empty() {
+            continue;
+        }
+
+        let remote_tasks = list_remote(config, list)?;
+        let remote_by_id: HashMap<&str, &RemoteTask> =
+            remote_tasks.iter().map(|t| (t.id.as_str(), t)).collect();
+
+        for todo in todos.iter().filter(|t| project_of(t) == mapping.project) {
+            let remote = todo
+                .remote_id
+                .as_deref()
+                .and_then(|id| remote_by_id.get(id).copied());
+
+            match (&todo.remote_id, remote) {
+                (None, _) => {
+                    let remote_id = create_remote(config, list, &todo.text, todo.completed)?;
+                    outcome.known_remote_ids.insert(remote_id.clone());
+                    outcome.changes.push(LocalChange::Link {
+                        todo_id: todo.id,
+                        remote_id,
+                    });
+                    outcome.pushed += 1;
+                }
+                (Some(remote_id), None) => {
+                    let local_changed = changed_since_sync(parse_time(&todo.updated_at));
+                    if config.known_remote_ids.contains(remote_id) && !local_changed {
+                        // Deleted remotely and untouched here
+                        outcome
+                            .changes
+                            .push(LocalChange::Delete { todo_id: todo.id });
+                        outcome.pulled += 1;
+                    } else {
+                        let new_id = create_remote(config, list, &todo.text, todo.completed)?;
+                        outcome.known_remote_ids.insert(new_id.clone());
+                        outcome.changes.push(LocalChange::Link {
+                            todo_id: todo.id,
+                            remote_id: new_id,
+                        });
+                        outcome.pushed += 1;
+                    }
+                }
+                (Some(remote_id), Some(remote)) => {
+                    outcome.known_remote_ids.insert(remote_id.clone());
+                    if remote.title == todo.text && remote.completed == todo.completed {
+                        continue;
+                    }
+
+                    let local_time = parse_time(&todo.updated_at);
+                    let local_changed = changed_since_sync(local_time);
+                    let remote_changed = changed_since_sync(remote.updated);
+
+                    let keep_local = match (local_changed, remote_changed) {
+                        (true, false) => true,
+                        (false, true) => false,
+                        _ => {
+                            outcome.conflicts += 1;
+                            match config.conflict_policy {
+                                ConflictPolicy::PreferLocal => true,
+                                ConflictPolicy::PreferRemote => false,
+                                ConflictPolicy::NewestWins => local_time >= remote.updated,
+                            }
+                        }
+                    };
+
+                    if keep_local {
+                        update_remote(config, list, remote, &todo.text, todo.completed)?;
+                        outcome.pushed += 1;
+                    } else {
+                        outcome.changes.push(LocalChange::Update {
+                            todo_id: todo.id,
+                            text: remote.title.clone(),
+                            completed: remote.completed,
+                        });
+                        outcome.pulled += 1;
+                    }
+                }
+            }
+        }
+
+        for remote in &remote_tasks {
+            if linked_ids.contains(remote.id.as_str()) {
+                continue;
+            }
+
+            if config.known_remote_ids.contains(&remote.id) {
+                // Synced before but deleted in FocusPad since then
+                delete_remote(config, list, remote)?;
+                outcome.pushed += 1;
+            } else {
+                let project = if mapping.project == DEFAULT_PROJECT {
+                    None
+                } else {
+                    Some(mapping.project.clone())
+                };
+                outcome.known_remote_ids.insert(remote.id.clone());
+                outcome.changes.push(LocalChange::Create {
+                    text: remote.title.clone(),
+                    completed: remote.completed,
+                    project,
+                    remote_id: remote.id.clone(),
+                });
+                outcome.pulled += 1;
+            }
+        }
+    }
+
+    Ok(outcome)
+}
+
+// --- HTTP via curl, same approach as the weather widget ---
+
+// Credentials go to curl as a config file on stdin, so they never show up in the
+// command line other users can read with `ps`
+pub fn curl(args: &[String], secrets: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let mut command = Command::new("curl");
+    command.arg("-sS").arg("-f").args(args);
+    if secrets.is_empty() {
+        command.stdin(Stdio::null());
+    } else {
+        command.arg("-K").arg("-").stdin(Stdio::piped());
+    }
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(secrets.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+
+    let body = String::from_utf8_lossy(&output.stdout).to_string();
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("request failed: {}", stderr.trim()).into());
+    }
+    Ok(body)
+}
+
+// One `option = "value"` line of a curl config file. Escaped like curl reads them, so a
+// line break in a token can't end the value and start another option.
+pub fn curl_config_line(option: &str, value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\x0b' => escaped.push_str("\\v"),
+            c => escaped.push(c),
+        }
+    }
+    format!("{} = \"{}\"\n", option, escaped)
+}
+
+fn list_remote(
+    config: &TaskSyncConfig,
+    list: &str,
+) -> Result<Vec<RemoteTask>, Box<dyn std::error::Error>> {
+    match config.provider {
+        SyncProvider::GoogleTasks => google_list(config, list),
+        SyncProvider::CalDav => caldav_list(config, list),
+    }
+}
+
+fn create_remote(
+    config: &TaskSyncConfig,
+    list: &str,
+    title: &str,
+    completed: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    match config.provider {
+        SyncProvider::GoogleTasks => google_create(config, list, title, completed),
+        SyncProvider::CalDav => {
+            let uid = uuid::Uuid::new_v4().to_string();
+            let href = format!("{}{}.ics", caldav_collection_path(config, list), uid);
+            caldav_put(config, &href, &uid, title, completed)?;
+            Ok(href)
+        }
+    }
+}
+
+fn update_remote(
+    config: &TaskSyncConfig,
+    list: &str,
+    remote: &RemoteTask,
+    title: &str,
+    completed: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match config.provider {
+        SyncProvider::GoogleTasks => {
+            let url = format!("{}/{}", google_tasks_url(list), remote.id);
+            let body = google_task_body(title, completed);
+            let mut args: Vec<String> =
+                ["-X", "PATCH", "-H", "Content-Type: application/json", "-d"]
+                    .map(String::from)
+                    .into();
+            args.push(body);
+            args.push(url);
+            curl(&args, &google_auth(config))?;
+            Ok(())
+        }
+        SyncProvider::CalDav => caldav_put(config, &remote.id, &remote.uid, title, completed),
+    }
+}
+
+fn delete_remote(
+    config: &TaskSyncConfig,
+    list: &str,
+    remote: &RemoteTask,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (secrets, url) = match config.provider {
+        SyncProvider::GoogleTasks => (
+            google_auth(config),
+            format!("{}/{}", google_tasks_url(list), remote.id),
+        ),
+        SyncProvider::CalDav => (caldav_auth(config), caldav_resource_url(config, &remote.id)),
+    };
+    let mut args: Vec<String> = ["-X", "DELETE"].map(String::from).into();
+    args.push(url);
+    curl(&args, &secrets)?;
+    Ok(())
+}
+
+// --- Google Tasks ---
+
+fn google_tasks_url(list: &str) -> String {
+    format!("https://tasks.googleapis.com/tasks/v1/lists/{}/tasks", list)
+}
+
+fn google_auth(config: &TaskSyncConfig) -> String {
+    curl_config_line(
+        "header",
+        &format!(
+            "Authorization: Bearer {}",
+            config.google_access_token.trim()
+        ),
+    )
+}
+
+fn google_task_body(title: &str, completed: bool) -> String {
+    serde_json::json!({
+        "title": title,
+        "status": if completed { "completed" } else { "needsAction" },
+    })
+    .to_string()
+}
+
+fn google_list(
+    config: &TaskSyncConfig,
+    list: &str,
+) -> Result<Vec<RemoteTask>, Box<dyn std::error::Error>> {
+    let mut tasks = Vec::new();
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let mut url = format!(
+            "{}?showCompleted=true&showHidden=true&maxResults=100",
+            google_tasks_url(list)
+        );
+        if let Some(token) = &page_token {
+            url.push_str(&format!("&pageToken={}", token));
+        }
+
+        let body = curl(&[url], &google_auth(config))?;
+        let json: serde_json::Value = serde_json::from_str(&body)?;
+
+        if let Some(items) = json["items"].as_array() {
+            for item in items {
+                let id = match item["id"].as_str() {
+                    Some(id) => id.to_string(),
+                    None => continue,
+                };
+                let title = item["title"].as_str().unwrap_or("").to_string();
+                if title.trim().is_empty() {
+                    continue;
+                }
+                tasks.push(RemoteTask {
+                    uid: id.clone(),
+                    id,
+                    title,
+                    completed: item["status"].as_str() == Some("completed"),
+                    updated: item["updated"]
+                        .as_str()
+                        .and_then(|u| DateTime::parse_from_rfc3339(u).ok())
+                        .map(|t| t.with_timezone(&Utc)),
+                });
+            }
+        }
+
+        page_token = json["nextPageToken"].as_str().map(|s| s.to_string());
+        if page_token.is_none() {
+            break;
+        }
+    }
+
+    Ok(tasks)
+}
+
+fn google_create(
+    config: &TaskSyncConfig,
+    list: &str,
+    title: &str,
+    completed: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut args: Vec<String> = ["-X", "POST", "-H", "Content-Type: application/json", "-d"]
+        .map(String::from)
+        .into();
+    args.push(google_task_body(title, completed));
+    args.push(google_tasks_url(list));
+
+    let body = curl(&args, &google_auth(config))?;
+    let json: serde_json::Value = serde_json::from_str(&body)?;
+    json["id"]
+        .as_str()
+        .map(|id| id.to_string())
+        .ok_or_else(|| "Google Tasks did not return an id".into())
+}
+
+// --- CalDAV (VTODO) ---
+
+fn caldav_auth(config: &TaskSyncConfig) -> String {
+    curl_config_line(
+        "user",
+        &format!("{}:{}", config.caldav_username, config.caldav_password),
+    )
+}
+
+// "https://host:port" part of the configured URL
+fn caldav_origin(config: &TaskSyncConfig) -> String {
+    let url = config.caldav_url.trim();
+    let after_scheme = url.find("://").map(|i| i + 3).unwrap_or(0);
+    match url[after_scheme..].find('/') {
+        Some(slash) => url[..after_scheme + slash].to_string(),
+        None => url.to_string(),
+    }
+}
+
+fn caldav_collection_path(config: &TaskSyncConfig, list: &str) -> String {
+    let url = config.caldav_url.trim().trim_end_matches('/');
+    let origin = caldav_origin(config);
+    format!("{}/{}/", &url[origin.len()..], list.trim_matches('/'))
+}
+
+fn caldav_resource_url(config: &TaskSyncConfig, href: &str) -> String {
+    if href.starts_with("http://") || href.starts_with("https://") {
+        href.to_string()
+    } else {
+        format!("{}{}", caldav_origin(config), href)
+    }
+}
+
+fn caldav_list(
+    config: &TaskSyncConfig,
+    list: &str,
+) -> Result<Vec<RemoteTask>, Box<dyn std::error::Error>> {
+    let query = r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop><d:getetag/><c:calendar-data/></d:prop>
+  <c:filter><c:comp-filter name="VCALENDAR"><c:comp-filter name="VTODO"/></c:comp-filter></c:filter>
+</c:calendar-query>"#;
+
+    let mut args: Vec<String> = [
+        "-X",
+        "REPORT",
+        "-H",
+        "Depth: 1",
+        "-H",
+        "Content-Type: application/xml; charset=utf-8",
+        "-d",
+    ]
+    .map(String::from)
+    .into();
+    args.push(query.to_string());
+    args.push(caldav_resource_url(
+        config,
+        &caldav_collection_path(config, list),
+    ));
+    let body = curl(&args, &caldav_auth(config))?;
+
+    let response_re = Regex::new(r"(?s)<(?:\w+:)?response\b[^>]*>(.*?)</(?:\w+:)?response>")?;
+    let href_re = Regex::new(r"(?s)<(?:\w+:)?href\b[^>]*>(.*?)</(?:\w+:)?href>")?;
+    let data_re = Regex::new(r"(?s)<(?:\w+:)?calendar-data\b[^>]*>(.*?)</(?:\w+:)?calendar-data>")?;
+
+    let mut tasks = Vec::new();
+    for response in response_re.captures_iter(&body) {
+        let inner = &response[1];
+        let href = match href_re.captures(inner) {
+            Some(c) => xml_unescape(c[1].trim()),
+            None => continue,
+        };
+        let data = match data_re.captures(inner) {
+            Some(c) => xml_unescape(&c[1]),
+            None => continue,
+        };
+        if let Some(task) = parse_vtodo(&href, &data) {
+            tasks.push(task);
+        }
+    }
+    Ok(tasks)
+}
+
+fn caldav_put(
+    config: &TaskSyncConfig,
+    href: &str,
+    uid: &str,
+    title: &str,
+    completed: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let status = if completed {
+        "COMPLETED"
+    } else {
+        "NEEDS-ACTION"
+    };
+    let ics = format!(
+        "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//FocusPad//Todo Sync//EN\r\nBEGIN:VTODO\r\nUID:{}\r\nDTSTAMP:{}\r\nLAST-MODIFIED:{}\r\nSUMMARY:{}\r\nSTATUS:{}\r\nEND:VTODO\r\nEND:VCALENDAR\r\n",
+        uid,
+        stamp,
+        stamp,
+        ics_escape(title),
+        status
+    );
+
+    let mut args: Vec<String> = [
+        "-X",
+        "PUT",
+        "-H",
+        "Content-Type: text/calendar; charset=utf-8",
+        "--data-binary",
+    ]
+    .map(String::from)
+    .into();
+    args.push(ics);
+    args.push(caldav_resource_url(config, href));
+    curl(&args, &caldav_auth(config))?;
+    Ok(())
+}
+
+fn parse_vtodo(href: &str, ics: &str) -> Option<RemoteTask> {
+    // Unfold continuation lines first (RFC 5545 section 3.1)
+    let unfolded = ics.replace("\r\n ", "").replace("\n ", "");
+    let mut in_todo = false;
+    let mut uid = None;
+    let mut title = None;
+    let mut completed = false;
+    let mut updated = None;
+
+    for line in unfolded.lines() {
+        let line = line.trim_end_matches('\r');
+        if line == "BEGIN:VTODO" {
+            in_todo = true;
+            continue;
+        }
+        if line == "END:VTODO" {
+            break;
+        }
+        if !in_todo {
+            continue;
+        }
+
+        let (name, value) = match line.split_once(':') {
+            Some(parts) => parts,
+            None => continue,
+        };
+        // Drop parameters such as SUMMARY;LANGUAGE=en
+        let name = name.split(';').next().unwrap_or(name);
+        match name {
+            "UID" => uid = Some(value.to_string()),
+            "SUMMARY" => title = Some(ics_unescape(value)),
+            "STATUS" => completed = value == "COMPLETED",
+            "LAST-MODIFIED" => {
+                updated = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+                    .ok()
+                    .map(|t| DateTime::<Utc>::from_naive_utc_and_offset(t, Utc));
+            }
+            _ => {}
+        }
+    }
+
+    Some(RemoteTask {
+        id: href.to_string(),
+        uid: uid?,
+        title: title?,
+        completed,
+        updated,
+    })
+}
+
+fn ics_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn ics_unescape(text: &str) -> String {
+    text.replace("\\n", "\n")
+        .replace("\\N", "\n")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+fn xml_unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#13;", "\r")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,402 @@
+use crate::data::StudySession;
+use crate::error;
+use crate::task_sync::{curl, curl_config_line, SyncStatus};
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+const AUTO_PUSH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+// Manual entries have no start time, so they are reported as starting here
+const MANUAL_ENTRY_START: &str = "09:00";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TimeProvider {
+    Toggl,
+    Clockify,
+}
+
+impl TimeProvider {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeProvider::Toggl => "Toggl Track",
+            TimeProvider::Clockify => "Clockify",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMapping {
+    pub subject: String, // Session description, e.g. "Biology"
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeExportConfig {
+    pub enabled: bool,
+    pub provider: TimeProvider,
+    pub api_token: String,
+    pub workspace_id: String,
+    pub default_project_id: String, // For subjects without a mapping; empty sends no project
+    pub mappings: Vec<ProjectMapping>,
+    pub since: String,           // YYYY-MM-DD; earlier sessions are never sent
+    pub pushed: HashSet<String>, // Keys of the entries already sent
+}
+
+impl Default for TimeExportConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: TimeProvider::Toggl,
+            api_token: String::new(),
+            workspace_id: String::new(),
+            default_project_id: String::new(),
+            mappings: Vec::new(),
+            since: String::new(),
+            pushed: HashSet::new(),
+        }
+    }
+}
+
+impl TimeExportConfig {
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = get_config_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(&self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn project_for(&self, subject: &str) -> Option<&str> {
+        let id = self
+            .mappings
+            .iter()
+            .find(|m| m.subject.trim().eq_ignore_ascii_case(subject.trim()))
+            .map_or(self.default_project_id.trim(), |m| m.project_id.trim());
+        (!id.is_empty()).then_some(id)
+    }
+}
+
+fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut path = crate::profile::config_dir().ok_or("Could not find config directory")?;
+    path.push("time_export.json");
+    Ok(path)
+}
+
+#[derive(Debug, Clone)]
+struct TimeEntry {
+    key: String,
+    description: String,
+    project_id: Option<String>,
+    start: DateTime<Utc>,
+    minutes: f64,
+}
+
+struct PushOutcome {
+    pushed: Vec<String>,
+    error: Option<String>,
+}
+
+pub struct TimeExport {
+    pub config: TimeExportConfig,
+    pub status: SyncStatus,
+    receiver: Option<Receiver<PushOutcome>>,
+    last_attempt: Option<Instant>,
+}
+
+impl TimeExport {
+    pub fn new() -> Self {
+        Self {
+            config: TimeExportConfig::load().unwrap_or_default(),
+            status: SyncStatus::Idle,
+            receiver: None,
+            last_attempt: None,
+        }
+    }
+
+    pub fn is_pushing(&self) -> bool {
+        self.receiver.is_some()
+    }
+
+    pub fn pending_count(&self, sessions: &[StudySession]) -> usize {
+        pending_entries(&self.config, sessions).len()
+    }
+
+    pub fn status_text(&self) -> String {
+        match &self.status {
+            SyncStatus::Idle => format!("Not sent to {} yet", self.config.provider.name()),
+            SyncStatus::Syncing => format!("⟳ Sending to {}...", self.config.provider.name()),
+            SyncStatus::Synced(summary) => summary.clone(),
+            SyncStatus::Failed(error) => format!("⚠ Export failed: {}", error),
+        }
+    }
+
+    // Sends the sessions that haven't been pushed yet in the background
+    pub fn start(&mut self, sessions: &[StudySession]) {
+        if self.is_pushing() {
+            return;
+        }
+        self.last_attempt = Some(Instant::now());
+
+        let entries = pending_entries(&self.config, sessions);
+        if entries.is_empty() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let config = self.config.clone();
+        std::thread::spawn(move || {
+            let _ = sender.send(push_entries(&config, &entries));
+        });
+
+        self.receiver = Some(receiver);
+        self.status = SyncStatus::Syncing;
+    }
+
+    // Called every frame; pushes new sessions periodically and records finished pushes
+    pub fn update(&mut self, sessions: &[StudySession]) -> Option<String> {
+        if self.config.enabled && !self.is_pushing() {
+            let due = self
+                .last_attempt
+                .is_none_or(|last| last.elapsed() >= AUTO_PUSH_INTERVAL);
+            if due {
+                self.start(sessions);
+            }
+        }
+
+        let outcome = match &self.receiver {
+            Some(receiver) => match receiver.try_recv() {
+                Ok(outcome) => outcome,
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => PushOutcome {
+                    pushed: Vec::new(),
+                    error: Some("export worker stopped".to_string()),
+                },
+            },
+            None => return None,
+        };
+        self.receiver = None;
+
+        let count = outcome.pushed.len();
+        self.config.pushed.extend(outcome.pushed);
+        if count > 0 {
+            if let Err(e) = self.config.save() {
+                error::report("Could not save time export state", e);
+            }
+        }
+
+        let provider = self.config.provider.name();
+        match outcome.error {
+            Some(e) => {
+                self.status = SyncStatus::Failed(e.clone());
+                Some(format!("Sending sessions to {} failed: {}", provider, e))
+            }
+            None => {
+                let summary = format!(
+                    "✔ Sent {} session(s) to {} at {}",
+                    count,
+                    provider,
+                    Local::now().format("%H:%M")
+                );
+                self.status = SyncStatus::Synced(summary.clone());
+                Some(summary)
+            }
+        }
+    }
+}
+
+// One entry per timer run, or per record for sessions entered by hand
+fn pending_entries(config: &TimeExportConfig, sessions: &[StudySession]) -> Vec<TimeEntry> {
+    if config.since.is_empty() {
+        return Vec::new();
+    }
+
+    let mut entries = Vec::new();
+    for session in sessions
+        .iter()
+        .filter(|s| s.date.as_str() >= config.since.as_str())
+    {
+        let description = session.description.clone().unwrap_or_default();
+        let runs: Vec<(&str, f64)> = if session.spans.is_empty() {
+            vec![(MANUAL_ENTRY_START, session.minutes)]
+        } else {
+            session
+                .spans
+                .iter()
+                .map(|span| (span.start.as_str(), span.minutes))
+                .collect()
+        };
+
+        for (start, minutes) in runs {
+            let key = format!("{}T{}+{:.1}|{}", session.date, start, minutes, description);
+            if minutes <= 0.0 || config.pushed.contains(&key) {
+                continue;
+            }
+
+            // Hand-entered time grows in place as more is logged, so only the minutes added
+            // since the last push go out, right after the ones already sent
+            let sent = if session.spans.is_empty() {
+                sent_minutes(config, &session.date, start, &description)
+            } else {
+                0.0
+            };
+            let minutes = minutes - sent;
+            if minutes < 0.05 {
+                continue;
+            }
+            let start = NaiveDateTime::parse_from_str(
+                &format!("{} {}", session.date, start),
+                "%Y-%m-%d %H:%M",
+            )
+            .ok()
+            .map(|naive| naive + chrono::Duration::seconds((sent * 60.0).round() as i64))
+            .and_then(|naive| Local.from_local_datetime(&naive).earliest());
+            if let Some(start) = start {
+                entries.push(TimeEntry {
+                    key,
+                    project_id: config.project_for(&description).map(str::to_string),
+                    description: description.clone(),
+                    start: start.with_timezone(&Utc),
+                    minutes,
+                });
+            }
+        }
+    }
+    entries
+}
+
+// Largest running total already pushed for a hand-entered record
+fn sent_minutes(config: &TimeExportConfig, date: &str, start: &str, description: &str) -> f64 {
+    let prefix = format!("{}T{}+", date, start);
+    let suffix = format!("|{}", description);
+    config
+        .pushed
+        .iter()
+        .filter_map(|key| {
+            key.strip_prefix(&prefix)?
+                .strip_suffix(&suffix)?
+                .parse()
+                .ok()
+        })
+        .fold(0.0, f64::max)
+}
+
+// Stops at the first failure; the entries sent before it are still recorded
+fn push_entries(config: &TimeExportConfig, entries: &[TimeEntry]) -> PushOutcome {
+    let mut outcome = PushOutcome {
+        pushed: Vec::new(),
+        error: None,
+    };
+    if config.api_token.trim().is_empty() || config.workspace_id.trim().is_empty() {
+        outcome.error = Some("API token and workspace id are required".to_string());
+        return outcome;
+    }
+
+    for entry in entries {
+        let result = match config.provider {
+            TimeProvider::Toggl => toggl_create(config, entry),
+            TimeProvider::Clockify => clockify_create(config, entry),
+        };
+        match result {
+            Ok(()) => outcome.pushed.push(entry.key.clone()),
+            Err(e) => {
+                outcome.error = Some(e.to_string());
+                break;
+            }
+        }
+    }
+    outcome
+}
+
+// --- HTTP via curl, shared with the todo sync ---
+
+fn entry_end(entry: &TimeEntry) -> DateTime<Utc> {
+    entry.start + chrono::Duration::seconds((entry.minutes * 60.0).round() as i64)
+}
+
+// --- Toggl Track (API v9) ---
+
+fn toggl_create(
+    config: &TimeExportConfig,
+    entry: &TimeEntry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let workspace_id: u64 = config
+        .workspace_id
+        .trim()
+        .parse()
+        .map_err(|_| "the Toggl workspace id must be a number")?;
+    let project_id = match &entry.project_id {
+        Some(id) => Some(
+            id.parse::<u64>()
+                .map_err(|_| format!("Toggl project id \"{}\" must be a number", id))?,
+        ),
+        None => None,
+    };
+
+    let body = serde_json::json!({
+        "created_with": "FocusPad",
+        "workspace_id": workspace_id,
+        "project_id": project_id,
+        "description": entry.description,
+        "start": entry.start.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        "stop": entry_end(entry).to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        "duration": (entry.minutes * 60.0).round() as i64,
+    });
+
+    let mut args: Vec<String> = ["-X", "POST", "-H", "Content-Type: application/json", "-d"]
+        .map(String::from)
+        .into();
+    args.push(body.to_string());
+    args.push(format!(
+        "https://api.track.toggl.com/api/v9/workspaces/{}/time_entries",
+        workspace_id
+    ));
+    let secrets = curl_config_line("user", &format!("{}:api_token", config.api_token.trim()));
+    curl(&args, &secrets)?;
+    Ok(())
+}
+
+// --- Clockify (API v1) ---
+
+fn clockify_create(
+    config: &TimeExportConfig,
+    entry: &TimeEntry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut body = serde_json::json!({
+        "description": entry.description,
+        "start": entry.start.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        "end": entry_end(entry).to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+    });
+    if let Some(project_id) = &entry.project_id {
+        body["projectId"] = serde_json::Value::String(project_id.clone());
+    }
+
+    let mut args: Vec<String> = ["-X", "POST", "-H", "Content-Type: application/json", "-d"]
+        .map(String::from)
+        .into();
+    args.push(body.to_string());
+    args.push(format!(
+        "https://api.clockify.me/api/v1/workspaces/{}/time-entries",
+        config.workspace_id.trim()
+    ));
+    let secrets = curl_config_line("header", &format!("X-Api-Key: {}", config.api_token.trim()));
+    curl(&args, &secrets)?;
+    Ok(())
+}
@@ -5,6 +5,7 @@ pub struct Timer {
     pub accumulated_time: Duration,
     pub is_running: bool,
     pub time_offset: Duration, // Debug feature - time offset for time manipulation
+    pub target: Option<Duration>, // Planned length when started from a time block
 }
 
 impl Timer {
@@ -14,6 +15,7 @@ impl Timer {
             accumulated_time: Duration::from_secs(0),
             is_running: false,
             time_offset: Duration::from_secs(0),
+            target: None,
         }
     }
 
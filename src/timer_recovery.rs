@@ -0,0 +1,138 @@
+use crate::data::Lap;
+use crate::timer::Timer;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// Keeps the timer on disk while it has time on it, so a crash or an accidental close
+// doesn't lose the session. Sits next to study_data.json, so each profile has its own.
+const STATE_FILE: &str = "timer_state.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimerSnapshot {
+    pub running_since: Option<String>, // RFC 3339 start of the running stretch; None while paused
+    pub accumulated_secs: f64,
+    #[serde(default)]
+    pub target_secs: Option<u64>,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub notes: Vec<String>,
+    #[serde(default)]
+    pub laps: Vec<Lap>,
+}
+
+impl TimerSnapshot {
+    fn of(timer: &Timer, description: &str, notes: Vec<String>, laps: Vec<Lap>) -> Self {
+        let running_since = timer.start_time.filter(|_| timer.is_running).map(|start| {
+            let elapsed =
+                chrono::Duration::from_std(start.elapsed()).unwrap_or(chrono::Duration::zero());
+            (Local::now() - elapsed).to_rfc3339()
+        });
+        Self {
+            running_since,
+            accumulated_secs: timer.accumulated_time.as_secs_f64(),
+            target_secs: timer.target.map(|t| t.as_secs()),
+            description: description.to_string(),
+            notes,
+            laps,
+        }
+    }
+
+    pub fn started_at(&self) -> Option<DateTime<Local>> {
+        self.running_since
+            .as_deref()
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|t| t.with_timezone(&Local))
+    }
+
+    // When the session began, counting the time it ran before its last pause
+    pub fn session_start(&self) -> Option<DateTime<Local>> {
+        let earlier = chrono::Duration::milliseconds((self.accumulated_secs * 1000.0) as i64);
+        self.started_at().map(|start| start - earlier)
+    }
+
+    pub fn was_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    // Counts the time since the app went away if the timer was running
+    pub fn elapsed_minutes(&self) -> f64 {
+        let running = self.started_at().map_or(0.0, |start| {
+            (Local::now() - start).num_seconds().max(0) as f64
+        });
+        (self.accumulated_secs + running) / 60.0
+    }
+}
+
+fn load() -> Option<TimerSnapshot> {
+    let json = fs::read_to_string(STATE_FILE).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+fn save(snapshot: &TimerSnapshot) -> Result<(), Box<dyn std::error::Error>> {
+    let json = serde_json::to_string_pretty(snapshot)?;
+    fs::write(STATE_FILE, json)?;
+    Ok(())
+}
+
+fn clear() -> Result<(), Box<dyn std::error::Error>> {
+    if Path::new(STATE_FILE).exists() {
+        fs::remove_file(STATE_FILE)?;
+    }
+    Ok(())
+}
+
+pub struct TimerRecovery {
+    pub recovered: Option<TimerSnapshot>, // Left over from the last run, until the user decides
+    pub log_minutes: f64,
+    // (start, accumulated, description, note count, lap count) last written, to avoid a
+    // write per frame
+    last_saved: Option<(Option<Instant>, Duration, String, usize, usize)>,
+}
+
+impl TimerRecovery {
+    pub fn new() -> Self {
+        let recovered = load().filter(|snapshot| snapshot.elapsed_minutes() > 0.0);
+        Self {
+            log_minutes: recovered
+                .as_ref()
+                .map_or(0.0, |snapshot| snapshot.elapsed_minutes().round()),
+            recovered,
+            last_saved: None,
+        }
+    }
+
+    // Writes the timer out when it starts, pauses, resets or its details change
+    pub fn update(&mut self, timer: &Timer, description: &str, notes: Vec<String>, laps: Vec<Lap>) {
+        if self.recovered.is_some() {
+            return; // Keep the old session on disk until the user has decided
+        }
+        let key = (
+            timer.start_time.filter(|_| timer.is_running),
+            timer.accumulated_time,
+            description.to_string(),
+            notes.len(),
+            laps.len(),
+        );
+        if self.last_saved.as_ref() == Some(&key) {
+            return;
+        }
+        self.last_saved = Some(key);
+
+        let result = if timer.is_running || timer.accumulated_time > Duration::ZERO {
+            save(&TimerSnapshot::of(timer, description, notes, laps))
+        } else {
+            clear()
+        };
+        if let Err(e) = result {
+            crate::error::report("Could not save the timer state", e);
+        }
+    }
+
+    pub fn dismiss(&mut self) {
+        self.recovered = None;
+    }
+}
@@ -0,0 +1,139 @@
+use crate::app::Tab;
+use crate::data::{StudyData, TodoPriority};
+use crate::ui::todo_tab;
+use chrono::Local;
+use eframe::egui;
+use std::time::Duration;
+
+// Each item stays this long before the next one rotates in
+const CYCLE_SECONDS: f64 = 8.0;
+const MAX_CHARS: usize = 24;
+
+enum Target {
+    Todos,
+    Habits,
+    Reminders,
+}
+
+struct AgendaItem {
+    text: String,
+    target: Target,
+}
+
+fn shorten(text: &str) -> String {
+    if text.chars().count() > MAX_CHARS {
+        let short: String = text.chars().take(MAX_CHARS).collect();
+        format!("{}…", short)
+    } else {
+        text.to_string()
+    }
+}
+
+fn priority_rank(priority: TodoPriority) -> u8 {
+    match priority {
+        TodoPriority::High => 0,
+        TodoPriority::Normal => 1,
+        TodoPriority::Low => 2,
+    }
+}
+
+fn items(study_data: &StudyData) -> Vec<AgendaItem> {
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+    let mut items = Vec::new();
+
+    // Overdue and due-today work first, then by priority and the nearest due date
+    let top_todo = study_data
+        .todos
+        .iter()
+        .filter(|t| !t.completed)
+        .min_by_key(|t| {
+            let due_now = t.due_date.as_ref().is_some_and(|d| *d <= today);
+            (
+                !due_now,
+                priority_rank(t.priority),
+                t.due_date.is_none(),
+                t.due_date.clone(),
+                t.id,
+            )
+        });
+    if let Some(todo) = top_todo {
+        items.push(AgendaItem {
+            text: format!("✅ {}", todo.text),
+            target: Target::Todos,
+        });
+    }
+
+    let next_reminder = study_data
+        .reminders
+        .iter()
+        .filter(|r| !r.is_completed && !r.is_snoozed())
+        .min_by_key(|r| (r.due_date.clone(), r.due_time.clone()));
+    if let Some(reminder) = next_reminder {
+        let when = if reminder.due_date == today {
+            reminder
+                .due_time
+                .clone()
+                .unwrap_or_else(|| "today".to_string())
+        } else {
+            reminder.due_date.clone()
+        };
+        items.push(AgendaItem {
+            text: format!("🔔 {} · {}", reminder.title, when),
+            target: Target::Reminders,
+        });
+    }
+
+    let active: Vec<_> = study_data
+        .habits
+        .iter()
+        .filter(|h| !h.is_paused_on(&today))
+        .collect();
+    if !active.is_empty() {
+        let done = active
+            .iter()
+            .filter(|h| h.completion_dates.contains(&today))
+            .count();
+        items.push(AgendaItem {
+            text: format!("🔥 {}/{} habits", done, active.len()),
+            target: Target::Habits,
+        });
+    }
+
+    items
+}
+
+// Rotates through today's top todo, the next reminder and habit progress; returns the tab to open when clicked
+pub fn render(ui: &mut egui::Ui, study_data: &StudyData) -> Option<Tab> {
+    let items = items(study_data);
+    if items.is_empty() {
+        return None;
+    }
+
+    let time = ui.input(|i| i.time);
+    let item = &items[(time / CYCLE_SECONDS) as usize % items.len()];
+    let hover: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+    let response = ui
+        .add(egui::Label::new(shorten(&item.text)).sense(egui::Sense::click()))
+        .on_hover_text(format!("{}\n\nClick to open", hover.join("\n")));
+
+    if items.len() > 1 {
+        let remaining = CYCLE_SECONDS - time % CYCLE_SECONDS;
+        ui.ctx()
+            .request_repaint_after(Duration::from_secs_f64(remaining));
+    }
+
+    if !response.clicked() {
+        return None;
+    }
+    match item.target {
+        Target::Todos => {
+            todo_tab::show_todos();
+            Some(Tab::Todo)
+        }
+        Target::Habits => {
+            todo_tab::show_habits();
+            Some(Tab::Todo)
+        }
+        Target::Reminders => Some(Tab::Reminder),
+    }
+}
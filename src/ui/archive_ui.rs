@@ -0,0 +1,101 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use eframe::egui;
+use std::cell::RefCell;
+
+thread_local! {
+    static KIND_FILTER: RefCell<Option<&'static str>> = RefCell::new(None);
+    static SEARCH: RefCell<String> = RefCell::new(String::new());
+}
+
+const KINDS: [&str; 3] = ["Todo", "Habit", "Reminder"];
+
+pub fn display(
+    ctx: &egui::Context,
+    study_data: &mut StudyData,
+    open: &mut bool,
+    status: &mut StatusMessage,
+) {
+    let mut restore = None;
+    let mut delete = None;
+
+    egui::Window::new("📦 Archive")
+        .open(open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size([520.0, 400.0])
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "{} item(s). Archived items stay out of the active lists but still count in stats.",
+                study_data.archive.len()
+            ));
+
+            let kind = KIND_FILTER.with(|filter| {
+                let mut filter = filter.borrow_mut();
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut *filter, None, "All");
+                    for kind in KINDS {
+                        ui.selectable_value(&mut *filter, Some(kind), format!("{}s", kind));
+                    }
+                    ui.separator();
+                    SEARCH.with(|search| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut *search.borrow_mut())
+                                .hint_text("🔍 Search")
+                                .desired_width(150.0),
+                        );
+                    });
+                });
+                *filter
+            });
+            let query = SEARCH.with(|search| search.borrow().trim().to_lowercase());
+            ui.separator();
+
+            let entries: Vec<_> = study_data
+                .archive
+                .iter()
+                .rev()
+                .filter(|e| kind.is_none_or(|k| e.item.kind() == k))
+                .filter(|e| query.is_empty() || e.item.label().to_lowercase().contains(&query))
+                .collect();
+            if entries.is_empty() {
+                ui.label("Nothing archived here.");
+                return;
+            }
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("archive_entries")
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        // Newest first
+                        for entry in entries {
+                            ui.label(egui::RichText::new(entry.item.kind()).strong());
+                            ui.label(entry.item.label());
+                            ui.label(egui::RichText::new(&entry.archived_at).small());
+                            ui.horizontal(|ui| {
+                                if ui.button("↩ Restore").clicked() {
+                                    restore = Some(entry.id);
+                                }
+                                if ui.button("🗑").on_hover_text("Move to trash").clicked() {
+                                    delete = Some(entry.id);
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+
+    if let Some(id) = restore {
+        match study_data.restore_from_archive(id) {
+            Ok(()) => status.show("Item restored"),
+            Err(e) => status.error(&format!("Could not restore: {}", e)),
+        }
+    }
+    if let Some(id) = delete {
+        if let Err(e) = study_data.delete_from_archive(id) {
+            status.error(&format!("Error saving: {}", e));
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::app::StatusMessage;
+use crate::data::{Assignment, StudyData};
+use chrono::{Local, NaiveDate};
+use eframe::egui;
+use egui::{Color32, RichText, ScrollArea, TextEdit};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    static NEW_COURSE: RefCell<String> = RefCell::new(String::new());
+    static NEW_TITLE: RefCell<String> = RefCell::new(String::new());
+    static NEW_DUE_DATE: RefCell<String> = RefCell::new(String::new());
+    static NEW_WEIGHT: RefCell<String> = RefCell::new(String::from("10"));
+    // Grade text being typed, keyed by assignment id, applied when the field loses focus
+    static GRADE_EDITS: RefCell<HashMap<u64, String>> = RefCell::new(HashMap::new());
+}
+
+pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut StatusMessage) {
+    ui.heading("Assignments");
+
+    NEW_DUE_DATE.with(|date| {
+        let mut date = date.borrow_mut();
+        if date.is_empty() {
+            *date = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        }
+    });
+
+    ui.collapsing("Add New Assignment", |ui| {
+        add_form(ui, study_data, status);
+    });
+    ui.separator();
+
+    let grades = study_data.course_grades();
+    if grades.is_empty() {
+        ui.label("No assignments yet. Add one above to start tracking a course.");
+        return;
+    }
+
+    ui.strong("Course Grades");
+    course_grade_grid(ui, study_data);
+    ui.separator();
+
+    let today = Local::now().date_naive();
+    let mut graded = Vec::new();
+    let mut deleted = None;
+
+    ScrollArea::vertical()
+        .id_source("assignments_scroll")
+        .show(ui, |ui| {
+            for grade in &grades {
+                ui.collapsing(RichText::new(&grade.course).strong(), |ui| {
+                    let mut assignments: Vec<&Assignment> = study_data
+                        .assignments
+                        .iter()
+                        .filter(|a| a.course == grade.course)
+                        .collect();
+                    assignments.sort_by(|a, b| a.due_date.cmp(&b.due_date));
+
+                    egui::Grid::new(("assignments_grid", &grade.course))
+                        .num_columns(5)
+                        .spacing([16.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.strong("Assignment");
+                            ui.strong("Due");
+                            ui.strong("Weight");
+                            ui.strong("Grade %");
+                            ui.label("");
+                            ui.end_row();
+
+                            for assignment in assignments {
+                                ui.label(&assignment.title);
+                                let overdue = assignment.grade.is_none()
+                                    && NaiveDate::parse_from_str(&assignment.due_date, "%Y-%m-%d")
+                                        .map_or(false, |due| due < today);
+                                if overdue {
+                                    ui.colored_label(
+                                        Color32::from_rgb(213, 94, 0),
+                                        format!("{} (overdue)", assignment.due_date),
+                                    );
+                                } else {
+                                    ui.label(&assignment.due_date);
+                                }
+                                ui.label(format!("{:.0}%", assignment.weight));
+
+                                if let Some(grade) = grade_field(ui, assignment) {
+                                    graded.push((assignment.id, grade));
+                                }
+                                if ui.small_button("🗑").on_hover_text("Delete").clicked() {
+                                    deleted = Some(assignment.id);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+            }
+        });
+
+    for (id, grade) in graded {
+        if let Err(e) = study_data.set_assignment_grade(id, grade) {
+            status.error(&format!("Failed to save grade: {}", e));
+        }
+    }
+    if let Some(id) = deleted {
+        match study_data.delete_assignment(id) {
+            Ok(()) => status.show("Assignment moved to trash"),
+            Err(e) => status.error(&format!("Failed to delete assignment: {}", e)),
+        }
+    }
+}
+
+// Shared with the stats tab
+pub fn course_grade_grid(ui: &mut egui::Ui, study_data: &StudyData) {
+    egui::Grid::new("course_grades_grid")
+        .num_columns(3)
+        .spacing([24.0, 4.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Course");
+            ui.strong("Running Grade");
+            ui.strong("Secured");
+            ui.end_row();
+
+            for grade in study_data.course_grades() {
+                ui.label(&grade.course);
+                match grade.running_grade() {
+                    Some(running) => ui.label(format!("{:.1}%", running)),
+                    None => ui.label("-"),
+                };
+                ui.label(format!(
+                    "{:.1} of {:.0} graded ({:.0}% total weight)",
+                    grade.earned, grade.graded_weight, grade.total_weight
+                ))
+                .on_hover_text("Points of the final grade earned so far, out of the weight graded");
+                ui.end_row();
+            }
+        });
+}
+
+// Returns the new grade once the field loses focus; an empty field clears it
+fn grade_field(ui: &mut egui::Ui, assignment: &Assignment) -> Option<Option<f64>> {
+    GRADE_EDITS.with(|edits| {
+        let mut edits = edits.borrow_mut();
+        let text = edits.entry(assignment.id).or_insert_with(|| {
+            assignment
+                .grade
+                .map(|g| format!("{}", g))
+                .unwrap_or_default()
+        });
+        let response = ui.add(
+            TextEdit::singleline(text)
+                .hint_text("-")
+                .desired_width(50.0),
+        );
+        if !response.lost_focus() {
+            return None;
+        }
+
+        let trimmed = text.trim();
+        let grade = if trimmed.is_empty() {
+            None
+        } else {
+            match trimmed.trim_end_matches('%').parse::<f64>() {
+                Ok(grade) if grade >= 0.0 => Some(grade),
+                _ => {
+                    edits.remove(&assignment.id);
+                    return None;
+                }
+            }
+        };
+        edits.remove(&assignment.id);
+        (grade != assignment.grade).then_some(grade)
+    })
+}
+
+fn add_form(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut StatusMessage) {
+    NEW_COURSE.with(|course| {
+        NEW_TITLE.with(|title| {
+            NEW_DUE_DATE.with(|due_date| {
+                NEW_WEIGHT.with(|weight| {
+                    let mut course = course.borrow_mut();
+                    let mut title = title.borrow_mut();
+                    let mut due_date = due_date.borrow_mut();
+                    let mut weight = weight.borrow_mut();
+
+                    ui.horizontal(|ui| {
+                        ui.label("Course:");
+                        ui.add(
+                            TextEdit::singleline(&mut *course)
+                                .hint_text("e.g. Biology 101")
+                                .desired_width(200.0),
+                        );
+                        // Quick picks for courses already in use
+                        let mut courses: Vec<String> = study_data
+                            .assignments
+                            .iter()
+                            .map(|a| a.course.clone())
+                            .collect();
+                        courses.sort();
+                        courses.dedup();
+                        if !courses.is_empty() {
+                            egui::ComboBox::from_id_source("assignment_course_pick")
+                                .selected_text("Existing")
+                                .show_ui(ui, |ui| {
+                                    for name in courses {
+                                        if ui.selectable_label(*course == name, &name).clicked() {
+                                            *course = name;
+                                        }
+                                    }
+                                });
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Title:");
+                        ui.add(
+                            TextEdit::singleline(&mut *title)
+                                .hint_text("e.g. Lab report 2")
+                                .desired_width(280.0),
+                        );
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Due Date:");
+                        ui.add(
+                            TextEdit::singleline(&mut *due_date)
+                                .hint_text("YYYY-MM-DD")
+                                .desired_width(100.0),
+                        );
+                        ui.label("Weight %:");
+                        ui.add(TextEdit::singleline(&mut *weight).desired_width(50.0));
+                    });
+
+                    if ui.button("Add Assignment").clicked() {
+                        let weight_value = weight.trim().trim_end_matches('%').parse::<f64>();
+                        if course.trim().is_empty() || title.trim().is_empty() {
+                            status.warn("Course and title are required");
+                        } else if NaiveDate::parse_from_str(due_date.trim(), "%Y-%m-%d").is_err() {
+                            status.warn("Invalid date format. Use YYYY-MM-DD");
+                        } else if !matches!(weight_value, Ok(w) if w >= 0.0) {
+                            status.warn("Weight must be a number of percent");
+                        } else {
+                            let assignment = Assignment {
+                                id: 0,
+                                course: course.trim().to_string(),
+                                title: title.trim().to_string(),
+                                due_date: due_date.trim().to_string(),
+                                weight: weight_value.unwrap_or(0.0),
+                                grade: None,
+                                reminder_id: None,
+                            };
+                            match study_data.add_assignment(assignment) {
+                                Ok(()) => {
+                                    status.show("Assignment added with a due date reminder");
+                                    title.clear();
+                                }
+                                Err(e) => status.error(&format!("Failed to add assignment: {}", e)),
+                            }
+                        }
+                    }
+                });
+            });
+        });
+    });
+}
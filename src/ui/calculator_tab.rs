@@ -10,6 +10,7 @@ thread_local! {
     static MEMORY: std::cell::RefCell<f64> = std::cell::RefCell::new(0.0);
     static ANGLE_MODE: std::cell::RefCell<AngleMode> = std::cell::RefCell::new(AngleMode::Degrees);
     static LOG_BASE: std::cell::RefCell<f64> = std::cell::RefCell::new(10.0); // Default log base
+    static GRADES_MODE: std::cell::RefCell<bool> = std::cell::RefCell::new(false);
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -45,7 +46,21 @@ pub fn display(ui: &mut Ui, status: &mut StatusMessage) {
 
         ui.add_space(10.0);
 
-        display_calculator(ui, status);
+        let grades_mode = GRADES_MODE.with(|mode| {
+            let mut mode = mode.borrow_mut();
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut *mode, false, "🔢 Standard");
+                ui.selectable_value(&mut *mode, true, "🎓 Grades / GPA");
+            });
+            *mode
+        });
+        ui.add_space(10.0);
+
+        if grades_mode {
+            super::grade_calculator::display(ui, status);
+        } else {
+            display_calculator(ui, status);
+        }
     });
 }
 
@@ -561,7 +576,7 @@ fn calculate_result(status: &mut StatusMessage) {
     });
 
     if error {
-        status.show("Error: Invalid operation");
+        status.warn("Error: Invalid operation");
         DISPLAY.with(|display| {
             *display.borrow_mut() = "Error".to_string();
         });
@@ -606,7 +621,7 @@ where
     });
 
     if error {
-        status.show("Error: Invalid operation");
+        status.warn("Error: Invalid operation");
         DISPLAY.with(|display| {
             *display.borrow_mut() = "Error".to_string();
         });
@@ -0,0 +1,160 @@
+use crate::ui::flashcard::{Card, Deck, Grade, Review};
+use chrono::{Local, NaiveDate};
+use eframe::egui;
+use std::cell::RefCell;
+
+thread_local! {
+    // (deck id, card id) of the card being inspected
+    static OPEN_CARD: RefCell<Option<(u64, u64)>> = RefCell::new(None);
+}
+
+pub fn open(deck_id: u64, card_id: u64) {
+    OPEN_CARD.with(|card| *card.borrow_mut() = Some((deck_id, card_id)));
+}
+
+pub fn display(ctx: &egui::Context, decks: &[Deck]) {
+    let Some((deck_id, card_id)) = OPEN_CARD.with(|card| *card.borrow()) else {
+        return;
+    };
+    let Some(card) = decks
+        .iter()
+        .find(|d| d.id == deck_id)
+        .and_then(|deck| deck.cards.iter().find(|c| c.id == card_id))
+    else {
+        OPEN_CARD.with(|card| *card.borrow_mut() = None);
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new("🕘 Card History")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size([460.0, 320.0])
+        .show(ctx, |ui| {
+            let front: String = card.front.chars().take(80).collect();
+            ui.label(egui::RichText::new(front).strong());
+            ui.label(due_reason(card));
+            ui.small(format!(
+                "Interval {} · Ease {:.2} · {} lapses · Average answer {}",
+                days(card.current_interval),
+                card.current_ease_factor,
+                card.reviews
+                    .iter()
+                    .filter(|r| r.grade == Grade::Again)
+                    .count(),
+                answer_time(card.average_answer_seconds())
+            ));
+            if let Some(schedule) = &card.reverse_schedule {
+                ui.small(format!(
+                    "Reversed: due {} · Interval {} · Ease {:.2}",
+                    schedule.due_date,
+                    days(schedule.current_interval),
+                    schedule.current_ease_factor
+                ));
+            }
+            ui.separator();
+
+            // Both directions in the order they were graded, forward first on the same day
+            let mut reviews: Vec<(&Review, bool)> = card
+                .reviews
+                .iter()
+                .map(|review| (review, false))
+                .chain(
+                    card.reverse_schedule
+                        .iter()
+                        .flat_map(|s| s.reviews.iter().map(|review| (review, true))),
+                )
+                .collect();
+            reviews.sort_by(|a, b| a.0.date.cmp(&b.0.date));
+            if reviews.is_empty() {
+                ui.label("Not reviewed yet.");
+                return;
+            }
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("card_history_reviews")
+                    .num_columns(7)
+                    .striped(true)
+                    .spacing([14.0, 4.0])
+                    .show(ui, |ui| {
+                        for header in ["Date", "", "Grade", "Time", "Interval", "Ease", "Next due"]
+                        {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        ui.end_row();
+
+                        // Newest first
+                        for (review, reversed) in reviews.into_iter().rev() {
+                            ui.label(&review.date);
+                            if reversed {
+                                ui.label("↺").on_hover_text("Reversed (back → front)");
+                            } else {
+                                ui.label("");
+                            }
+                            ui.label(format!("{:?}", review.grade));
+                            ui.label(answer_time(review.answer_seconds));
+                            ui.label(days(review.interval));
+                            ui.label(format!("{:.2}", review.ease_factor));
+                            if review.algorithm_enabled {
+                                ui.label(due_after(review));
+                            } else {
+                                ui.label("Same day")
+                                    .on_hover_text("Graded with spaced repetition off");
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+
+    if !open {
+        OPEN_CARD.with(|card| *card.borrow_mut() = None);
+    }
+}
+
+fn days(interval: u32) -> String {
+    match interval {
+        0 => "—".to_string(),
+        1 => "1 day".to_string(),
+        n => format!("{} days", n),
+    }
+}
+
+fn answer_time(seconds: Option<f32>) -> String {
+    seconds.map_or_else(|| "—".to_string(), |s| format!("{:.1} s", s))
+}
+
+fn due_after(review: &Review) -> String {
+    NaiveDate::parse_from_str(&review.date, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.checked_add_days(chrono::Days::new(review.interval as u64)))
+        .map_or_else(|| "—".to_string(), |d| d.format("%Y-%m-%d").to_string())
+}
+
+// Answers "why is this card due already?" from its last forward review
+fn due_reason(card: &Card) -> String {
+    let Some(last) = card.reviews.last() else {
+        return "New card, never reviewed.".to_string();
+    };
+    if !last.algorithm_enabled {
+        return format!(
+            "Last graded {} with spaced repetition off, so it stays due every day.",
+            last.date
+        );
+    }
+    let today = Local::now().format("%Y-%m-%d").to_string();
+    let due = if card.due_date <= today {
+        "Due now"
+    } else {
+        "Next due"
+    };
+    format!(
+        "{} ({}): graded {:?} on {}, which set a {} interval.",
+        due,
+        card.due_date,
+        last.grade,
+        last.date,
+        days(last.interval)
+    )
+}
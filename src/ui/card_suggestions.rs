@@ -0,0 +1,322 @@
+use crate::app::StatusMessage;
+use crate::card_generator::{self, CardGeneratorConfig, GeneratorBackend, SuggestedCard};
+use crate::data::StudyData;
+use crate::ui::flashcard::Card;
+use eframe::egui;
+use std::cell::RefCell;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+struct Suggestion {
+    accepted: bool,
+    front: String,
+    back: String,
+}
+
+struct SuggestionWindow {
+    source: String,
+    deck_id: Option<u64>,
+    receiver: Option<Receiver<Result<Vec<SuggestedCard>, String>>>,
+    suggestions: Vec<Suggestion>,
+    error: Option<String>,
+}
+
+thread_local! {
+    static WINDOW: RefCell<Option<SuggestionWindow>> = RefCell::new(None);
+    // Loaded once rather than read from disk every frame
+    static CONFIG: RefCell<Option<CardGeneratorConfig>> = RefCell::new(None);
+}
+
+fn with_config<R>(f: impl FnOnce(&mut CardGeneratorConfig) -> R) -> R {
+    CONFIG.with(|config| {
+        f(config
+            .borrow_mut()
+            .get_or_insert_with(|| CardGeneratorConfig::load().unwrap_or_default()))
+    })
+}
+
+// Entry points only show up once a backend is set up in Settings
+pub fn is_enabled() -> bool {
+    with_config(|config| config.enabled)
+}
+
+pub fn open_with_text(text: String, deck_id: Option<u64>) {
+    WINDOW.with(|window| {
+        *window.borrow_mut() = Some(SuggestionWindow {
+            source: text,
+            deck_id,
+            receiver: None,
+            suggestions: Vec::new(),
+            error: None,
+        });
+    });
+}
+
+pub fn display(ctx: &egui::Context, study_data: &mut StudyData, status: &mut StatusMessage) {
+    WINDOW.with(|window| {
+        let mut window = window.borrow_mut();
+        let Some(state) = window.as_mut() else {
+            return;
+        };
+        poll(state);
+        if state.receiver.is_some() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(250));
+        }
+
+        let mut open = true;
+        let mut add = false;
+        egui::Window::new("✨ Suggest Cards")
+            .open(&mut open)
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                ui.label("Text to make cards from:");
+                egui::ScrollArea::vertical()
+                    .id_source("suggest_source")
+                    .max_height(140.0)
+                    .show(ui, |ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(&mut state.source)
+                                .hint_text("Paste notes here")
+                                .desired_width(f32::INFINITY)
+                                .desired_rows(5),
+                        );
+                    });
+
+                ui.horizontal(|ui| {
+                    let running = state.receiver.is_some();
+                    if ui
+                        .add_enabled(
+                            !running && !state.source.trim().is_empty(),
+                            egui::Button::new("✨ Suggest"),
+                        )
+                        .clicked()
+                    {
+                        start(state);
+                    }
+                    if running {
+                        ui.spinner();
+                        ui.label("Waiting for the model...");
+                    }
+                });
+                if let Some(error) = &state.error {
+                    ui.colored_label(egui::Color32::from_rgb(213, 94, 0), error);
+                }
+
+                if state.suggestions.is_empty() {
+                    return;
+                }
+                ui.separator();
+                ui.label("Untick or edit suggestions before adding them:");
+                egui::ScrollArea::vertical()
+                    .id_source("suggest_list")
+                    .max_height(300.0)
+                    .show(ui, |ui| {
+                        for (index, suggestion) in state.suggestions.iter_mut().enumerate() {
+                            ui.push_id(index, |ui| {
+                                ui.horizontal(|ui| {
+                                    ui.checkbox(&mut suggestion.accepted, "");
+                                    ui.vertical(|ui| {
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut suggestion.front)
+                                                .desired_width(f32::INFINITY),
+                                        );
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut suggestion.back)
+                                                .desired_width(f32::INFINITY),
+                                        );
+                                    });
+                                });
+                            });
+                            ui.add_space(4.0);
+                        }
+                    });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let selected = state
+                        .deck_id
+                        .and_then(|id| study_data.decks.iter().find(|d| d.id == id))
+                        .map_or("Choose a deck", |d| d.name.as_str());
+                    egui::ComboBox::from_id_source("suggest_deck")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            for deck in &study_data.decks {
+                                ui.selectable_value(&mut state.deck_id, Some(deck.id), &deck.name);
+                            }
+                        });
+
+                    let count = state.suggestions.iter().filter(|s| s.accepted).count();
+                    if ui
+                        .add_enabled(
+                            count > 0 && state.deck_id.is_some(),
+                            egui::Button::new(format!("➕ Add {} Cards", count)),
+                        )
+                        .clicked()
+                    {
+                        add = true;
+                    }
+                });
+            });
+
+        if add {
+            let accepted: Vec<&Suggestion> = state
+                .suggestions
+                .iter()
+                .filter(|s| s.accepted && !s.front.trim().is_empty())
+                .collect();
+            if let Some(deck) = state
+                .deck_id
+                .and_then(|id| study_data.decks.iter_mut().find(|d| d.id == id))
+            {
+                for suggestion in &accepted {
+                    deck.add_card(Card::new(
+                        deck.id,
+                        suggestion.front.trim().to_string(),
+                        suggestion.back.trim().to_string(),
+                    ));
+                }
+                let message = format!("Added {} cards to {}", accepted.len(), deck.name);
+                match study_data.save() {
+                    Ok(()) => status.show(&message),
+                    Err(e) => status.error(&format!("Error saving: {}", e)),
+                }
+                open = false;
+            }
+        }
+        if !open {
+            *window = None;
+        }
+    });
+}
+
+fn start(state: &mut SuggestionWindow) {
+    let config = with_config(|config| config.clone());
+    let text = state.source.clone();
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let result = card_generator::suggest_cards(&config, &text).map_err(|e| e.to_string());
+        let _ = sender.send(result);
+    });
+    state.receiver = Some(receiver);
+    state.error = None;
+}
+
+fn poll(state: &mut SuggestionWindow) {
+    let result = match &state.receiver {
+        Some(receiver) => match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => Err("card generation stopped".to_string()),
+        },
+        None => return,
+    };
+    state.receiver = None;
+
+    match result {
+        Ok(cards) => {
+            state.suggestions = cards
+                .into_iter()
+                .map(|card| Suggestion {
+                    accepted: true,
+                    front: card.front,
+                    back: card.back,
+                })
+                .collect();
+        }
+        Err(e) => state.error = Some(e),
+    }
+}
+
+pub fn settings_section(ui: &mut egui::Ui, status: &mut StatusMessage) {
+    with_config(|config| {
+        let mut changed = ui
+            .checkbox(
+                &mut config.enabled,
+                "Suggest flashcards from notes with a language model",
+            )
+            .changed();
+        ui.label(
+            egui::RichText::new(
+                "Notes are only sent when you press Suggest. Use a local model to keep them on this computer.",
+            )
+            .small(),
+        );
+        if !config.enabled {
+            if changed {
+                save(config, status);
+            }
+            return;
+        }
+        ui.add_space(5.0);
+
+        ui.horizontal(|ui| {
+            ui.label("Backend:");
+            egui::ComboBox::from_id_source("card_generator_backend")
+                .selected_text(config.backend.name())
+                .show_ui(ui, |ui| {
+                    for backend in GeneratorBackend::all() {
+                        let name = backend.name();
+                        changed |= ui
+                            .selectable_value(&mut config.backend, backend, name)
+                            .changed();
+                    }
+                });
+        });
+
+        egui::Grid::new("card_generator_grid")
+            .num_columns(2)
+            .spacing([10.0, 6.0])
+            .show(ui, |ui| {
+                if config.backend == GeneratorBackend::Command {
+                    ui.label("Command:");
+                    changed |= ui
+                        .add(
+                            egui::TextEdit::singleline(&mut config.command)
+                                .hint_text("llm -m mistral")
+                                .desired_width(260.0),
+                        )
+                        .lost_focus();
+                    ui.end_row();
+                } else {
+                    ui.label("Endpoint:");
+                    changed |= ui
+                        .add(egui::TextEdit::singleline(&mut config.endpoint).desired_width(260.0))
+                        .lost_focus();
+                    ui.end_row();
+
+                    ui.label("Model:");
+                    changed |= ui
+                        .add(egui::TextEdit::singleline(&mut config.model).desired_width(160.0))
+                        .lost_focus();
+                    ui.end_row();
+                }
+
+                if config.backend == GeneratorBackend::OpenAiCompatible {
+                    ui.label("API key:");
+                    changed |= ui
+                        .add(
+                            egui::TextEdit::singleline(&mut config.api_key)
+                                .password(true)
+                                .desired_width(260.0),
+                        )
+                        .lost_focus();
+                    ui.end_row();
+                }
+
+                ui.label("Cards per request:");
+                changed |= ui
+                    .add(egui::DragValue::new(&mut config.max_cards).clamp_range(1..=50))
+                    .changed();
+                ui.end_row();
+            });
+
+        if changed {
+            save(config, status);
+        }
+    });
+}
+
+fn save(config: &CardGeneratorConfig, status: &mut StatusMessage) {
+    if let Err(e) = config.save() {
+        status.error(&format!("Failed to save card generation settings: {}", e));
+    }
+}
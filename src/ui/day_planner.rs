@@ -0,0 +1,265 @@
+use crate::app::StatusMessage;
+use crate::data::{StudyData, TimeBlock};
+use crate::settings::ColorTheme;
+use crate::timer::Timer;
+use crate::ui::timer_tab;
+use chrono::{Duration, Local, NaiveDate, Timelike};
+use eframe::egui;
+use std::cell::RefCell;
+
+struct DayPlannerState {
+    date: NaiveDate,
+    new_block: String,
+}
+
+thread_local! {
+    static DAY_STATE: RefCell<DayPlannerState> = RefCell::new(DayPlannerState {
+        date: Local::now().date_naive(),
+        new_block: String::new(),
+    });
+}
+
+// Returns true when a block started the timer
+pub fn display(
+    ui: &mut egui::Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    colors: &ColorTheme,
+    timer: &mut Timer,
+) -> bool {
+    let mut started = false;
+    let mut delete = None;
+
+    DAY_STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let today = Local::now().date_naive();
+
+        ui.horizontal(|ui| {
+            if ui.button("◀").clicked() {
+                state.date -= Duration::days(1);
+            }
+            ui.strong(state.date.format("%A, %b %d").to_string());
+            if ui.button("▶").clicked() {
+                state.date += Duration::days(1);
+            }
+            if state.date != today && ui.button("Today").clicked() {
+                state.date = today;
+            }
+        });
+        let date = state.date.format("%Y-%m-%d").to_string();
+
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut state.new_block)
+                    .hint_text("9:00-10:30 Linear Algebra")
+                    .desired_width(260.0),
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if (ui.button("Add Block").clicked() || submitted) && !state.new_block.trim().is_empty()
+            {
+                match TimeBlock::parse(&date, &state.new_block) {
+                    Some(block) => match study_data.add_time_block(block) {
+                        Ok(()) => state.new_block.clear(),
+                        Err(e) => status.error(&format!("Error saving: {}", e)),
+                    },
+                    None => status.warn("Write blocks like \"9:00-10:30 Linear Algebra\""),
+                }
+            }
+        });
+        ui.add_space(10.0);
+
+        let mut blocks: Vec<&TimeBlock> = study_data
+            .time_blocks
+            .iter()
+            .filter(|b| b.date == date)
+            .collect();
+        blocks.sort_by(|a, b| {
+            a.start_minute()
+                .unwrap_or(0.0)
+                .total_cmp(&b.start_minute().unwrap_or(0.0))
+        });
+
+        render_day_lanes(ui, study_data, &blocks, &date, colors);
+        ui.add_space(10.0);
+
+        if blocks.is_empty() {
+            ui.label(
+                egui::RichText::new("No blocks planned for this day.")
+                    .color(colors.text_secondary_color32()),
+            );
+            return;
+        }
+
+        let now = Local::now();
+        let now_minute = now.hour() as f64 * 60.0 + now.minute() as f64;
+        egui::Grid::new("time_blocks_grid")
+            .num_columns(4)
+            .spacing([16.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for block in &blocks {
+                    let planned = block.planned_minutes();
+                    let finished = state.date < today
+                        || (state.date == today
+                            && block.end_minute().map_or(false, |end| end <= now_minute));
+
+                    ui.label(format!("{}–{}", block.start, block.end));
+                    ui.label(egui::RichText::new(&block.subject).strong());
+
+                    if finished {
+                        let logged = study_data.logged_minutes_in_block(block);
+                        let ratio = logged / planned.max(1.0);
+                        let color = if ratio >= 0.8 {
+                            egui::Color32::from_rgb(0, 158, 115)
+                        } else if ratio >= 0.4 {
+                            egui::Color32::from_rgb(230, 159, 0)
+                        } else {
+                            egui::Color32::from_rgb(213, 94, 0)
+                        };
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{:.0} of {:.0} min logged ({:.0}%)",
+                                logged,
+                                planned,
+                                ratio * 100.0
+                            ))
+                            .color(color),
+                        );
+                    } else {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("{:.0} min planned", planned));
+                            let idle = !timer.is_running && timer.get_elapsed_minutes() < 0.01;
+                            if state.date == today
+                                && ui
+                                    .add_enabled(idle, egui::Button::new("▶ Start"))
+                                    .on_hover_text("Start the timer with this subject and length")
+                                    .on_disabled_hover_text("Save or stop the running timer first")
+                                    .clicked()
+                            {
+                                timer_tab::start_planned(timer, &block.subject, planned);
+                                status.show(&format!("Started {}", block.subject));
+                                started = true;
+                            }
+                        });
+                    }
+
+                    if ui.small_button("✖").on_hover_text("Remove block").clicked() {
+                        delete = Some(block.id);
+                    }
+                    ui.end_row();
+                }
+            });
+    });
+
+    if let Some(id) = delete {
+        if let Err(e) = study_data.delete_time_block(id) {
+            status.error(&format!("Error saving: {}", e));
+        }
+    }
+    started
+}
+
+// Planned blocks above the study actually logged that day, on a shared hour axis
+fn render_day_lanes(
+    ui: &mut egui::Ui,
+    study_data: &StudyData,
+    blocks: &[&TimeBlock],
+    date: &str,
+    colors: &ColorTheme,
+) {
+    let spans: Vec<(f64, f64)> = study_data
+        .sessions
+        .iter()
+        .filter(|s| s.date == date)
+        .flat_map(|s| &s.spans)
+        .filter_map(|span| Some((span.start_minute()?, span.minutes)))
+        .collect();
+
+    // Show 6:00 to 22:00 unless something falls outside it
+    let earliest = blocks
+        .iter()
+        .filter_map(|b| b.start_minute())
+        .chain(spans.iter().map(|s| s.0))
+        .fold(6.0 * 60.0, f64::min);
+    let latest = blocks
+        .iter()
+        .filter_map(|b| b.end_minute())
+        .chain(spans.iter().map(|s| s.0 + s.1))
+        .fold(22.0 * 60.0, f64::max);
+    let first_hour = (earliest / 60.0).floor();
+    let last_hour = (latest / 60.0).ceil().min(24.0);
+
+    let label_width = 70.0;
+    let lane_height = 24.0;
+    let size = egui::vec2(ui.available_width() * 0.95, 20.0 + lane_height * 2.0 + 8.0);
+    let (rect, _) = ui.allocate_at_least(size, egui::Sense::hover());
+    let painter = ui.painter();
+    painter.rect_filled(rect, 4.0, egui::Color32::from_rgb(27, 27, 27));
+
+    let axis_left = rect.left() + label_width;
+    let axis_width = rect.right() - 15.0 - axis_left;
+    let span_minutes = (last_hour - first_hour) * 60.0;
+    let x_for =
+        |minute: f64| axis_left + ((minute - first_hour * 60.0) / span_minutes) as f32 * axis_width;
+    let lanes_top = rect.top() + 20.0;
+    let text_color = egui::Color32::from_rgb(220, 220, 220);
+
+    for hour in first_hour as u32..=last_hour as u32 {
+        let x = x_for(hour as f64 * 60.0);
+        painter.line_segment(
+            [
+                egui::pos2(x, lanes_top),
+                egui::pos2(x, lanes_top + lane_height * 2.0),
+            ],
+            egui::Stroke::new(0.5, egui::Color32::from_rgb(70, 70, 70)),
+        );
+        painter.text(
+            egui::pos2(x, rect.top() + 4.0),
+            egui::Align2::CENTER_TOP,
+            format!("{:02}", hour),
+            egui::FontId::proportional(11.0),
+            text_color,
+        );
+    }
+
+    for (lane, label) in ["Planned", "Logged"].iter().enumerate() {
+        painter.text(
+            egui::pos2(
+                rect.left() + 8.0,
+                lanes_top + lane_height * (lane as f32 + 0.5),
+            ),
+            egui::Align2::LEFT_CENTER,
+            label,
+            egui::FontId::default(),
+            text_color,
+        );
+    }
+
+    for block in blocks {
+        if let (Some(start), Some(end)) = (block.start_minute(), block.end_minute()) {
+            let bar = egui::Rect::from_min_max(
+                egui::pos2(x_for(start), lanes_top + 4.0),
+                egui::pos2(x_for(end), lanes_top + lane_height - 4.0),
+            );
+            painter.rect_filled(bar, 2.0, colors.accent_color32());
+            if ui.rect_contains_pointer(bar) {
+                egui::show_tooltip_text(
+                    ui.ctx(),
+                    egui::Id::new("day_planner_tip"),
+                    format!("{}–{} {}", block.start, block.end, block.subject),
+                );
+            }
+        }
+    }
+
+    for (start, minutes) in spans {
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(x_for(start), lanes_top + lane_height + 4.0),
+            egui::pos2(
+                x_for(start + minutes).max(x_for(start) + 2.0),
+                lanes_top + lane_height * 2.0 - 4.0,
+            ),
+        );
+        painter.rect_filled(bar, 2.0, egui::Color32::from_rgb(66, 133, 244));
+    }
+}
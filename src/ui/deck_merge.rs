@@ -0,0 +1,264 @@
+use crate::data::TrashedItem;
+use crate::ui::duplicate_finder::{fold_into, normalize};
+use crate::ui::flashcard::{Card, Deck};
+use eframe::egui;
+use std::collections::HashMap;
+
+// What happens to a card whose front is already in the other deck
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuplicatePolicy {
+    Fold, // Tags, images and a differing answer go into the existing card
+    Skip, // The extra card goes to the trash untouched
+    KeepBoth,
+}
+
+impl DuplicatePolicy {
+    pub const ALL: [DuplicatePolicy; 3] = [
+        DuplicatePolicy::Fold,
+        DuplicatePolicy::Skip,
+        DuplicatePolicy::KeepBoth,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DuplicatePolicy::Fold => "Merge into the existing card",
+            DuplicatePolicy::Skip => "Move the extra card to the trash",
+            DuplicatePolicy::KeepBoth => "Keep both cards",
+        }
+    }
+}
+
+pub struct DeckMerge {
+    pub first: Option<u64>,
+    pub second: Option<u64>,
+    pub keep_second_details: bool, // Name, description and review settings
+    pub policy: DuplicatePolicy,
+}
+
+pub enum MergeAction {
+    None,
+    Merged {
+        kept: u64,
+        removed: u64,
+        message: String,
+    },
+    Close,
+}
+
+impl DeckMerge {
+    pub fn new() -> Self {
+        Self {
+            first: None,
+            second: None,
+            keep_second_details: false,
+            policy: DuplicatePolicy::Fold,
+        }
+    }
+}
+
+// Fronts of the second deck already in the first, as (second deck card index, first deck
+// card id). The second deck's cards go by index since its ids are about to be reassigned.
+fn duplicate_fronts(first: &Deck, second: &Deck) -> Vec<(usize, u64)> {
+    let fronts: HashMap<String, u64> = first
+        .cards
+        .iter()
+        .map(|c| (normalize(&c.front), c.id))
+        .filter(|(front, _)| !front.is_empty())
+        .collect();
+    second
+        .cards
+        .iter()
+        .enumerate()
+        .filter_map(|(index, c)| fronts.get(&normalize(&c.front)).map(|id| (index, *id)))
+        .collect()
+}
+
+// Everything ends up in the first deck; the second one is removed once empty
+fn merge(
+    decks: &mut Vec<Deck>,
+    first_id: u64,
+    second_id: u64,
+    keep_second_details: bool,
+    policy: DuplicatePolicy,
+    trash: &mut Vec<TrashedItem>,
+) -> Option<(usize, usize)> {
+    let second_index = decks.iter().position(|d| d.id == second_id)?;
+    if first_id == second_id || !decks.iter().any(|d| d.id == first_id) {
+        return None;
+    }
+    let second = decks.remove(second_index);
+    let first = decks.iter_mut().find(|d| d.id == first_id)?;
+
+    let duplicates: HashMap<usize, u64> = duplicate_fronts(first, &second).into_iter().collect();
+    if keep_second_details {
+        first.name = second.name.clone();
+        first.description = second.description.clone();
+        first.reverse_cards = second.reverse_cards;
+        first.settings = second.settings.clone();
+    }
+    for preset in &second.review_presets {
+        if keep_second_details {
+            first.review_presets.retain(|p| p.name != preset.name);
+        }
+        if !first.review_presets.iter().any(|p| p.name == preset.name) {
+            first.review_presets.push(preset.clone());
+        }
+    }
+
+    let mut added = 0;
+    let mut resolved = 0;
+    // The second deck is gone, so trashed cards restore into the merged one
+    let trash_card = |mut card: Card, first: &Deck| {
+        card.deck_id = first.id;
+        TrashedItem::Card {
+            deck_id: first.id,
+            deck_name: first.name.clone(),
+            card,
+        }
+    };
+    for (index, card) in second.cards.into_iter().enumerate() {
+        let existing = duplicates.get(&index).copied();
+        match (existing, policy) {
+            (Some(existing), DuplicatePolicy::Fold) => {
+                if let Some(target) = first.cards.iter_mut().find(|c| c.id == existing) {
+                    fold_into(target, &card);
+                }
+                resolved += 1;
+                trash.push(trash_card(card, first));
+            }
+            (Some(_), DuplicatePolicy::Skip) => {
+                resolved += 1;
+                trash.push(trash_card(card, first));
+            }
+            _ => {
+                first.add_card(card);
+                added += 1;
+            }
+        }
+    }
+    first.touch();
+    Some((added, resolved))
+}
+
+pub fn display(
+    ctx: &egui::Context,
+    merge_state: &mut DeckMerge,
+    decks: &mut Vec<Deck>,
+    trash: &mut Vec<TrashedItem>,
+) -> MergeAction {
+    let mut action = MergeAction::None;
+    let mut open = true;
+    let mut confirmed = false;
+
+    egui::Window::new("🔗 Merge Decks")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            let deck_name = |id: Option<u64>| {
+                id.and_then(|id| decks.iter().find(|d| d.id == id))
+                    .map_or("Choose a deck".to_string(), |d| d.name.clone())
+            };
+
+            egui::Grid::new("merge_decks_grid")
+                .num_columns(2)
+                .spacing([10.0, 6.0])
+                .show(ui, |ui| {
+                    let (first, second) = (merge_state.first, merge_state.second);
+                    for (label, slot, other) in [
+                        ("Merge into", &mut merge_state.first, second),
+                        ("Cards from", &mut merge_state.second, first),
+                    ] {
+                        ui.label(label);
+                        egui::ComboBox::from_id_source(label)
+                            .selected_text(deck_name(*slot))
+                            .show_ui(ui, |ui| {
+                                for deck in decks.iter().filter(|d| Some(d.id) != other) {
+                                    ui.selectable_value(slot, Some(deck.id), &deck.name);
+                                }
+                            });
+                        ui.end_row();
+                    }
+                });
+
+            let (Some(first), Some(second)) = (
+                merge_state
+                    .first
+                    .and_then(|id| decks.iter().find(|d| d.id == id)),
+                merge_state
+                    .second
+                    .and_then(|id| decks.iter().find(|d| d.id == id)),
+            ) else {
+                ui.label("Pick two decks to combine.");
+                return;
+            };
+
+            ui.separator();
+            ui.label("Keep the name, description and review settings of:");
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut merge_state.keep_second_details, false, &first.name);
+                ui.radio_value(&mut merge_state.keep_second_details, true, &second.name);
+            });
+
+            let duplicates = duplicate_fronts(first, second);
+            ui.separator();
+            if duplicates.is_empty() {
+                ui.label("No card fronts appear in both decks.");
+            } else {
+                ui.label(format!(
+                    "{} card(s) in {} have a front already in {}:",
+                    duplicates.len(),
+                    second.name,
+                    first.name
+                ));
+                egui::ScrollArea::vertical()
+                    .max_height(120.0)
+                    .show(ui, |ui| {
+                        for (index, _) in &duplicates {
+                            ui.small(format!("• {}", second.cards[*index].front));
+                        }
+                    });
+                for policy in DuplicatePolicy::ALL {
+                    ui.radio_value(&mut merge_state.policy, policy, policy.label());
+                }
+            }
+
+            ui.separator();
+            ui.label(
+                egui::RichText::new(format!(
+                    "{} will be removed after its {} card(s) move over.",
+                    second.name,
+                    second.cards.len()
+                ))
+                .small(),
+            );
+            confirmed = ui.button("🔗 Merge").clicked();
+        });
+
+    if confirmed {
+        if let (Some(first), Some(second)) = (merge_state.first, merge_state.second) {
+            let result = merge(
+                decks,
+                first,
+                second,
+                merge_state.keep_second_details,
+                merge_state.policy,
+                trash,
+            );
+            if let Some((added, resolved)) = result {
+                let mut message = format!("Merged decks: {} card(s) moved", added);
+                if resolved > 0 {
+                    message.push_str(&format!(", {} duplicate(s) resolved", resolved));
+                }
+                action = MergeAction::Merged {
+                    kept: first,
+                    removed: second,
+                    message,
+                };
+            }
+        }
+    } else if !open {
+        action = MergeAction::Close;
+    }
+    action
+}
@@ -0,0 +1,205 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use crate::deck_sync::{DeckSync, DeckSyncStatus};
+use eframe::egui;
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct DeckSyncForm {
+    join_code: String,
+    share_deck: Option<u64>,
+    share_code: String,
+    share_token: String,
+}
+
+thread_local! {
+    static FORM: RefCell<DeckSyncForm> = RefCell::new(DeckSyncForm::default());
+}
+
+pub fn display(
+    ctx: &egui::Context,
+    deck_sync: &mut DeckSync,
+    study_data: &mut StudyData,
+    open: &mut bool,
+    status: &mut StatusMessage,
+) {
+    let mut config_changed = false;
+    let mut publish = None;
+    let mut remove = None;
+
+    egui::Window::new("👥 Shared Decks")
+        .open(open)
+        .resizable(true)
+        .default_width(480.0)
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(
+                    "Subscribe to a deck your study group maintains. Updates are merged into your copy; your reviews and schedule stay on this computer.",
+                )
+                .small(),
+            );
+            ui.add_space(8.0);
+
+            ui.horizontal(|ui| {
+                ui.label("Server:");
+                config_changed |= ui
+                    .add(
+                        egui::TextEdit::singleline(&mut deck_sync.config.server_url)
+                            .hint_text("https://decks.example.org")
+                            .desired_width(280.0),
+                    )
+                    .lost_focus();
+            });
+            config_changed |= ui
+                .checkbox(
+                    &mut deck_sync.config.auto_pull,
+                    "Check for updates every 30 minutes",
+                )
+                .changed();
+
+            ui.horizontal(|ui| {
+                let color = match deck_sync.status {
+                    DeckSyncStatus::Failed(_) => egui::Color32::from_rgb(213, 94, 0),
+                    _ => ui.visuals().text_color(),
+                };
+                ui.label(egui::RichText::new(deck_sync.status_text()).color(color));
+                if ui
+                    .add_enabled(!deck_sync.is_syncing(), egui::Button::new("⟳ Pull Now"))
+                    .clicked()
+                {
+                    deck_sync.pull();
+                }
+            });
+            ui.separator();
+
+            if deck_sync.config.subscriptions.is_empty() {
+                ui.label("No shared decks yet.");
+            } else {
+                egui::Grid::new("deck_sync_grid")
+                    .num_columns(5)
+                    .spacing([12.0, 6.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for subscription in &deck_sync.config.subscriptions {
+                            ui.strong(&subscription.code);
+                            let deck_name = subscription
+                                .deck_id
+                                .and_then(|id| study_data.decks.iter().find(|d| d.id == id))
+                                .map_or("not downloaded yet", |d| d.name.as_str());
+                            ui.label(deck_name);
+                            ui.label(if subscription.is_maintainer() {
+                                "Maintainer"
+                            } else {
+                                "Member"
+                            });
+                            ui.label(match &subscription.last_sync {
+                                Some(last) => format!("v{} · {}", subscription.version, last),
+                                None => "never synced".to_string(),
+                            });
+                            ui.horizontal(|ui| {
+                                if subscription.is_maintainer()
+                                    && ui
+                                        .add_enabled(
+                                            !deck_sync.is_syncing(),
+                                            egui::Button::new("⬆ Publish"),
+                                        )
+                                        .clicked()
+                                {
+                                    publish = Some(subscription.code.clone());
+                                }
+                                if ui
+                                    .small_button("✖")
+                                    .on_hover_text("Stop syncing; the local deck is kept")
+                                    .clicked()
+                                {
+                                    remove = Some(subscription.code.clone());
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            }
+            ui.separator();
+
+            FORM.with(|form| {
+                let mut form = form.borrow_mut();
+
+                ui.strong("Join a shared deck");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut form.join_code)
+                            .hint_text("Deck code from your group")
+                            .desired_width(200.0),
+                    );
+                    if ui.button("Join").clicked() && !form.join_code.trim().is_empty() {
+                        deck_sync.config.subscribe(&form.join_code, "", None);
+                        form.join_code.clear();
+                        config_changed = true;
+                        deck_sync.pull();
+                    }
+                });
+                ui.add_space(6.0);
+
+                ui.strong("Share one of your decks");
+                ui.horizontal(|ui| {
+                    let selected = form
+                        .share_deck
+                        .and_then(|id| study_data.decks.iter().find(|d| d.id == id))
+                        .map_or("Choose a deck", |d| d.name.as_str());
+                    egui::ComboBox::from_id_source("share_deck")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            for deck in &study_data.decks {
+                                ui.selectable_value(
+                                    &mut form.share_deck,
+                                    Some(deck.id),
+                                    &deck.name,
+                                );
+                            }
+                        });
+                    ui.add(
+                        egui::TextEdit::singleline(&mut form.share_code)
+                            .hint_text("Deck code")
+                            .desired_width(120.0),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut form.share_token)
+                            .hint_text("Maintainer token from the server")
+                            .password(true)
+                            .desired_width(220.0),
+                    );
+                    let ready = form.share_deck.is_some()
+                        && !form.share_code.trim().is_empty()
+                        && !form.share_token.trim().is_empty();
+                    if ui
+                        .add_enabled(ready, egui::Button::new("Share"))
+                        .clicked()
+                    {
+                        deck_sync
+                            .config
+                            .subscribe(&form.share_code, &form.share_token, form.share_deck);
+                        publish = Some(form.share_code.trim().to_string());
+                        *form = DeckSyncForm::default();
+                        config_changed = true;
+                    }
+                });
+            });
+        });
+
+    if let Some(code) = remove {
+        deck_sync.config.subscriptions.retain(|s| s.code != code);
+        config_changed = true;
+    }
+    if config_changed {
+        if let Err(e) = deck_sync.config.save() {
+            status.error(&format!("Could not save shared deck settings: {}", e));
+        }
+    }
+    if let Some(code) = publish {
+        if let Err(e) = deck_sync.publish(study_data, &code) {
+            status.warn(&e);
+        }
+    }
+}
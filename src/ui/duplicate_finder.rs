@@ -0,0 +1,298 @@
+use crate::data::TrashedItem;
+use crate::ui::flashcard::{Card, Deck};
+use eframe::egui;
+
+// Fronts at least this similar after normalizing count as near-duplicates
+const SIMILARITY_THRESHOLD: f32 = 0.9;
+
+pub struct DuplicateCard {
+    pub deck_id: u64,
+    pub card_id: u64,
+    pub deck_name: String,
+    pub front: String,
+    pub back: String,
+    pub reviews: usize,
+}
+
+pub struct DuplicateGroup {
+    pub cards: Vec<DuplicateCard>,
+    pub exact: bool, // Every front normalizes to the same text
+    pub keep: usize, // Index into cards of the one that survives a merge or delete
+}
+
+pub struct DuplicateFinder {
+    pub groups: Vec<DuplicateGroup>,
+    scanned_cards: usize,
+}
+
+pub enum DuplicateAction {
+    None,
+    Changed(String),
+    Close,
+}
+
+impl DuplicateFinder {
+    pub fn scan(decks: &[Deck]) -> Self {
+        // (normalized front of the first card, cards in the group)
+        let mut groups: Vec<(String, Vec<DuplicateCard>, bool)> = Vec::new();
+        let mut scanned_cards = 0;
+
+        for deck in decks {
+            for card in &deck.cards {
+                scanned_cards += 1;
+                let normalized = normalize(&card.front);
+                if normalized.is_empty() {
+                    continue;
+                }
+
+                let entry = DuplicateCard {
+                    deck_id: deck.id,
+                    card_id: card.id,
+                    deck_name: deck.name.clone(),
+                    front: card.front.clone(),
+                    back: card.back.clone(),
+                    reviews: card.reviews.len(),
+                };
+
+                let existing = groups.iter_mut().find(|(key, _, _)| {
+                    *key == normalized || similarity(key, &normalized) >= SIMILARITY_THRESHOLD
+                });
+                match existing {
+                    Some((key, cards, exact)) => {
+                        *exact &= *key == normalized;
+                        cards.push(entry);
+                    }
+                    None => groups.push((normalized, vec![entry], true)),
+                }
+            }
+        }
+
+        let groups = groups
+            .into_iter()
+            .filter(|(_, cards, _)| cards.len() > 1)
+            .map(|(_, cards, exact)| {
+                // Default to keeping the card with the most review history
+                let keep = cards
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(_, c)| c.reviews)
+                    .map_or(0, |(i, _)| i);
+                DuplicateGroup { cards, exact, keep }
+            })
+            .collect();
+
+        Self {
+            groups,
+            scanned_cards,
+        }
+    }
+}
+
+// Lowercase, drop punctuation and collapse whitespace so "What is DNA?" matches "what is dna"
+pub fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// 1.0 for equal strings, falling towards 0.0 with edit distance
+fn similarity(a: &str, b: &str) -> f32 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    // Too different in length to pass the threshold, skip the distance
+    let length_gap = a.len().abs_diff(b.len()) as f32 / longest as f32;
+    if length_gap > 1.0 - SIMILARITY_THRESHOLD {
+        return 0.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    1.0 - previous[b.len()] as f32 / longest as f32
+}
+
+// Gives the kept card the other's tags, any image it lacks and a differing answer
+pub fn fold_into(target: &mut Card, card: &Card) {
+    target.tags.extend(card.tags.iter().cloned());
+    if target.front_image.is_none() {
+        target.front_image = card.front_image.clone();
+    }
+    if target.back_image.is_none() {
+        target.back_image = card.back_image.clone();
+    }
+    if !card.back.trim().is_empty()
+        && normalize(&card.back) != normalize(&target.back)
+        && !target.back.contains(card.back.trim())
+    {
+        target.back = format!("{}\n{}", target.back.trim_end(), card.back.trim());
+    }
+}
+
+fn take_card(decks: &mut [Deck], deck_id: u64, card_id: u64) -> Option<Card> {
+    let deck = decks.iter_mut().find(|d| d.id == deck_id)?;
+    let index = deck.cards.iter().position(|c| c.id == card_id)?;
+    Some(deck.cards.remove(index))
+}
+
+// Moves the other cards of a group to the trash; when merging, their tags, missing
+// images and differing answers are folded into the kept card first
+fn resolve_group(
+    decks: &mut [Deck],
+    group: &DuplicateGroup,
+    merge: bool,
+    trash: &mut Vec<TrashedItem>,
+) -> usize {
+    let kept = &group.cards[group.keep];
+    let mut removed = Vec::new();
+    for (i, other) in group.cards.iter().enumerate() {
+        if i != group.keep {
+            if let Some(card) = take_card(decks, other.deck_id, other.card_id) {
+                removed.push((other.deck_id, other.deck_name.clone(), card));
+            }
+        }
+    }
+
+    if merge {
+        let target = decks
+            .iter_mut()
+            .find(|d| d.id == kept.deck_id)
+            .and_then(|d| d.cards.iter_mut().find(|c| c.id == kept.card_id));
+        if let Some(target) = target {
+            for (_, _, card) in &removed {
+                fold_into(target, card);
+            }
+        }
+    }
+
+    let count = removed.len();
+    for (deck_id, deck_name, card) in removed {
+        trash.push(TrashedItem::Card {
+            deck_id,
+            deck_name,
+            card,
+        });
+    }
+    count
+}
+
+pub fn display(
+    ctx: &egui::Context,
+    finder: &mut DuplicateFinder,
+    decks: &mut [Deck],
+    trash: &mut Vec<TrashedItem>,
+) -> DuplicateAction {
+    let mut action = DuplicateAction::None;
+    let mut open = true;
+    let mut resolved: Option<(usize, bool)> = None;
+    let mut skipped = None;
+
+    egui::Window::new("🔍 Find Duplicates")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size([520.0, 450.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{} group(s) of duplicate fronts in {} cards",
+                    finder.groups.len(),
+                    finder.scanned_cards
+                ));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button("⟳ Rescan").clicked() {
+                        *finder = DuplicateFinder::scan(decks);
+                    }
+                });
+            });
+            ui.label(
+                egui::RichText::new(
+                    "Pick the card to keep. Merge folds the others' tags, images and answers into it; Delete just removes them.",
+                )
+                .small(),
+            );
+            ui.separator();
+
+            if finder.groups.is_empty() {
+                ui.label("No duplicates found.");
+                return;
+            }
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for (group_index, group) in finder.groups.iter_mut().enumerate() {
+                    ui.push_id(group_index, |ui| {
+                        ui.group(|ui| {
+                            ui.set_width(ui.available_width());
+                            ui.label(
+                                egui::RichText::new(if group.exact {
+                                    "Identical fronts"
+                                } else {
+                                    "Similar fronts"
+                                })
+                                .strong(),
+                            );
+
+                            for (i, card) in group.cards.iter().enumerate() {
+                                ui.horizontal(|ui| {
+                                    ui.radio_value(&mut group.keep, i, "");
+                                    ui.vertical(|ui| {
+                                        ui.label(format!("{}  →  {}", card.front, card.back));
+                                        ui.label(
+                                            egui::RichText::new(format!(
+                                                "{} · {} review(s)",
+                                                card.deck_name, card.reviews
+                                            ))
+                                            .small(),
+                                        );
+                                    });
+                                });
+                            }
+
+                            ui.horizontal(|ui| {
+                                if ui.button("🔗 Merge into kept").clicked() {
+                                    resolved = Some((group_index, true));
+                                }
+                                if ui.button("🗑 Delete others").clicked() {
+                                    resolved = Some((group_index, false));
+                                }
+                                if ui.button("Skip").clicked() {
+                                    skipped = Some(group_index);
+                                }
+                            });
+                        });
+                    });
+                }
+            });
+        });
+
+    if let Some((index, merge)) = resolved {
+        let group = finder.groups.remove(index);
+        let removed = resolve_group(decks, &group, merge, trash);
+        action = DuplicateAction::Changed(format!(
+            "{} {} duplicate card(s)",
+            if merge { "Merged" } else { "Deleted" },
+            removed
+        ));
+    } else if let Some(index) = skipped {
+        finder.groups.remove(index);
+    }
+
+    if !open && matches!(action, DuplicateAction::None) {
+        action = DuplicateAction::Close;
+    }
+    action
+}
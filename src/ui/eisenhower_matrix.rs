@@ -0,0 +1,198 @@
+use crate::app::StatusMessage;
+use crate::data::{StudyData, Todo, TodoPriority};
+use crate::settings::ColorTheme;
+use chrono::{Duration, Local};
+use eframe::egui;
+use std::cell::RefCell;
+
+// (urgent, important, title, hint)
+const QUADRANTS: [(bool, bool, &str, &str); 4] = [
+    (true, true, "Do First", "Urgent and important"),
+    (false, true, "Schedule", "Important, not urgent"),
+    (true, false, "Delegate", "Urgent, not important"),
+    (false, false, "Eliminate", "Neither urgent nor important"),
+];
+
+enum MatrixChange {
+    Classify(u64, bool, bool),
+    Priority(u64, TodoPriority),
+    DueDate(u64, Option<String>),
+}
+
+thread_local! {
+    static DRAGGED_TODO: RefCell<Option<u64>> = RefCell::new(None);
+}
+
+pub fn display(
+    ui: &mut egui::Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    colors: &ColorTheme,
+) {
+    ui.label(
+        egui::RichText::new(
+            "Important means high priority; urgent means due within two days. Drag ☰ to reclassify, right-click a task to set its priority or due date.",
+        )
+        .small()
+        .color(colors.text_secondary_color32()),
+    );
+    ui.add_space(6.0);
+
+    let mut changes = Vec::new();
+    let mut quadrant_rects = Vec::new();
+    let mut dragged = DRAGGED_TODO.with(|d| *d.borrow());
+
+    for row in QUADRANTS.chunks(2) {
+        ui.columns(2, |columns| {
+            for (ui, &(urgent, important, title, hint)) in columns.iter_mut().zip(row) {
+                let todos: Vec<&Todo> = study_data
+                    .todos
+                    .iter()
+                    .filter(|t| {
+                        !t.completed && t.is_urgent() == urgent && t.is_important() == important
+                    })
+                    .collect();
+
+                let frame = egui::Frame::group(ui.style()).show(ui, |ui| {
+                    ui.set_min_height(200.0);
+                    ui.set_width(ui.available_width());
+                    ui.horizontal(|ui| {
+                        ui.strong(title);
+                        ui.label(
+                            egui::RichText::new(format!("{} · {}", hint, todos.len()))
+                                .small()
+                                .color(colors.text_secondary_color32()),
+                        );
+                    });
+                    ui.separator();
+
+                    egui::ScrollArea::vertical()
+                        .id_source(("eisenhower", urgent, important))
+                        .max_height(180.0)
+                        .show(ui, |ui| {
+                            for todo in &todos {
+                                todo_row(ui, todo, colors, &mut dragged, &mut changes);
+                            }
+                        });
+                });
+                quadrant_rects.push((urgent, important, frame.response.rect));
+            }
+        });
+    }
+
+    // Drop the dragged todo onto the quadrant under the pointer
+    if let Some(id) = dragged {
+        ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grabbing);
+        let target = ui.ctx().pointer_interact_pos().and_then(|pointer| {
+            quadrant_rects
+                .iter()
+                .find(|(_, _, rect)| rect.contains(pointer))
+                .copied()
+        });
+        if let Some((_, _, rect)) = target {
+            ui.painter().rect_stroke(
+                rect.expand(2.0),
+                egui::Rounding::same(4.0),
+                egui::Stroke::new(1.5, colors.accent_color32()),
+            );
+        }
+
+        if ui.input(|i| i.pointer.any_released()) {
+            if let Some((urgent, important, _)) = target {
+                let moved = study_data.todos.iter().any(|t| {
+                    t.id == id && (t.is_urgent(), t.is_important()) != (urgent, important)
+                });
+                if moved {
+                    changes.push(MatrixChange::Classify(id, urgent, important));
+                }
+            }
+            dragged = None;
+        }
+    }
+    DRAGGED_TODO.with(|d| *d.borrow_mut() = dragged);
+
+    for change in changes {
+        let result = match change {
+            MatrixChange::Classify(id, urgent, important) => {
+                study_data.classify_todo(id, urgent, important)
+            }
+            MatrixChange::Priority(id, priority) => study_data.set_todo_priority(id, priority),
+            MatrixChange::DueDate(id, due_date) => study_data.set_todo_due_date(id, due_date),
+        };
+        if let Err(e) = result {
+            status.error(&format!("Error updating todo: {}", e));
+        }
+    }
+}
+
+fn todo_row(
+    ui: &mut egui::Ui,
+    todo: &Todo,
+    colors: &ColorTheme,
+    dragged: &mut Option<u64>,
+    changes: &mut Vec<MatrixChange>,
+) {
+    let row = ui.horizontal(|ui| {
+        let handle = ui
+            .add(egui::Label::new("☰").sense(egui::Sense::drag()))
+            .on_hover_text("Drag to another quadrant");
+        if handle.hovered() {
+            ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grab);
+        }
+        if handle.drag_started() {
+            *dragged = Some(todo.id);
+        }
+
+        ui.add(
+            egui::Label::new(egui::RichText::new(&todo.text).color(colors.text_primary_color32()))
+                .wrap(true),
+        );
+        if let Some(due) = &todo.due_date {
+            let time = todo.due_time.as_deref().unwrap_or_default();
+            ui.label(
+                egui::RichText::new(format!("due {} {}", due, time).trim_end())
+                    .small()
+                    .color(colors.text_secondary_color32()),
+            );
+        }
+    });
+
+    if *dragged == Some(todo.id) {
+        ui.painter().rect_stroke(
+            row.response.rect,
+            egui::Rounding::same(3.0),
+            egui::Stroke::new(1.0, colors.accent_color32()),
+        );
+    }
+
+    row.response
+        .interact(egui::Sense::click())
+        .context_menu(|ui| {
+            ui.label(egui::RichText::new("Priority").strong());
+            for priority in [TodoPriority::High, TodoPriority::Normal, TodoPriority::Low] {
+                if ui
+                    .radio(todo.priority == priority, priority.as_str())
+                    .clicked()
+                {
+                    changes.push(MatrixChange::Priority(todo.id, priority));
+                    ui.close_menu();
+                }
+            }
+            ui.separator();
+            ui.label(egui::RichText::new("Due").strong());
+            let today = Local::now().date_naive();
+            for (label, days) in [("Today", 0), ("Tomorrow", 1), ("In a week", 7)] {
+                if ui.button(label).clicked() {
+                    let due = (today + Duration::days(days))
+                        .format("%Y-%m-%d")
+                        .to_string();
+                    changes.push(MatrixChange::DueDate(todo.id, Some(due)));
+                    ui.close_menu();
+                }
+            }
+            if todo.due_date.is_some() && ui.button("Clear due date").clicked() {
+                changes.push(MatrixChange::DueDate(todo.id, None));
+                ui.close_menu();
+            }
+        });
+}
@@ -0,0 +1,611 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use crate::split_view_ui::SplitViewUI;
+use crate::tab_manager::TabInstance;
+use crate::ui::flashcard::Card;
+use crate::ui::image_viewer_tab::{self, CardDraft};
+use crate::web_clip;
+use eframe::egui::{self, Color32, FontId};
+use epub::doc::{EpubDoc, NavPoint};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+const MIN_FONT_SIZE: f32 = 10.0;
+const MAX_FONT_SIZE: f32 = 32.0;
+const TEXT_WIDTH: f32 = 760.0; // Longer lines are hard to read
+const SAVE_DISTANCE: f32 = 400.0; // Scrolling this far saves the reading position
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Bookmark {
+    chapter: usize,
+    offset: f32,
+    label: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct BookProgress {
+    #[serde(default)]
+    chapter: usize,
+    #[serde(default)]
+    offset: f32,
+    #[serde(default)]
+    bookmarks: Vec<Bookmark>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReaderSettings {
+    font_size: f32,
+    #[serde(default)]
+    books: HashMap<String, BookProgress>, // Keyed by file path
+}
+
+impl Default for ReaderSettings {
+    fn default() -> Self {
+        Self {
+            font_size: 16.0,
+            books: HashMap::new(),
+        }
+    }
+}
+
+struct TocEntry {
+    label: String,
+    chapter: usize,
+    depth: usize,
+}
+
+struct ReaderState {
+    doc: EpubDoc<BufReader<File>>,
+    title: String,
+    toc: Vec<TocEntry>,
+    chapter: usize,
+    text: String,
+    headings: Vec<usize>, // Line numbers of headings in `text`
+    scroll_to: Option<f32>,
+    offset: f32,
+    saved_offset: f32,
+    content_height: f32,
+    selection: String,
+    show_contents: bool,
+    card: Option<CardDraft>,
+}
+
+thread_local! {
+    // Keyed by file path, so each book keeps its open document
+    static READERS: RefCell<HashMap<String, ReaderState>> = RefCell::new(HashMap::new());
+    static SETTINGS: RefCell<Option<ReaderSettings>> = RefCell::new(None);
+}
+
+impl ReaderState {
+    fn open(path: &str, progress: &BookProgress) -> Result<Self, Box<dyn std::error::Error>> {
+        let doc = EpubDoc::new(path)?;
+        if doc.get_num_pages() == 0 {
+            return Err("The book has no chapters".into());
+        }
+        let title = doc.mdata("title").unwrap_or_else(|| {
+            Path::new(path)
+                .file_stem()
+                .map_or("Book".to_string(), |s| s.to_string_lossy().to_string())
+        });
+        let mut toc = Vec::new();
+        flatten_toc(&doc, &doc.toc, 0, &mut toc);
+
+        let mut state = Self {
+            doc,
+            title,
+            toc,
+            chapter: 0,
+            text: String::new(),
+            headings: Vec::new(),
+            scroll_to: None,
+            offset: 0.0,
+            saved_offset: progress.offset,
+            content_height: 0.0,
+            selection: String::new(),
+            show_contents: false,
+            card: None,
+        };
+        state.go_to(progress.chapter);
+        state.scroll_to = Some(progress.offset);
+        Ok(state)
+    }
+
+    fn chapter_count(&self) -> usize {
+        self.doc.get_num_pages()
+    }
+
+    fn go_to(&mut self, chapter: usize) {
+        self.chapter = chapter.min(self.chapter_count() - 1);
+        let html = if self.doc.set_current_page(self.chapter) {
+            self.doc.get_current_str().map(|(html, _)| html)
+        } else {
+            None
+        };
+        let (text, headings) = plain_text(&web_clip::body_to_markdown(&html.unwrap_or_default()));
+        self.text = text;
+        self.headings = headings;
+        self.selection.clear();
+        self.scroll_to = Some(0.0);
+        self.offset = 0.0;
+    }
+
+    fn chapter_label(&self, chapter: usize) -> String {
+        self.toc
+            .iter()
+            .find(|entry| entry.chapter == chapter)
+            .map_or_else(
+                || format!("Section {}", chapter + 1),
+                |entry| entry.label.clone(),
+            )
+    }
+
+    fn progress(&self, bookmarks: Vec<Bookmark>) -> BookProgress {
+        BookProgress {
+            chapter: self.chapter,
+            offset: self.offset,
+            bookmarks,
+        }
+    }
+}
+
+fn flatten_toc(
+    doc: &EpubDoc<BufReader<File>>,
+    points: &[NavPoint],
+    depth: usize,
+    toc: &mut Vec<TocEntry>,
+) {
+    for point in points {
+        // Entries can point into the middle of a chapter file
+        let content = point.content.to_string_lossy();
+        let file = PathBuf::from(content.split('#').next().unwrap_or_default());
+        if let Some(chapter) = doc.resource_uri_to_chapter(&file) {
+            toc.push(TocEntry {
+                label: point.label.trim().to_string(),
+                chapter,
+                depth,
+            });
+        }
+        flatten_toc(doc, &point.children, depth + 1, toc);
+    }
+}
+
+// Drops the markdown markup, since the chapter is read rather than edited
+fn plain_text(markdown: &str) -> (String, Vec<usize>) {
+    let image = Regex::new(r"!\[([^\]]*)\]\([^)]*\)").unwrap();
+    let link = Regex::new(r"\[([^\]]*)\]\([^)]*\)").unwrap();
+
+    let mut text = String::new();
+    let mut headings = Vec::new();
+    for (i, line) in markdown
+        .lines()
+        .filter(|line| !line.starts_with("```"))
+        .enumerate()
+    {
+        let line = image.replace_all(line, |caps: &regex::Captures| {
+            let alt = caps[1].trim();
+            if alt.is_empty() {
+                String::new()
+            } else {
+                format!("[Image: {}]", alt)
+            }
+        });
+        let line = link.replace_all(&line, "$1");
+
+        let mut line = line.as_ref();
+        let mut indent = String::new();
+        while let Some(rest) = line.strip_prefix("> ") {
+            indent.push_str("    ");
+            line = rest;
+        }
+        let hashes = line.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+            headings.push(i);
+            line = &line[hashes + 1..];
+        } else if line == "---" {
+            line = "";
+        }
+
+        text.push_str(&indent);
+        text.push_str(&strip_emphasis(line));
+        text.push('\n');
+    }
+    (text.trim_end().to_string(), headings)
+}
+
+// Asterisks with spaces on both sides are kept, e.g. in "a * b"
+fn strip_emphasis(line: &str) -> String {
+    let chars: Vec<char> = line.chars().filter(|&c| c != '`').collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let spaced = |j: Option<usize>| {
+            j.and_then(|j| chars.get(j))
+                .map_or(true, |c| c.is_whitespace())
+        };
+        if c == '*' && !(spaced(i.checked_sub(1)) && spaced(Some(i + 1))) {
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+pub fn display(
+    ui: &mut egui::Ui,
+    tab: Option<&mut TabInstance>,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+) {
+    let Some(tab) = tab else {
+        return;
+    };
+    let Some(path) = tab.file_path.clone() else {
+        ui.vertical_centered(|ui| {
+            ui.add_space(40.0);
+            ui.heading("EPUB Reader");
+            ui.label("Drop an .epub file on the window, or pick a book to read.");
+            if ui.button("📂 Open Book…").clicked() {
+                if let Some(picked) = rfd::FileDialog::new()
+                    .add_filter("EPUB", &["epub"])
+                    .pick_file()
+                {
+                    tab.title = picked.file_stem().map_or("Book".to_string(), |name| {
+                        name.to_string_lossy().to_string()
+                    });
+                    tab.file_path = Some(picked.to_string_lossy().to_string());
+                }
+            }
+        });
+        return;
+    };
+
+    SETTINGS.with(|settings| {
+        let mut settings = settings.borrow_mut();
+        let settings = settings.get_or_insert_with(|| load().unwrap_or_default());
+
+        READERS.with(|readers| {
+            let mut readers = readers.borrow_mut();
+            if !readers.contains_key(&path) {
+                let progress = settings.books.get(&path).cloned().unwrap_or_default();
+                match ReaderState::open(&path, &progress) {
+                    Ok(state) => {
+                        readers.insert(path.clone(), state);
+                    }
+                    Err(e) => {
+                        ui.colored_label(
+                            Color32::from_rgb(213, 94, 0),
+                            format!("Could not open {}: {}", path, e),
+                        );
+                        return;
+                    }
+                }
+            }
+            let Some(state) = readers.get_mut(&path) else {
+                return;
+            };
+
+            let chapter = state.chapter;
+            let font_size = settings.font_size;
+            let mut changed = toolbar(ui, state, settings, &path);
+            ui.separator();
+
+            if state.show_contents {
+                egui::SidePanel::left(egui::Id::new(("epub_contents", &path)))
+                    .resizable(true)
+                    .default_width(220.0)
+                    .show_inside(ui, |ui| contents(ui, state));
+            }
+            reader_text(ui, state, font_size, &path);
+
+            changed |= state.chapter != chapter
+                || settings.font_size != font_size
+                || (state.offset - state.saved_offset).abs() > SAVE_DISTANCE;
+            if changed {
+                let bookmarks = settings
+                    .books
+                    .get(&path)
+                    .map(|book| book.bookmarks.clone())
+                    .unwrap_or_default();
+                settings
+                    .books
+                    .insert(path.clone(), state.progress(bookmarks));
+                state.saved_offset = state.offset;
+                if let Err(e) = save(settings) {
+                    status.error(&format!("Could not save reading progress: {}", e));
+                }
+            }
+
+            if let Some(draft) = image_viewer_tab::card_dialog(
+                ui.ctx(),
+                "epub_reader_card",
+                "The selected passage is the answer.",
+                &mut state.card,
+                study_data,
+            ) {
+                add_card(draft, study_data, status);
+            }
+        });
+    });
+}
+
+// Returns true when bookmarks changed and need saving
+fn toolbar(
+    ui: &mut egui::Ui,
+    state: &mut ReaderState,
+    settings: &mut ReaderSettings,
+    path: &str,
+) -> bool {
+    let mut changed = false;
+    ui.horizontal_wrapped(|ui| {
+        ui.strong(&state.title);
+        ui.separator();
+        ui.toggle_value(&mut state.show_contents, "☰ Contents");
+
+        let count = state.chapter_count();
+        if ui
+            .add_enabled(state.chapter > 0, egui::Button::new("◀"))
+            .on_hover_text("Previous chapter")
+            .clicked()
+        {
+            state.go_to(state.chapter - 1);
+        }
+        let mut selected = state.chapter;
+        egui::ComboBox::from_id_source(("epub_chapter", path))
+            .selected_text(state.chapter_label(state.chapter))
+            .width(220.0)
+            .show_ui(ui, |ui| {
+                for chapter in 0..count {
+                    ui.selectable_value(&mut selected, chapter, state.chapter_label(chapter));
+                }
+            });
+        if selected != state.chapter {
+            state.go_to(selected);
+        }
+        if ui
+            .add_enabled(state.chapter + 1 < count, egui::Button::new("▶"))
+            .on_hover_text("Next chapter")
+            .clicked()
+        {
+            state.go_to(state.chapter + 1);
+        }
+        ui.label(format!("{} / {}", state.chapter + 1, count));
+
+        ui.separator();
+        if ui
+            .add_enabled(settings.font_size > MIN_FONT_SIZE, egui::Button::new("A−"))
+            .on_hover_text("Smaller text")
+            .clicked()
+        {
+            settings.font_size = (settings.font_size - 1.0).max(MIN_FONT_SIZE);
+        }
+        ui.label(format!("{:.0}", settings.font_size));
+        if ui
+            .add_enabled(settings.font_size < MAX_FONT_SIZE, egui::Button::new("A+"))
+            .on_hover_text("Larger text")
+            .clicked()
+        {
+            settings.font_size = (settings.font_size + 1.0).min(MAX_FONT_SIZE);
+        }
+
+        ui.separator();
+        let book = settings.books.entry(path.to_string()).or_default();
+        ui.menu_button(format!("🔖 Bookmarks ({})", book.bookmarks.len()), |ui| {
+            if ui.button("➕ Bookmark this spot").clicked() {
+                let percent = if state.content_height > 0.0 {
+                    (state.offset / state.content_height * 100.0).round() as u32
+                } else {
+                    0
+                };
+                book.bookmarks.push(Bookmark {
+                    chapter: state.chapter,
+                    offset: state.offset,
+                    label: format!("{} ({}%)", state.chapter_label(state.chapter), percent),
+                });
+                book.bookmarks.sort_by(|a, b| {
+                    (a.chapter, a.offset)
+                        .partial_cmp(&(b.chapter, b.offset))
+                        .unwrap()
+                });
+                changed = true;
+                ui.close_menu();
+            }
+            if !book.bookmarks.is_empty() {
+                ui.separator();
+            }
+            let mut removed = None;
+            for (i, bookmark) in book.bookmarks.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    if ui.button(&bookmark.label).clicked() {
+                        if bookmark.chapter != state.chapter {
+                            state.go_to(bookmark.chapter);
+                        }
+                        state.scroll_to = Some(bookmark.offset);
+                        ui.close_menu();
+                    }
+                    if ui.small_button("🗑").on_hover_text("Remove").clicked() {
+                        removed = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = removed {
+                book.bookmarks.remove(i);
+                changed = true;
+            }
+        });
+
+        if ui
+            .add_enabled(
+                !state.selection.trim().is_empty(),
+                egui::Button::new("🃏 To Flashcard"),
+            )
+            .on_hover_text("Makes a card with the selected passage as the answer")
+            .on_disabled_hover_text("Select a passage first")
+            .clicked()
+        {
+            start_card(state);
+        }
+    });
+    changed
+}
+
+fn contents(ui: &mut egui::Ui, state: &mut ReaderState) {
+    ui.strong("Contents");
+    egui::ScrollArea::vertical()
+        .id_source("epub_contents_scroll")
+        .show(ui, |ui| {
+            if state.toc.is_empty() {
+                ui.label("This book has no table of contents.");
+            }
+            let mut target = None;
+            for entry in &state.toc {
+                ui.horizontal(|ui| {
+                    ui.add_space(entry.depth as f32 * 12.0);
+                    if ui
+                        .selectable_label(entry.chapter == state.chapter, &entry.label)
+                        .clicked()
+                    {
+                        target = Some(entry.chapter);
+                    }
+                });
+            }
+            if let Some(chapter) = target {
+                state.go_to(chapter);
+            }
+        });
+}
+
+fn reader_text(ui: &mut egui::Ui, state: &mut ReaderState, font_size: f32, path: &str) {
+    let mut scroll = egui::ScrollArea::vertical()
+        .id_source(("epub_text", path, state.chapter))
+        .auto_shrink([false, false]);
+    if let Some(offset) = state.scroll_to.take() {
+        scroll = scroll.vertical_scroll_offset(offset);
+    }
+    scroll = SplitViewUI::lock_scroll(scroll);
+
+    let headings = &state.headings;
+    let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+        let mut job = egui::text::LayoutJob::default();
+        for (i, line) in text.split_inclusive('\n').enumerate() {
+            let heading = headings.binary_search(&i).is_ok();
+            job.append(
+                line,
+                0.0,
+                egui::TextFormat {
+                    font_id: FontId::proportional(if heading {
+                        font_size * 1.4
+                    } else {
+                        font_size
+                    }),
+                    color: if heading {
+                        ui.visuals().strong_text_color()
+                    } else {
+                        ui.visuals().text_color()
+                    },
+                    ..Default::default()
+                },
+            );
+        }
+        job.wrap.max_width = wrap_width;
+        ui.fonts(|fonts| fonts.layout_job(job))
+    };
+
+    let output = scroll.show(ui, |ui| {
+        if state.text.trim().is_empty() {
+            ui.label("This section has no text.");
+            return None;
+        }
+        let output = ui.vertical_centered(|ui| {
+            // Read-only, but still selectable so passages can be copied or carded
+            let mut text = state.text.as_str();
+            egui::TextEdit::multiline(&mut text)
+                .frame(false)
+                .desired_width(ui.available_width().min(TEXT_WIDTH))
+                .layouter(&mut layouter)
+                .show(ui)
+        });
+        Some(output.inner)
+    });
+    SplitViewUI::track_scroll(ui, &output);
+    state.offset = output.state.offset.y;
+    state.content_height = output.content_size.y;
+
+    let Some(text_output) = output.inner else {
+        return;
+    };
+    if let Some(range) = text_output.cursor_range {
+        let [start, end] = range.as_ccursor_range().sorted();
+        state.selection = state
+            .text
+            .chars()
+            .skip(start.index)
+            .take(end.index - start.index)
+            .collect();
+    }
+    text_output.response.context_menu(|ui| {
+        if ui
+            .add_enabled(
+                !state.selection.trim().is_empty(),
+                egui::Button::new("🃏 Create Flashcard from Selection"),
+            )
+            .clicked()
+        {
+            start_card(state);
+            ui.close_menu();
+        }
+    });
+}
+
+fn start_card(state: &mut ReaderState) {
+    state.card = Some(CardDraft {
+        back: state.selection.trim().to_string(),
+        ..Default::default()
+    });
+}
+
+fn add_card(draft: CardDraft, study_data: &mut StudyData, status: &mut StatusMessage) {
+    if let Some(deck) = study_data
+        .decks
+        .iter_mut()
+        .find(|d| Some(d.id) == draft.deck_id)
+    {
+        let deck_name = deck.name.clone();
+        deck.add_card(Card::new(
+            deck.id,
+            draft.front.trim().to_string(),
+            draft.back.trim().to_string(),
+        ));
+        match study_data.save() {
+            Ok(()) => status.show(&format!("Flashcard added to {}", deck_name)),
+            Err(e) => status.error(&format!("Error saving: {}", e)),
+        }
+    }
+}
+
+fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut path = crate::profile::config_dir().ok_or("Could not find config directory")?;
+    path.push("epub_reader.json");
+    Ok(path)
+}
+
+fn load() -> Result<ReaderSettings, Box<dyn std::error::Error>> {
+    let path = get_config_path()?;
+    if !path.exists() {
+        return Ok(ReaderSettings::default());
+    }
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+fn save(settings: &ReaderSettings) -> Result<(), Box<dyn std::error::Error>> {
+    let path = get_config_path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(settings)?)?;
+    Ok(())
+}
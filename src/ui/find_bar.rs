@@ -0,0 +1,209 @@
+use eframe::egui::{self, Color32, RichText};
+use regex::{NoExpand, Regex, RegexBuilder};
+use std::ops::Range;
+
+// Find and replace within one document, opened with Ctrl+F or Ctrl+H
+pub struct FindBar {
+    pub query: String,
+    pub replacement: String,
+    pub regex: bool,
+    pub case_sensitive: bool,
+    pub show_replace: bool,
+    pub current: usize, // Index of the selected match
+    focus: bool,
+    select_current: bool, // Select the current match once the text has been searched again
+}
+
+#[derive(Default)]
+pub struct FindOutcome {
+    pub select: Option<Range<usize>>, // Byte range of a match to select in the editor
+    pub replaced: Option<usize>,
+    pub close: bool,
+}
+
+impl FindBar {
+    pub fn new(show_replace: bool) -> Self {
+        Self {
+            query: String::new(),
+            replacement: String::new(),
+            regex: false,
+            case_sensitive: false,
+            show_replace,
+            current: 0,
+            focus: true,
+            select_current: false,
+        }
+    }
+
+    // Ctrl+F or Ctrl+H again while open
+    pub fn reopen(&mut self, show_replace: bool) {
+        self.show_replace |= show_replace;
+        self.focus = true;
+    }
+
+    // None while there is nothing to search for
+    pub fn pattern(&self) -> Option<Result<Regex, regex::Error>> {
+        build_pattern(&self.query, self.regex, self.case_sensitive)
+    }
+}
+
+// Shared with replacing across all notes
+pub fn build_pattern(
+    query: &str,
+    regex: bool,
+    case_sensitive: bool,
+) -> Option<Result<Regex, regex::Error>> {
+    if query.is_empty() {
+        return None;
+    }
+    let pattern = if regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    Some(
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!case_sensitive)
+            .build(),
+    )
+}
+
+pub fn show(ui: &mut egui::Ui, bar: &mut FindBar, text: &mut String) -> FindOutcome {
+    let mut outcome = FindOutcome::default();
+    let pattern = bar.pattern();
+    let matches: Vec<Range<usize>> = match &pattern {
+        Some(Ok(re)) => re.find_iter(text).map(|m| m.range()).collect(),
+        _ => Vec::new(),
+    };
+    if bar.current >= matches.len() {
+        bar.current = 0;
+    }
+    if std::mem::take(&mut bar.select_current) {
+        outcome.select = matches.get(bar.current).cloned();
+    }
+
+    let mut step: Option<isize> = None;
+    ui.horizontal(|ui| {
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut bar.query)
+                .hint_text("Find")
+                .desired_width(200.0),
+        );
+        if std::mem::take(&mut bar.focus) {
+            response.request_focus();
+        }
+        if response.changed() {
+            bar.current = 0;
+            bar.select_current = true;
+        }
+        // Enter keeps focus in the field, so it can be pressed repeatedly
+        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+            step = Some(if ui.input(|i| i.modifiers.shift) {
+                -1
+            } else {
+                1
+            });
+            response.request_focus();
+        }
+        if response.has_focus() && ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+            outcome.close = true;
+        }
+
+        match &pattern {
+            Some(Err(_)) => {
+                ui.label(RichText::new("Invalid pattern").color(Color32::from_rgb(213, 94, 0)));
+            }
+            Some(Ok(_)) if matches.is_empty() => {
+                ui.label("No matches");
+            }
+            Some(Ok(_)) => {
+                ui.label(format!("{} of {}", bar.current + 1, matches.len()));
+            }
+            None => {}
+        }
+
+        if ui
+            .add_enabled(!matches.is_empty(), egui::Button::new("⬆"))
+            .on_hover_text("Previous match (Shift+Enter)")
+            .clicked()
+        {
+            step = Some(-1);
+        }
+        if ui
+            .add_enabled(!matches.is_empty(), egui::Button::new("⬇"))
+            .on_hover_text("Next match (Enter)")
+            .clicked()
+        {
+            step = Some(1);
+        }
+        let case_changed = ui
+            .toggle_value(&mut bar.case_sensitive, "Aa")
+            .on_hover_text("Match case")
+            .changed();
+        let regex_changed = ui
+            .toggle_value(&mut bar.regex, ".*")
+            .on_hover_text("Regular expression")
+            .changed();
+        if case_changed || regex_changed {
+            bar.current = 0;
+            bar.select_current = true;
+        }
+        ui.toggle_value(&mut bar.show_replace, "Replace");
+        if ui.button("✖").on_hover_text("Close (Esc)").clicked() {
+            outcome.close = true;
+        }
+    });
+
+    if let Some(step) = step.filter(|_| !matches.is_empty()) {
+        let count = matches.len() as isize;
+        bar.current = (bar.current as isize + step).rem_euclid(count) as usize;
+        outcome.select = Some(matches[bar.current].clone());
+    }
+
+    if bar.show_replace {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut bar.replacement)
+                    .hint_text(if bar.regex {
+                        "Replace with ($1 for groups)"
+                    } else {
+                        "Replace with"
+                    })
+                    .desired_width(200.0),
+            );
+            let Some(Ok(re)) = &pattern else {
+                return;
+            };
+            if ui
+                .add_enabled(!matches.is_empty(), egui::Button::new("Replace"))
+                .clicked()
+            {
+                let range = matches[bar.current].clone();
+                let replaced = if bar.regex {
+                    re.replace(&text[range.clone()], bar.replacement.as_str())
+                        .into_owned()
+                } else {
+                    bar.replacement.clone()
+                };
+                text.replace_range(range, &replaced);
+                outcome.replaced = Some(1);
+                // The next match now has the same index
+                bar.select_current = true;
+            }
+            if ui
+                .add_enabled(!matches.is_empty(), egui::Button::new("Replace All"))
+                .clicked()
+            {
+                let replaced = if bar.regex {
+                    re.replace_all(text, bar.replacement.as_str())
+                } else {
+                    re.replace_all(text, NoExpand(&bar.replacement))
+                };
+                *text = replaced.into_owned();
+                outcome.replaced = Some(matches.len());
+            }
+        });
+    }
+
+    outcome
+}
@@ -1,5 +1,6 @@
 use crate::image_handler::CardImage;
 use chrono::{Local, NaiveDate};
+use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -20,6 +21,198 @@ pub struct Review {
     pub interval: u32,
     pub ease_factor: f32,
     pub algorithm_enabled: bool,
+    #[serde(default)]
+    pub answer_seconds: Option<f32>, // From showing the question to revealing the answer
+}
+
+// One grade given in the reviewer, kept in the study data's review log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewLogEntry {
+    pub timestamp: String, // "%Y-%m-%d %H:%M:%S"
+    pub deck_id: u64,
+    pub card_id: u64,
+    pub grade: Grade,
+    #[serde(default)]
+    pub reversed: bool,
+    #[serde(default)]
+    pub answer_seconds: Option<f32>,
+}
+
+impl ReviewLogEntry {
+    pub fn new(
+        deck_id: u64,
+        card_id: u64,
+        grade: Grade,
+        reversed: bool,
+        answer_seconds: Option<f32>,
+    ) -> Self {
+        Self {
+            timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            deck_id,
+            card_id,
+            grade,
+            reversed,
+            answer_seconds,
+        }
+    }
+
+    pub fn date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(self.timestamp.get(..10)?, "%Y-%m-%d").ok()
+    }
+}
+
+// Review state for the back -> front direction of a card
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReverseSchedule {
+    pub reviews: Vec<Review>,
+    pub current_interval: u32,
+    pub current_ease_factor: f32,
+    pub due_date: String, // YYYY-MM-DD format
+}
+
+impl ReverseSchedule {
+    fn new() -> Self {
+        Self {
+            reviews: Vec::new(),
+            current_interval: 1,
+            current_ease_factor: 2.5,
+            due_date: Local::now().format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+// Moves or copies cards into another deck along with their review history; returns how many
+pub fn transfer_cards(
+    decks: &mut [Deck],
+    from: u64,
+    to: u64,
+    card_ids: &HashSet<u64>,
+    copy: bool,
+) -> usize {
+    if from == to || !decks.iter().any(|d| d.id == to) {
+        return 0;
+    }
+    let Some(source) = decks.iter_mut().find(|d| d.id == from) else {
+        return 0;
+    };
+
+    let cards: Vec<Card> = if copy {
+        source
+            .cards
+            .iter()
+            .filter(|c| card_ids.contains(&c.id))
+            .cloned()
+            .map(|mut card| {
+                // A copy is a card of its own for deck sharing
+                card.shared_uid = None;
+                card
+            })
+            .collect()
+    } else {
+        let (moved, kept): (Vec<Card>, Vec<Card>) = std::mem::take(&mut source.cards)
+            .into_iter()
+            .partition(|c| card_ids.contains(&c.id));
+        source.cards = kept;
+        if !moved.is_empty() {
+            source.touch();
+        }
+        moved
+    };
+
+    let count = cards.len();
+    if let Some(target) = decks.iter_mut().find(|d| d.id == to) {
+        for card in cards {
+            target.add_card(card);
+        }
+        if count > 0 {
+            target.touch();
+        }
+    }
+    count
+}
+
+const SLOW_ANSWER_FACTOR: f32 = 1.5;
+
+// Nearest-rank percentile of sorted values, `fraction` between 0 and 1
+pub fn percentile(sorted: &[f32], fraction: f32) -> f32 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let index = ((sorted.len() - 1) as f32 * fraction).round() as usize;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+// Applies one review with SM-2 and returns it together with the next due date
+fn schedule_review(
+    current_interval: u32,
+    current_ease_factor: f32,
+    grade: Grade,
+    algorithm_enabled: bool,
+    answer_seconds: Option<f32>,
+) -> (Review, String) {
+    let now = Local::now().format("%Y-%m-%d").to_string();
+
+    // Apply SM-2 algorithm only if enabled
+    let (new_interval, new_ease_factor) = if algorithm_enabled {
+        match grade {
+            Grade::Again => (1, (current_ease_factor - 0.15).max(1.3)),
+            Grade::Hard => {
+                let interval = (current_interval as f32 * 1.2).round() as u32;
+                (interval.max(1), (current_ease_factor - 0.15).max(1.3))
+            }
+            Grade::Good => {
+                let interval = (current_interval as f32 * current_ease_factor).round() as u32;
+                (interval.max(1), current_ease_factor)
+            }
+            Grade::Easy => {
+                let interval = (current_interval as f32 * current_ease_factor * 1.3).round() as u32;
+                (interval.max(1), (current_ease_factor + 0.15).min(2.5))
+            }
+        }
+    } else {
+        // When algorithm is disabled, keep cards immediately available
+        (0, current_ease_factor)
+    };
+
+    // Set due date - if algorithm disabled, make it available today
+    let due_date = if algorithm_enabled {
+        NaiveDate::parse_from_str(&now, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.checked_add_days(chrono::Days::new(new_interval as u64)))
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or(now.clone())
+    } else {
+        now.clone() // Always available today when algorithm is off
+    };
+
+    let review = Review {
+        date: now,
+        grade,
+        interval: new_interval,
+        ease_factor: new_ease_factor,
+        algorithm_enabled,
+        answer_seconds,
+    };
+    (review, due_date)
+}
+
+// The note a card was extracted from, so the reviewer can jump back to it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardSource {
+    pub path: String,
+    pub heading: Option<String>, // Nearest heading above the extracted text
+}
+
+impl CardSource {
+    pub fn label(&self) -> String {
+        let name = std::path::Path::new(&self.path)
+            .file_name()
+            .map_or(self.path.clone(), |n| n.to_string_lossy().to_string());
+        match &self.heading {
+            Some(heading) => format!("{} › {}", name, heading),
+            None => name,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,15 +221,27 @@ pub struct Card {
     pub deck_id: u64,
     pub front: String,
     pub back: String,
+    #[serde(default)]
+    pub notes: String, // Mnemonics, sources or page numbers, shown after the answer
     pub tags: HashSet<String>,
     pub front_image: Option<CardImage>,
     pub back_image: Option<CardImage>,
     pub created_at: String, // ISO date format
+    #[serde(default)]
+    pub modified_at: String, // Empty until the card is first edited
     pub reviews: Vec<Review>,
     pub current_interval: u32,
     pub current_ease_factor: f32,
     pub due_date: String, // YYYY-MM-DD format
     pub is_new: bool,
+    #[serde(default)]
+    pub reverse: bool, // Also review this card back -> front
+    #[serde(default)]
+    pub reverse_schedule: Option<ReverseSchedule>,
+    #[serde(default)]
+    pub shared_uid: Option<String>, // Identifies the card across copies of a shared deck
+    #[serde(default)]
+    pub source: Option<CardSource>,
 }
 
 impl Card {
@@ -47,71 +252,117 @@ impl Card {
             deck_id,
             front,
             back,
+            notes: String::new(),
             front_image: None,
             back_image: None,
             tags: HashSet::new(),
-            created_at: now.clone(),
+            created_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            modified_at: String::new(),
             reviews: Vec::new(),
             current_interval: 1,
             current_ease_factor: 2.5,
             due_date: now,
             is_new: true,
+            reverse: false,
+            reverse_schedule: None,
+            shared_uid: None,
+            source: None,
         }
     }
 
-    pub fn add_review(&mut self, grade: Grade, algorithm_enabled: bool) {
-        let now = Local::now().format("%Y-%m-%d").to_string();
+    pub fn touch(&mut self) {
+        self.modified_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    }
 
-        // Apply SM-2 algorithm only if enabled
-        let (new_interval, new_ease_factor) = if algorithm_enabled {
-            match grade {
-                Grade::Again => (1, (self.current_ease_factor - 0.15).max(1.3)),
-                Grade::Hard => {
-                    let interval = (self.current_interval as f32 * 1.2).round() as u32;
-                    (interval.max(1), (self.current_ease_factor - 0.15).max(1.3))
-                }
-                Grade::Good => {
-                    let interval =
-                        (self.current_interval as f32 * self.current_ease_factor).round() as u32;
-                    (interval.max(1), self.current_ease_factor)
-                }
-                Grade::Easy => {
-                    let interval = (self.current_interval as f32 * self.current_ease_factor * 1.3)
-                        .round() as u32;
-                    (interval.max(1), (self.current_ease_factor + 0.15).min(2.5))
-                }
-            }
+    // Same content as a brand new card, with none of the review history
+    pub fn fresh_copy(&self) -> Card {
+        let mut card = Card::new(self.deck_id, self.front.clone(), self.back.clone());
+        card.notes = self.notes.clone();
+        card.tags = self.tags.clone();
+        card.front_image = self.front_image.clone();
+        card.back_image = self.back_image.clone();
+        card.reverse = self.reverse;
+        card.source = self.source.clone();
+        card
+    }
+
+    // Cards saved before edits were tracked count as last changed when created
+    pub fn last_modified(&self) -> &str {
+        if self.modified_at.is_empty() {
+            &self.created_at
         } else {
-            // When algorithm is disabled, keep cards immediately available
-            (0, self.current_ease_factor)
-        };
+            &self.modified_at
+        }
+    }
 
-        let review = Review {
-            date: now.clone(),
+    pub fn add_review(
+        &mut self,
+        grade: Grade,
+        algorithm_enabled: bool,
+        answer_seconds: Option<f32>,
+    ) {
+        let (review, due_date) = schedule_review(
+            self.current_interval,
+            self.current_ease_factor,
             grade,
-            interval: new_interval,
-            ease_factor: new_ease_factor,
             algorithm_enabled,
-        };
+            answer_seconds,
+        );
 
+        self.current_interval = review.interval;
+        self.current_ease_factor = review.ease_factor;
         self.reviews.push(review);
-        self.current_interval = new_interval;
-        self.current_ease_factor = new_ease_factor;
-
-        // Set due date - if algorithm disabled, make it available today
-        self.due_date = if algorithm_enabled {
-            NaiveDate::parse_from_str(&now, "%Y-%m-%d")
-                .ok()
-                .and_then(|d| d.checked_add_days(chrono::Days::new(new_interval as u64)))
-                .map(|d| d.format("%Y-%m-%d").to_string())
-                .unwrap_or(now.clone())
-        } else {
-            now.clone() // Always available today when algorithm is off
-        };
-
+        self.due_date = due_date;
         self.is_new = false;
     }
 
+    // The reversed direction keeps its own schedule so it doesn't disturb the card's
+    pub fn add_reverse_review(
+        &mut self,
+        grade: Grade,
+        algorithm_enabled: bool,
+        answer_seconds: Option<f32>,
+    ) {
+        let schedule = self
+            .reverse_schedule
+            .get_or_insert_with(ReverseSchedule::new);
+        let (review, due_date) = schedule_review(
+            schedule.current_interval,
+            schedule.current_ease_factor,
+            grade,
+            algorithm_enabled,
+            answer_seconds,
+        );
+
+        schedule.current_interval = review.interval;
+        schedule.current_ease_factor = review.ease_factor;
+        schedule.reviews.push(review);
+        schedule.due_date = due_date;
+    }
+
+    pub fn has_reverse(&self, deck_reverse: bool) -> bool {
+        deck_reverse || self.reverse
+    }
+
+    pub fn is_reverse_due(&self, algorithm_enabled: bool) -> bool {
+        if !algorithm_enabled {
+            return true;
+        }
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        match &self.reverse_schedule {
+            Some(schedule) => schedule.due_date <= today,
+            None => true, // Never reviewed in reverse yet
+        }
+    }
+
+    pub fn get_reverse_difficulty(&self) -> Grade {
+        self.reverse_schedule
+            .as_ref()
+            .and_then(|s| s.reviews.last())
+            .map(|r| r.grade.clone())
+            .unwrap_or(Grade::Again)
+    }
+
     pub fn get_difficulty(&self) -> Grade {
         if self.reviews.is_empty() {
             Grade::Again // New cards are considered "Again"
@@ -119,6 +370,24 @@ impl Card {
             self.reviews.last().unwrap().grade.clone()
         }
     }
+
+    pub fn average_answer_seconds(&self) -> Option<f32> {
+        let times: Vec<f32> = self
+            .reviews
+            .iter()
+            .filter_map(|r| r.answer_seconds)
+            .collect();
+        (!times.is_empty()).then(|| times.iter().sum::<f32>() / times.len() as f32)
+    }
+
+    // Answers that take much longer than usual for the deck count towards a card's
+    // difficulty, even when it was graded Good
+    pub fn is_slow(&self, deck_median: Option<f32>) -> bool {
+        match (self.average_answer_seconds(), deck_median) {
+            (Some(average), Some(median)) => average > median * SLOW_ANSWER_FACTOR,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -127,7 +396,140 @@ pub struct Deck {
     pub name: String,
     pub description: Option<String>,
     pub created_at: String, // ISO date format
+    #[serde(default)]
+    pub modified_at: String, // Empty until the deck is first edited
     pub cards: Vec<Card>,
+    #[serde(default)]
+    pub reverse_cards: bool, // Review every card in both directions
+    #[serde(default)]
+    pub review_presets: Vec<ReviewPreset>,
+    #[serde(default)]
+    pub settings: DeckSettings,
+}
+
+// Defaults the reviewer picks up when the deck is opened, edited from ⚙ in the deck list
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeckSettings {
+    pub new_card_order: NewCardOrder,
+    pub new_cards_per_day: u32, // Cards seen for the first time; 0 for no limit
+    pub daily_limit: u32,       // Reviews per day; 0 for no limit
+    pub image_size: ImageSize,
+    pub default_preset: Option<String>, // Applied instead of the limit above when set
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum NewCardOrder {
+    #[default]
+    Mixed, // In with the due cards, following the review order
+    Oldest,
+    Newest,
+    Random,
+}
+
+impl NewCardOrder {
+    pub const ALL: [NewCardOrder; 4] = [
+        NewCardOrder::Mixed,
+        NewCardOrder::Oldest,
+        NewCardOrder::Newest,
+        NewCardOrder::Random,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NewCardOrder::Mixed => "Mixed in",
+            NewCardOrder::Oldest => "Oldest first",
+            NewCardOrder::Newest => "Newest first",
+            NewCardOrder::Random => "Random",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            NewCardOrder::Mixed => "New cards come up alongside the cards due for review",
+            NewCardOrder::Oldest => "After the due cards, in the order they were added",
+            NewCardOrder::Newest => "After the due cards, most recently added first",
+            NewCardOrder::Random => "After the due cards, in random order",
+        }
+    }
+}
+
+// How large card images are shown while reviewing
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ImageSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl ImageSize {
+    pub const ALL: [ImageSize; 3] = [ImageSize::Small, ImageSize::Medium, ImageSize::Large];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ImageSize::Small => "Small",
+            ImageSize::Medium => "Medium",
+            ImageSize::Large => "Large",
+        }
+    }
+
+    pub fn scale(&self) -> f32 {
+        match self {
+            ImageSize::Small => 0.6,
+            ImageSize::Medium => 1.0,
+            ImageSize::Large => 1.6,
+        }
+    }
+}
+
+// Review settings saved on a deck under a name, e.g. "Cram" and "Normal"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewPreset {
+    pub name: String,
+    pub difficulty: Option<Grade>, // Only cards last graded this; None reviews all cards
+    pub algorithm_enabled: bool,
+    pub daily_limit: u32, // Reviews per day in the deck; 0 for no limit
+    pub timed_mode: bool,
+    pub answer_time_limit: u32, // Seconds
+    #[serde(default)]
+    pub order: ReviewOrder,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ReviewOrder {
+    #[default]
+    Weighted, // Random, with harder cards coming up more often
+    Shuffled,
+    Created,
+    Alphabetical,
+}
+
+impl ReviewOrder {
+    pub const ALL: [ReviewOrder; 4] = [
+        ReviewOrder::Weighted,
+        ReviewOrder::Shuffled,
+        ReviewOrder::Created,
+        ReviewOrder::Alphabetical,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReviewOrder::Weighted => "Weighted",
+            ReviewOrder::Shuffled => "Shuffled",
+            ReviewOrder::Created => "Created",
+            ReviewOrder::Alphabetical => "A–Z",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ReviewOrder::Weighted => "Random, with difficult cards coming up more often",
+            ReviewOrder::Shuffled => "Every card once, in random order",
+            ReviewOrder::Created => "Every card once, in the order it was added",
+            ReviewOrder::Alphabetical => "Every card once, sorted by question",
+        }
+    }
 }
 
 impl Deck {
@@ -138,10 +540,122 @@ impl Deck {
             name,
             description,
             created_at: now,
+            modified_at: String::new(),
             cards: Vec::new(),
+            reverse_cards: false,
+            review_presets: Vec::new(),
+            settings: DeckSettings::default(),
         }
     }
 
+    pub fn touch(&mut self) {
+        self.modified_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    }
+
+    pub fn last_modified(&self) -> &str {
+        if self.modified_at.is_empty() {
+            &self.created_at
+        } else {
+            &self.modified_at
+        }
+    }
+
+    // A separate deck with the same cards, e.g. a variant for another exam's scope.
+    // Copies never share a sync identity with the original.
+    pub fn duplicate(&self, id: u64, name: String, keep_progress: bool) -> Deck {
+        let mut deck = Deck::new(name, self.description.clone());
+        deck.id = id;
+        deck.reverse_cards = self.reverse_cards;
+        deck.review_presets = self.review_presets.clone();
+        deck.settings = self.settings.clone();
+        deck.cards = self
+            .cards
+            .iter()
+            .map(|card| {
+                let mut copy = if keep_progress {
+                    let mut copy = card.clone();
+                    copy.shared_uid = None;
+                    copy
+                } else {
+                    let mut copy = card.fresh_copy();
+                    copy.id = card.id;
+                    copy
+                };
+                copy.deck_id = id;
+                copy
+            })
+            .collect();
+        deck
+    }
+
+    // Cards get the next free id in the deck so edits and deletes only touch one card
+    pub fn add_card(&mut self, mut card: Card) {
+        card.id = self.cards.iter().map(|c| c.id).max().map_or(1, |id| id + 1);
+        card.deck_id = self.id;
+        self.cards.push(card);
+    }
+
+    // Reviews given in this deck today, in either direction
+    pub fn reviews_today(&self) -> usize {
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let count = |reviews: &[Review]| reviews.iter().filter(|r| r.date == today).count();
+        self.cards
+            .iter()
+            .map(|card| {
+                let reverse = card
+                    .reverse_schedule
+                    .as_ref()
+                    .map_or(0, |schedule| count(&schedule.reviews));
+                count(&card.reviews) + reverse
+            })
+            .sum()
+    }
+
+    // Cards reviewed for the first time today
+    pub fn new_cards_today(&self) -> usize {
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        self.cards
+            .iter()
+            .filter(|card| card.reviews.first().is_some_and(|r| r.date == today))
+            .count()
+    }
+
+    // Indices of never reviewed cards, in the deck's new card order and cut to what
+    // is left of today's new card limit
+    pub fn pick_new_cards(&self, mut indices: Vec<usize>) -> Vec<usize> {
+        match self.settings.new_card_order {
+            NewCardOrder::Mixed | NewCardOrder::Oldest => indices.sort_by_key(|&index| {
+                let card = &self.cards[index];
+                (card.created_at.clone(), card.id)
+            }),
+            NewCardOrder::Newest => indices.sort_by_key(|&index| {
+                let card = &self.cards[index];
+                std::cmp::Reverse((card.created_at.clone(), card.id))
+            }),
+            NewCardOrder::Random => indices.shuffle(&mut rand::thread_rng()),
+        }
+        if self.settings.new_cards_per_day > 0 {
+            let left =
+                (self.settings.new_cards_per_day as usize).saturating_sub(self.new_cards_today());
+            indices.truncate(left);
+        }
+        indices
+    }
+
+    // Median over every timed forward review in the deck
+    pub fn median_answer_seconds(&self) -> Option<f32> {
+        let mut times: Vec<f32> = self
+            .cards
+            .iter()
+            .flat_map(|card| card.reviews.iter().filter_map(|r| r.answer_seconds))
+            .collect();
+        if times.is_empty() {
+            return None;
+        }
+        times.sort_by(|a, b| a.total_cmp(b));
+        Some(percentile(&times, 0.5))
+    }
+
     pub fn get_due_cards(&self, algorithm_enabled: bool) -> Vec<&Card> {
         if algorithm_enabled {
             let today = Local::now().format("%Y-%m-%d").to_string();
@@ -155,18 +669,30 @@ impl Deck {
         }
     }
 
-    pub fn get_cards_by_difficulty_for_review(
+    pub fn get_due_reverse_cards(&self, algorithm_enabled: bool) -> Vec<&Card> {
+        self.cards
+            .iter()
+            .filter(|card| {
+                card.has_reverse(self.reverse_cards) && card.is_reverse_due(algorithm_enabled)
+            })
+            .collect()
+    }
+
+    // Indices rather than cards, so cards sharing an id can't be mixed up
+    pub fn card_indices_by_difficulty_for_review(
         &self,
         difficulty: &Grade,
         algorithm_enabled: bool,
-    ) -> Vec<&Card> {
+    ) -> Vec<usize> {
         let today = Local::now().format("%Y-%m-%d").to_string();
         self.cards
             .iter()
-            .filter(|card| {
+            .enumerate()
+            .filter(|(_, card)| {
                 let is_due = if algorithm_enabled { card.due_date <= today } else { true };
                 is_due && matches!(card.get_difficulty(), d if std::mem::discriminant(&d) == std::mem::discriminant(difficulty))
             })
+            .map(|(index, _)| index)
             .collect()
     }
 }
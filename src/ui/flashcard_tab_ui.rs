@@ -1,22 +1,72 @@
+use crate::ui::card_history;
+use crate::ui::quiz_ui::{self, QuizAction};
+use crate::ui::review_heatmap;
 use crate::StudyTimerApp;
 use eframe::egui;
 
-pub fn display(ui: &mut egui::Ui, _ctx: &egui::Context, app: &mut StudyTimerApp) {
-    // Check if we're in fullscreen mode first
-    if app.flashcard_reviewer.is_fullscreen {
-        // Display only the fullscreen flashcard reviewer
-        if let Some(deck_id) = app.deck_manager_ui.selected_deck_id {
-            if let Some(deck) = app.study_data.decks.iter_mut().find(|d| d.id == deck_id) {
-                app.flashcard_reviewer.display(ui, deck);
-            }
+// The reviewer on its own, shown by the app in place of the tab bar, navigation and status bar
+pub fn display_fullscreen(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
+    let deck = app
+        .deck_manager_ui
+        .selected_deck_id
+        .and_then(|deck_id| app.study_data.decks.iter_mut().find(|d| d.id == deck_id));
+    match deck {
+        Some(deck) => {
+            egui::Frame::none()
+                .inner_margin(egui::Margin::same(20.0))
+                .show(ui, |ui| app.flashcard_reviewer.display(ui, deck));
         }
-        return;
+        None => app.flashcard_reviewer.is_fullscreen = false,
+    }
+    if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+        app.flashcard_reviewer.is_fullscreen = false;
     }
 
-    egui::TopBottomPanel::top("flashcard_tab_header").show_inside(ui, |ui| {
+    open_card_source(app);
+    record_reviews(app);
+    card_history::display(ui.ctx(), &app.study_data.decks);
+}
+
+pub fn display(ui: &mut egui::Ui, _ctx: &egui::Context, app: &mut StudyTimerApp) {
+    egui::TopBottomPanel::top(ui.id().with("flashcard_tab_header")).show_inside(ui, |ui| {
         ui.horizontal(|ui| {
             ui.heading("🎯 Flashcards");
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .button("📊 Review Heatmap")
+                    .on_hover_text("Cards reviewed per day")
+                    .clicked()
+                {
+                    review_heatmap::open();
+                }
+                if ui
+                    .button("👥 Shared Decks")
+                    .on_hover_text("Sync decks with your study group")
+                    .clicked()
+                {
+                    app.show_deck_sync = true;
+                }
+                if ui
+                    .button("📡 Share Nearby")
+                    .on_hover_text("Send a deck to FocusPad on another computer nearby")
+                    .clicked()
+                {
+                    if let Some(deck_id) = app.deck_manager_ui.selected_deck_id {
+                        crate::ui::lan_share_ui::choose_deck(deck_id);
+                    }
+                    app.show_lan_share = true;
+                }
+                if crate::ui::card_suggestions::is_enabled()
+                    && ui
+                        .button("✨ Suggest Cards")
+                        .on_hover_text("Turn pasted notes into flashcards")
+                        .clicked()
+                {
+                    crate::ui::card_suggestions::open_with_text(
+                        String::new(),
+                        app.deck_manager_ui.selected_deck_id,
+                    );
+                }
                 ui.label(format!(
                     "📅 Due cards: {}",
                     app.study_data.get_due_cards_count()
@@ -33,16 +83,52 @@ pub fn display(ui: &mut egui::Ui, _ctx: &egui::Context, app: &mut StudyTimerApp)
             display_single_view(ui, app);
         }
     });
+
+    open_card_source(app);
+    record_reviews(app);
+    review_heatmap::display(ui.ctx(), &app.study_data, &app.settings);
+    card_history::display(ui.ctx(), &app.study_data.decks);
+    if std::mem::take(&mut app.flashcard_reviewer.deck_changed) {
+        if let Err(err) = app.study_data.save() {
+            app.status.error(&format!("Error saving: {}", err));
+        }
+    }
+    if let Some(message) = app.deck_manager_ui.status_message.take() {
+        app.status.show(&message);
+    }
+}
+
+// "Go to source" in the reviewer opens the note the card was extracted from
+fn open_card_source(app: &mut StudyTimerApp) {
+    if let Some(source) = app.flashcard_reviewer.open_source.take() {
+        // The note opens in a tab, which fullscreen review would hide
+        app.flashcard_reviewer.is_fullscreen = false;
+        app.open_note_at(
+            std::path::Path::new(&source.path),
+            source.heading.as_deref(),
+        );
+    }
+}
+
+// Grades from the reviewer go into the review log behind the heatmap
+fn record_reviews(app: &mut StudyTimerApp) {
+    let reviews = std::mem::take(&mut app.flashcard_reviewer.logged_reviews);
+    app.study_data.review_log.extend(reviews);
 }
 
 fn display_single_view(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
+    if app.deck_manager_ui.quiz.is_some() {
+        display_quiz(ui, app);
+        return;
+    }
+
     match app.deck_manager_ui.view_mode {
         crate::ui::flashcard_ui::ViewMode::DeckList => {
             // Show deck management only
             let needs_save = app.deck_manager_ui.display(ui, &mut app.study_data.decks);
             if needs_save {
                 if let Err(err) = app.study_data.save() {
-                    app.status.show(&format!("Error saving: {}", err));
+                    app.status.error(&format!("Error saving: {}", err));
                 }
             }
         }
@@ -56,7 +142,7 @@ fn display_single_view(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
                         if let Some(deck) =
                             app.study_data.decks.iter_mut().find(|d| d.id == deck_id)
                         {
-                            egui::SidePanel::left("review_panel")
+                            egui::SidePanel::left(ui.id().with("review_panel"))
                                 .resizable(true)
                                 .exact_width(350.0)
                                 .min_width(300.0)
@@ -93,7 +179,7 @@ fn display_single_view(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
                         let needs_save = app.deck_manager_ui.display(ui, &mut app.study_data.decks);
                         if needs_save {
                             if let Err(err) = app.study_data.save() {
-                                app.status.show(&format!("Error saving: {}", err));
+                                app.status.error(&format!("Error saving: {}", err));
                             }
                         }
                     });
@@ -132,6 +218,24 @@ fn display_single_view(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
     }
 }
 
+fn display_quiz(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
+    let action = match &mut app.deck_manager_ui.quiz {
+        Some(quiz) => quiz_ui::display(ui, quiz),
+        None => return,
+    };
+
+    match action {
+        QuizAction::None => {}
+        QuizAction::Finished(result) => {
+            app.study_data.quiz_results.push(result);
+            if let Err(err) = app.study_data.save() {
+                app.status.error(&format!("Error saving: {}", err));
+            }
+        }
+        QuizAction::Close => app.deck_manager_ui.quiz = None,
+    }
+}
+
 fn display_split_view(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
     // Handle split view functionality if needed
     // For now, just display the single view
@@ -1,13 +1,30 @@
+use crate::data::TrashedItem;
+use crate::error;
 use crate::image_handler::{CardImage, ImageManager};
-use crate::ui::flashcard::{Deck, Grade};
+use crate::pdf_export::{self, DeckLayout};
+use crate::quizlet_import::{self, CardSeparator, ImportOptions, TermSeparator};
+use crate::ui::flashcard::{
+    Card, CardSource, Deck, DeckSettings, Grade, ImageSize, NewCardOrder, ReviewLogEntry,
+    ReviewOrder, ReviewPreset,
+};
+use crate::ui::card_history;
+use crate::ui::deck_merge::{self, DeckMerge, MergeAction};
+use crate::ui::duplicate_finder::{self, DuplicateAction, DuplicateFinder};
+use crate::ui::quiz_ui::QuizSession;
+use crate::ui::rich_text::linkified_label;
+use crate::ui::spell_check_ui;
+use crate::vault_export;
 use arboard::Clipboard;
 use base64::Engine;
 use eframe::egui;
 use egui::TextureHandle;
 use image;
 use rand::prelude::SliceRandom;
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 #[allow(dead_code)]
 pub struct FlashcardReviewer {
@@ -24,13 +41,26 @@ pub struct FlashcardReviewer {
     edit_card_back: String,
     review_mode: ReviewMode,
     current_difficulty_filter: Option<Grade>,
-    weighted_cards: Vec<usize>,
+    weighted_cards: Vec<(usize, bool)>, // (card index, reversed)
     pub show_image_dialog: bool,
     pub pending_image_side: Option<ImageSide>, // Front or Back
     pub selected_image_path: Option<PathBuf>,
     pub algorithm_enabled: bool,
     texture_cache: HashMap<u64, TextureHandle>,
     pub right_panel_open: bool,
+    pub timed_mode: bool,
+    pub answer_time_limit: u32, // Seconds
+    question_shown_at: Option<Instant>,
+    answer_seconds: Option<f32>, // How long the current card took to reveal
+    swipe_start: Option<egui::Pos2>, // Where a swipe over the revealed card began
+    timed_out: bool,
+    pub daily_limit: u32, // Reviews per day in the deck; 0 for no limit
+    pub review_order: ReviewOrder,
+    shuffle_seed: u64, // Keeps a shuffled order stable until the session starts over
+    preset_name: String,
+    pub deck_changed: bool, // Presets were saved on the deck, which should be written out
+    pub open_source: Option<CardSource>, // "Go to source" was clicked, for the app to open
+    pub logged_reviews: Vec<ReviewLogEntry>, // Graded since the last frame, for the review log
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -68,6 +98,19 @@ impl FlashcardReviewer {
             selected_image_path: None,
             texture_cache: HashMap::new(),
             right_panel_open: true,
+            timed_mode: false,
+            answer_time_limit: 20,
+            question_shown_at: None,
+            answer_seconds: None,
+            swipe_start: None,
+            timed_out: false,
+            daily_limit: 0,
+            review_order: ReviewOrder::Weighted,
+            shuffle_seed: 0,
+            preset_name: String::new(),
+            deck_changed: false,
+            open_source: None,
+            logged_reviews: Vec::new(),
         }
     }
 
@@ -87,6 +130,9 @@ impl FlashcardReviewer {
         back_to_decks: &mut bool,
         right_panel_open: &mut bool,
     ) {
+        if self.selected_deck_id != Some(deck.id) {
+            self.open_deck(deck);
+        }
         if self.is_fullscreen {
             self.display_fullscreen(ui, deck);
         } else {
@@ -225,6 +271,8 @@ impl FlashcardReviewer {
             ui.horizontal(|ui| {
                 if ui.button("← Back to Decks").clicked() {
                     *back_to_decks = true;
+                    // Opening the deck again starts from its settings
+                    self.selected_deck_id = None;
                 }
                 ui.separator();
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -244,6 +292,8 @@ impl FlashcardReviewer {
 
             ui.separator();
 
+            self.render_presets(ui, deck);
+
             // Review mode selection
             ui.horizontal(|ui| {
                 ui.label("Review Mode:");
@@ -301,6 +351,20 @@ impl FlashcardReviewer {
                 }
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Order:");
+                for order in ReviewOrder::ALL {
+                    if ui
+                        .selectable_label(self.review_order == order, order.name())
+                        .on_hover_text(order.description())
+                        .clicked()
+                    {
+                        self.review_order = order;
+                        self.reset_review_session(deck);
+                    }
+                }
+            });
+
             ui.separator();
 
             // Algorithm toggle
@@ -315,28 +379,62 @@ impl FlashcardReviewer {
                 ui.label("(When disabled, all cards are always available for review)");
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Timed Answers:");
+                if ui
+                    .checkbox(&mut self.timed_mode, "Countdown per card")
+                    .changed()
+                {
+                    self.question_shown_at = None;
+                    self.timed_out = false;
+                }
+                ui.add_enabled(
+                    self.timed_mode,
+                    egui::DragValue::new(&mut self.answer_time_limit)
+                        .clamp_range(5..=300)
+                        .suffix(" s"),
+                );
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Daily Limit:");
+                ui.add(
+                    egui::DragValue::new(&mut self.daily_limit)
+                        .clamp_range(0..=9999)
+                        .suffix(" reviews"),
+                );
+                ui.label(format!("({} today, 0 for no limit)", deck.reviews_today()));
+            });
+
             ui.separator();
 
             // Get card data first, then drop the borrow
-            let card_data = if let Some(card) = self.get_current_card(deck) {
-                Some((
-                    card.front.clone(),
-                    card.back.clone(),
-                    card.front_image.clone(),
-                    card.back_image.clone(),
-                ))
-            } else {
-                None
-            };
-
-            if let Some((card_front, card_back, front_image, back_image)) = card_data {
+            let image_scale = deck.settings.image_size.scale();
+            let card_data = self.get_current_card_data(deck);
+            let card_notes = self.get_current_card_notes(deck);
+            let card_source = self.get_current_card_source(deck);
+            let card_id = self
+                .get_current_entry(deck)
+                .and_then(|(index, _)| deck.cards.get(index))
+                .map(|card| card.id);
+
+            if let Some((card_front, card_back, front_image, back_image, reversed)) = card_data {
+                let swipe_top = ui.cursor().top();
                 // Card counter
                 let total_cards = self.get_review_cards_count(deck);
-                ui.label(format!(
-                    "Card {} of {}",
-                    self.current_card_index + 1,
-                    total_cards
-                ));
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "Card {} of {}",
+                        self.current_card_index + 1,
+                        total_cards
+                    ));
+                    if reversed {
+                        ui.label(
+                            egui::RichText::new("↺ Reversed")
+                                .color(egui::Color32::from_rgb(23, 162, 184)),
+                        );
+                    }
+                });
                 ui.add_space(10.0);
 
                 // Question
@@ -358,7 +456,11 @@ impl FlashcardReviewer {
                                     // Display front image if available with smaller size
                                     if let Some(front_image) = &front_image {
                                         ui.add_space(10.0);
-                                        self.display_image(ui, front_image, [250.0, 150.0]);
+                                        self.display_image(
+                                            ui,
+                                            front_image,
+                                            scaled([250.0, 150.0], image_scale),
+                                        );
                                         // Reduced from [300.0, 200.0]
                                     }
                                 });
@@ -368,14 +470,18 @@ impl FlashcardReviewer {
 
                 ui.add_space(10.0);
 
+                self.render_countdown(ui);
+
                 if self.show_answer {
+                    self.render_timeout_hint(ui, 14.0);
+
                     // Grade buttons in the middle (between question and answer)
                     ui.horizontal(|ui| {
                         ui.spacing_mut().button_padding = egui::vec2(12.0, 8.0);
 
                         if ui
                             .button(
-                                egui::RichText::new("Again")
+                                egui::RichText::new("✖ Again")
                                     .color(egui::Color32::from_rgb(220, 53, 69)),
                             )
                             .clicked()
@@ -384,7 +490,7 @@ impl FlashcardReviewer {
                         }
                         if ui
                             .button(
-                                egui::RichText::new("Hard")
+                                egui::RichText::new("⚠ Hard")
                                     .color(egui::Color32::from_rgb(255, 193, 7)),
                             )
                             .clicked()
@@ -393,7 +499,7 @@ impl FlashcardReviewer {
                         }
                         if ui
                             .button(
-                                egui::RichText::new("Good")
+                                egui::RichText::new("✔ Good")
                                     .color(egui::Color32::from_rgb(40, 167, 69)),
                             )
                             .clicked()
@@ -402,7 +508,7 @@ impl FlashcardReviewer {
                         }
                         if ui
                             .button(
-                                egui::RichText::new("Easy")
+                                egui::RichText::new("★ Easy")
                                     .color(egui::Color32::from_rgb(23, 162, 184)),
                             )
                             .clicked()
@@ -427,29 +533,69 @@ impl FlashcardReviewer {
                                     .min_scrolled_height(150.0) // Reduced from 200.0
                                     .auto_shrink([false; 2])
                                     .show(ui, |ui| {
-                                        ui.label(egui::RichText::new(&card_back).size(14.0));
+                                        linkified_label(
+                                            ui,
+                                            egui::RichText::new(&card_back).size(14.0),
+                                        );
 
                                         // Display back image if available with smaller size
                                         if let Some(back_image) = &back_image {
                                             ui.add_space(10.0);
-                                            self.display_image(ui, back_image, [250.0, 150.0]);
+                                            self.display_image(
+                                                ui,
+                                                back_image,
+                                                scaled([250.0, 150.0], image_scale),
+                                            );
                                             // Reduced from [400.0, 300.0]
                                         }
                                     });
                             });
                         });
                     });
+
+                    if let Some(notes) = &card_notes {
+                        ui.add_space(10.0);
+                        ui.group(|ui| {
+                            ui.vertical(|ui| {
+                                ui.label(egui::RichText::new("Notes:").strong());
+                                linkified_label(ui, egui::RichText::new(notes).italics());
+                            });
+                        });
+                    }
+
+                    ui.add_space(5.0);
+                    ui.horizontal(|ui| {
+                        if let Some(source) = card_source {
+                            if ui
+                                .button("📄 Go to source")
+                                .on_hover_text(source.label())
+                                .clicked()
+                            {
+                                self.open_source = Some(source);
+                            }
+                        }
+                        if let Some(card_id) = card_id {
+                            if ui.button("🕘 History").clicked() {
+                                card_history::open(deck.id, card_id);
+                            }
+                        }
+                    });
+
+                    if let Some(grade) = self.handle_swipe(ui, swipe_top) {
+                        self.grade_card(deck, grade);
+                    }
                 } else {
                     if ui
                         .button(egui::RichText::new("Show Answer").size(16.0))
                         .clicked()
                     {
-                        self.show_answer = true;
+                        self.reveal_answer();
                     }
                 }
             } else {
+                let message = self.no_cards_message(deck);
                 ui.centered_and_justified(|ui| {
-                    ui.label(egui::RichText::new("No cards available for review").size(18.0));
+                    ui.label(egui::RichText::new(message).size(18.0));
                 });
             }
         });
@@ -475,21 +621,23 @@ impl FlashcardReviewer {
             ui.add_space(20.0);
 
             // Get card data first, then drop the borrow
-            let card_data = if let Some(card) = self.get_current_card(deck) {
-                Some((
-                    card.front.clone(),
-                    card.back.clone(),
-                    card.front_image.clone(),
-                    card.back_image.clone(),
-                ))
-            } else {
-                None
-            };
+            let image_scale = deck.settings.image_size.scale();
+            let card_data = self.get_current_card_data(deck);
+            let card_notes = self.get_current_card_notes(deck);
+            let card_source = self.get_current_card_source(deck);
 
-            if let Some((card_front, card_back, front_image, back_image)) = card_data {
+            if let Some((card_front, card_back, front_image, back_image, reversed)) = card_data {
+                let swipe_top = ui.cursor().top();
                 // Question
                 ui.add_space(20.0);
                 ui.label(egui::RichText::new("Question").size(24.0).strong());
+                if reversed {
+                    ui.label(
+                        egui::RichText::new("↺ Reversed")
+                            .size(16.0)
+                            .color(egui::Color32::from_rgb(23, 162, 184)),
+                    );
+                }
                 ui.add_space(15.0);
 
                 // Question content with size limit
@@ -504,7 +652,11 @@ impl FlashcardReviewer {
                                 // Display front image if available with controlled size
                                 if let Some(front_image) = &front_image {
                                     ui.add_space(10.0);
-                                    self.display_image(ui, front_image, [350.0, 200.0]);
+                                    self.display_image(
+                                        ui,
+                                        front_image,
+                                        scaled([350.0, 200.0], image_scale),
+                                    );
                                     // Reduced from [400.0, 300.0]
                                 }
                             });
@@ -513,9 +665,12 @@ impl FlashcardReviewer {
 
                 ui.add_space(20.0);
 
+                self.render_countdown(ui);
+
                 if self.show_answer {
                     ui.label(egui::RichText::new("Answer").size(24.0).strong());
                     ui.add_space(15.0);
+                    self.render_timeout_hint(ui, 18.0);
 
                     // Grade buttons FIRST - always visible at the top
                     ui.allocate_ui_with_layout(
@@ -526,13 +681,13 @@ impl FlashcardReviewer {
 
                             // Add flexible space before buttons
                             ui.allocate_space(egui::Vec2::new(
-                                (ui.available_width() - 320.0) / 2.0, // Reduced from 350.0
+                                (ui.available_width() - 380.0) / 2.0, // Room for the grade symbols
                                 0.0,
                             ));
 
                             if ui
                                 .button(
-                                    egui::RichText::new("Again")
+                                    egui::RichText::new("✖ Again")
                                         .size(16.0) // Reduced from 18.0
                                         .color(egui::Color32::from_rgb(220, 53, 69)),
                                 )
@@ -542,7 +697,7 @@ impl FlashcardReviewer {
                             }
                             if ui
                                 .button(
-                                    egui::RichText::new("Hard")
+                                    egui::RichText::new("⚠ Hard")
                                         .size(16.0) // Reduced from 18.0
                                         .color(egui::Color32::from_rgb(255, 193, 7)),
                                 )
@@ -552,7 +707,7 @@ impl FlashcardReviewer {
                             }
                             if ui
                                 .button(
-                                    egui::RichText::new("Good")
+                                    egui::RichText::new("✔ Good")
                                         .size(16.0) // Reduced from 18.0
                                         .color(egui::Color32::from_rgb(40, 167, 69)),
                                 )
@@ -562,7 +717,7 @@ impl FlashcardReviewer {
                             }
                             if ui
                                 .button(
-                                    egui::RichText::new("Easy")
+                                    egui::RichText::new("★ Easy")
                                         .size(16.0) // Reduced from 18.0
                                         .color(egui::Color32::from_rgb(23, 162, 184)),
                                 )
@@ -582,96 +737,428 @@ impl FlashcardReviewer {
                             .max_height(300.0) // Limit answer area height
                             .show(ui, |ui| {
                                 ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
-                                    ui.label(egui::RichText::new(&card_back).size(28.0)); // Reduced from 32.0
+                                    linkified_label(ui, egui::RichText::new(&card_back).size(28.0)); // Reduced from 32.0
 
                                     // Display back image if available with controlled size
                                     if let Some(back_image) = &back_image {
                                         ui.add_space(10.0);
-                                        self.display_image(ui, back_image, [350.0, 200.0]);
+                                        self.display_image(
+                                            ui,
+                                            back_image,
+                                            scaled([350.0, 200.0], image_scale),
+                                        );
                                         // Reduced from [400.0, 300.0]
                                     }
                                 });
                             });
                     });
+
+                    if let Some(notes) = &card_notes {
+                        ui.add_space(15.0);
+                        linkified_label(ui, egui::RichText::new(notes).size(18.0).italics());
+                    }
+
+                    if let Some(source) = card_source {
+                        ui.add_space(15.0);
+                        if ui
+                            .button(egui::RichText::new("📄 Go to source").size(16.0))
+                            .on_hover_text(source.label())
+                            .clicked()
+                        {
+                            // The note opens in a tab, which fullscreen review would cover
+                            self.is_fullscreen = false;
+                            self.open_source = Some(source);
+                        }
+                    }
+
+                    if let Some(grade) = self.handle_swipe(ui, swipe_top) {
+                        self.grade_card(deck, grade);
+                    }
                 } else {
                     if ui
                         .button(egui::RichText::new("Show Answer").size(20.0))
                         .clicked()
                     {
-                        self.show_answer = true;
+                        self.reveal_answer();
                     }
                 }
             } else {
-                ui.label(egui::RichText::new("No cards available for review").size(24.0));
+                ui.label(egui::RichText::new(self.no_cards_message(deck)).size(24.0));
             }
         });
     }
 
-    fn get_current_card<'a>(&self, deck: &'a Deck) -> Option<&'a crate::ui::flashcard::Card> {
-        match &self.review_mode {
-            ReviewMode::All => {
-                if self.weighted_cards.is_empty() {
-                    None
-                } else {
-                    let card_index =
-                        self.weighted_cards[self.current_card_index % self.weighted_cards.len()];
-                    deck.cards.get(card_index)
+    fn daily_limit_reached(&self, deck: &Deck) -> bool {
+        self.daily_limit > 0 && deck.reviews_today() >= self.daily_limit as usize
+    }
+
+    fn no_cards_message(&self, deck: &Deck) -> String {
+        if self.daily_limit_reached(deck) {
+            format!(
+                "Daily limit of {} reviews reached for this deck",
+                self.daily_limit
+            )
+        } else {
+            "No cards available for review".to_string()
+        }
+    }
+
+    fn current_preset(&self, name: &str) -> ReviewPreset {
+        ReviewPreset {
+            name: name.to_string(),
+            difficulty: match &self.review_mode {
+                ReviewMode::All => None,
+                ReviewMode::ByDifficulty(grade) => Some(grade.clone()),
+            },
+            algorithm_enabled: self.algorithm_enabled,
+            daily_limit: self.daily_limit,
+            timed_mode: self.timed_mode,
+            answer_time_limit: self.answer_time_limit,
+            order: self.review_order,
+        }
+    }
+
+    // Starts the deck from its own settings: the default preset if it still exists,
+    // otherwise just its daily limit
+    fn open_deck(&mut self, deck: &Deck) {
+        self.selected_deck_id = Some(deck.id);
+        let preset = deck.settings.default_preset.as_ref().and_then(|name| {
+            deck.review_presets
+                .iter()
+                .find(|preset| preset.name == *name)
+        });
+        match preset {
+            Some(preset) => self.apply_preset(preset, deck),
+            None => {
+                self.daily_limit = deck.settings.daily_limit;
+                self.reset_review_session(deck);
+            }
+        }
+    }
+
+    fn apply_preset(&mut self, preset: &ReviewPreset, deck: &Deck) {
+        self.review_mode = match &preset.difficulty {
+            Some(grade) => ReviewMode::ByDifficulty(grade.clone()),
+            None => ReviewMode::All,
+        };
+        self.algorithm_enabled = preset.algorithm_enabled;
+        self.daily_limit = preset.daily_limit;
+        self.timed_mode = preset.timed_mode;
+        self.answer_time_limit = preset.answer_time_limit;
+        self.review_order = preset.order;
+        self.reset_review_session(deck);
+    }
+
+    // The deck's saved presets, one click each, and saving the current settings as one
+    fn render_presets(&mut self, ui: &mut egui::Ui, deck: &mut Deck) {
+        let mut applied = None;
+        let mut removed = None;
+        let mut updated = None;
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Presets:");
+            for (index, preset) in deck.review_presets.iter().enumerate() {
+                let active = self.current_preset(&preset.name) == *preset;
+                let response = ui
+                    .selectable_label(active, &preset.name)
+                    .on_hover_text(describe_preset(preset));
+                if response.clicked() {
+                    applied = Some(index);
+                }
+                response.context_menu(|ui| {
+                    if ui.button("💾 Update to Current Settings").clicked() {
+                        updated = Some(index);
+                        ui.close_menu();
+                    }
+                    if ui.button("🗑 Delete Preset").clicked() {
+                        removed = Some(index);
+                        ui.close_menu();
+                    }
+                });
+            }
+
+            ui.menu_button("➕ Save Preset", |ui| {
+                ui.label("Save the settings below on this deck");
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.preset_name)
+                        .hint_text("Cram mode")
+                        .desired_width(160.0),
+                );
+                let submitted =
+                    response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                let name = self.preset_name.trim().to_string();
+                if (ui
+                    .add_enabled(!name.is_empty(), egui::Button::new("Save"))
+                    .clicked()
+                    || submitted)
+                    && !name.is_empty()
+                {
+                    // Saving under an existing name replaces that preset
+                    let preset = self.current_preset(&name);
+                    match deck.review_presets.iter_mut().find(|p| p.name == name) {
+                        Some(existing) => *existing = preset,
+                        None => deck.review_presets.push(preset),
+                    }
+                    self.preset_name.clear();
+                    self.deck_changed = true;
+                    ui.close_menu();
                 }
+            });
+        });
+
+        if let Some(index) = applied {
+            let preset = deck.review_presets[index].clone();
+            self.apply_preset(&preset, deck);
+        }
+        if let Some(index) = updated {
+            let name = deck.review_presets[index].name.clone();
+            deck.review_presets[index] = self.current_preset(&name);
+            self.deck_changed = true;
+        }
+        if let Some(index) = removed {
+            deck.review_presets.remove(index);
+            self.deck_changed = true;
+        }
+        ui.separator();
+    }
+
+    fn reveal_answer(&mut self) {
+        self.show_answer = true;
+        self.answer_seconds = self
+            .question_shown_at
+            .map(|shown| shown.elapsed().as_secs_f32());
+    }
+
+    // Counts down while the question is showing and reveals the answer when time runs out.
+    // The question's start is kept in every mode for answer times.
+    fn render_countdown(&mut self, ui: &mut egui::Ui) {
+        if self.show_answer {
+            return;
+        }
+        let started = *self.question_shown_at.get_or_insert_with(Instant::now);
+        if !self.timed_mode {
+            return;
+        }
+
+        let limit = self.answer_time_limit.max(1) as f32;
+        let remaining = limit - started.elapsed().as_secs_f32();
+        if remaining <= 0.0 {
+            self.reveal_answer();
+            self.timed_out = true;
+            return;
+        }
+
+        let fraction = remaining / limit;
+        let color = if fraction < 0.25 {
+            egui::Color32::from_rgb(220, 53, 69)
+        } else if fraction < 0.5 {
+            egui::Color32::from_rgb(255, 193, 7)
+        } else {
+            egui::Color32::from_rgb(40, 167, 69)
+        };
+        ui.add(
+            egui::ProgressBar::new(fraction)
+                .fill(color)
+                .text(format!("⏱ {:.0}s", remaining.ceil())),
+        );
+        ui.add_space(10.0);
+        ui.ctx().request_repaint_after(Duration::from_millis(100));
+    }
+
+    // Swiping over the revealed card grades it: left for Again, right for Good, up for Easy.
+    // The card is tinted in the grade's color while the swipe is under way.
+    fn handle_swipe(&mut self, ui: &mut egui::Ui, top: f32) -> Option<Grade> {
+        let area = egui::Rect::from_min_max(
+            egui::pos2(ui.max_rect().left(), top),
+            egui::pos2(ui.max_rect().right(), ui.cursor().top()),
+        );
+        let (pressed, released, origin, position) = ui.input(|i| {
+            (
+                i.pointer.any_pressed(),
+                i.pointer.any_released(),
+                i.pointer.press_origin(),
+                i.pointer.latest_pos(),
+            )
+        });
+        if pressed {
+            self.swipe_start = origin.filter(|pos| area.contains(*pos));
+        }
+        let start = self.swipe_start?;
+        let offset = position.map_or(egui::Vec2::ZERO, |pos| pos - start);
+        let swipe = swipe_grade(offset);
+        if released {
+            self.swipe_start = None;
+            return swipe.and_then(|(grade, progress)| (progress >= 1.0).then_some(grade));
+        }
+
+        if let Some((grade, progress)) = swipe {
+            let (label, color) = match grade {
+                Grade::Again => ("✖ Again", egui::Color32::from_rgb(220, 53, 69)),
+                Grade::Good => ("✔ Good", egui::Color32::from_rgb(40, 167, 69)),
+                _ => ("★ Easy", egui::Color32::from_rgb(23, 162, 184)),
+            };
+            let painter = ui.painter();
+            painter.rect_filled(area, 8.0, color.gamma_multiply(0.35 * progress));
+            if progress >= 1.0 {
+                painter.rect_stroke(area, 8.0, egui::Stroke::new(3.0, color));
             }
-            ReviewMode::ByDifficulty(grade) => {
-                let filtered_cards =
-                    deck.get_cards_by_difficulty_for_review(grade, self.algorithm_enabled);
-                filtered_cards.get(self.current_card_index).copied() // Convert from &&Card to &Card
+            painter.text(
+                area.center(),
+                egui::Align2::CENTER_CENTER,
+                label,
+                egui::FontId::proportional(20.0 + 12.0 * progress),
+                color,
+            );
+        }
+        None
+    }
+
+    fn render_timeout_hint(&self, ui: &mut egui::Ui, size: f32) {
+        if self.timed_out {
+            ui.label(
+                egui::RichText::new("⏰ Time's up! Consider grading this card Again.")
+                    .size(size)
+                    .color(egui::Color32::from_rgb(220, 53, 69)),
+            );
+            ui.add_space(5.0);
+        }
+    }
+
+    // Cards of the chosen difficulty, with reversed directions graded on their own history
+    fn difficulty_entries(&self, deck: &Deck, grade: &Grade) -> Vec<(usize, bool)> {
+        let mut entries: Vec<(usize, bool)> = deck
+            .card_indices_by_difficulty_for_review(grade, self.algorithm_enabled)
+            .into_iter()
+            .map(|index| (index, false))
+            .collect();
+        for (index, card) in deck.cards.iter().enumerate() {
+            if card.has_reverse(deck.reverse_cards)
+                && card.is_reverse_due(self.algorithm_enabled)
+                && std::mem::discriminant(&card.get_reverse_difficulty())
+                    == std::mem::discriminant(grade)
+            {
+                entries.push((index, true));
             }
         }
+        self.order_entries(deck, &mut entries);
+        entries
     }
 
-    fn get_current_card_mut<'a>(
-        &mut self,
-        deck: &'a mut Deck,
-    ) -> Option<&'a mut crate::ui::flashcard::Card> {
+    // Sorts entries for the ordered and shuffled review orders. Reversed entries come
+    // after all forward ones, so a card's answer isn't asked right after it was shown.
+    fn order_entries(&self, deck: &Deck, entries: &mut [(usize, bool)]) {
+        match self.review_order {
+            ReviewOrder::Weighted => {}
+            ReviewOrder::Shuffled => entries.sort_by_key(|entry| {
+                let mut hasher = DefaultHasher::new();
+                (self.shuffle_seed, entry).hash(&mut hasher);
+                hasher.finish()
+            }),
+            ReviewOrder::Created => entries.sort_by_key(|&(index, reversed)| {
+                let card = &deck.cards[index];
+                (reversed, card.created_at.clone(), card.id)
+            }),
+            ReviewOrder::Alphabetical => entries.sort_by_cached_key(|&(index, reversed)| {
+                let card = &deck.cards[index];
+                let question = if reversed { &card.back } else { &card.front };
+                (reversed, question.to_lowercase())
+            }),
+        }
+    }
+
+    fn get_current_entry(&self, deck: &Deck) -> Option<(usize, bool)> {
         match &self.review_mode {
             ReviewMode::All => {
                 if self.weighted_cards.is_empty() {
                     None
                 } else {
-                    let card_index =
-                        self.weighted_cards[self.current_card_index % self.weighted_cards.len()];
-                    deck.cards.get_mut(card_index)
-                }
-            }
-            ReviewMode::ByDifficulty(grade) => {
-                // For difficulty-specific review, we need to find the actual card by comparing
-                let filtered_cards =
-                    deck.get_cards_by_difficulty_for_review(grade, self.algorithm_enabled);
-                if let Some(&target_card) = filtered_cards.get(self.current_card_index) {
-                    let target_id = target_card.id;
-                    deck.cards.iter_mut().find(|c| c.id == target_id)
-                } else {
-                    None
+                    Some(self.weighted_cards[self.current_card_index % self.weighted_cards.len()])
                 }
             }
+            ReviewMode::ByDifficulty(grade) => self
+                .difficulty_entries(deck, grade)
+                .get(self.current_card_index)
+                .copied(),
+        }
+    }
+
+    // Front, back and images of the current card, swapped when it is shown reversed
+    fn get_current_card_data(
+        &self,
+        deck: &Deck,
+    ) -> Option<(String, String, Option<CardImage>, Option<CardImage>, bool)> {
+        if self.daily_limit_reached(deck) {
+            return None;
+        }
+        let (index, reversed) = self.get_current_entry(deck)?;
+        let card = deck.cards.get(index)?;
+        if reversed {
+            Some((
+                card.back.clone(),
+                card.front.clone(),
+                card.back_image.clone(),
+                card.front_image.clone(),
+                true,
+            ))
+        } else {
+            Some((
+                card.front.clone(),
+                card.back.clone(),
+                card.front_image.clone(),
+                card.back_image.clone(),
+                false,
+            ))
         }
     }
 
+    // Notes belong to the card, so they show in both directions
+    fn get_current_card_notes(&self, deck: &Deck) -> Option<String> {
+        let card = self.get_current_card(deck)?;
+        let notes = card.notes.trim();
+        (!notes.is_empty()).then(|| notes.to_string())
+    }
+
+    fn get_current_card_source(&self, deck: &Deck) -> Option<CardSource> {
+        self.get_current_card(deck)?.source.clone()
+    }
+
+    fn get_current_card<'a>(&self, deck: &'a Deck) -> Option<&'a Card> {
+        let (index, _) = self.get_current_entry(deck)?;
+        deck.cards.get(index)
+    }
+
     fn get_review_cards_count(&self, deck: &Deck) -> usize {
         match &self.review_mode {
             ReviewMode::All => self.weighted_cards.len(),
-            ReviewMode::ByDifficulty(grade) => deck
-                .get_cards_by_difficulty_for_review(grade, self.algorithm_enabled)
-                .len(),
+            ReviewMode::ByDifficulty(grade) => self.difficulty_entries(deck, grade).len(),
         }
     }
 
     fn grade_card(&mut self, deck: &mut Deck, grade: Grade) {
-        if let Some(card) = self.get_current_card_mut(deck) {
-            card.add_review(grade, self.algorithm_enabled);
+        if let Some((index, reversed)) = self.get_current_entry(deck) {
+            if let Some(card) = deck.cards.get_mut(index) {
+                let seconds = self.answer_seconds.take();
+                self.logged_reviews.push(ReviewLogEntry::new(
+                    deck.id,
+                    card.id,
+                    grade.clone(),
+                    reversed,
+                    seconds,
+                ));
+                if reversed {
+                    card.add_reverse_review(grade, self.algorithm_enabled, seconds);
+                } else {
+                    card.add_review(grade, self.algorithm_enabled, seconds);
+                }
+            }
         }
         self.next_card(deck);
     }
 
     fn next_card(&mut self, deck: &Deck) {
         self.show_answer = false;
+        self.question_shown_at = None;
+        self.answer_seconds = None;
+        self.timed_out = false;
         let total_cards = self.get_review_cards_count(deck);
 
         if total_cards > 0 {
@@ -680,6 +1167,10 @@ impl FlashcardReviewer {
             self.current_card_index = 0;
         }
 
+        // A completed cycle starts over with a fresh shuffle
+        if self.current_card_index == 0 {
+            self.shuffle_seed = rand::random();
+        }
         // If we're in "All" mode and completed a cycle, refresh the weighted cards
         if matches!(self.review_mode, ReviewMode::All) && self.current_card_index == 0 {
             self.setup_weighted_cards(deck);
@@ -689,6 +1180,10 @@ impl FlashcardReviewer {
     fn reset_review_session(&mut self, deck: &Deck) {
         self.current_card_index = 0;
         self.show_answer = false;
+        self.question_shown_at = None;
+        self.answer_seconds = None;
+        self.timed_out = false;
+        self.shuffle_seed = rand::random();
 
         if matches!(self.review_mode, ReviewMode::All) {
             self.setup_weighted_cards(deck);
@@ -697,50 +1192,202 @@ impl FlashcardReviewer {
 
     fn setup_weighted_cards(&mut self, deck: &Deck) {
         self.weighted_cards.clear();
+        // Ordered and shuffled reviews show every card once
+        let weighted = self.review_order == ReviewOrder::Weighted;
 
         let due_cards = deck.get_due_cards(self.algorithm_enabled);
+        let median_seconds = deck.median_answer_seconds();
+        let mut new_cards = Vec::new();
 
         for (deck_index, card) in deck.cards.iter().enumerate() {
             // Only include due cards
             if !due_cards.iter().any(|&due_card| due_card.id == card.id) {
                 continue;
             }
+            if card.reviews.is_empty() {
+                new_cards.push(deck_index);
+                continue;
+            }
 
             let weight = match card.get_difficulty() {
+                _ if !weighted => 1,
                 Grade::Again | Grade::Hard => 4, // High frequency for difficult cards
                 Grade::Good | Grade::Easy => 2,  // Lower frequency for easier cards
             };
+            // Slow answers come up a little more often too
+            let weight = if weighted && card.is_slow(median_seconds) {
+                weight + 1
+            } else {
+                weight
+            };
+
+            for _ in 0..weight {
+                self.weighted_cards.push((deck_index, false));
+            }
+        }
+
+        // New cards are graded like "Again" cards when mixed in
+        let new_cards = deck.pick_new_cards(new_cards);
+        let mixed = deck.settings.new_card_order == NewCardOrder::Mixed;
+        if mixed {
+            let weight = if weighted { 4 } else { 1 };
+            for &deck_index in &new_cards {
+                for _ in 0..weight {
+                    self.weighted_cards.push((deck_index, false));
+                }
+            }
+        }
+
+        for (deck_index, card) in deck.cards.iter().enumerate() {
+            if !card.has_reverse(deck.reverse_cards) || !card.is_reverse_due(self.algorithm_enabled)
+            {
+                continue;
+            }
+
+            let weight = match card.get_reverse_difficulty() {
+                _ if !weighted => 1,
+                Grade::Again | Grade::Hard => 4,
+                Grade::Good | Grade::Easy => 2,
+            };
 
             for _ in 0..weight {
-                self.weighted_cards.push(deck_index);
+                self.weighted_cards.push((deck_index, true));
             }
         }
 
-        // Shuffle the weighted cards for randomness
-        let mut rng = rand::thread_rng();
-        self.weighted_cards.shuffle(&mut rng);
+        if weighted {
+            // Shuffle the weighted cards for randomness
+            let mut rng = rand::thread_rng();
+            self.weighted_cards.shuffle(&mut rng);
+        } else {
+            let mut entries = std::mem::take(&mut self.weighted_cards);
+            self.order_entries(deck, &mut entries);
+            self.weighted_cards = entries;
+        }
+        if !mixed {
+            self.weighted_cards
+                .extend(new_cards.into_iter().map(|deck_index| (deck_index, false)));
+        }
     }
 }
 
+const SWIPE_DISTANCE: f32 = 120.0;
+
+// The grade a swipe points at and how far along it is; releasing at 1.0 grades the card
+fn swipe_grade(offset: egui::Vec2) -> Option<(Grade, f32)> {
+    if offset.length() < 20.0 {
+        return None;
+    }
+    let (grade, distance) = if -offset.y > offset.x.abs() {
+        (Grade::Easy, -offset.y)
+    } else if offset.x.abs() > offset.y {
+        if offset.x < 0.0 {
+            (Grade::Again, -offset.x)
+        } else {
+            (Grade::Good, offset.x)
+        }
+    } else {
+        return None; // Down is left for scrolling
+    };
+    Some((grade, (distance / SWIPE_DISTANCE).min(1.0)))
+}
+
+// Image bounds for the deck's image size setting
+fn scaled(size: [f32; 2], scale: f32) -> [f32; 2] {
+    [size[0] * scale, size[1] * scale]
+}
+
+fn describe_preset(preset: &ReviewPreset) -> String {
+    let mut parts = vec![match &preset.difficulty {
+        Some(grade) => format!("{:?} cards", grade),
+        None => "All cards".to_string(),
+    }];
+    parts.push(preset.order.description().to_lowercase());
+    parts.push(if preset.algorithm_enabled {
+        "spaced repetition".to_string()
+    } else {
+        "every card available".to_string()
+    });
+    if preset.daily_limit > 0 {
+        parts.push(format!("{} reviews a day", preset.daily_limit));
+    }
+    if preset.timed_mode {
+        parts.push(format!("{} s per card", preset.answer_time_limit));
+    }
+    parts.join(", ")
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ViewMode {
     DeckList,
     DeckView,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CardSort {
+    Created,
+    Modified,
+    Difficulty,
+    Due,
+}
+
+impl CardSort {
+    pub const ALL: [CardSort; 4] = [
+        CardSort::Created,
+        CardSort::Modified,
+        CardSort::Difficulty,
+        CardSort::Due,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardSort::Created => "Created",
+            CardSort::Modified => "Modified",
+            CardSort::Difficulty => "Difficulty",
+            CardSort::Due => "Due",
+        }
+    }
+}
+
+// Hardest cards first: never reviewed and "Again" before "Easy"
+fn difficulty_rank(card: &Card) -> u8 {
+    match card.get_difficulty() {
+        _ if card.reviews.is_empty() => 0,
+        Grade::Again => 1,
+        Grade::Hard => 2,
+        Grade::Good => 3,
+        Grade::Easy => 4,
+    }
+}
+
 pub struct DeckManagerUI {
     pub selected_deck_id: Option<u64>,
     pub new_deck_name: String,
     pub new_deck_description: String,
     pub new_card_front: String,
     pub new_card_back: String,
+    pub new_card_reverse: bool,
     pub view_mode: ViewMode,
+    pub card_sort: CardSort,
     pub edit_deck_id: Option<u64>,
     pub edit_deck_name: String,
     pub edit_deck_description: String,
+    pub settings_deck_id: Option<u64>, // Deck whose ⚙ settings are open
+    pub settings_draft: DeckSettings,
+    pub settings_reverse: bool,
+    pub duplicate_deck_id: Option<u64>,
+    pub duplicate_deck_name: String,
+    pub duplicate_keep_progress: bool,
+    pub selected_cards: HashSet<u64>,
+    pub selected_cards_deck: Option<u64>, // Deck the selection belongs to
+    pub show_card_transfer: bool,
+    pub transfer_target: Option<u64>,
+    pub transfer_copy: bool,
     pub edit_card_id: Option<u64>,
     pub edit_card_front: String,
     pub edit_card_back: String,
+    pub edit_card_notes: String,
+    pub edit_card_reverse: bool,
     pub delete_confirmation: Option<String>, // Holds the type of item being deleted ("deck" or "card")
     pub item_to_delete: Option<u64>,         // ID of item to delete
     pub show_image_dialog: bool,
@@ -749,6 +1396,16 @@ pub struct DeckManagerUI {
     pub pending_front_image: Option<CardImage>,
     pub pending_back_image: Option<CardImage>,
     pub right_panel_open: bool, // New field for toggling right panel
+    pub show_quizlet_import: bool,
+    pub quizlet_text: String,
+    pub quizlet_deck_name: String,
+    pub quizlet_options: ImportOptions,
+    pub quizlet_error: Option<String>,
+    pub status_message: Option<String>, // Shown in the status bar by the flashcard tab
+    pub quiz: Option<QuizSession>,
+    pub duplicate_finder: Option<DuplicateFinder>,
+    pub deck_merge: Option<DeckMerge>,
+    pub trashed: Vec<TrashedItem>, // Deleted decks and cards, moved to the trash by the app
 }
 
 impl DeckManagerUI {
@@ -759,13 +1416,28 @@ impl DeckManagerUI {
             new_deck_description: String::new(),
             new_card_front: String::new(),
             new_card_back: String::new(),
+            new_card_reverse: false,
             view_mode: ViewMode::DeckList,
+            card_sort: CardSort::Created,
             edit_deck_id: None,
             edit_deck_name: String::new(),
             edit_deck_description: String::new(),
+            settings_deck_id: None,
+            settings_draft: DeckSettings::default(),
+            settings_reverse: false,
+            duplicate_deck_id: None,
+            duplicate_deck_name: String::new(),
+            duplicate_keep_progress: false,
+            selected_cards: HashSet::new(),
+            selected_cards_deck: None,
+            show_card_transfer: false,
+            transfer_target: None,
+            transfer_copy: false,
             edit_card_id: None,
             edit_card_front: String::new(),
             edit_card_back: String::new(),
+            edit_card_notes: String::new(),
+            edit_card_reverse: false,
             delete_confirmation: None,
             item_to_delete: None,
             show_image_dialog: false,
@@ -774,6 +1446,16 @@ impl DeckManagerUI {
             pending_front_image: None,
             pending_back_image: None,
             right_panel_open: true, // Default to open
+            show_quizlet_import: false,
+            quizlet_text: String::new(),
+            quizlet_deck_name: String::new(),
+            quizlet_options: ImportOptions::default(),
+            quizlet_error: None,
+            status_message: None,
+            quiz: None,
+            duplicate_finder: None,
+            deck_merge: None,
+            trashed: Vec::new(),
         }
     }
 
@@ -804,7 +1486,9 @@ impl DeckManagerUI {
                         if ui.button("Delete").clicked() {
                             if delete_type == "deck" {
                                 if let Some(deck_id) = self.item_to_delete {
-                                    decks.retain(|d| d.id != deck_id);
+                                    if let Some(index) = decks.iter().position(|d| d.id == deck_id) {
+                                        self.trashed.push(TrashedItem::Deck(decks.remove(index)));
+                                    }
                                     if self.selected_deck_id == Some(deck_id) {
                                         self.selected_deck_id = None;
                                         self.view_mode = ViewMode::DeckList;
@@ -816,7 +1500,15 @@ impl DeckManagerUI {
                                     (self.selected_deck_id, self.item_to_delete)
                                 {
                                     if let Some(deck) = decks.iter_mut().find(|d| d.id == deck_id) {
-                                        deck.cards.retain(|c| c.id != card_id);
+                                        if let Some(index) =
+                                            deck.cards.iter().position(|c| c.id == card_id)
+                                        {
+                                            self.trashed.push(TrashedItem::Card {
+                                                deck_id,
+                                                deck_name: deck.name.clone(),
+                                                card: deck.cards.remove(index),
+                                            });
+                                        }
                                         needs_save = true;
                                     }
                                 }
@@ -889,6 +1581,11 @@ impl DeckManagerUI {
                                 ui.label(desc);
                             }
                             ui.label(format!("Cards: {}", deck.cards.len()));
+                            ui.small(format!(
+                                "Created {} · Modified {}",
+                                deck.created_at,
+                                deck.last_modified()
+                            ));
                         });
 
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -898,6 +1595,38 @@ impl DeckManagerUI {
                                 self.item_to_delete = Some(deck.id);
                             }
 
+                            if ui.button("📝 Quiz").clicked() {
+                                match QuizSession::new(deck) {
+                                    Ok(quiz) => self.quiz = Some(quiz),
+                                    Err(e) => self.status_message = Some(e),
+                                }
+                            }
+
+                            ui.menu_button("📤", |ui| {
+                                if ui.button("Markdown (Q&A)").clicked() {
+                                    ui.close_menu();
+                                    self.export_deck_markdown(deck);
+                                }
+                                for layout in [DeckLayout::Table, DeckLayout::CardSheet] {
+                                    if ui.button(layout.name()).clicked() {
+                                        ui.close_menu();
+                                        self.export_deck_pdf(deck, layout);
+                                    }
+                                }
+                            });
+
+                            if ui.button("⎘").on_hover_text("Duplicate deck").clicked() {
+                                self.duplicate_deck_id = Some(deck.id);
+                                self.duplicate_deck_name = format!("{} (copy)", deck.name);
+                                self.duplicate_keep_progress = false;
+                            }
+
+                            if ui.button("⚙").on_hover_text("Deck settings").clicked() {
+                                self.settings_deck_id = Some(deck.id);
+                                self.settings_draft = deck.settings.clone();
+                                self.settings_reverse = deck.reverse_cards;
+                            }
+
                             // Edit deck button
                             if ui.button("✏").clicked() {
                                 self.edit_deck_id = Some(deck.id);
@@ -956,6 +1685,101 @@ impl DeckManagerUI {
             self.new_deck_description.clear();
             needs_save = true;
         }
+
+        if ui.button("📥 Import Quizlet Set").clicked() {
+            self.show_quizlet_import = true;
+            self.quizlet_error = None;
+        }
+
+        if ui.button("🔍 Find Duplicates").clicked() {
+            self.duplicate_finder = Some(DuplicateFinder::scan(decks));
+        }
+
+        if decks.len() > 1 && ui.button("🔗 Merge Decks").clicked() {
+            self.deck_merge = Some(DeckMerge::new());
+        }
+
+        if self.show_quizlet_import {
+            needs_save |= self.display_quizlet_import(ui, decks);
+        }
+
+        if let Some(finder) = self.duplicate_finder.as_mut() {
+            match duplicate_finder::display(ui.ctx(), finder, decks, &mut self.trashed) {
+                DuplicateAction::None => {}
+                DuplicateAction::Changed(message) => {
+                    self.status_message = Some(message);
+                    needs_save = true;
+                }
+                DuplicateAction::Close => self.duplicate_finder = None,
+            }
+        }
+
+        if let Some(merge) = self.deck_merge.as_mut() {
+            match deck_merge::display(ui.ctx(), merge, decks, &mut self.trashed) {
+                MergeAction::None => {}
+                MergeAction::Merged {
+                    kept,
+                    removed,
+                    message,
+                } => {
+                    if self.selected_deck_id == Some(removed) {
+                        self.selected_deck_id = Some(kept);
+                    }
+                    self.status_message = Some(message);
+                    self.deck_merge = None;
+                    needs_save = true;
+                }
+                MergeAction::Close => self.deck_merge = None,
+            }
+        }
+
+        if let Some(source_id) = self.duplicate_deck_id {
+            egui::Window::new("Duplicate Deck")
+                .collapsible(false)
+                .resizable(false)
+                .show(ui.ctx(), |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Name:");
+                        ui.add(egui::TextEdit::singleline(&mut self.duplicate_deck_name));
+                    });
+                    ui.checkbox(
+                        &mut self.duplicate_keep_progress,
+                        "Keep review history and due dates",
+                    );
+                    ui.small("Otherwise every card starts again as new.");
+
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        let name = self.duplicate_deck_name.trim().to_string();
+                        if ui
+                            .add_enabled(!name.is_empty(), egui::Button::new("Duplicate"))
+                            .clicked()
+                        {
+                            let id = self.get_next_deck_id(decks);
+                            if let Some(deck) = decks.iter().find(|d| d.id == source_id) {
+                                let copy =
+                                    deck.duplicate(id, name.clone(), self.duplicate_keep_progress);
+                                self.status_message = Some(format!(
+                                    "Created {} with {} cards",
+                                    name,
+                                    copy.cards.len()
+                                ));
+                                decks.push(copy);
+                                needs_save = true;
+                            }
+                            self.duplicate_deck_id = None;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            self.duplicate_deck_id = None;
+                        }
+                    });
+                });
+        }
+
+        if let Some(settings_id) = self.settings_deck_id {
+            needs_save |= self.display_deck_settings(ui, decks, settings_id);
+        }
+
         // Edit deck dialog
         if let Some(edit_id) = self.edit_deck_id {
             egui::Window::new("Edit Deck")
@@ -982,6 +1806,7 @@ impl DeckManagerUI {
                                 } else {
                                     Some(self.edit_deck_description.clone())
                                 };
+                                deck.touch();
                                 needs_save = true;
                             }
                             self.edit_deck_id = None;
@@ -996,15 +1821,127 @@ impl DeckManagerUI {
         needs_save
     }
 
+    fn display_deck_settings(
+        &mut self,
+        ui: &mut egui::Ui,
+        decks: &mut [Deck],
+        deck_id: u64,
+    ) -> bool {
+        let Some(deck) = decks.iter_mut().find(|d| d.id == deck_id) else {
+            self.settings_deck_id = None;
+            return false;
+        };
+        let mut saved = false;
+        let mut close = false;
+        let draft = &mut self.settings_draft;
+
+        egui::Window::new(format!("⚙ {} Settings", deck.name))
+            .id(egui::Id::new("deck_settings_window"))
+            .collapsible(false)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                egui::Grid::new("deck_settings_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("New card order:");
+                        egui::ComboBox::from_id_source("deck_new_card_order")
+                            .selected_text(draft.new_card_order.name())
+                            .show_ui(ui, |ui| {
+                                for order in NewCardOrder::ALL {
+                                    ui.selectable_value(
+                                        &mut draft.new_card_order,
+                                        order,
+                                        order.name(),
+                                    )
+                                    .on_hover_text(order.description());
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label("New cards per day:");
+                        ui.add(
+                            egui::DragValue::new(&mut draft.new_cards_per_day)
+                                .clamp_range(0..=9999)
+                                .suffix(" cards"),
+                        );
+                        ui.end_row();
+
+                        ui.label("Daily limit:");
+                        ui.add(
+                            egui::DragValue::new(&mut draft.daily_limit)
+                                .clamp_range(0..=9999)
+                                .suffix(" reviews"),
+                        );
+                        ui.end_row();
+
+                        ui.label("Image size:");
+                        ui.horizontal(|ui| {
+                            for size in ImageSize::ALL {
+                                ui.selectable_value(&mut draft.image_size, size, size.name());
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Review preset:");
+                        egui::ComboBox::from_id_source("deck_default_preset")
+                            .selected_text(draft.default_preset.as_deref().unwrap_or("None"))
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut draft.default_preset, None, "None");
+                                for preset in &deck.review_presets {
+                                    ui.selectable_value(
+                                        &mut draft.default_preset,
+                                        Some(preset.name.clone()),
+                                        &preset.name,
+                                    );
+                                }
+                            });
+                        ui.end_row();
+                    });
+
+                ui.checkbox(
+                    &mut self.settings_reverse,
+                    "Also review reversed cards (back → front)",
+                );
+                ui.small("0 means no limit. A review preset overrides the daily limit.");
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        deck.settings = draft.clone();
+                        deck.reverse_cards = self.settings_reverse;
+                        deck.touch();
+                        saved = true;
+                        close = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if close {
+            self.settings_deck_id = None;
+        }
+        saved
+    }
+
     fn display_deck_view(&mut self, ui: &mut egui::Ui, decks: &mut Vec<Deck>) -> bool {
         let mut needs_save = false;
 
+        // Card ids are only unique within a deck
+        if self.selected_cards_deck != self.selected_deck_id {
+            self.selected_cards.clear();
+            self.selected_cards_deck = self.selected_deck_id;
+        }
+
         if let Some(deck_id) = self.selected_deck_id {
             if let Some(deck) = decks.iter_mut().find(|d| d.id == deck_id) {
                 // Only show the entire panel if toggle is enabled
                 if self.right_panel_open {
-                    // Use TopBottomPanel to create better layout
-                    egui::TopBottomPanel::top("deck_header").show_inside(ui, |ui| {
+                    // Use TopBottomPanel to create better layout, with ids per pane
+                    let id = ui.id();
+                    egui::TopBottomPanel::top(id.with("deck_header")).show_inside(ui, |ui| {
                         // Header
                         ui.horizontal(|ui| {
                             ui.heading(&deck.name);
@@ -1015,7 +1952,7 @@ impl DeckManagerUI {
                         }
                     });
 
-                    egui::TopBottomPanel::top("add_card_section").show_inside(ui, |ui| {
+                    egui::TopBottomPanel::top(id.with("add_card_section")).show_inside(ui, |ui| {
                         ui.separator();
 
                         // Add new card section
@@ -1023,7 +1960,7 @@ impl DeckManagerUI {
                         ui.add_space(5.0);
 
                         ui.label("Front (Question):");
-                        ui.add(egui::TextEdit::multiline(&mut self.new_card_front).desired_rows(3));
+                        spell_check_ui::multiline(ui, &mut self.new_card_front, 3);
 
                         ui.horizontal(|ui| {
                             if ui.button("📷 Add Image to Front").clicked() {
@@ -1034,7 +1971,7 @@ impl DeckManagerUI {
                         });
 
                         ui.label("Back (Answer):");
-                        ui.add(egui::TextEdit::multiline(&mut self.new_card_back).desired_rows(3));
+                        spell_check_ui::multiline(ui, &mut self.new_card_back, 3);
 
                         ui.horizontal(|ui| {
                             if ui.button("📷 Add Image to Back").clicked() {
@@ -1044,6 +1981,10 @@ impl DeckManagerUI {
                             }
                         });
 
+                        if !deck.reverse_cards {
+                            ui.checkbox(&mut self.new_card_reverse, "Add reversed card");
+                        }
+
                         ui.add_space(10.0);
 
                         if ui.button("Add Card").clicked()
@@ -1063,8 +2004,9 @@ impl DeckManagerUI {
                             if let Some(back_image) = self.pending_back_image.take() {
                                 new_card.back_image = Some(back_image);
                             }
+                            new_card.reverse = self.new_card_reverse;
 
-                            deck.cards.push(new_card);
+                            deck.add_card(new_card);
                             self.new_card_front.clear();
                             self.new_card_back.clear();
                             needs_save = true;
@@ -1075,7 +2017,33 @@ impl DeckManagerUI {
 
                     // Cards list - now takes up remaining space
                     egui::CentralPanel::default().show_inside(ui, |ui| {
-                        ui.heading("📋 Cards in Deck");
+                        ui.horizontal(|ui| {
+                            ui.heading("📋 Cards in Deck");
+                            ui.with_layout(
+                                egui::Layout::right_to_left(egui::Align::Center),
+                                |ui| {
+                                    for sort in CardSort::ALL.iter().rev() {
+                                        ui.selectable_value(
+                                            &mut self.card_sort,
+                                            *sort,
+                                            sort.name(),
+                                        );
+                                    }
+                                    ui.label("Sort by:");
+                                },
+                            );
+                        });
+                        if !self.selected_cards.is_empty() {
+                            ui.horizontal(|ui| {
+                                ui.label(format!("{} selected", self.selected_cards.len()));
+                                if ui.button("⇄ Move or Copy…").clicked() {
+                                    self.show_card_transfer = true;
+                                }
+                                if ui.button("Clear selection").clicked() {
+                                    self.selected_cards.clear();
+                                }
+                            });
+                        }
                         ui.add_space(10.0);
 
                         if deck.cards.is_empty() {
@@ -1083,13 +2051,43 @@ impl DeckManagerUI {
                                 ui.label("No cards in this deck yet. Add some cards above!");
                             });
                         } else {
+                            let mut duplicate_card = None;
                             // Now the scroll area will take up all remaining vertical space
                             egui::ScrollArea::vertical()
                                 .auto_shrink([false; 2])
                                 .show(ui, |ui| {
-                                    for card in &deck.cards {
+                                    let mut cards: Vec<&Card> = deck.cards.iter().collect();
+                                    match self.card_sort {
+                                        CardSort::Created => {
+                                            cards.sort_by(|a, b| a.created_at.cmp(&b.created_at))
+                                        }
+                                        // Most recently edited first
+                                        CardSort::Modified => cards.sort_by(|a, b| {
+                                            b.last_modified().cmp(a.last_modified())
+                                        }),
+                                        // Slow answers first among the same grade
+                                        CardSort::Difficulty => {
+                                            let median = deck.median_answer_seconds();
+                                            cards.sort_by_key(|card| {
+                                                (difficulty_rank(card), !card.is_slow(median))
+                                            })
+                                        }
+                                        CardSort::Due => {
+                                            cards.sort_by(|a, b| a.due_date.cmp(&b.due_date))
+                                        }
+                                    }
+                                    for card in cards {
                                         ui.group(|ui| {
                                             ui.horizontal(|ui| {
+                                                let mut selected =
+                                                    self.selected_cards.contains(&card.id);
+                                                if ui.checkbox(&mut selected, "").changed() {
+                                                    if selected {
+                                                        self.selected_cards.insert(card.id);
+                                                    } else {
+                                                        self.selected_cards.remove(&card.id);
+                                                    }
+                                                }
                                                 ui.vertical(|ui| {
                                                     ui.label(
                                                         egui::RichText::new("Front:").strong(),
@@ -1097,7 +2095,16 @@ impl DeckManagerUI {
                                                     ui.label(&card.front);
                                                     ui.add_space(5.0);
                                                     ui.label(egui::RichText::new("Back:").strong());
-                                                    ui.label(&card.back);
+                                                    linkified_label(ui, &card.back);
+                                                    if card.has_reverse(deck.reverse_cards) {
+                                                        ui.small("↺ Reviewed both ways");
+                                                    }
+                                                    ui.small(format!(
+                                                        "Created {} · Modified {} · Due {}",
+                                                        card.created_at,
+                                                        card.last_modified(),
+                                                        card.due_date
+                                                    ));
                                                 });
 
                                                 ui.with_layout(
@@ -1110,12 +2117,43 @@ impl DeckManagerUI {
                                                             self.item_to_delete = Some(card.id);
                                                         }
 
+                                                        if ui
+                                                            .button("⇄")
+                                                            .on_hover_text(
+                                                                "Move or copy to another deck",
+                                                            )
+                                                            .clicked()
+                                                        {
+                                                            self.selected_cards.clear();
+                                                            self.selected_cards.insert(card.id);
+                                                            self.show_card_transfer = true;
+                                                        }
+
+                                                        if ui
+                                                            .button("🕘")
+                                                            .on_hover_text("Review history")
+                                                            .clicked()
+                                                        {
+                                                            card_history::open(deck.id, card.id);
+                                                        }
+
+                                                        if ui
+                                                            .button("⎘")
+                                                            .on_hover_text("Duplicate card")
+                                                            .clicked()
+                                                        {
+                                                            duplicate_card = Some(card.id);
+                                                        }
+
                                                         // Edit card button
                                                         if ui.button("✏").clicked() {
                                                             self.edit_card_id = Some(card.id);
                                                             self.edit_card_front =
                                                                 card.front.clone();
                                                             self.edit_card_back = card.back.clone();
+                                                            self.edit_card_notes =
+                                                                card.notes.clone();
+                                                            self.edit_card_reverse = card.reverse;
                                                         }
                                                     },
                                                 );
@@ -1124,6 +2162,16 @@ impl DeckManagerUI {
                                         ui.add_space(8.0);
                                     }
                                 });
+
+                            let copy = duplicate_card
+                                .and_then(|id| deck.cards.iter().find(|c| c.id == id))
+                                .map(|card| card.fresh_copy());
+                            if let Some(copy) = copy {
+                                deck.add_card(copy);
+                                deck.touch();
+                                self.status_message = Some("Card duplicated".to_string());
+                                needs_save = true;
+                            }
                         }
                     });
                 } else {
@@ -1146,10 +2194,18 @@ impl DeckManagerUI {
                 .default_size([400.0, 300.0])
                 .show(ui.ctx(), |ui| {
                     ui.label("Front (Question):");
-                    ui.add(egui::TextEdit::multiline(&mut self.edit_card_front).desired_rows(3));
+                    spell_check_ui::multiline(ui, &mut self.edit_card_front, 3);
 
                     ui.label("Back (Answer):");
-                    ui.add(egui::TextEdit::multiline(&mut self.edit_card_back).desired_rows(3));
+                    spell_check_ui::multiline(ui, &mut self.edit_card_back, 3);
+
+                    ui.label("Notes (shown after the answer):");
+                    spell_check_ui::multiline(ui, &mut self.edit_card_notes, 2);
+
+                    ui.checkbox(
+                        &mut self.edit_card_reverse,
+                        "Also review this card reversed (back → front)",
+                    );
 
                     ui.separator();
                     ui.horizontal(|ui| {
@@ -1161,6 +2217,9 @@ impl DeckManagerUI {
                                     {
                                         card.front = self.edit_card_front.clone();
                                         card.back = self.edit_card_back.clone();
+                                        card.notes = self.edit_card_notes.clone();
+                                        card.reverse = self.edit_card_reverse;
+                                        card.touch();
                                         needs_save = true;
                                     }
                                 }
@@ -1174,9 +2233,261 @@ impl DeckManagerUI {
                 });
         }
 
+        if self.show_card_transfer {
+            needs_save |= self.display_card_transfer(ui, decks);
+        }
+
+        needs_save
+    }
+
+    fn display_card_transfer(&mut self, ui: &mut egui::Ui, decks: &mut [Deck]) -> bool {
+        let Some(from) = self.selected_deck_id else {
+            self.show_card_transfer = false;
+            return false;
+        };
+        let targets: Vec<(u64, String)> = decks
+            .iter()
+            .filter(|d| d.id != from)
+            .map(|d| (d.id, d.name.clone()))
+            .collect();
+        if self
+            .transfer_target
+            .is_some_and(|id| !targets.iter().any(|(t, _)| *t == id))
+        {
+            self.transfer_target = None;
+        }
+
+        let mut needs_save = false;
+        let mut open = true;
+        egui::Window::new("Move or Copy Cards")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                ui.label(format!("{} card(s) selected", self.selected_cards.len()));
+                if targets.is_empty() {
+                    ui.label("Create another deck first.");
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut self.transfer_copy, false, "Move");
+                    ui.radio_value(&mut self.transfer_copy, true, "Copy");
+                });
+                let selected_name = self
+                    .transfer_target
+                    .and_then(|id| targets.iter().find(|(t, _)| *t == id))
+                    .map_or("Choose a deck", |(_, name)| name.as_str());
+                egui::ComboBox::from_label("To deck")
+                    .selected_text(selected_name)
+                    .show_ui(ui, |ui| {
+                        for (id, name) in &targets {
+                            ui.selectable_value(&mut self.transfer_target, Some(*id), name);
+                        }
+                    });
+                ui.small("Review history and due dates go along with the cards.");
+
+                ui.separator();
+                let action = if self.transfer_copy { "Copy" } else { "Move" };
+                let Some(to) = self.transfer_target else {
+                    ui.add_enabled(false, egui::Button::new(action));
+                    return;
+                };
+                if ui.button(action).clicked() {
+                    let count = crate::ui::flashcard::transfer_cards(
+                        decks,
+                        from,
+                        to,
+                        &self.selected_cards,
+                        self.transfer_copy,
+                    );
+                    let verb = if self.transfer_copy {
+                        "Copied"
+                    } else {
+                        "Moved"
+                    };
+                    let name = targets
+                        .iter()
+                        .find(|(t, _)| *t == to)
+                        .map_or("", |(_, name)| name.as_str());
+                    self.status_message = Some(format!("{} {} card(s) to {}", verb, count, name));
+                    self.selected_cards.clear();
+                    self.show_card_transfer = false;
+                    needs_save = count > 0;
+                }
+            });
+        if !open {
+            self.show_card_transfer = false;
+        }
+        needs_save
+    }
+
+    fn display_quizlet_import(&mut self, ui: &mut egui::Ui, decks: &mut Vec<Deck>) -> bool {
+        let mut needs_save = false;
+        let mut open = true;
+
+        egui::Window::new("Import Quizlet Set")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([450.0, 400.0])
+            .show(ui.ctx(), |ui| {
+                ui.label("Paste the text from Quizlet's Export dialog, or load an exported file (text or JSON).");
+                ui.add_space(5.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Deck name:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.quizlet_deck_name)
+                            .hint_text("Enter deck name"),
+                    );
+                    if ui.button("📁 Load File").clicked() {
+                        if let Some(path) = rfd::FileDialog::new()
+                            .add_filter("Quizlet export", &["txt", "csv", "tsv", "json"])
+                            .pick_file()
+                        {
+                            match std::fs::read_to_string(&path) {
+                                Ok(text) => {
+                                    self.quizlet_text = text;
+                                    if self.quizlet_deck_name.is_empty() {
+                                        if let Some(stem) = path.file_stem() {
+                                            self.quizlet_deck_name =
+                                                stem.to_string_lossy().to_string();
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    self.quizlet_error = Some(format!("Failed to read file: {}", e))
+                                }
+                            }
+                        }
+                    }
+                });
+
+                let options = &mut self.quizlet_options;
+                ui.horizontal(|ui| {
+                    ui.label("Between term and definition:");
+                    ui.selectable_value(&mut options.term_separator, TermSeparator::Tab, "Tab");
+                    ui.selectable_value(&mut options.term_separator, TermSeparator::Comma, "Comma");
+                    ui.selectable_value(&mut options.term_separator, TermSeparator::Custom, "Custom");
+                    if options.term_separator == TermSeparator::Custom {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut options.custom_term_separator)
+                                .desired_width(40.0),
+                        );
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Between cards:");
+                    ui.selectable_value(&mut options.card_separator, CardSeparator::NewLine, "New line");
+                    ui.selectable_value(&mut options.card_separator, CardSeparator::Semicolon, "Semicolon");
+                    ui.selectable_value(&mut options.card_separator, CardSeparator::Custom, "Custom");
+                    if options.card_separator == CardSeparator::Custom {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut options.custom_card_separator)
+                                .desired_width(40.0),
+                        );
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Front of card:");
+                    ui.selectable_value(&mut options.definition_first, false, "Term");
+                    ui.selectable_value(&mut options.definition_first, true, "Definition");
+                });
+
+                ui.add_space(5.0);
+                egui::ScrollArea::vertical()
+                    .id_source("quizlet_import_text")
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(&mut self.quizlet_text)
+                                .desired_rows(10)
+                                .desired_width(f32::INFINITY)
+                                .hint_text("term\tdefinition"),
+                        );
+                    });
+
+                let preview = quizlet_import::parse_set(&self.quizlet_text, &self.quizlet_options);
+                match &preview {
+                    Ok(parsed) if parsed.skipped > 0 => {
+                        ui.label(format!(
+                            "{} cards found, {} lines skipped",
+                            parsed.cards.len(),
+                            parsed.skipped
+                        ));
+                    }
+                    Ok(parsed) => {
+                        ui.label(format!("{} cards found", parsed.cards.len()));
+                    }
+                    Err(e) if !self.quizlet_text.trim().is_empty() => {
+                        ui.colored_label(egui::Color32::RED, e.to_string());
+                    }
+                    Err(_) => {}
+                }
+
+                if let Some(error) = &self.quizlet_error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+
+                ui.separator();
+                let can_import = preview.is_ok() && !self.quizlet_deck_name.trim().is_empty();
+                if ui
+                    .add_enabled(can_import, egui::Button::new("Import"))
+                    .clicked()
+                {
+                    if let Ok(parsed) = preview {
+                        let mut deck = Deck::new(
+                            self.quizlet_deck_name.trim().to_string(),
+                            Some("Imported from Quizlet".to_string()),
+                        );
+                        deck.id = self.get_next_deck_id(decks);
+                        for (front, back) in parsed.cards {
+                            deck.add_card(crate::ui::flashcard::Card::new(deck.id, front, back));
+                        }
+                        decks.push(deck);
+
+                        self.quizlet_text.clear();
+                        self.quizlet_deck_name.clear();
+                        self.quizlet_error = None;
+                        self.show_quizlet_import = false;
+                        needs_save = true;
+                    }
+                }
+            });
+
+        if !open {
+            self.show_quizlet_import = false;
+        }
         needs_save
     }
 
+    fn export_deck_markdown(&mut self, deck: &Deck) {
+        if let Some(path) = rfd::FileDialog::new()
+            .add_filter("Markdown", &["md"])
+            .set_file_name(&format!("{}.md", deck.name))
+            .save_file()
+        {
+            self.status_message = Some(match vault_export::export_deck_markdown(deck, &path) {
+                Ok(()) => format!("Exported {} to {}", deck.name, path.display()),
+                Err(e) => format!("Markdown export failed: {}", e),
+            });
+        }
+    }
+
+    fn export_deck_pdf(&mut self, deck: &Deck, layout: DeckLayout) {
+        if let Some(path) = rfd::FileDialog::new()
+            .add_filter("PDF", &["pdf"])
+            .set_file_name(&format!("{}.pdf", deck.name))
+            .save_file()
+        {
+            self.status_message = Some(match pdf_export::export_deck(deck, layout, &path) {
+                Ok(()) => format!("Exported {} to {}", deck.name, path.display()),
+                Err(e) => format!("PDF export failed: {}", e),
+            });
+        }
+    }
+
     fn get_next_deck_id(&self, decks: &[Deck]) -> u64 {
         if let Some(max_id) = decks.iter().map(|d| d.id).max() {
             max_id + 1
@@ -1198,7 +2509,7 @@ impl DeckManagerUI {
                 self.apply_image_to_card(decks, card_image, needs_save);
             }
             Err(e) => {
-                eprintln!("Error loading image: {}", e);
+                error::report("Could not load image", e);
             }
         }
     }
@@ -1214,17 +2525,17 @@ impl DeckManagerUI {
                                 self.apply_image_to_card(decks, card_image, needs_save);
                             }
                             Err(e) => {
-                                eprintln!("Error processing clipboard image: {}", e);
+                                error::report("Could not read the clipboard image", e);
                             }
                         }
                     }
                     Err(e) => {
-                        eprintln!("No image found in clipboard or error: {}", e);
+                        error::report("No image found in the clipboard", e);
                     }
                 }
             }
             Err(e) => {
-                eprintln!("Failed to access clipboard: {}", e);
+                error::report("Could not access the clipboard", e);
             }
         }
     }
@@ -1281,6 +2592,7 @@ impl DeckManagerUI {
                             ImageSide::Front => card.front_image = Some(card_image),
                             ImageSide::Back => card.back_image = Some(card_image),
                         }
+                        card.touch();
                         *needs_save = true;
                     }
                 } else {
@@ -0,0 +1,287 @@
+use crate::app::StatusMessage;
+use eframe::egui::{self, RichText, TextEdit, Ui};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::fs;
+use std::path::PathBuf;
+
+thread_local! {
+    static GRADE_BOOK: RefCell<Option<GradeBook>> = RefCell::new(None);
+    static NEW_SEMESTER: RefCell<String> = RefCell::new(String::new());
+}
+
+// Letter grades on the usual 4.0 scale, with the lowest percent that earns them
+const SCALE: [(&str, f64, f64); 12] = [
+    ("A", 4.0, 93.0),
+    ("A-", 3.7, 90.0),
+    ("B+", 3.3, 87.0),
+    ("B", 3.0, 83.0),
+    ("B-", 2.7, 80.0),
+    ("C+", 2.3, 77.0),
+    ("C", 2.0, 73.0),
+    ("C-", 1.7, 70.0),
+    ("D+", 1.3, 67.0),
+    ("D", 1.0, 63.0),
+    ("D-", 0.7, 60.0),
+    ("F", 0.0, 0.0),
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct GradeBook {
+    semesters: Vec<Semester>,
+    #[serde(default)]
+    selected: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Semester {
+    name: String,
+    courses: Vec<CourseEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CourseEntry {
+    name: String,
+    grade: String, // Percent ("87.5") or letter ("B+")
+    credits: f64,
+}
+
+impl CourseEntry {
+    fn percent(&self) -> Option<f64> {
+        self.grade.trim().trim_end_matches('%').parse().ok()
+    }
+
+    fn grade_points(&self) -> Option<f64> {
+        let grade = self.grade.trim().to_uppercase();
+        if let Some((_, points, _)) = SCALE.iter().find(|(letter, _, _)| *letter == grade) {
+            return Some(*points);
+        }
+        let percent = self.percent()?;
+        SCALE
+            .iter()
+            .find(|(_, _, min)| percent >= *min)
+            .map(|(_, points, _)| *points)
+    }
+}
+
+struct Averages {
+    credits: f64,
+    weighted_percent: Option<f64>, // Only over courses graded in percent
+    gpa: Option<f64>,
+}
+
+fn averages<'a>(courses: impl Iterator<Item = &'a CourseEntry>) -> Averages {
+    let (mut credits, mut percent_credits, mut percent_sum, mut point_credits, mut point_sum) =
+        (0.0, 0.0, 0.0, 0.0, 0.0);
+    for course in courses.filter(|c| c.credits > 0.0) {
+        credits += course.credits;
+        if let Some(percent) = course.percent() {
+            percent_credits += course.credits;
+            percent_sum += percent * course.credits;
+        }
+        if let Some(points) = course.grade_points() {
+            point_credits += course.credits;
+            point_sum += points * course.credits;
+        }
+    }
+    Averages {
+        credits,
+        weighted_percent: (percent_credits > 0.0).then(|| percent_sum / percent_credits),
+        gpa: (point_credits > 0.0).then(|| point_sum / point_credits),
+    }
+}
+
+fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut path = crate::profile::config_dir().ok_or("Could not find config directory")?;
+    path.push("grade_calculator.json");
+    Ok(path)
+}
+
+fn load() -> Result<GradeBook, Box<dyn std::error::Error>> {
+    let path = get_config_path()?;
+    if !path.exists() {
+        return Ok(GradeBook::default());
+    }
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+fn save(book: &GradeBook) -> Result<(), Box<dyn std::error::Error>> {
+    let path = get_config_path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(book)?)?;
+    Ok(())
+}
+
+fn summary(averages: &Averages) -> String {
+    format!(
+        "{:.1} credits · average {} · GPA {}",
+        averages.credits,
+        averages
+            .weighted_percent
+            .map_or("-".to_string(), |p| format!("{:.1}%", p)),
+        averages
+            .gpa
+            .map_or("-".to_string(), |g| format!("{:.2}", g)),
+    )
+}
+
+pub fn display(ui: &mut Ui, status: &mut StatusMessage) {
+    GRADE_BOOK.with(|book| {
+        let mut book = book.borrow_mut();
+        let book = book.get_or_insert_with(|| {
+            load().unwrap_or_else(|e| {
+                status.error(&format!("Failed to load grades: {}", e));
+                GradeBook::default()
+            })
+        });
+        if grade_book(ui, book) {
+            if let Err(e) = save(book) {
+                status.error(&format!("Failed to save grades: {}", e));
+            }
+        }
+    });
+}
+
+// Returns true when something changed and needs saving
+fn grade_book(ui: &mut Ui, book: &mut GradeBook) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        ui.label("Semester:");
+        let selected_name = book
+            .semesters
+            .get(book.selected)
+            .map_or("None".to_string(), |s| s.name.clone());
+        egui::ComboBox::from_id_source("grade_semester")
+            .selected_text(selected_name)
+            .show_ui(ui, |ui| {
+                for (index, semester) in book.semesters.iter().enumerate() {
+                    if ui
+                        .selectable_label(book.selected == index, &semester.name)
+                        .clicked()
+                    {
+                        book.selected = index;
+                        changed = true;
+                    }
+                }
+            });
+
+        NEW_SEMESTER.with(|name| {
+            let mut name = name.borrow_mut();
+            ui.add(
+                TextEdit::singleline(&mut *name)
+                    .hint_text("e.g. Fall 2026")
+                    .desired_width(120.0),
+            );
+            if ui.button("➕ Semester").clicked() && !name.trim().is_empty() {
+                book.semesters.push(Semester {
+                    name: name.trim().to_string(),
+                    courses: Vec::new(),
+                });
+                book.selected = book.semesters.len() - 1;
+                name.clear();
+                changed = true;
+            }
+        });
+
+        if book.selected < book.semesters.len()
+            && ui
+                .button("🗑")
+                .on_hover_text("Delete this semester")
+                .clicked()
+        {
+            book.semesters.remove(book.selected);
+            book.selected = book.selected.saturating_sub(1);
+            changed = true;
+        }
+    });
+    ui.add_space(10.0);
+
+    let Some(semester) = book.semesters.get_mut(book.selected) else {
+        ui.label("Add a semester to start entering course grades and credits.");
+        return changed;
+    };
+
+    let mut removed = None;
+    egui::Grid::new("grade_courses_grid")
+        .num_columns(4)
+        .spacing([12.0, 4.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Course");
+            ui.strong("Grade (% or letter)");
+            ui.strong("Credits");
+            ui.label("");
+            ui.end_row();
+
+            for (index, course) in semester.courses.iter_mut().enumerate() {
+                ui.push_id(index, |ui| {
+                    changed |= ui
+                        .add(TextEdit::singleline(&mut course.name).desired_width(160.0))
+                        .lost_focus();
+                });
+                ui.push_id(("grade", index), |ui| {
+                    let valid = course.grade.trim().is_empty() || course.grade_points().is_some();
+                    let mut edit = TextEdit::singleline(&mut course.grade).desired_width(80.0);
+                    if !valid {
+                        edit = edit.text_color(egui::Color32::from_rgb(213, 94, 0));
+                    }
+                    changed |= ui.add(edit).lost_focus();
+                });
+                changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut course.credits)
+                            .speed(0.5)
+                            .clamp_range(0.0..=30.0),
+                    )
+                    .changed();
+                if ui
+                    .small_button("✖")
+                    .on_hover_text("Remove course")
+                    .clicked()
+                {
+                    removed = Some(index);
+                }
+                ui.end_row();
+            }
+        });
+
+    if let Some(index) = removed {
+        semester.courses.remove(index);
+        changed = true;
+    }
+    if ui.button("➕ Add Course").clicked() {
+        semester.courses.push(CourseEntry {
+            name: String::new(),
+            grade: String::new(),
+            credits: 3.0,
+        });
+        changed = true;
+    }
+
+    ui.add_space(10.0);
+    ui.separator();
+    ui.label(
+        RichText::new(format!(
+            "This semester: {}",
+            summary(&averages(semester.courses.iter()))
+        ))
+        .strong(),
+    );
+    if book.semesters.len() > 1 {
+        let all = book.semesters.iter().flat_map(|s| s.courses.iter());
+        ui.label(format!("Cumulative: {}", summary(&averages(all))));
+    }
+    ui.label(
+        RichText::new(
+            "Percent grades are converted to the 4.0 scale (A ≥ 93, A- ≥ 90, B+ ≥ 87, …)",
+        )
+        .small()
+        .weak(),
+    );
+
+    changed
+}
@@ -1,12 +1,13 @@
 use crate::app::StatusMessage;
 use crate::data::StudyData;
+use crate::settings::{AppSettings, ClockFormat};
 use charming::{
     component::{Axis, Title},
     element::AxisType,
     series::Line,
     Chart,
 };
-use chrono::{Datelike, Duration, Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveTime};
 use eframe::egui;
 use eframe::egui::Ui;
 use std::cell::RefCell;
@@ -25,14 +26,12 @@ thread_local! {
     static GRAPH_STATE: RefCell<GraphState> = RefCell::new(GraphState::default());
 }
 
-// Helper function to get the start of a week containing the given date
-fn start_of_week(date: NaiveDate) -> NaiveDate {
-    // Start of week is Monday
-    let days_from_monday = date.weekday().num_days_from_monday() as i64;
-    date - Duration::days(days_from_monday)
-}
-
-pub fn display(ui: &mut Ui, study_data: &StudyData, status: &mut StatusMessage) {
+pub fn display(
+    ui: &mut Ui,
+    study_data: &StudyData,
+    status: &mut StatusMessage,
+    settings: &AppSettings,
+) {
     ui.vertical_centered(|ui| {
         ui.heading("Weekly Study Graph");
     });
@@ -84,15 +83,15 @@ pub fn display(ui: &mut Ui, study_data: &StudyData, status: &mut StatusMessage)
     let today = Local::now().date_naive();
     let week_offset = GRAPH_STATE.with(|state| state.borrow().week_offset);
 
-    let week_start = start_of_week(today) + Duration::days(week_offset * 7);
+    let week_start = settings.week_start.week_of(today) + Duration::days(week_offset * 7);
     let week_end = week_start + Duration::days(6);
 
     // Display week range
     ui.vertical_centered(|ui| {
         ui.label(format!(
             "Week: {} to {}",
-            week_start.format("%b %d, %Y"),
-            week_end.format("%b %d, %Y")
+            settings.date_format.format(week_start),
+            settings.date_format.format(week_end)
         ));
     });
 
@@ -213,7 +212,7 @@ pub fn display(ui: &mut Ui, study_data: &StudyData, status: &mut StatusMessage)
                 painter.text(
                     egui::pos2(x_center, bar_rect.top() - 5.0),
                     egui::Align2::CENTER_BOTTOM,
-                    format!("{:.1}", value),
+                    settings.number_format.format(value, 1),
                     egui::FontId::default(),
                     text_color,
                 );
@@ -266,6 +265,15 @@ pub fn display(ui: &mut Ui, study_data: &StudyData, status: &mut StatusMessage)
 
     ui.add_space(20.0);
 
+    // When each session happened across the day, to spot fragmented study time
+    ui.vertical_centered(|ui| {
+        ui.heading("Session Timeline");
+        ui.add_space(10.0);
+    });
+    render_timeline(ui, study_data, week_start, settings);
+
+    ui.add_space(20.0);
+
     // Add GitHub-style yearly commit streak heatmap
     ui.vertical_centered(|ui| {
         ui.heading("Annual Study Activity");
@@ -274,7 +282,7 @@ pub fn display(ui: &mut Ui, study_data: &StudyData, status: &mut StatusMessage)
 
     // Create a heatmap with GitHub-like appearance
     // This will always show the full year regardless of week selection
-    render_heatmap(ui, study_data, week_start);
+    render_heatmap(ui, study_data, settings);
 
     ui.add_space(10.0);
 
@@ -288,24 +296,154 @@ pub fn display(ui: &mut Ui, study_data: &StudyData, status: &mut StatusMessage)
 
     ui.vertical_centered(|ui| {
         ui.label(format!(
-            "Week total: {:.1} minutes ({:.1} hours)",
-            week_total,
-            week_total / 60.0
+            "Week total: {}",
+            settings.number_format.minutes_and_hours(week_total)
         ));
         ui.label(format!(
-            "Daily average: {:.1} minutes ({:.1} hours)",
-            avg_per_day,
-            avg_per_day / 60.0
+            "Daily average: {}",
+            settings.number_format.minutes_and_hours(avg_per_day)
         ));
     });
+}
+
+// One row per day of the week with a bar for each timed span across a 24h axis
+fn render_timeline(
+    ui: &mut Ui,
+    study_data: &StudyData,
+    week_start: NaiveDate,
+    settings: &AppSettings,
+) {
+    let label_width = 60.0;
+    let axis_height = 20.0;
+    let row_height = 22.0;
+    let available_width = ui.available_width() * 0.95;
+
+    let bar_color = egui::Color32::from_rgb(66, 133, 244);
+    let text_color = egui::Color32::from_rgb(220, 220, 220);
+    let grid_color = egui::Color32::from_rgb(70, 70, 70);
+
+    // (start minute, length in minutes, description) for each day
+    let days = (0..7)
+        .map(|day| {
+            let date = week_start + Duration::days(day);
+            let date_str = date.format("%Y-%m-%d").to_string();
+            let mut spans = Vec::new();
+            let mut untimed = 0.0;
+            for session in study_data.sessions.iter().filter(|s| s.date == date_str) {
+                let timed: f64 = session.spans.iter().map(|s| s.minutes).sum();
+                untimed += (session.minutes - timed).max(0.0);
+                for span in &session.spans {
+                    if let Some(start) = span.start_minute() {
+                        spans.push((start, span.minutes, session.description.clone()));
+                    }
+                }
+            }
+            spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+            (date, spans, untimed)
+        })
+        .collect::<Vec<_>>();
+
+    ui.vertical_centered(|ui| {
+        let size = egui::vec2(available_width, axis_height + row_height * 7.0 + 10.0);
+        let (rect, _) = ui.allocate_at_least(size, egui::Sense::hover());
+        let painter = ui.painter();
+        painter.rect_filled(rect, 4.0, egui::Color32::from_rgb(27, 27, 27));
+
+        let axis_left = rect.left() + label_width;
+        let axis_width = rect.right() - 15.0 - axis_left;
+        let x_for = |minute: f64| axis_left + (minute / 1440.0) as f32 * axis_width;
+        let rows_top = rect.top() + axis_height;
 
-    // Status message
-    status.render(ui);
+        // Hour grid every 3 hours
+        for hour in (0..=24).step_by(3) {
+            let x = x_for(hour as f64 * 60.0);
+            painter.line_segment(
+                [
+                    egui::pos2(x, rows_top),
+                    egui::pos2(x, rows_top + row_height * 7.0),
+                ],
+                egui::Stroke::new(0.5, grid_color),
+            );
+            painter.text(
+                egui::pos2(x, rect.top() + 4.0),
+                egui::Align2::CENTER_TOP,
+                format!("{:02}", hour),
+                egui::FontId::proportional(11.0),
+                text_color,
+            );
+        }
+
+        let mut hovered = None;
+        for (row, (date, spans, untimed)) in days.iter().enumerate() {
+            let y = rows_top + row as f32 * row_height;
+            painter.text(
+                egui::pos2(rect.left() + 8.0, y + row_height / 2.0),
+                egui::Align2::LEFT_CENTER,
+                date.format("%a %d").to_string(),
+                egui::FontId::default(),
+                text_color,
+            );
+
+            for (start, minutes, description) in spans {
+                let end = (start + minutes).min(1440.0);
+                // Keep very short spans visible
+                let right = x_for(end).max(x_for(*start) + 2.0);
+                let bar_rect = egui::Rect::from_min_max(
+                    egui::pos2(x_for(*start), y + 4.0),
+                    egui::pos2(right, y + row_height - 4.0),
+                );
+                painter.rect_filled(bar_rect, 2.0, bar_color);
+                if ui.rect_contains_pointer(bar_rect) {
+                    hovered = Some(format!(
+                        "{} {}–{} · {:.0} min{}",
+                        date.format("%a"),
+                        format_minute(*start, settings.clock_format),
+                        format_minute(end, settings.clock_format),
+                        minutes,
+                        description
+                            .as_ref()
+                            .map(|d| format!(" · {}", d))
+                            .unwrap_or_default()
+                    ));
+                }
+            }
+
+            let row_rect = egui::Rect::from_min_size(
+                egui::pos2(rect.left(), y),
+                egui::vec2(rect.width(), row_height),
+            );
+            if hovered.is_none() && ui.rect_contains_pointer(row_rect) {
+                let studied: f64 = spans.iter().map(|s| s.1).sum();
+                let mut text = format!(
+                    "{}: {} block(s), {:.0} min",
+                    date.format("%a %d %b"),
+                    spans.len(),
+                    studied
+                );
+                if *untimed > 0.0 {
+                    text.push_str(&format!(" (+{:.0} min logged without a time)", untimed));
+                }
+                hovered = Some(text);
+            }
+        }
+
+        if let Some(text) = hovered {
+            egui::show_tooltip_text(ui.ctx(), egui::Id::new("session_timeline_tip"), text);
+        }
+    });
+}
+
+// Minute of the day; the end of the day wraps around to midnight
+fn format_minute(minute: f64, clock_format: ClockFormat) -> String {
+    let seconds = (minute as u32 * 60) % 86_400;
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0)
+        .map(|time| clock_format.format(time))
+        .unwrap_or_default()
 }
 
 // Redesigned render_heatmap function in GitHub style showing a full year
 
-fn render_heatmap(ui: &mut Ui, study_data: &StudyData, _current_week_start: NaiveDate) {
+fn render_heatmap(ui: &mut Ui, study_data: &StudyData, settings: &AppSettings) {
     // Use today's date to determine the year to display
     let today = Local::now().date_naive();
     let current_year = today.year();
@@ -337,7 +475,7 @@ fn render_heatmap(ui: &mut Ui, study_data: &StudyData, _current_week_start: Naiv
         let day_count = 7; // 7 days per week
 
         // Calculate total weeks in the year plus padding
-        let first_day_offset = year_start.weekday().num_days_from_sunday() as usize;
+        let first_day_offset = settings.week_start.offset(year_start) as usize;
         let total_days_in_year = if year_end.leap_year() { 366 } else { 365 };
         let total_weeks = (total_days_in_year + first_day_offset + 6) / 7;
 
@@ -364,7 +502,7 @@ fn render_heatmap(ui: &mut Ui, study_data: &StudyData, _current_week_start: Naiv
             let month_date = NaiveDate::from_ymd_opt(current_year, month as u32 + 1, 1).unwrap();
             // Calculate the week number (0-based) for this month
             let days_since_start = (month_date - year_start).num_days();
-            let week_num = (days_since_start / 7) as f32;
+            let week_num = ((days_since_start + first_day_offset as i64) / 7) as f32;
 
             // Position the month label
             let x_pos = rect.left() + horizontal_padding + week_num * (cell_size + cell_margin);
@@ -379,8 +517,7 @@ fn render_heatmap(ui: &mut Ui, study_data: &StudyData, _current_week_start: Naiv
         }
 
         // Draw day labels (vertical axis)
-        let days = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
-        for (i, day) in days.iter().enumerate() {
+        for (i, day) in settings.week_start.day_names().iter().enumerate() {
             let y_pos = rect.top()
                 + vertical_padding
                 + (i as f32) * (cell_size + cell_margin)
@@ -411,7 +548,7 @@ fn render_heatmap(ui: &mut Ui, study_data: &StudyData, _current_week_start: Naiv
         // Continue until end of year instead of just today
         while current_date <= year_end {
             let week_of_year = (current_date.ordinal0() + first_day_offset as u32) / 7;
-            let day_of_week = current_date.weekday().num_days_from_sunday() as usize;
+            let day_of_week = settings.week_start.offset(current_date) as usize;
 
             let date_str = current_date.format("%Y-%m-%d").to_string();
 
@@ -454,8 +591,11 @@ fn render_heatmap(ui: &mut Ui, study_data: &StudyData, _current_week_start: Naiv
 
         // Draw tooltip for hovered cell (after all cells, to be on top)
         if let Some((date, cell_rect, activity_level)) = hovered_cell {
-            let tooltip_text =
-                format!("{}: {:.1} minutes", date.format("%Y-%m-%d"), activity_level);
+            let tooltip_text = format!(
+                "{}: {} minutes",
+                settings.date_format.format(date),
+                settings.number_format.format(activity_level, 1)
+            );
 
             // Position tooltip above the cell
             let tooltip_pos = egui::pos2(cell_rect.center().x, cell_rect.top() - 5.0);
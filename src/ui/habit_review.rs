@@ -0,0 +1,112 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use crate::settings::ColorTheme;
+use chrono::Local;
+use eframe::egui;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+thread_local! {
+    // Habits ticked in the open review, None while it is closed
+    static CHECKED: RefCell<Option<HashSet<u64>>> = RefCell::new(None);
+}
+
+pub fn open() {
+    CHECKED.with(|checked| *checked.borrow_mut() = Some(HashSet::new()));
+}
+
+pub fn display(
+    ctx: &egui::Context,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    colors: &ColorTheme,
+) {
+    if CHECKED.with(|checked| checked.borrow().is_none()) {
+        return;
+    }
+
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+    let pending: Vec<_> = study_data
+        .habits
+        .iter()
+        .filter(|h| !h.completion_dates.contains(&today) && !h.is_paused_on(&today))
+        .collect();
+
+    let mut open = true;
+    let mut save = false;
+    let mut close = false;
+
+    egui::Window::new("🌙 Evening Review")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            if pending.is_empty() {
+                ui.label("Every habit is checked off for today. Nice work!");
+                ui.add_space(5.0);
+                close = ui.button("Close").clicked();
+                return;
+            }
+
+            ui.label(
+                egui::RichText::new(format!("{} habit(s) still open today", pending.len()))
+                    .color(colors.text_secondary_color32()),
+            );
+            ui.add_space(5.0);
+
+            CHECKED.with(|checked| {
+                let mut checked = checked.borrow_mut();
+                let Some(checked) = checked.as_mut() else {
+                    return;
+                };
+
+                egui::ScrollArea::vertical()
+                    .max_height(300.0)
+                    .show(ui, |ui| {
+                        for habit in &pending {
+                            let mut done = checked.contains(&habit.id);
+                            let label = format!("{}  ·  {}", habit.name, habit.category);
+                            if ui.checkbox(&mut done, label).changed() {
+                                if done {
+                                    checked.insert(habit.id);
+                                } else {
+                                    checked.remove(&habit.id);
+                                }
+                            }
+                        }
+                    });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Check all").clicked() {
+                        checked.extend(pending.iter().map(|h| h.id));
+                    }
+                    if ui.button("Clear").clicked() {
+                        checked.clear();
+                    }
+                    ui.separator();
+                    save = ui
+                        .add_enabled(
+                            !checked.is_empty(),
+                            egui::Button::new(format!("💾 Save ({})", checked.len())),
+                        )
+                        .clicked();
+                    close = ui.button("Cancel").clicked();
+                });
+            });
+        });
+
+    if save {
+        let ids: Vec<u64> =
+            CHECKED.with(|checked| checked.borrow().iter().flatten().copied().collect());
+        match study_data.mark_habits_complete_today(&ids) {
+            Ok(()) => status.show(&format!("{} habit(s) checked off for today", ids.len())),
+            Err(e) => status.error(&format!("Error saving habits: {}", e)),
+        }
+        close = true;
+    }
+    if close || !open {
+        CHECKED.with(|checked| *checked.borrow_mut() = None);
+    }
+}
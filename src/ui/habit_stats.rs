@@ -0,0 +1,145 @@
+use crate::data::{HabitStats, StudyData};
+use crate::settings::ColorTheme;
+use eframe::egui;
+use std::cell::RefCell;
+
+thread_local! {
+    static OPEN_HABIT: RefCell<Option<u64>> = RefCell::new(None);
+}
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+pub fn open(habit_id: u64) {
+    OPEN_HABIT.with(|habit| *habit.borrow_mut() = Some(habit_id));
+}
+
+pub fn display(ctx: &egui::Context, study_data: &StudyData, colors: &ColorTheme) {
+    let Some(habit_id) = OPEN_HABIT.with(|habit| *habit.borrow()) else {
+        return;
+    };
+    let (Some(habit), Some(stats)) = (
+        study_data.habits.iter().find(|h| h.id == habit_id),
+        study_data.get_habit_stats(habit_id),
+    ) else {
+        OPEN_HABIT.with(|habit| *habit.borrow_mut() = None);
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new(format!("📊 {}", habit.name))
+        .id(egui::Id::new("habit_stats_window"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            egui::Grid::new("habit_stats_numbers")
+                .num_columns(2)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    let rows = [
+                        (
+                            "Current streak",
+                            format!("🔥 {} days", stats.current_streak),
+                        ),
+                        ("Best streak", format!("🏆 {} days", stats.best_streak)),
+                        ("Total completions", stats.total_completions.to_string()),
+                        (
+                            "Last 7 days",
+                            format!("{:.0}%", stats.completion_rate_7_days * 100.0),
+                        ),
+                        (
+                            "Last 30 days",
+                            format!("{:.0}%", stats.completion_rate_30_days * 100.0),
+                        ),
+                    ];
+                    for (label, value) in rows {
+                        ui.label(egui::RichText::new(label).color(colors.text_secondary_color32()));
+                        ui.label(egui::RichText::new(value).strong());
+                        ui.end_row();
+                    }
+                });
+
+            ui.separator();
+            ui.label(egui::RichText::new("Completions by weekday").strong());
+            weekday_bars(ui, &stats, colors);
+
+            ui.separator();
+            ui.label(
+                egui::RichText::new(format!("Last {} weeks", stats.weekly_trend.len())).strong(),
+            );
+            sparkline(ui, &stats.weekly_trend, colors);
+        });
+
+    if !open {
+        OPEN_HABIT.with(|habit| *habit.borrow_mut() = None);
+    }
+}
+
+fn weekday_bars(ui: &mut egui::Ui, stats: &HabitStats, colors: &ColorTheme) {
+    let max = stats
+        .weekday_completions
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let bar_height = 60.0;
+
+    ui.horizontal(|ui| {
+        for (day, count) in WEEKDAYS.iter().zip(stats.weekday_completions) {
+            ui.vertical(|ui| {
+                ui.set_width(32.0);
+                let (rect, response) =
+                    ui.allocate_exact_size(egui::vec2(24.0, bar_height), egui::Sense::hover());
+                let height = bar_height * count as f32 / max as f32;
+                let bar = egui::Rect::from_min_max(
+                    egui::pos2(rect.left(), rect.bottom() - height),
+                    rect.right_bottom(),
+                );
+                ui.painter()
+                    .rect_filled(bar, egui::Rounding::same(2.0), colors.accent_color32());
+                response.on_hover_text(format!("{}: {} completions", day, count));
+                ui.label(egui::RichText::new(*day).small());
+            });
+        }
+    });
+}
+
+fn sparkline(ui: &mut egui::Ui, weeks: &[usize], colors: &ColorTheme) {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(240.0, 50.0), egui::Sense::hover());
+    if weeks.len() < 2 {
+        return;
+    }
+
+    // A week can have at most 7 completions
+    let step = rect.width() / (weeks.len() - 1) as f32;
+    let points: Vec<egui::Pos2> = weeks
+        .iter()
+        .enumerate()
+        .map(|(i, count)| {
+            egui::pos2(
+                rect.left() + step * i as f32,
+                rect.bottom() - rect.height() * (*count).min(7) as f32 / 7.0,
+            )
+        })
+        .collect();
+
+    let painter = ui.painter();
+    painter.line_segment(
+        [rect.left_bottom(), rect.right_bottom()],
+        egui::Stroke::new(1.0, colors.text_secondary_color32()),
+    );
+    painter.add(egui::Shape::line(
+        points.clone(),
+        egui::Stroke::new(2.0, colors.accent_color32()),
+    ));
+    for point in &points {
+        painter.circle_filled(*point, 2.5, colors.accent_color32());
+    }
+
+    let summary: Vec<String> = weeks.iter().map(|c| c.to_string()).collect();
+    response.on_hover_text(format!(
+        "Completions per week, oldest first: {}",
+        summary.join(", ")
+    ));
+}
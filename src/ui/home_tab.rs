@@ -0,0 +1,597 @@
+use crate::app::Tab;
+use crate::data::StudyData;
+use crate::quotes;
+use crate::timer::Timer;
+use crate::StudyTimerApp;
+use chrono::{Datelike, Duration, Local};
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HomeWidget {
+    LifetimeHours,
+    Streaks,
+    DueCards,
+    WeeklyChart,
+    Quote,
+    Timer,
+    Habits,
+    Weather,
+    Goal,
+}
+
+impl HomeWidget {
+    pub const ALL: [HomeWidget; 9] = [
+        HomeWidget::LifetimeHours,
+        HomeWidget::Streaks,
+        HomeWidget::DueCards,
+        HomeWidget::WeeklyChart,
+        HomeWidget::Quote,
+        HomeWidget::Timer,
+        HomeWidget::Habits,
+        HomeWidget::Weather,
+        HomeWidget::Goal,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HomeWidget::LifetimeHours => "Lifetime hours",
+            HomeWidget::Streaks => "Streaks",
+            HomeWidget::DueCards => "Decks due",
+            HomeWidget::WeeklyChart => "This week",
+            HomeWidget::Quote => "Quote of the day",
+            HomeWidget::Timer => "Timer",
+            HomeWidget::Habits => "Today's habits",
+            HomeWidget::Weather => "Weather",
+            HomeWidget::Goal => "Daily goal",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WidgetSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl WidgetSize {
+    pub const ALL: [WidgetSize; 3] = [WidgetSize::Small, WidgetSize::Medium, WidgetSize::Large];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WidgetSize::Small => "S",
+            WidgetSize::Medium => "M",
+            WidgetSize::Large => "L",
+        }
+    }
+
+    // Share of a row's width
+    fn fraction(&self) -> f32 {
+        match self {
+            WidgetSize::Small => 1.0 / 3.0,
+            WidgetSize::Medium => 0.5,
+            WidgetSize::Large => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HomeWidgetConfig {
+    pub widget: HomeWidget,
+    pub size: WidgetSize,
+}
+
+pub fn default_widgets() -> Vec<HomeWidgetConfig> {
+    [
+        (HomeWidget::LifetimeHours, WidgetSize::Small),
+        (HomeWidget::Streaks, WidgetSize::Small),
+        (HomeWidget::DueCards, WidgetSize::Small),
+        (HomeWidget::WeeklyChart, WidgetSize::Large),
+        (HomeWidget::Quote, WidgetSize::Large),
+    ]
+    .into_iter()
+    .map(|(widget, size)| HomeWidgetConfig { widget, size })
+    .collect()
+}
+
+enum LayoutChange {
+    Move(usize, isize),
+    Resize(usize, WidgetSize),
+    Remove(usize),
+    Add(HomeWidget),
+}
+
+thread_local! {
+    static EDITING: Cell<bool> = Cell::new(false);
+}
+
+pub fn display(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
+    let mut editing = EDITING.with(Cell::get);
+    let mut change = None;
+
+    ui.horizontal(|ui| {
+        ui.heading("🏠 Home");
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let label = if editing { "✔ Done" } else { "✏ Customize" };
+            if ui.button(label).clicked() {
+                editing = !editing;
+            }
+            if editing {
+                ui.menu_button("➕ Add widget", |ui| {
+                    let shown = &app.settings.home_widgets;
+                    let hidden: Vec<HomeWidget> = HomeWidget::ALL
+                        .into_iter()
+                        .filter(|widget| !shown.iter().any(|c| c.widget == *widget))
+                        .collect();
+                    if hidden.is_empty() {
+                        ui.label("All widgets are shown");
+                    }
+                    for widget in hidden {
+                        if ui.button(widget.name()).clicked() {
+                            change = Some(LayoutChange::Add(widget));
+                            ui.close_menu();
+                        }
+                    }
+                });
+            }
+        });
+    });
+    EDITING.with(|cell| cell.set(editing));
+    ui.separator();
+
+    if app.settings.home_widgets.is_empty() {
+        ui.vertical_centered(|ui| {
+            ui.add_space(50.0);
+            ui.label("No widgets shown. Add some with ✏ Customize.");
+        });
+    }
+
+    let mut jump_to = None;
+    let widgets = app.settings.home_widgets.clone();
+    egui::ScrollArea::vertical()
+        .auto_shrink([false; 2])
+        .show(ui, |ui| {
+            let width = ui.available_width();
+            let spacing = ui.spacing().item_spacing.x;
+            for row in rows(&widgets) {
+                ui.horizontal_top(|ui| {
+                    for index in row {
+                        let config = &widgets[index];
+                        let widget_width = width * config.size.fraction() - spacing;
+                        ui.allocate_ui(egui::vec2(widget_width, 0.0), |ui| {
+                            ui.group(|ui| {
+                                ui.set_width(widget_width - 2.0 * ui.spacing().item_spacing.x);
+                                ui.horizontal(|ui| {
+                                    ui.strong(config.widget.name());
+                                    if editing {
+                                        if let Some(edit) = edit_controls(ui, index, config) {
+                                            change = Some(edit);
+                                        }
+                                    }
+                                });
+                                ui.add_space(4.0);
+                                if let Some(tab) = show_widget(ui, app, config.widget) {
+                                    jump_to = Some(tab);
+                                }
+                            });
+                        });
+                    }
+                });
+                ui.add_space(8.0);
+            }
+        });
+
+    if let Some(change) = change {
+        apply_change(&mut app.settings.home_widgets, change);
+        if let Err(e) = app.settings.save() {
+            app.status
+                .error(&format!("Failed to save home widgets: {}", e));
+        }
+    }
+    if let Some(tab_type) = jump_to {
+        app.open_tab(tab_type);
+    }
+}
+
+// Indices of the widgets on each row, filling rows left to right by size
+fn rows(widgets: &[HomeWidgetConfig]) -> Vec<Vec<usize>> {
+    let mut rows: Vec<Vec<usize>> = Vec::new();
+    let mut used = 0.0;
+    for (index, config) in widgets.iter().enumerate() {
+        let fraction = config.size.fraction();
+        match rows.last_mut() {
+            Some(row) if used + fraction <= 1.01 => row.push(index),
+            _ => {
+                rows.push(vec![index]);
+                used = 0.0;
+            }
+        }
+        used += fraction;
+    }
+    rows
+}
+
+fn edit_controls(
+    ui: &mut egui::Ui,
+    index: usize,
+    config: &HomeWidgetConfig,
+) -> Option<LayoutChange> {
+    let mut change = None;
+    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+        if ui.small_button("✖").on_hover_text("Remove").clicked() {
+            change = Some(LayoutChange::Remove(index));
+        }
+        for size in WidgetSize::ALL.iter().rev() {
+            if ui
+                .selectable_label(config.size == *size, size.label())
+                .clicked()
+            {
+                change = Some(LayoutChange::Resize(index, *size));
+            }
+        }
+        if ui.small_button("▶").on_hover_text("Move later").clicked() {
+            change = Some(LayoutChange::Move(index, 1));
+        }
+        if ui.small_button("◀").on_hover_text("Move earlier").clicked() {
+            change = Some(LayoutChange::Move(index, -1));
+        }
+    });
+    change
+}
+
+fn apply_change(widgets: &mut Vec<HomeWidgetConfig>, change: LayoutChange) {
+    match change {
+        LayoutChange::Move(index, offset) => {
+            let target = index as isize + offset;
+            if target >= 0 && (target as usize) < widgets.len() {
+                widgets.swap(index, target as usize);
+            }
+        }
+        LayoutChange::Resize(index, size) => widgets[index].size = size,
+        LayoutChange::Remove(index) => {
+            widgets.remove(index);
+        }
+        LayoutChange::Add(widget) => widgets.push(HomeWidgetConfig {
+            widget,
+            size: WidgetSize::Small,
+        }),
+    }
+}
+
+// Returns the tab to jump to when a widget's shortcut is clicked
+fn show_widget(ui: &mut egui::Ui, app: &mut StudyTimerApp, widget: HomeWidget) -> Option<Tab> {
+    match widget {
+        HomeWidget::LifetimeHours => lifetime_hours(ui, &app.study_data),
+        HomeWidget::Streaks => streaks(ui, &app.study_data),
+        HomeWidget::DueCards => due_cards(ui, &app.study_data),
+        HomeWidget::WeeklyChart => weekly_chart(ui, &app.study_data),
+        HomeWidget::Quote => {
+            quote(ui, app);
+            None
+        }
+        HomeWidget::Timer => timer(ui, &app.timer),
+        HomeWidget::Habits => habits(ui, app),
+        HomeWidget::Weather => {
+            weather(ui, app);
+            None
+        }
+        HomeWidget::Goal => goal(ui, app),
+    }
+}
+
+fn lifetime_hours(ui: &mut egui::Ui, study_data: &StudyData) -> Option<Tab> {
+    let total_minutes = study_data.get_total_minutes();
+    ui.label(egui::RichText::new(format!("{:.1} h", total_minutes / 60.0)).size(28.0));
+    ui.horizontal(|ui| {
+        ui.label(format!(
+            "{} sessions · {:.0} min today",
+            study_data.sessions.len(),
+            study_data.get_today_minutes()
+        ));
+        if ui.small_button("Stats →").clicked() {
+            return Some(Tab::Stats);
+        }
+        None
+    })
+    .inner
+}
+
+fn streaks(ui: &mut egui::Ui, study_data: &StudyData) -> Option<Tab> {
+    let study_streak = study_data.study_streak();
+    ui.label(format!(
+        "⏱ Studied {} day{} in a row",
+        study_streak,
+        if study_streak == 1 { "" } else { "s" }
+    ));
+
+    let mut habits: Vec<(&str, u32)> = study_data
+        .habits
+        .iter()
+        .map(|habit| (habit.name.as_str(), habit.calculate_current_streak()))
+        .filter(|(_, streak)| *streak > 0)
+        .collect();
+    habits.sort_by(|a, b| b.1.cmp(&a.1));
+    for (name, streak) in habits.iter().take(5) {
+        ui.label(format!("🔥 {}: {} day streak", name, streak));
+    }
+    if habits.is_empty() && !study_data.habits.is_empty() {
+        ui.label("No habit streaks running. Check one off today!");
+    }
+
+    if ui.small_button("Habits →").clicked() {
+        return Some(Tab::Todo);
+    }
+    None
+}
+
+fn due_cards(ui: &mut egui::Ui, study_data: &StudyData) -> Option<Tab> {
+    let mut due: Vec<(&str, usize)> = study_data
+        .decks
+        .iter()
+        .map(|deck| {
+            let count = deck.get_due_cards(true).len() + deck.get_due_reverse_cards(true).len();
+            (deck.name.as_str(), count)
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    due.sort_by(|a, b| b.1.cmp(&a.1));
+
+    if due.is_empty() {
+        ui.label("Nothing due. All caught up!");
+    }
+    for (name, count) in &due {
+        ui.label(format!("🃏 {}: {} due", name, count));
+    }
+
+    if !study_data.decks.is_empty() && ui.small_button("Review →").clicked() {
+        return Some(Tab::Flashcards);
+    }
+    None
+}
+
+// Minutes studied on each of the last seven days, today on the right
+fn weekly_chart(ui: &mut egui::Ui, study_data: &StudyData) -> Option<Tab> {
+    let today = Local::now().date_naive();
+    let days: Vec<(String, f64)> = (0..7)
+        .rev()
+        .map(|offset| {
+            let date = today - Duration::days(offset);
+            let date_str = date.format("%Y-%m-%d").to_string();
+            let minutes = study_data
+                .sessions
+                .iter()
+                .filter(|s| s.date == date_str)
+                .map(|s| s.minutes)
+                .sum();
+            (date.weekday().to_string(), minutes)
+        })
+        .collect();
+
+    let max_minutes = days
+        .iter()
+        .fold(0.0, |acc: f64, (_, m)| acc.max(*m))
+        .max(1.0);
+    let size = egui::vec2(ui.available_width().min(420.0), 120.0);
+    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter();
+    let text_color = ui.visuals().text_color();
+    let bar_color = egui::Color32::from_rgb(66, 133, 244);
+
+    let label_height = 16.0;
+    let chart_height = rect.height() - 2.0 * label_height;
+    let slot = rect.width() / days.len() as f32;
+    for (i, (day, minutes)) in days.iter().enumerate() {
+        let x_center = rect.left() + (i as f32 + 0.5) * slot;
+        let bottom = rect.bottom() - label_height;
+        let height = (*minutes / max_minutes) as f32 * chart_height;
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(x_center - slot * 0.3, bottom - height),
+            egui::pos2(x_center + slot * 0.3, bottom),
+        );
+        painter.rect_filled(bar, 3.0, bar_color);
+        painter.text(
+            egui::pos2(x_center, bottom + 2.0),
+            egui::Align2::CENTER_TOP,
+            day,
+            egui::FontId::proportional(11.0),
+            text_color,
+        );
+        if *minutes > 0.0 {
+            painter.text(
+                egui::pos2(x_center, bar.top() - 2.0),
+                egui::Align2::CENTER_BOTTOM,
+                format!("{:.0}", minutes),
+                egui::FontId::proportional(11.0),
+                text_color,
+            );
+        }
+    }
+
+    let week_minutes: f64 = days.iter().map(|(_, m)| m).sum();
+    ui.horizontal(|ui| {
+        ui.label(format!("{:.1} h in the last 7 days", week_minutes / 60.0));
+        if ui.small_button("Graph →").clicked() {
+            return Some(Tab::Graph);
+        }
+        None
+    })
+    .inner
+}
+
+fn quote(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
+    let settings = &mut app.settings;
+    let mut pool = quotes::bundled();
+    if let Some(path) = &settings.quotes_file {
+        match quotes::load_file(path) {
+            Ok(file_quotes) if !file_quotes.is_empty() => pool = file_quotes,
+            Ok(_) => {
+                ui.small("The quotes file has no quotes, showing the built-in ones");
+            }
+            Err(e) => {
+                ui.small(format!("Could not read the quotes file: {}", e));
+            }
+        }
+    }
+    // Pinned quotes are kept as "Text — Author", which parses back the same way
+    if settings.pinned_quotes_only && !settings.pinned_quotes.is_empty() {
+        pool = quotes::parse(&settings.pinned_quotes.join("\n"));
+    }
+
+    let mut changed = false;
+    if let Some(quote) = quotes::of_the_day(&pool) {
+        ui.label(
+            egui::RichText::new(format!("“{}”", quote.text))
+                .italics()
+                .size(16.0),
+        );
+        if !quote.author.is_empty() {
+            ui.label(format!("— {}", quote.author));
+        }
+
+        let key = quote.key();
+        let pinned = settings.pinned_quotes.contains(&key);
+        ui.horizontal(|ui| {
+            if ui
+                .selectable_label(pinned, "📌")
+                .on_hover_text(if pinned { "Unpin" } else { "Pin to favorites" })
+                .clicked()
+            {
+                if pinned {
+                    settings.pinned_quotes.retain(|k| *k != key);
+                } else {
+                    settings.pinned_quotes.push(key.clone());
+                }
+                changed = true;
+            }
+            changed |= ui
+                .add_enabled(
+                    !settings.pinned_quotes.is_empty(),
+                    egui::Checkbox::new(&mut settings.pinned_quotes_only, "Pinned only"),
+                )
+                .changed();
+        });
+    }
+
+    ui.horizontal(|ui| {
+        if ui
+            .small_button("📂 Quotes file…")
+            .on_hover_text("One quote per line, as \"Text — Author\"")
+            .clicked()
+        {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("Text", &["txt", "md"])
+                .pick_file()
+            {
+                settings.quotes_file = Some(path.to_string_lossy().to_string());
+                quotes::reload();
+                changed = true;
+            }
+        }
+        if settings.quotes_file.is_some()
+            && ui
+                .small_button("✖")
+                .on_hover_text("Use the built-in quotes")
+                .clicked()
+        {
+            settings.quotes_file = None;
+            changed = true;
+        }
+    });
+
+    if changed {
+        if let Err(e) = settings.save() {
+            app.status
+                .error(&format!("Failed to save quote settings: {}", e));
+        }
+    }
+}
+
+fn timer(ui: &mut egui::Ui, timer: &Timer) -> Option<Tab> {
+    let elapsed = timer.get_elapsed_time().as_secs();
+    ui.label(
+        egui::RichText::new(format!(
+            "{:02}:{:02}:{:02}",
+            elapsed / 3600,
+            elapsed / 60 % 60,
+            elapsed % 60
+        ))
+        .size(28.0)
+        .monospace(),
+    );
+    ui.horizontal(|ui| {
+        ui.label(if timer.is_running {
+            "▶ Running"
+        } else if elapsed > 0 {
+            "⏸ Paused"
+        } else {
+            "Not started"
+        });
+        if ui.small_button("Timer →").clicked() {
+            return Some(Tab::Timer);
+        }
+        None
+    })
+    .inner
+}
+
+// Habits can be checked off for today right from the widget
+fn habits(ui: &mut egui::Ui, app: &mut StudyTimerApp) -> Option<Tab> {
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+    if app.study_data.habits.is_empty() {
+        ui.label("No habits yet.");
+    }
+
+    let mut completed = None;
+    for habit in &app.study_data.habits {
+        let mut done = habit.completion_dates.contains(&today);
+        let response = ui.add_enabled(!done, egui::Checkbox::new(&mut done, &habit.name));
+        if response.changed() {
+            completed = Some(habit.id);
+        }
+    }
+    if let Some(id) = completed {
+        if let Err(e) = app.study_data.mark_habit_complete_today(id) {
+            app.status.error(&format!("Error saving: {}", e));
+        }
+    }
+
+    if ui.small_button("Habits →").clicked() {
+        return Some(Tab::Todo);
+    }
+    None
+}
+
+fn weather(ui: &mut egui::Ui, app: &mut StudyTimerApp) {
+    match &app.weather_widget.city {
+        Some(city) => ui.label(city),
+        None => ui.label("No city set"),
+    };
+    if app.weather_widget.render(ui) {
+        let _ = app.weather_widget.save();
+    }
+}
+
+fn goal(ui: &mut egui::Ui, app: &mut StudyTimerApp) -> Option<Tab> {
+    let goal = app.settings.daily_goal_minutes;
+    if goal == 0 {
+        ui.label("No daily goal set.");
+        if ui.small_button("Settings →").clicked() {
+            return Some(Tab::Settings);
+        }
+        return None;
+    }
+
+    let minutes = app.study_data.get_today_minutes();
+    let progress = (minutes / goal as f64).min(1.0) as f32;
+    ui.add(
+        egui::ProgressBar::new(progress)
+            .text(format!("{:.0} / {} min", minutes, goal))
+            .desired_width(ui.available_width()),
+    );
+    if progress >= 1.0 {
+        ui.label("🎯 Reached for today!");
+    }
+    None
+}
@@ -0,0 +1,620 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use crate::image_handler::CardImage;
+use crate::tab_manager::TabInstance;
+use crate::ui::flashcard::Card;
+use eframe::egui::{self, Color32, Pos2, Rect, Stroke, Vec2};
+use image::RgbaImage;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::Path;
+
+const MIN_ZOOM: f32 = 0.05;
+const MAX_ZOOM: f32 = 20.0;
+const COLORS: [Color32; 5] = [
+    Color32::from_rgb(230, 60, 60),
+    Color32::from_rgb(250, 200, 40),
+    Color32::from_rgb(60, 180, 90),
+    Color32::from_rgb(50, 120, 230),
+    Color32::WHITE,
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tool {
+    Pan,
+    Arrow,
+    Box,
+    Text,
+}
+
+// Positions are in image pixels, so annotations stay put while zooming
+enum Annotation {
+    Arrow {
+        from: Pos2,
+        to: Pos2,
+        color: Color32,
+    },
+    Box {
+        rect: Rect,
+        color: Color32,
+    },
+    Text {
+        pos: Pos2,
+        text: String,
+        color: Color32,
+    },
+}
+
+struct ViewerState {
+    image: RgbaImage,
+    texture: egui::TextureHandle,
+    zoom: Option<f32>, // None fits the image to the view
+    pan: Vec2,
+    tool: Tool,
+    color: Color32,
+    label: String,
+    drag_start: Option<Pos2>,
+    annotations: Vec<Annotation>,
+    card: Option<CardDraft>,
+}
+
+#[derive(Default)]
+pub struct CardDraft {
+    pub deck_id: Option<u64>,
+    pub front: String,
+    pub back: String,
+}
+
+thread_local! {
+    // Keyed by file path, so each image keeps its view and annotations
+    static VIEWERS: RefCell<HashMap<String, ViewerState>> = RefCell::new(HashMap::new());
+}
+
+impl ViewerState {
+    fn load(ctx: &egui::Context, path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let image = image::open(path)?.to_rgba8();
+        let size = [image.width() as usize, image.height() as usize];
+        let color_image =
+            egui::ColorImage::from_rgba_unmultiplied(size, image.as_flat_samples().as_slice());
+        let texture = ctx.load_texture(
+            format!("image_viewer_{}", path),
+            color_image,
+            egui::TextureOptions::default(),
+        );
+        Ok(Self {
+            image,
+            texture,
+            zoom: None,
+            pan: Vec2::ZERO,
+            tool: Tool::Pan,
+            color: COLORS[0],
+            label: String::new(),
+            drag_start: None,
+            annotations: Vec::new(),
+            card: None,
+        })
+    }
+
+    fn image_size(&self) -> Vec2 {
+        Vec2::new(self.image.width() as f32, self.image.height() as f32)
+    }
+
+    // Annotation line width in image pixels, so it reads the same on small and large images
+    fn stroke_width(&self) -> f32 {
+        (self.image.width().max(self.image.height()) as f32 / 300.0).max(2.0)
+    }
+}
+
+pub fn display(
+    ui: &mut egui::Ui,
+    tab: Option<&mut TabInstance>,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+) {
+    let Some(tab) = tab else {
+        return;
+    };
+    let Some(path) = tab.file_path.clone() else {
+        ui.vertical_centered(|ui| {
+            ui.add_space(40.0);
+            ui.heading("Image Viewer");
+            ui.label("Drop an image on the window, or pick one to view and annotate.");
+            if ui.button("📂 Open Image…").clicked() {
+                if let Some(picked) = crate::image_handler::open_file_dialog() {
+                    tab.title = picked.file_name().map_or("Image".to_string(), |name| {
+                        name.to_string_lossy().to_string()
+                    });
+                    tab.file_path = Some(picked.to_string_lossy().to_string());
+                }
+            }
+        });
+        return;
+    };
+
+    VIEWERS.with(|viewers| {
+        let mut viewers = viewers.borrow_mut();
+        if !viewers.contains_key(&path) {
+            match ViewerState::load(ui.ctx(), &path) {
+                Ok(state) => {
+                    viewers.insert(path.clone(), state);
+                }
+                Err(e) => {
+                    ui.colored_label(
+                        Color32::from_rgb(213, 94, 0),
+                        format!("Could not open {}: {}", path, e),
+                    );
+                    return;
+                }
+            }
+        }
+        let Some(state) = viewers.get_mut(&path) else {
+            return;
+        };
+
+        toolbar(ui, state);
+        ui.separator();
+        canvas(ui, state);
+        if let Some(draft) = card_dialog(
+            ui.ctx(),
+            "image_viewer_card",
+            "The image goes on the front.",
+            &mut state.card,
+            study_data,
+        ) {
+            let image = card_image(ui.ctx(), state, &path);
+            add_image_card(draft, image, study_data, status);
+        }
+    });
+}
+
+fn toolbar(ui: &mut egui::Ui, state: &mut ViewerState) {
+    ui.horizontal_wrapped(|ui| {
+        ui.selectable_value(&mut state.tool, Tool::Pan, "✋ Pan");
+        ui.selectable_value(&mut state.tool, Tool::Arrow, "↗ Arrow");
+        ui.selectable_value(&mut state.tool, Tool::Box, "⬜ Box");
+        ui.selectable_value(&mut state.tool, Tool::Text, "🔤 Text");
+        if state.tool == Tool::Text {
+            ui.add(
+                egui::TextEdit::singleline(&mut state.label)
+                    .hint_text("Label, then click the image")
+                    .desired_width(160.0),
+            );
+        }
+
+        ui.separator();
+        for color in COLORS {
+            let (rect, response) = ui.allocate_exact_size(Vec2::splat(16.0), egui::Sense::click());
+            ui.painter().rect_filled(rect, 3.0, color);
+            if state.color == color {
+                ui.painter().rect_stroke(
+                    rect.expand(2.0),
+                    3.0,
+                    Stroke::new(2.0, ui.visuals().text_color()),
+                );
+            }
+            if response.clicked() {
+                state.color = color;
+            }
+        }
+
+        ui.separator();
+        if ui
+            .add_enabled(!state.annotations.is_empty(), egui::Button::new("↶ Undo"))
+            .clicked()
+        {
+            state.annotations.pop();
+        }
+        if ui
+            .add_enabled(!state.annotations.is_empty(), egui::Button::new("🗑 Clear"))
+            .clicked()
+        {
+            state.annotations.clear();
+        }
+
+        ui.separator();
+        if ui.button("➖").clicked() {
+            state.zoom = state.zoom.map(|z| (z / 1.25).max(MIN_ZOOM));
+        }
+        if ui.button("➕").clicked() {
+            state.zoom = state.zoom.map(|z| (z * 1.25).min(MAX_ZOOM));
+        }
+        if ui.button("Fit").clicked() {
+            state.zoom = None;
+            state.pan = Vec2::ZERO;
+        }
+        if ui.button("100%").clicked() {
+            state.zoom = Some(1.0);
+            state.pan = Vec2::ZERO;
+        }
+
+        ui.separator();
+        if ui.button("🃏 Send to Flashcard").clicked() {
+            state.card = Some(CardDraft {
+                deck_id: None,
+                front: String::new(),
+                back: String::new(),
+            });
+        }
+    });
+}
+
+fn canvas(ui: &mut egui::Ui, state: &mut ViewerState) {
+    let (response, painter) =
+        ui.allocate_painter(ui.available_size(), egui::Sense::click_and_drag());
+    let view = response.rect;
+    painter.rect_filled(view, 0.0, Color32::from_gray(30));
+
+    let size = state.image_size();
+    let fit = (view.width() / size.x).min(view.height() / size.y).min(1.0);
+    let zoom = state.zoom.unwrap_or(fit);
+    if state.zoom.is_none() {
+        state.pan = Vec2::ZERO;
+    }
+
+    // Zoom around the pointer with the scroll wheel
+    if let Some(pointer) = response.hover_pos() {
+        let scroll = ui.input(|i| i.scroll_delta.y);
+        if scroll != 0.0 {
+            let new_zoom = (zoom * (1.0 + scroll * 0.002)).clamp(MIN_ZOOM, MAX_ZOOM);
+            let anchor = pointer - view.center() - state.pan;
+            state.pan -= anchor * (new_zoom / zoom - 1.0);
+            state.zoom = Some(new_zoom);
+        }
+    }
+    let zoom = state.zoom.unwrap_or(fit);
+    let image_rect = Rect::from_center_size(view.center() + state.pan, size * zoom);
+    let to_image = |p: Pos2| ((p - image_rect.min) / zoom).to_pos2();
+    let to_screen = |p: Pos2| image_rect.min + p.to_vec2() * zoom;
+
+    let painter = painter.with_clip_rect(view);
+    painter.image(
+        state.texture.id(),
+        image_rect,
+        Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
+        Color32::WHITE,
+    );
+
+    let width = state.stroke_width() * zoom;
+    for annotation in &state.annotations {
+        paint(&painter, annotation, width, to_screen);
+    }
+
+    match state.tool {
+        Tool::Pan => {
+            if response.dragged() {
+                state.pan += response.drag_delta();
+                state.zoom = Some(zoom);
+            }
+        }
+        Tool::Arrow | Tool::Box => {
+            if response.drag_started() {
+                state.drag_start = response.interact_pointer_pos().map(to_image);
+            }
+            let current = response.interact_pointer_pos().map(to_image);
+            if let (Some(start), Some(end)) = (state.drag_start, current) {
+                let annotation = shape(state.tool, start, end, state.color);
+                if response.drag_released() {
+                    if start.distance(end) * zoom > 4.0 {
+                        state.annotations.push(annotation);
+                    }
+                    state.drag_start = None;
+                } else {
+                    paint(&painter, &annotation, width, to_screen);
+                }
+            }
+        }
+        Tool::Text => {
+            if response.clicked() && !state.label.trim().is_empty() {
+                if let Some(pos) = response.interact_pointer_pos() {
+                    state.annotations.push(Annotation::Text {
+                        pos: to_image(pos),
+                        text: state.label.trim().to_string(),
+                        color: state.color,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn shape(tool: Tool, start: Pos2, end: Pos2, color: Color32) -> Annotation {
+    match tool {
+        Tool::Box => Annotation::Box {
+            rect: Rect::from_two_pos(start, end),
+            color,
+        },
+        _ => Annotation::Arrow {
+            from: start,
+            to: end,
+            color,
+        },
+    }
+}
+
+// The two lines of an arrow head at `to`
+fn arrow_head(from: Pos2, to: Pos2, length: f32) -> [Pos2; 2] {
+    let direction = (to - from).normalized();
+    let back = -direction * length;
+    let side = direction.rot90() * length * 0.5;
+    [to + back + side, to + back - side]
+}
+
+fn paint(
+    painter: &egui::Painter,
+    annotation: &Annotation,
+    width: f32,
+    to_screen: impl Fn(Pos2) -> Pos2,
+) {
+    match annotation {
+        Annotation::Arrow { from, to, color } => {
+            let stroke = Stroke::new(width, *color);
+            let (from, to) = (to_screen(*from), to_screen(*to));
+            painter.line_segment([from, to], stroke);
+            for end in arrow_head(from, to, width * 5.0) {
+                painter.line_segment([to, end], stroke);
+            }
+        }
+        Annotation::Box { rect, color } => {
+            let rect = Rect::from_two_pos(to_screen(rect.min), to_screen(rect.max));
+            painter.rect_stroke(rect, 0.0, Stroke::new(width, *color));
+        }
+        Annotation::Text { pos, text, color } => {
+            painter.text(
+                to_screen(*pos),
+                egui::Align2::LEFT_TOP,
+                text,
+                egui::FontId::proportional(width * 8.0),
+                *color,
+            );
+        }
+    }
+}
+
+// Draws the annotations into a copy of the image, for use outside the viewer
+fn burn_in(ctx: &egui::Context, state: &ViewerState) -> RgbaImage {
+    let mut image = state.image.clone();
+    let width = state.stroke_width();
+    for annotation in &state.annotations {
+        match annotation {
+            Annotation::Arrow { from, to, color } => {
+                draw_line(&mut image, *from, *to, width, *color);
+                for end in arrow_head(*from, *to, width * 5.0) {
+                    draw_line(&mut image, *to, end, width, *color);
+                }
+            }
+            Annotation::Box { rect, color } => {
+                let corners = [
+                    rect.left_top(),
+                    rect.right_top(),
+                    rect.right_bottom(),
+                    rect.left_bottom(),
+                ];
+                for i in 0..4 {
+                    draw_line(&mut image, corners[i], corners[(i + 1) % 4], width, *color);
+                }
+            }
+            Annotation::Text { pos, text, color } => {
+                draw_text(ctx, &mut image, *pos, text, width * 8.0, *color);
+            }
+        }
+    }
+    image
+}
+
+fn blend(image: &mut RgbaImage, x: i64, y: i64, color: Color32, coverage: f32) {
+    if x < 0 || y < 0 || x >= image.width() as i64 || y >= image.height() as i64 {
+        return;
+    }
+    let pixel = image.get_pixel_mut(x as u32, y as u32);
+    let alpha = coverage.clamp(0.0, 1.0);
+    for (channel, value) in pixel
+        .0
+        .iter_mut()
+        .take(3)
+        .zip([color.r(), color.g(), color.b()])
+    {
+        *channel = (*channel as f32 * (1.0 - alpha) + value as f32 * alpha).round() as u8;
+    }
+    pixel.0[3] = pixel.0[3].max((alpha * 255.0) as u8);
+}
+
+// A thick line, as the set of pixels within half the width of the segment
+pub fn draw_line(image: &mut RgbaImage, from: Pos2, to: Pos2, width: f32, color: Color32) {
+    let radius = width / 2.0;
+    let bounds = Rect::from_two_pos(from, to).expand(radius + 1.0);
+    let segment = to - from;
+    let length_sq = segment.length_sq().max(f32::EPSILON);
+    for y in bounds.min.y.floor() as i64..=bounds.max.y.ceil() as i64 {
+        for x in bounds.min.x.floor() as i64..=bounds.max.x.ceil() as i64 {
+            let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let t = ((p - from).dot(segment) / length_sq).clamp(0.0, 1.0);
+            let distance = p.distance(from + segment * t);
+            // Soft edge one pixel wide
+            blend(image, x, y, color, radius + 0.5 - distance);
+        }
+    }
+}
+
+// Copies glyph coverage out of egui's font atlas
+fn draw_text(
+    ctx: &egui::Context,
+    image: &mut RgbaImage,
+    pos: Pos2,
+    text: &str,
+    height: f32,
+    color: Color32,
+) {
+    let font_size = 32.0;
+    // Laying out first makes sure the glyphs are in the atlas
+    let (galley, atlas) = ctx.fonts(|fonts| {
+        let galley = fonts.layout_no_wrap(
+            text.to_string(),
+            egui::FontId::proportional(font_size),
+            color,
+        );
+        (galley, fonts.image())
+    });
+    let scale = height / font_size; // Image pixels per point
+
+    for row in &galley.rows {
+        for glyph in &row.glyphs {
+            let uv = glyph.uv_rect;
+            let top_left = glyph.pos + uv.offset;
+            let dest_min = pos + top_left.to_vec2() * scale;
+            let dest_size = uv.size * scale;
+            let texels = Vec2::new(
+                (uv.max[0] - uv.min[0]) as f32,
+                (uv.max[1] - uv.min[1]) as f32,
+            );
+            if texels.x <= 0.0 || texels.y <= 0.0 {
+                continue;
+            }
+            for dy in 0..dest_size.y.ceil() as i64 {
+                for dx in 0..dest_size.x.ceil() as i64 {
+                    let tx =
+                        uv.min[0] as usize + ((dx as f32 + 0.5) / dest_size.x * texels.x) as usize;
+                    let ty =
+                        uv.min[1] as usize + ((dy as f32 + 0.5) / dest_size.y * texels.y) as usize;
+                    let coverage = atlas
+                        .pixels
+                        .get(ty * atlas.size[0] + tx)
+                        .copied()
+                        .unwrap_or(0.0);
+                    if coverage > 0.0 {
+                        blend(
+                            image,
+                            dest_min.x as i64 + dx,
+                            dest_min.y as i64 + dy,
+                            color,
+                            coverage,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn card_image(
+    ctx: &egui::Context,
+    state: &ViewerState,
+    path: &str,
+) -> Result<CardImage, Box<dyn std::error::Error>> {
+    let mut png = Vec::new();
+    burn_in(ctx, state).write_to(&mut Cursor::new(&mut png), image::ImageOutputFormat::Png)?;
+    let stem = Path::new(path)
+        .file_stem()
+        .map_or("image".to_string(), |s| s.to_string_lossy().to_string());
+    CardImage::new(format!("{}_annotated.png", stem), png)
+}
+
+// Shared with the whiteboard; returns the draft once the user confirms it
+pub fn card_dialog(
+    ctx: &egui::Context,
+    id_source: &str,
+    note: &str,
+    card: &mut Option<CardDraft>,
+    study_data: &StudyData,
+) -> Option<CardDraft> {
+    let draft = card.as_mut()?;
+    let mut open = true;
+    let mut create = false;
+    let mut cancel = false;
+
+    egui::Window::new("Send to Flashcard")
+        .id(egui::Id::new(id_source))
+        .open(&mut open)
+        .collapsible(false)
+        .default_size([360.0, 240.0])
+        .show(ctx, |ui| {
+            if study_data.decks.is_empty() {
+                ui.label("Create a deck in the Flashcards tab first.");
+                return;
+            }
+            if !draft
+                .deck_id
+                .map_or(false, |id| study_data.decks.iter().any(|d| d.id == id))
+            {
+                draft.deck_id = study_data.decks.first().map(|d| d.id);
+            }
+            let selected_name = study_data
+                .decks
+                .iter()
+                .find(|d| Some(d.id) == draft.deck_id)
+                .map(|d| d.name.clone())
+                .unwrap_or_default();
+            ui.horizontal(|ui| {
+                ui.label("Deck:");
+                egui::ComboBox::from_id_source((id_source, "deck"))
+                    .selected_text(selected_name)
+                    .show_ui(ui, |ui| {
+                        for deck in &study_data.decks {
+                            ui.selectable_value(&mut draft.deck_id, Some(deck.id), &deck.name);
+                        }
+                    });
+            });
+            ui.label(note);
+            ui.label("Front (Question):");
+            ui.add(
+                egui::TextEdit::multiline(&mut draft.front)
+                    .desired_rows(2)
+                    .hint_text("The question to answer"),
+            );
+            ui.label("Back (Answer):");
+            ui.add(egui::TextEdit::multiline(&mut draft.back).desired_rows(3));
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!draft.back.trim().is_empty(), egui::Button::new("Create"))
+                    .clicked()
+                {
+                    create = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancel = true;
+                }
+            });
+        });
+
+    if create {
+        return card.take();
+    }
+    if cancel || !open {
+        *card = None;
+    }
+    None
+}
+
+pub fn add_image_card(
+    draft: CardDraft,
+    image: Result<CardImage, Box<dyn std::error::Error>>,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+) {
+    let image = match image {
+        Ok(image) => image,
+        Err(e) => {
+            status.error(&format!("Could not prepare the image: {}", e));
+            return;
+        }
+    };
+    if let Some(deck) = study_data
+        .decks
+        .iter_mut()
+        .find(|d| Some(d.id) == draft.deck_id)
+    {
+        let deck_name = deck.name.clone();
+        let mut card = Card::new(
+            deck.id,
+            draft.front.trim().to_string(),
+            draft.back.trim().to_string(),
+        );
+        card.front_image = Some(image);
+        deck.add_card(card);
+        match study_data.save() {
+            Ok(()) => status.show(&format!("Flashcard added to {}", deck_name)),
+            Err(e) => status.error(&format!("Error saving: {}", e)),
+        }
+    }
+}
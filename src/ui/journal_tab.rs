@@ -0,0 +1,296 @@
+use crate::app::StatusMessage;
+use crate::data::{JournalAnswer, JournalEntry, Mood, StudyData, JOURNAL_PROMPTS};
+use chrono::{Duration, Local, NaiveDate};
+use eframe::egui;
+use egui::{RichText, ScrollArea, TextEdit};
+use std::cell::RefCell;
+
+thread_local! {
+    static SELECTED_DATE: RefCell<Option<NaiveDate>> = RefCell::new(None);
+    // Entry being written for the selected date, saved back on Save or when leaving the date
+    static DRAFT: RefCell<Option<JournalEntry>> = RefCell::new(None);
+    static SEARCH: RefCell<String> = RefCell::new(String::new());
+}
+
+const RECENT_ENTRIES: usize = 10;
+
+pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut StatusMessage) {
+    ui.heading("📔 Journal");
+    ui.add_space(5.0);
+
+    let today = Local::now().date_naive();
+    let selected = SELECTED_DATE.with(|date| *date.borrow_mut().get_or_insert(today));
+    let mut go_to = None;
+
+    ui.horizontal(|ui| {
+        if ui.button("◀").on_hover_text("Previous day").clicked() {
+            go_to = Some(selected - Duration::days(1));
+        }
+        ui.strong(selected.format("%A, %B %-d, %Y").to_string());
+        if ui
+            .add_enabled(selected < today, egui::Button::new("▶"))
+            .on_hover_text("Next day")
+            .clicked()
+        {
+            go_to = Some(selected + Duration::days(1));
+        }
+        if selected != today && ui.button("Today").clicked() {
+            go_to = Some(today);
+        }
+
+        ui.separator();
+        SEARCH.with(|search| {
+            ui.add(
+                TextEdit::singleline(&mut *search.borrow_mut())
+                    .hint_text("🔍 Search entries")
+                    .desired_width(200.0),
+            );
+        });
+    });
+    ui.separator();
+
+    let query = SEARCH.with(|search| search.borrow().trim().to_string());
+    if !query.is_empty() {
+        if let Some(date) = search_results(ui, study_data, &query) {
+            go_to = Some(date);
+            SEARCH.with(|search| search.borrow_mut().clear());
+        }
+    } else {
+        ScrollArea::vertical()
+            .id_source("journal_scroll")
+            .show(ui, |ui| {
+                editor(ui, study_data, status, selected);
+                ui.add_space(15.0);
+                if let Some(date) = recent_entries(ui, study_data, selected) {
+                    go_to = Some(date);
+                }
+            });
+    }
+
+    if let Some(date) = go_to {
+        // Leaving a date keeps what was written for it
+        save_draft(study_data, status);
+        SELECTED_DATE.with(|selected| *selected.borrow_mut() = Some(date));
+    }
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+fn save_draft(study_data: &mut StudyData, status: &mut StatusMessage) {
+    let draft = DRAFT.with(|draft| draft.borrow_mut().take());
+    let Some(draft) = draft else {
+        return;
+    };
+    let unchanged = match study_data.journal_entry(&draft.date) {
+        Some(saved) => *saved == draft,
+        None => draft.is_empty(),
+    };
+    if unchanged {
+        return;
+    }
+    if let Err(e) = study_data.save_journal_entry(draft) {
+        status.error(&format!("Error saving journal entry: {}", e));
+    }
+}
+
+fn editor(
+    ui: &mut egui::Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    date: NaiveDate,
+) {
+    let key = date.format("%Y-%m-%d").to_string();
+    let mut save = false;
+    let mut delete = false;
+
+    DRAFT.with(|draft| {
+        let mut draft = draft.borrow_mut();
+        if draft.as_ref().is_none_or(|d| d.date != key) {
+            *draft = Some(
+                study_data
+                    .journal_entry(&key)
+                    .cloned()
+                    .unwrap_or_else(|| JournalEntry::new(key.clone())),
+            );
+        }
+        let Some(entry) = draft.as_mut() else {
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.label("Mood:");
+            for mood in Mood::ALL {
+                let selected = entry.mood == Some(mood);
+                if ui
+                    .selectable_label(selected, RichText::new(mood.emoji()).size(20.0))
+                    .on_hover_text(mood.label())
+                    .clicked()
+                {
+                    entry.mood = if selected { None } else { Some(mood) };
+                }
+            }
+        });
+        ui.add_space(8.0);
+
+        let mut removed = None;
+        for (index, answer) in entry.answers.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.strong(&answer.prompt);
+                if ui
+                    .small_button("✖")
+                    .on_hover_text("Remove prompt")
+                    .clicked()
+                {
+                    removed = Some(index);
+                }
+            });
+            ui.add(
+                TextEdit::multiline(&mut answer.answer)
+                    .desired_rows(2)
+                    .desired_width(f32::INFINITY),
+            );
+            ui.add_space(5.0);
+        }
+        if let Some(index) = removed {
+            entry.answers.remove(index);
+        }
+
+        let unused: Vec<&str> = JOURNAL_PROMPTS
+            .iter()
+            .copied()
+            .filter(|p| !entry.answers.iter().any(|a| a.prompt == *p))
+            .collect();
+        if !unused.is_empty() {
+            ui.menu_button("➕ Add a prompt", |ui| {
+                for prompt in unused {
+                    if ui.button(prompt).clicked() {
+                        entry.answers.push(JournalAnswer {
+                            prompt: prompt.to_string(),
+                            answer: String::new(),
+                        });
+                        ui.close_menu();
+                    }
+                }
+            });
+        }
+        ui.add_space(8.0);
+
+        ui.label("Anything else:");
+        ui.add(
+            TextEdit::multiline(&mut entry.text)
+                .hint_text("Write freely...")
+                .desired_rows(6)
+                .desired_width(f32::INFINITY),
+        );
+        ui.add_space(5.0);
+
+        let saved = study_data.journal_entry(&key);
+        let dirty = match saved {
+            Some(saved) => *saved != *entry,
+            None => !entry.is_empty(),
+        };
+        ui.horizontal(|ui| {
+            save = ui
+                .add_enabled(dirty, egui::Button::new("💾 Save"))
+                .clicked();
+            if saved.is_some() && ui.button("🗑 Delete entry").clicked() {
+                delete = true;
+            }
+            if dirty {
+                ui.label(RichText::new("Unsaved changes").small().italics());
+            }
+        });
+    });
+
+    if save {
+        save_draft(study_data, status);
+        status.show("Journal entry saved");
+    } else if delete {
+        DRAFT.with(|draft| *draft.borrow_mut() = None);
+        match study_data.delete_journal_entry(&key) {
+            Ok(()) => status.show("Journal entry moved to the trash"),
+            Err(e) => status.error(&format!("Error deleting journal entry: {}", e)),
+        }
+    }
+}
+
+fn entry_row(ui: &mut egui::Ui, entry: &JournalEntry) -> bool {
+    let mood = entry.mood.map_or("·", |m| m.emoji());
+    let snippet: String = entry
+        .answers
+        .iter()
+        .map(|a| a.answer.as_str())
+        .chain(std::iter::once(entry.text.as_str()))
+        .find(|text| !text.trim().is_empty())
+        .unwrap_or("")
+        .lines()
+        .next()
+        .unwrap_or("")
+        .chars()
+        .take(80)
+        .collect();
+    let date = parse_date(&entry.date)
+        .map(|d| d.format("%a, %b %-d %Y").to_string())
+        .unwrap_or_else(|| entry.date.clone());
+
+    ui.horizontal(|ui| {
+        ui.label(mood);
+        let clicked = ui.link(date).clicked();
+        ui.label(RichText::new(snippet).weak());
+        clicked
+    })
+    .inner
+}
+
+// Returns the date of the entry clicked
+fn recent_entries(
+    ui: &mut egui::Ui,
+    study_data: &StudyData,
+    selected: NaiveDate,
+) -> Option<NaiveDate> {
+    let key = selected.format("%Y-%m-%d").to_string();
+    let recent: Vec<&JournalEntry> = study_data
+        .journal
+        .iter()
+        .rev()
+        .filter(|e| e.date != key)
+        .take(RECENT_ENTRIES)
+        .collect();
+    if recent.is_empty() {
+        return None;
+    }
+
+    ui.strong("Recent entries");
+    let mut clicked = None;
+    for entry in recent {
+        if entry_row(ui, entry) {
+            clicked = parse_date(&entry.date);
+        }
+    }
+    clicked
+}
+
+fn search_results(ui: &mut egui::Ui, study_data: &StudyData, query: &str) -> Option<NaiveDate> {
+    let matches: Vec<&JournalEntry> = study_data
+        .journal
+        .iter()
+        .rev()
+        .filter(|e| e.matches(query))
+        .collect();
+    ui.label(format!("{} matching entries", matches.len()));
+    ui.add_space(5.0);
+
+    let mut clicked = None;
+    ScrollArea::vertical()
+        .id_source("journal_search_scroll")
+        .show(ui, |ui| {
+            for entry in matches {
+                if entry_row(ui, entry) {
+                    clicked = parse_date(&entry.date);
+                }
+            }
+        });
+    clicked
+}
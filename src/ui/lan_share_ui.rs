@@ -0,0 +1,196 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use crate::lan_share::{LanShare, SharedItem};
+use crate::settings::AppSettings;
+use eframe::egui;
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+#[derive(Clone, PartialEq)]
+enum ShareChoice {
+    Deck(u64),
+    Note(PathBuf),
+}
+
+thread_local! {
+    static CHOICE: RefCell<Option<ShareChoice>> = RefCell::new(None);
+}
+
+// Preselects what the Share Nearby window sends
+pub fn choose_deck(deck_id: u64) {
+    CHOICE.with(|choice| *choice.borrow_mut() = Some(ShareChoice::Deck(deck_id)));
+}
+
+pub fn choose_note(path: PathBuf) {
+    CHOICE.with(|choice| *choice.borrow_mut() = Some(ShareChoice::Note(path)));
+}
+
+pub fn display(
+    ctx: &egui::Context,
+    lan_share: &mut LanShare,
+    study_data: &StudyData,
+    settings: &mut AppSettings,
+    open: &mut bool,
+    status: &mut StatusMessage,
+) {
+    let mut send_to = None;
+
+    egui::Window::new("📡 Share Nearby")
+        .open(open)
+        .resizable(false)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            let mut visible = lan_share.is_running();
+            if ui
+                .checkbox(&mut visible, "Let FocusPad on this network find me")
+                .changed()
+            {
+                if visible {
+                    if let Err(e) = lan_share.start() {
+                        status.error(&format!("Could not start Share Nearby: {}", e));
+                    }
+                } else {
+                    lan_share.stop();
+                }
+                settings.share_nearby = lan_share.is_running();
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save settings: {}", e));
+                }
+            }
+            ui.label(
+                egui::RichText::new("Others can only send you something after you accept it.")
+                    .small(),
+            );
+            ui.separator();
+
+            CHOICE.with(|choice| {
+                let mut choice = choice.borrow_mut();
+                let selected = match &*choice {
+                    Some(ShareChoice::Deck(id)) => study_data
+                        .decks
+                        .iter()
+                        .find(|d| d.id == *id)
+                        .map_or("Choose a deck".to_string(), |d| format!("🎯 {}", d.name)),
+                    Some(ShareChoice::Note(path)) => format!(
+                        "📝 {}",
+                        path.file_name().unwrap_or_default().to_string_lossy()
+                    ),
+                    None => "Choose what to send".to_string(),
+                };
+
+                ui.horizontal(|ui| {
+                    ui.label("Send:");
+                    egui::ComboBox::from_id_source("lan_share_item")
+                        .selected_text(selected)
+                        .width(240.0)
+                        .show_ui(ui, |ui| {
+                            if let Some(ShareChoice::Note(path)) = &*choice {
+                                let label = format!(
+                                    "📝 {}",
+                                    path.file_name().unwrap_or_default().to_string_lossy()
+                                );
+                                let note = Some(ShareChoice::Note(path.clone()));
+                                ui.selectable_value(&mut *choice, note, label);
+                            }
+                            for deck in &study_data.decks {
+                                ui.selectable_value(
+                                    &mut *choice,
+                                    Some(ShareChoice::Deck(deck.id)),
+                                    format!("🎯 {}", deck.name),
+                                );
+                            }
+                        });
+                });
+            });
+            ui.add_space(6.0);
+
+            if !lan_share.is_running() {
+                ui.label("Turn on sharing above to look for nearby devices.");
+                return;
+            }
+            if let Some(peer) = &lan_share.waiting_for {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(format!("Waiting for {} to accept...", peer));
+                });
+            }
+
+            ui.strong("Nearby");
+            if lan_share.peers.is_empty() {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Looking for FocusPad on this network...");
+                });
+            }
+            let has_choice = CHOICE.with(|choice| choice.borrow().is_some());
+            for (key, peer) in &lan_share.peers {
+                ui.horizontal(|ui| {
+                    ui.label(&peer.name);
+                    let ready = has_choice && lan_share.waiting_for.is_none();
+                    if ui.add_enabled(ready, egui::Button::new("Send")).clicked() {
+                        send_to = Some(key.clone());
+                    }
+                });
+            }
+        });
+
+    let Some(peer) = send_to else {
+        return;
+    };
+    let item = CHOICE.with(|choice| match choice.borrow().clone() {
+        Some(ShareChoice::Deck(id)) => study_data
+            .decks
+            .iter()
+            .find(|d| d.id == id)
+            .map(SharedItem::from_deck)
+            .ok_or_else(|| "That deck no longer exists".to_string()),
+        Some(ShareChoice::Note(path)) => SharedItem::from_note(&path).map_err(|e| e.to_string()),
+        None => Err("Choose what to send first".to_string()),
+    });
+    match item {
+        Ok(item) => lan_share.send(&peer, item),
+        Err(e) => status.warn(&e),
+    }
+}
+
+// Asks whether to take an item someone nearby is sending
+pub fn incoming_prompt(
+    ctx: &egui::Context,
+    lan_share: &mut LanShare,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+) {
+    let Some(share) = lan_share.incoming.front() else {
+        return;
+    };
+    let mut answer = None;
+
+    egui::Window::new("📡 Incoming Share")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "{} wants to send you {}.",
+                share.from,
+                share.item.describe()
+            ));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button("✔ Accept").clicked() {
+                    answer = Some(true);
+                }
+                if ui.button("✖ Reject").clicked() {
+                    answer = Some(false);
+                }
+            });
+        });
+
+    if let Some(accept) = answer {
+        match lan_share.answer(study_data, accept) {
+            Ok(Some(message)) => status.show(&message),
+            Ok(None) => {}
+            Err(e) => status.error(&format!("Could not save the shared item: {}", e)),
+        }
+    }
+}
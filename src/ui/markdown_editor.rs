@@ -1,9 +1,20 @@
+use crate::ui::find_bar::FindBar;
+use crate::ui::flashcard::CardSource;
 use crate::ui::markdown_renderer::MarkdownRendererState;
+use crate::ui::note_outline;
 use std::fs::{self, File, OpenOptions};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 
-const FILES_DIR: &str = "files";
+pub const FILES_DIR: &str = "files";
+
+// A card being created from selected note text
+pub struct FlashcardDraft {
+    pub front: String,
+    pub back: String,
+    pub deck_id: Option<u64>,
+    pub source: Option<CardSource>,
+}
 
 #[derive(PartialEq)]
 pub enum EditorMode {
@@ -29,6 +40,21 @@ pub struct MarkdownEditor {
     pub expanded_folders: Vec<PathBuf>,
     // Add new field for renderer state
     pub renderer_state: MarkdownRendererState,
+    pub show_reading_queue: bool,
+    pub selected_text: String,
+    pub selection_start: usize, // Character index where selected_text begins
+    pub flashcard_draft: Option<FlashcardDraft>,
+    pub last_flashcard_deck: Option<u64>,
+    pub base_font_size: f32, // Editor font size from settings, before zoom
+    pub show_outline: bool,
+    pub jump_to_line: Option<usize>, // Heading picked in the outline, for the editor
+    pub edited: bool,                // Content changed since take_edited_file last ran
+    pub opened: bool,                // A file was opened since take_opened_file last ran
+    pub cursor: Option<usize>,       // Character index of the text cursor, for inserting citations
+    pub typewriter_mode: bool,       // Keep the line being written in the middle, from settings
+    pub highlight_line: bool,
+    pub find: Option<FindBar>,
+    pub select_range: Option<(usize, usize)>, // Characters to select in the editor, e.g. a match
 }
 
 impl Default for MarkdownEditor {
@@ -52,6 +78,21 @@ impl Default for MarkdownEditor {
             selected_folder: None,
             expanded_folders: Vec::new(),
             renderer_state: MarkdownRendererState::default(),
+            show_reading_queue: false,
+            selected_text: String::new(),
+            selection_start: 0,
+            flashcard_draft: None,
+            last_flashcard_deck: None,
+            base_font_size: 14.0,
+            show_outline: false,
+            jump_to_line: None,
+            edited: false,
+            opened: false,
+            cursor: None,
+            typewriter_mode: false,
+            highlight_line: false,
+            find: None,
+            select_range: None,
         }
     }
 }
@@ -64,6 +105,9 @@ impl MarkdownEditor {
 
         self.current_content = content;
         self.current_file = Some(path.clone());
+        self.selected_text.clear();
+        self.cursor = None;
+        self.opened = true;
         Ok(())
     }
 
@@ -80,6 +124,22 @@ impl MarkdownEditor {
         Ok(())
     }
 
+    // The open note, if it was edited since the last call
+    pub fn take_edited_file(&mut self) -> Option<PathBuf> {
+        if !std::mem::take(&mut self.edited) {
+            return None;
+        }
+        self.current_file.clone()
+    }
+
+    // The open note, if it was opened since the last call
+    pub fn take_opened_file(&mut self) -> Option<PathBuf> {
+        if !std::mem::take(&mut self.opened) {
+            return None;
+        }
+        self.current_file.clone()
+    }
+
     pub fn create_file(&mut self, name: &str) -> Result<PathBuf, std::io::Error> {
         // Determine the directory where the file should be created
         let parent_dir = if let Some(folder) = &self.selected_folder {
@@ -197,6 +257,7 @@ impl MarkdownEditor {
 
     // Add markdown formatting to selected text
     pub fn add_formatting(&mut self, format_type: &str) {
+        self.edited = true;
         match format_type {
             "bold" => {
                 self.current_content.push_str("**Bold Text**");
@@ -222,6 +283,55 @@ impl MarkdownEditor {
         }
     }
 
+    // The selection becomes the answer, the user fills in the question
+    pub fn start_flashcard_from_selection(&mut self) {
+        let selection = self.selected_text.trim();
+        if selection.is_empty() {
+            return;
+        }
+        let source = self.current_file.as_ref().map(|path| CardSource {
+            path: path.to_string_lossy().to_string(),
+            heading: self.heading_above(self.selection_start),
+        });
+        self.flashcard_draft = Some(FlashcardDraft {
+            front: String::new(),
+            back: selection.to_string(),
+            deck_id: self.last_flashcard_deck,
+            source,
+        });
+    }
+
+    fn heading_above(&self, char_index: usize) -> Option<String> {
+        let line = self
+            .current_content
+            .chars()
+            .take(char_index)
+            .filter(|&c| c == '\n')
+            .count();
+        note_outline::headings(&self.current_content)
+            .into_iter()
+            .take_while(|heading| heading.line <= line)
+            .last()
+            .map(|heading| heading.title)
+    }
+
+    // Moves the editor and preview to a heading, returning false if the note has none by that name
+    pub fn go_to_heading(&mut self, title: &str) -> bool {
+        let Some(heading) = note_outline::headings(&self.current_content)
+            .into_iter()
+            .find(|heading| heading.title == title)
+        else {
+            return false;
+        };
+        if self.editor_mode != EditorMode::Preview {
+            self.jump_to_line = Some(heading.line);
+        }
+        if self.editor_mode != EditorMode::Edit {
+            self.renderer_state.scroll_to_line = Some(heading.line);
+        }
+        true
+    }
+
     // Get the base directory for resolving image paths
     pub fn get_base_dir(&self) -> PathBuf {
         if let Some(file_path) = &self.current_file {
@@ -232,8 +342,25 @@ impl MarkdownEditor {
         PathBuf::from(FILES_DIR)
     }
 
+    // Inserts "[@key]" at the text cursor, or at the end when the editor hasn't had focus
+    pub fn insert_citation(&mut self, key: &str) {
+        self.edited = true;
+        let citation = format!("[@{}]", key);
+        let index = self
+            .cursor
+            .and_then(|cursor| self.current_content.char_indices().nth(cursor))
+            .map_or(self.current_content.len(), |(index, _)| index);
+        self.current_content.insert_str(index, &citation);
+        self.cursor = Some(
+            self.current_content[..index + citation.len()]
+                .chars()
+                .count(),
+        );
+    }
+
     // Insert an image at cursor position
     pub fn insert_image(&mut self, file_path: &Path) {
+        self.edited = true;
         // Convert the path to be relative to the current file
         let base_dir = self.get_base_dir();
         let rel_path = if let Ok(rel) = file_path.strip_prefix(&base_dir) {
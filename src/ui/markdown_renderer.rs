@@ -1,15 +1,20 @@
+use crate::ui::rich_text::linkified_label;
 use eframe::egui::{self, Color32, RichText, TextureHandle};
 use std::collections::HashMap;
 use std::path::Path;
 
 pub struct MarkdownRendererState {
     pub image_cache: HashMap<String, TextureHandle>,
+    pub rtl: bool, // Right-align blocks for right-to-left notes
+    pub scroll_to_line: Option<usize>,
 }
 
 impl Default for MarkdownRendererState {
     fn default() -> Self {
         Self {
             image_cache: HashMap::new(),
+            rtl: false,
+            scroll_to_line: None,
         }
     }
 }
@@ -20,6 +25,22 @@ pub fn render_markdown(
     zoom_level: f32,
     renderer_state: &mut MarkdownRendererState,
     ctx: &egui::Context,
+) {
+    if renderer_state.rtl {
+        ui.with_layout(egui::Layout::top_down(egui::Align::Max), |ui| {
+            render_lines(ui, markdown, zoom_level, renderer_state, ctx);
+        });
+    } else {
+        render_lines(ui, markdown, zoom_level, renderer_state, ctx);
+    }
+}
+
+fn render_lines(
+    ui: &mut egui::Ui,
+    markdown: &str,
+    zoom_level: f32,
+    renderer_state: &mut MarkdownRendererState,
+    ctx: &egui::Context,
 ) {
     let font_size = 14.0 * zoom_level;
     ui.style_mut()
@@ -30,7 +51,12 @@ pub fn render_markdown(
 
     let lines = markdown.lines();
 
-    for line in lines {
+    for (index, line) in lines.enumerate() {
+        if renderer_state.scroll_to_line == Some(index) {
+            let rect = egui::Rect::from_min_size(ui.cursor().min, egui::vec2(1.0, 1.0));
+            ui.scroll_to_rect(rect, Some(egui::Align::TOP));
+            renderer_state.scroll_to_line = None;
+        }
         let trimmed = line.trim();
 
         // Handle image syntax: ![alt text](path/to/image.png)
@@ -173,7 +199,7 @@ pub fn render_markdown(
         else if trimmed.starts_with("- ") {
             ui.horizontal(|ui| {
                 ui.label(RichText::new("•").size(font_size));
-                ui.label(RichText::new(&trimmed[2..]).size(font_size));
+                linkified_label(ui, RichText::new(&trimmed[2..]).size(font_size));
             });
         }
         // Handle numbered lists
@@ -184,7 +210,7 @@ pub fn render_markdown(
             let content = &trimmed[2..].trim();
             ui.horizontal(|ui| {
                 ui.label(RichText::new(&trimmed[..2]).size(font_size));
-                ui.label(RichText::new(*content).size(font_size));
+                linkified_label(ui, RichText::new(*content).size(font_size));
             });
         }
         // Handle code blocks
@@ -217,12 +243,12 @@ pub fn render_markdown(
         else if trimmed.starts_with("> ") {
             ui.horizontal(|ui| {
                 ui.label(RichText::new("▌").size(font_size));
-                ui.label(RichText::new(&trimmed[2..]).italics().size(font_size));
+                linkified_label(ui, RichText::new(&trimmed[2..]).italics().size(font_size));
             });
         }
         // Regular text
         else if !trimmed.is_empty() {
-            ui.label(RichText::new(line).size(font_size));
+            linkified_label(ui, RichText::new(line).size(font_size));
         } else {
             ui.add_space(font_size * 0.5);
         }
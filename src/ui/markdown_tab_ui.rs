@@ -1,6 +1,23 @@
+use crate::app::StatusMessage;
+use crate::data::{ReadingItem, ReadingRating, StudyData};
+use crate::split_view_ui::SplitViewUI;
+use crate::ui::find_bar;
+use crate::ui::flashcard::Card;
 use crate::ui::markdown_editor::{EditorMode, MarkdownEditor};
 use crate::ui::markdown_renderer;
+use crate::ui::note_outline::{self, NoteStats};
+use crate::ui::snippets;
+use crate::ui::spell_check_ui;
+use crate::vault_export::{self, VaultFormat};
 use eframe::egui::{self, Color32, RichText};
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, TryRecvError};
+
+thread_local! {
+    static CLIP_URL: RefCell<String> = RefCell::new(String::new());
+    static CLIP_JOB: RefCell<Option<Receiver<Result<PathBuf, String>>>> = RefCell::new(None);
+}
 
 pub fn render_editor(
     ui: &mut egui::Ui,
@@ -38,9 +55,31 @@ pub fn render_editor(
             ui.selectable_value(&mut editor.editor_mode, EditorMode::Edit, "Edit");
             ui.selectable_value(&mut editor.editor_mode, EditorMode::Preview, "Preview");
             ui.selectable_value(&mut editor.editor_mode, EditorMode::Split, "Split");
+            ui.separator();
+            ui.toggle_value(&mut editor.show_outline, "☰ Outline");
+            ui.toggle_value(&mut editor.highlight_line, "▭ Line")
+                .on_hover_text("Highlight the line with the cursor");
+            ui.toggle_value(&mut editor.typewriter_mode, "⌨ Typewriter")
+                .on_hover_text("Keep the line being written in the middle of the editor");
         });
     });
 
+    if let Some(bar) = editor.find.as_mut() {
+        let outcome = find_bar::show(ui, bar, &mut editor.current_content);
+        if let Some(range) = outcome.select {
+            let start = editor.current_content[..range.start].chars().count();
+            let end = start + editor.current_content[range].chars().count();
+            editor.select_range = Some((start, end));
+        }
+        if let Some(count) = outcome.replaced {
+            editor.edited = true;
+            status_update(&format!("Replaced {} match(es)", count));
+        }
+        if outcome.close {
+            editor.find = None;
+        }
+    }
+
     // Add formatting buttons in their own row
     if editor.editor_mode == EditorMode::Edit || editor.editor_mode == EditorMode::Split {
         ui.horizontal(|ui| {
@@ -85,11 +124,41 @@ pub fn render_editor(
                     status_update("Image inserted");
                 }
             }
+
+            ui.separator();
+            let has_selection = !editor.selected_text.trim().is_empty();
+            if ui
+                .add_enabled(has_selection, egui::Button::new("🃏 To Flashcard"))
+                .on_hover_text("Turn the selected text into a flashcard")
+                .on_disabled_hover_text("Select some text first")
+                .clicked()
+            {
+                editor.start_flashcard_from_selection();
+            }
         });
     }
 
     ui.separator();
 
+    let stats = NoteStats::of(&editor.current_content);
+    egui::TopBottomPanel::bottom(ui.id().with("note_stats_footer")).show_inside(ui, |ui| {
+        ui.label(RichText::new(stats.summary()).small());
+    });
+    if editor.show_outline {
+        let clicked = egui::SidePanel::right(ui.id().with("note_outline"))
+            .default_width(200.0)
+            .show_inside(ui, |ui| note_outline::outline_panel(ui, &stats))
+            .inner;
+        if let Some(line) = clicked {
+            if editor.editor_mode != EditorMode::Preview {
+                editor.jump_to_line = Some(line);
+            }
+            if editor.editor_mode != EditorMode::Edit {
+                editor.renderer_state.scroll_to_line = Some(line);
+            }
+        }
+    }
+
     match editor.editor_mode {
         EditorMode::Edit => {
             render_edit_mode(ui, editor);
@@ -106,10 +175,9 @@ pub fn render_editor(
 fn render_edit_mode(ui: &mut egui::Ui, editor: &mut MarkdownEditor) {
     // Full editor
     let text_height = ui.available_height();
-    egui::ScrollArea::vertical()
-        .id_source("editor_scroll")
+    let output = SplitViewUI::lock_scroll(egui::ScrollArea::vertical().id_source("editor_scroll"))
         .show(ui, |ui| {
-            let font_size = 14.0 * editor.zoom_level;
+            let font_size = editor.base_font_size * editor.zoom_level;
             let text_style = egui::TextStyle::Monospace;
             ui.style_mut()
                 .text_styles
@@ -117,19 +185,146 @@ fn render_edit_mode(ui: &mut egui::Ui, editor: &mut MarkdownEditor) {
                 .unwrap()
                 .size = font_size;
 
-            ui.add_sized(
-                [ui.available_width(), text_height],
-                egui::TextEdit::multiline(&mut editor.current_content)
-                    .font(text_style)
-                    .desired_width(f32::INFINITY),
-            );
+            typewriter_space(ui, editor, text_height);
+            text_editor(ui, editor, text_height);
+            typewriter_space(ui, editor, text_height);
         });
+    SplitViewUI::track_scroll(ui, &output);
+}
+
+// Room above and below the text, so its first and last lines can be centered too
+fn typewriter_space(ui: &mut egui::Ui, editor: &MarkdownEditor, height: f32) {
+    if editor.typewriter_mode {
+        ui.add_space(height / 2.0);
+    }
+}
+
+// Current-line highlight and typewriter scrolling, for a focused editor
+fn caret_effects(
+    ui: &egui::Ui,
+    editor: &MarkdownEditor,
+    output: &egui::text_edit::TextEditOutput,
+    moved: bool,
+) {
+    let Some(range) = output.cursor_range.filter(|_| output.response.has_focus()) else {
+        return;
+    };
+    let caret = output
+        .galley
+        .pos_from_cursor(&range.primary)
+        .translate(output.text_draw_pos.to_vec2());
+    if editor.highlight_line {
+        let row = egui::Rect::from_x_y_ranges(output.response.rect.x_range(), caret.y_range());
+        let fill = ui.visuals().selection.bg_fill.gamma_multiply(0.25);
+        ui.painter().rect_filled(row, 0.0, fill);
+    }
+    if editor.typewriter_mode && moved {
+        ui.scroll_to_rect(caret, Some(egui::Align::Center));
+    }
+}
+
+// The editor text area, which also moves to a heading picked in the outline
+fn text_editor(ui: &mut egui::Ui, editor: &mut MarkdownEditor, height: f32) {
+    let matches = editor.find.as_ref().and_then(|bar| {
+        let pattern = bar.pattern()?.ok()?;
+        Some((pattern, bar.current))
+    });
+    let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+        let mut job =
+            spell_check_ui::highlight_job(ui, text, egui::TextStyle::Monospace, wrap_width);
+        if let Some((pattern, current)) = &matches {
+            highlight_matches(&mut job, text, pattern, *current);
+        }
+        ui.fonts(|fonts| fonts.layout_job(job))
+    };
+    let size = egui::vec2(ui.available_width(), height);
+    let layout = egui::Layout::centered_and_justified(ui.layout().main_dir());
+    let output = ui
+        .allocate_ui_with_layout(size, layout, |ui| {
+            egui::TextEdit::multiline(&mut editor.current_content)
+                .font(egui::TextStyle::Monospace)
+                .desired_width(f32::INFINITY)
+                .layouter(&mut layouter)
+                .show(ui)
+        })
+        .inner;
+
+    if let Some(line) = editor.jump_to_line.take() {
+        let cursor =
+            egui::text::CCursor::new(note_outline::line_start(&editor.current_content, line));
+        let mut state = output.state.clone();
+        state.set_ccursor_range(Some(egui::text::CCursorRange::one(cursor)));
+        state.store(ui.ctx(), output.response.id);
+        output.response.request_focus();
+        let rect = output
+            .galley
+            .pos_from_cursor(&output.galley.from_ccursor(cursor))
+            .translate(output.text_draw_pos.to_vec2());
+        ui.scroll_to_rect(rect, Some(egui::Align::TOP));
+    }
+    // A find match; the find field keeps focus, so the match shows as highlighted
+    if let Some((start, end)) = editor.select_range.take() {
+        let start = egui::text::CCursor::new(start);
+        let mut state = output.state.clone();
+        let range = egui::text::CCursorRange::two(start, egui::text::CCursor::new(end));
+        state.set_ccursor_range(Some(range));
+        state.store(ui.ctx(), output.response.id);
+        let rect = output
+            .galley
+            .pos_from_cursor(&output.galley.from_ccursor(start))
+            .translate(output.text_draw_pos.to_vec2());
+        ui.scroll_to_rect(rect, Some(egui::Align::Center));
+    }
+    let moved = output.response.changed()
+        || output.cursor_range.map(|range| range.primary.ccursor.index) != editor.cursor;
+    caret_effects(ui, editor, &output, moved);
+    if output.response.changed() {
+        editor.edited = true;
+        snippets::expand(ui.ctx(), output.response.id, &mut editor.current_content);
+    }
+    track_selection(editor, output.response);
+}
+
+// Remembers the selected text and offers the extract-to-flashcard context action
+fn track_selection(editor: &mut MarkdownEditor, response: egui::Response) {
+    if let Some(state) = egui::TextEdit::load_state(&response.ctx, response.id) {
+        if let Some(range) = state.ccursor_range() {
+            // Kept while the editor is unfocused, so citations inserted one after another
+            // follow each other
+            if response.has_focus() {
+                editor.cursor = Some(range.primary.index);
+            }
+            let start = range.primary.index.min(range.secondary.index);
+            let end = range.primary.index.max(range.secondary.index);
+            editor.selection_start = start;
+            editor.selected_text = editor
+                .current_content
+                .chars()
+                .skip(start)
+                .take(end - start)
+                .collect();
+        }
+    }
+
+    response.context_menu(|ui| {
+        let has_selection = !editor.selected_text.trim().is_empty();
+        if ui
+            .add_enabled(
+                has_selection,
+                egui::Button::new("🃏 Create Flashcard from Selection"),
+            )
+            .clicked()
+        {
+            editor.start_flashcard_from_selection();
+            ui.close_menu();
+        }
+        spell_check_ui::spelling_menu(ui, &mut editor.current_content);
+    });
 }
 
 fn render_preview_mode(ui: &mut egui::Ui, editor: &mut MarkdownEditor, ctx: &egui::Context) {
     // Full preview
-    egui::ScrollArea::vertical()
-        .id_source("preview_scroll")
+    let output = SplitViewUI::lock_scroll(egui::ScrollArea::vertical().id_source("preview_scroll"))
         .show(ui, |ui| {
             markdown_renderer::render_markdown(
                 ui,
@@ -139,6 +334,7 @@ fn render_preview_mode(ui: &mut egui::Ui, editor: &mut MarkdownEditor, ctx: &egu
                 ctx,
             );
         });
+    SplitViewUI::track_scroll(ui, &output);
 }
 
 fn render_split_mode(ui: &mut egui::Ui, editor: &mut MarkdownEditor, ctx: &egui::Context) {
@@ -147,24 +343,24 @@ fn render_split_mode(ui: &mut egui::Ui, editor: &mut MarkdownEditor, ctx: &egui:
         ui.columns(2, |columns| {
             // Editor on left column
             columns[0].heading("Editor");
-            egui::ScrollArea::vertical()
-                .id_source("editor_scroll_split")
-                .show(&mut columns[0], |ui| {
-                    let font_size = 14.0 * editor.zoom_level;
-                    let text_style = egui::TextStyle::Monospace;
-                    ui.style_mut()
-                        .text_styles
-                        .get_mut(&text_style)
-                        .unwrap()
-                        .size = font_size;
-
-                    ui.add_sized(
-                        [ui.available_width(), ui.available_height()],
-                        egui::TextEdit::multiline(&mut editor.current_content)
-                            .font(text_style)
-                            .desired_width(f32::INFINITY),
-                    );
-                });
+            let output = SplitViewUI::lock_scroll(
+                egui::ScrollArea::vertical().id_source("editor_scroll_split"),
+            )
+            .show(&mut columns[0], |ui| {
+                let font_size = editor.base_font_size * editor.zoom_level;
+                let text_style = egui::TextStyle::Monospace;
+                ui.style_mut()
+                    .text_styles
+                    .get_mut(&text_style)
+                    .unwrap()
+                    .size = font_size;
+
+                let height = ui.available_height();
+                typewriter_space(ui, editor, height);
+                text_editor(ui, editor, height);
+                typewriter_space(ui, editor, height);
+            });
+            SplitViewUI::track_scroll(&columns[0], &output);
 
             // Preview on right column
             columns[1].heading("Preview");
@@ -183,6 +379,165 @@ fn render_split_mode(ui: &mut egui::Ui, editor: &mut MarkdownEditor, ctx: &egui:
     });
 }
 
+// Just the note, centered at a comfortable line width
+pub fn display_zen(ui: &mut egui::Ui, app: &mut crate::app::StudyTimerApp) {
+    let Some(editor) = app.markdown_editor.as_mut() else {
+        return;
+    };
+    editor.base_font_size = app.settings.editor_font_size;
+    editor.typewriter_mode = app.settings.typewriter_mode;
+    editor.highlight_line = app.settings.highlight_line;
+
+    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+        ui.label(RichText::new("F11 to leave zen mode").small().weak());
+        if ui
+            .checkbox(
+                &mut app.settings.zen_focus_paragraph,
+                RichText::new("Focus paragraph").small().weak(),
+            )
+            .changed()
+        {
+            if let Err(e) = app.settings.save() {
+                app.status
+                    .error(&format!("Failed to save zen mode settings: {}", e));
+            }
+        }
+    });
+
+    if editor.current_file.is_none() {
+        ui.centered_and_justified(|ui| {
+            ui.label(RichText::new("Open a note in this tab to write in zen mode").weak());
+        });
+        return;
+    }
+
+    // The Save button is hidden, so Ctrl+S saves here; leaving zen mode saves too
+    if ui.input(|i| i.modifiers.command && i.key_pressed(egui::Key::S)) {
+        match editor.save_file() {
+            Ok(()) => app.status.show("File saved"),
+            Err(e) => app.status.error(&format!("Error saving file: {}", e)),
+        }
+    }
+
+    let font_size = editor.base_font_size * editor.zoom_level;
+    ui.style_mut()
+        .text_styles
+        .get_mut(&egui::TextStyle::Monospace)
+        .unwrap()
+        .size = font_size;
+
+    let focus = editor.cursor.filter(|_| app.settings.zen_focus_paragraph);
+    let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+        let mut job =
+            spell_check_ui::highlight_job(ui, text, egui::TextStyle::Monospace, wrap_width);
+        if let Some(cursor) = focus {
+            dim_other_paragraphs(&mut job, text, cursor);
+        }
+        ui.fonts(|fonts| fonts.layout_job(job))
+    };
+    let width = app.settings.zen_line_width.min(ui.available_width());
+    let height = ui.available_height();
+    let output = egui::ScrollArea::vertical()
+        .id_source("zen_scroll")
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            ui.add_space(24.0);
+            typewriter_space(ui, editor, height);
+            ui.vertical_centered(|ui| {
+                let output = egui::TextEdit::multiline(&mut editor.current_content)
+                    .font(egui::TextStyle::Monospace)
+                    .frame(false)
+                    .desired_width(width)
+                    .min_size(egui::vec2(width, ui.available_height()))
+                    .layouter(&mut layouter)
+                    .show(ui);
+                typewriter_space(ui, editor, height);
+                output
+            })
+            .inner
+        })
+        .inner;
+
+    if ui.memory(|m| m.focus().is_none()) {
+        output.response.request_focus();
+    }
+    let moved = output.response.changed()
+        || output.cursor_range.map(|range| range.primary.ccursor.index) != editor.cursor;
+    caret_effects(ui, editor, &output, moved);
+    if output.response.changed() {
+        editor.edited = true;
+        snippets::expand(ui.ctx(), output.response.id, &mut editor.current_content);
+    }
+    track_selection(editor, output.response);
+}
+
+// Fades all text outside the blank-line separated paragraph holding the cursor
+fn dim_other_paragraphs(job: &mut egui::text::LayoutJob, text: &str, cursor: usize) {
+    let at = text
+        .char_indices()
+        .nth(cursor)
+        .map_or(text.len(), |(i, _)| i);
+    let start = text[..at].rfind("\n\n").map_or(0, |i| i + 2);
+    let end = text[at..].find("\n\n").map_or(text.len(), |i| at + i);
+
+    let dim = |format: &mut egui::TextFormat| format.color = format.color.gamma_multiply(0.3);
+    restyle(job, 0..start, dim);
+    restyle(job, end..text.len(), dim);
+}
+
+// Marks find matches, the selected one more strongly. Very common patterns only get
+// their first matches marked, so typing stays fast.
+fn highlight_matches(
+    job: &mut egui::text::LayoutJob,
+    text: &str,
+    pattern: &regex::Regex,
+    current: usize,
+) {
+    const MAX_HIGHLIGHTS: usize = 500;
+    for (index, found) in pattern.find_iter(text).enumerate().take(MAX_HIGHLIGHTS) {
+        let alpha = if index == current { 150 } else { 60 };
+        restyle(job, found.range(), |format| {
+            format.background = Color32::from_rgba_unmultiplied(255, 200, 0, alpha)
+        });
+    }
+}
+
+// Changes the format of a byte range, cutting runs that cross its edges
+fn restyle(
+    job: &mut egui::text::LayoutJob,
+    within: std::ops::Range<usize>,
+    style: impl Fn(&mut egui::TextFormat),
+) {
+    if within.is_empty() {
+        return;
+    }
+    for section in std::mem::take(&mut job.sections) {
+        let range = section.byte_range.clone();
+        if range.end <= within.start || range.start >= within.end {
+            job.sections.push(section);
+            continue;
+        }
+        let mut cuts = vec![range.start];
+        cuts.extend(
+            [within.start, within.end]
+                .into_iter()
+                .filter(|cut| range.contains(cut) && *cut > range.start),
+        );
+        cuts.push(range.end);
+        for pair in cuts.windows(2) {
+            let mut piece = section.clone();
+            piece.byte_range = pair[0]..pair[1];
+            if pair[0] != range.start {
+                piece.leading_space = 0.0;
+            }
+            if pair[0] >= within.start && pair[1] <= within.end {
+                style(&mut piece.format);
+            }
+            job.sections.push(piece);
+        }
+    }
+}
+
 pub fn display(ui: &mut egui::Ui, app: &mut crate::app::StudyTimerApp, ctx: &egui::Context) {
     // Initialize the markdown editor if it's not already initialized
     if app.markdown_editor.is_none() {
@@ -191,6 +546,38 @@ pub fn display(ui: &mut egui::Ui, app: &mut crate::app::StudyTimerApp, ctx: &egu
 
     // Get a mutable reference to the editor
     if let Some(editor) = &mut app.markdown_editor {
+        editor.renderer_state.rtl = app.settings.rtl_layout;
+        editor.base_font_size = app.settings.editor_font_size;
+        editor.typewriter_mode = app.settings.typewriter_mode;
+        editor.highlight_line = app.settings.highlight_line;
+
+        // A finished clip opens in its own tab
+        let clipped = CLIP_JOB.with(|job| {
+            let mut job = job.borrow_mut();
+            let result = match job.as_ref().map(|receiver| receiver.try_recv()) {
+                Some(Ok(result)) => result,
+                Some(Err(TryRecvError::Disconnected)) => Err("The clipper stopped".to_string()),
+                Some(Err(TryRecvError::Empty)) => {
+                    ctx.request_repaint_after(std::time::Duration::from_millis(250));
+                    return None;
+                }
+                None => return None,
+            };
+            *job = None;
+            Some(result)
+        });
+        match clipped {
+            Some(Ok(path)) => {
+                app.tab_manager.add_file_tab(
+                    crate::app::Tab::Markdown,
+                    path.to_string_lossy().to_string(),
+                );
+                app.status.show(&format!("Clipped to {}", path.display()));
+            }
+            Some(Err(e)) => app.status.error(&format!("Clip failed: {}", e)),
+            None => {}
+        }
+
         // Add toggle button for file browser at the top
         ui.horizontal(|ui| {
             let collapse_text = if editor.file_browser_collapsed {
@@ -202,10 +589,177 @@ pub fn display(ui: &mut egui::Ui, app: &mut crate::app::StudyTimerApp, ctx: &egu
             if ui.button(collapse_text).clicked() {
                 editor.file_browser_collapsed = !editor.file_browser_collapsed;
             }
+
+            if let Some(path) = &editor.current_file {
+                let path = path.to_string_lossy();
+                let favorite = app.settings.is_favorite_note(&path);
+                let (star, hint) = if favorite {
+                    ("★", "Remove from favorites")
+                } else {
+                    ("☆", "Add to favorites, offered for new Markdown tabs")
+                };
+                if ui.button(star).on_hover_text(hint).clicked() {
+                    app.settings.toggle_favorite_note(&path);
+                    if let Err(e) = app.settings.save() {
+                        app.status
+                            .error(&format!("Failed to save favorites: {}", e));
+                    }
+                }
+            }
+
+            if let Some(path) = editor.current_file.clone() {
+                ui.menu_button("🔁 Read Later", |ui| {
+                    let mut sections = vec![None];
+                    sections.extend(note_headings(&editor.current_content).into_iter().map(Some));
+
+                    for section in sections {
+                        let label = section.clone().unwrap_or_else(|| "Whole note".to_string());
+                        if ui.button(label).clicked() {
+                            ui.close_menu();
+                            let path = path.to_string_lossy().to_string();
+                            match app.study_data.add_reading_item(path, section) {
+                                Ok(true) => app.status.show("Added to reading queue"),
+                                Ok(false) => app.status.show("Already in the reading queue"),
+                                Err(e) => app.status.error(&format!("Error saving: {}", e)),
+                            }
+                        }
+                    }
+                });
+            }
+
+            if let Some(path) = &editor.current_file {
+                let sessions = app.study_data.sessions_for_note(&path.to_string_lossy());
+                if !sessions.is_empty() {
+                    ui.menu_button("🕒 Sessions", |ui| {
+                        ui.label(RichText::new("Study sessions this note was edited in").small());
+                        ui.separator();
+                        egui::ScrollArea::vertical()
+                            .max_height(240.0)
+                            .show(ui, |ui| {
+                                for session in sessions {
+                                    ui.label(format!(
+                                        "{}  {:.0} min  {}",
+                                        session.date,
+                                        session.minutes,
+                                        session.description.as_deref().unwrap_or("")
+                                    ));
+                                }
+                            });
+                    });
+                }
+            }
+
+            if let Some(path) = editor.current_file.clone() {
+                if ui
+                    .button("📡 Share Nearby")
+                    .on_hover_text("Send this note to FocusPad on another computer nearby")
+                    .clicked()
+                {
+                    // Send what's on screen, not the last saved version
+                    let _ = editor.save_file();
+                    crate::ui::lan_share_ui::choose_note(path);
+                    app.show_lan_share = true;
+                }
+            }
+
+            if editor.current_file.is_some()
+                && crate::ui::card_suggestions::is_enabled()
+                && ui
+                    .button("✨ Suggest Cards")
+                    .on_hover_text("Suggest flashcards from the selection, or the whole note")
+                    .clicked()
+            {
+                let text = if editor.selected_text.trim().is_empty() {
+                    editor.current_content.clone()
+                } else {
+                    editor.selected_text.clone()
+                };
+                crate::ui::card_suggestions::open_with_text(text, editor.last_flashcard_deck);
+            }
+
+            let clipping = CLIP_JOB.with(|job| job.borrow().is_some());
+            if clipping {
+                ui.spinner();
+                ui.label("Clipping…");
+            } else {
+                ui.menu_button("🌐 Clip URL", |ui| {
+                    ui.label("Save a web page as a note in files/clips");
+                    CLIP_URL.with(|url| {
+                        let mut url = url.borrow_mut();
+                        let response = ui.add(
+                            egui::TextEdit::singleline(&mut *url)
+                                .hint_text("https://")
+                                .desired_width(280.0),
+                        );
+                        let submitted =
+                            response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                        if (ui.button("Clip").clicked() || submitted) && !url.trim().is_empty() {
+                            let receiver = crate::web_clip::start(url.trim().to_string());
+                            CLIP_JOB.with(|job| *job.borrow_mut() = Some(receiver));
+                            url.clear();
+                            ui.close_menu();
+                        }
+                    });
+                });
+            }
+
+            if ui
+                .button("📑 References")
+                .on_hover_text("Manage references and cite them in this note")
+                .clicked()
+            {
+                crate::ui::references_ui::open();
+            }
+
+            if ui
+                .button("🔎 Replace in Notes")
+                .on_hover_text("Find and replace across every note (Ctrl+Shift+F)")
+                .clicked()
+            {
+                crate::ui::project_replace::open();
+            }
+
+            let due_count = app.study_data.get_due_reading_items().len();
+            let queue_label = format!("📚 Due Notes ({})", due_count);
+            if ui
+                .selectable_label(editor.show_reading_queue, queue_label)
+                .clicked()
+            {
+                editor.show_reading_queue = !editor.show_reading_queue;
+            }
+
+            ui.menu_button("📤 Export Vault", |ui| {
+                for format in [VaultFormat::Obsidian, VaultFormat::Notion] {
+                    if ui.button(format.name()).clicked() {
+                        ui.close_menu();
+                        // Notes are read from disk, make sure the open one is up to date
+                        if editor.current_file.is_some() {
+                            let _ = editor.save_file();
+                        }
+                        if let Some(folder) = rfd::FileDialog::new().pick_folder() {
+                            match vault_export::export_vault(&app.study_data, &folder, format) {
+                                Ok(summary) => app.status.show(&format!(
+                                    "Exported {} notes, {} todos, {} decks and {} attachments",
+                                    summary.notes,
+                                    summary.todos,
+                                    summary.decks,
+                                    summary.attachments
+                                )),
+                                Err(e) => app.status.error(&format!("Export failed: {}", e)),
+                            }
+                        }
+                    }
+                }
+            });
         });
 
         ui.separator();
 
+        if editor.show_reading_queue {
+            render_reading_queue(ui, editor, &mut app.study_data, &mut app.status);
+            ui.separator();
+        }
+
         if editor.file_browser_collapsed {
             // Only show editor when file browser is collapsed
             ui.with_layout(egui::Layout::top_down_justified(egui::Align::LEFT), |ui| {
@@ -231,6 +785,218 @@ pub fn display(ui: &mut egui::Ui, app: &mut crate::app::StudyTimerApp, ctx: &egu
                 });
             });
         }
+
+        if editor.flashcard_draft.is_some() {
+            render_flashcard_dialog(ctx, editor, &mut app.study_data, &mut app.status);
+        }
+
+        // Toolbar toggles apply to every note, and are kept for next time
+        if (editor.typewriter_mode, editor.highlight_line)
+            != (app.settings.typewriter_mode, app.settings.highlight_line)
+        {
+            app.settings.typewriter_mode = editor.typewriter_mode;
+            app.settings.highlight_line = editor.highlight_line;
+            if let Err(e) = app.settings.save() {
+                app.status
+                    .error(&format!("Failed to save editor settings: {}", e));
+            }
+        }
     }
 }
 
+fn note_headings(content: &str) -> Vec<String> {
+    note_outline::headings(content)
+        .into_iter()
+        .map(|heading| heading.title)
+        .collect()
+}
+
+fn render_reading_queue(
+    ui: &mut egui::Ui,
+    editor: &mut MarkdownEditor,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+) {
+    ui.heading("📚 Notes Due Today");
+
+    let due: Vec<ReadingItem> = study_data
+        .get_due_reading_items()
+        .into_iter()
+        .cloned()
+        .collect();
+
+    if due.is_empty() {
+        let upcoming = study_data
+            .reading_queue
+            .iter()
+            .map(|item| item.due_date.as_str())
+            .min();
+        match upcoming {
+            Some(date) => ui.label(format!("Nothing due. Next note comes back on {}.", date)),
+            None => ui.label("Your reading queue is empty. Use 🔁 Read Later on a note to add it."),
+        };
+        return;
+    }
+
+    let mut action: Option<(u64, Option<ReadingRating>)> = None;
+
+    egui::ScrollArea::vertical()
+        .id_source("reading_queue_scroll")
+        .max_height(180.0)
+        .show(ui, |ui| {
+            for item in &due {
+                let path = PathBuf::from(&item.path);
+                let exists = path.exists();
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| item.path.clone());
+
+                ui.horizontal(|ui| {
+                    let title = match &item.section {
+                        Some(section) => format!("{} › {}", name, section),
+                        None => name.clone(),
+                    };
+                    if exists {
+                        if ui.link(title).clicked() {
+                            match editor.open_file(&path) {
+                                Ok(()) => editor.selected_entry = Some(path.clone()),
+                                Err(e) => status.error(&format!("Error opening file: {}", e)),
+                            }
+                        }
+                    } else {
+                        ui.label(RichText::new(format!("{} (missing)", title)).weak());
+                    }
+
+                    ui.label(
+                        RichText::new(format!("read {} times", item.times_read))
+                            .small()
+                            .weak(),
+                    );
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("✔ Done").on_hover_text("Remove from the queue").clicked() {
+                            action = Some((item.id, None));
+                        }
+                        if exists {
+                            if ui.button("Later").clicked() {
+                                action = Some((item.id, Some(ReadingRating::Later)));
+                            }
+                            if ui.button("Good").clicked() {
+                                action = Some((item.id, Some(ReadingRating::Good)));
+                            }
+                            if ui.button("Sooner").clicked() {
+                                action = Some((item.id, Some(ReadingRating::Sooner)));
+                            }
+                        }
+                    });
+                });
+            }
+        });
+
+    if let Some((id, rating)) = action {
+        let result = match rating {
+            Some(rating) => study_data.review_reading_item(id, rating),
+            None => study_data.remove_reading_item(id),
+        };
+        if let Err(e) = result {
+            status.error(&format!("Error saving: {}", e));
+        }
+    }
+}
+
+fn render_flashcard_dialog(
+    ctx: &egui::Context,
+    editor: &mut MarkdownEditor,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+) {
+    let mut open = true;
+    let mut create = false;
+    let mut cancel = false;
+
+    if let Some(draft) = &mut editor.flashcard_draft {
+        egui::Window::new("Create Flashcard")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([400.0, 300.0])
+            .show(ctx, |ui| {
+                if study_data.decks.is_empty() {
+                    ui.label("Create a deck in the Flashcards tab first.");
+                    return;
+                }
+
+                // Fall back to the first deck if the remembered one was deleted
+                if !draft
+                    .deck_id
+                    .map_or(false, |id| study_data.decks.iter().any(|d| d.id == id))
+                {
+                    draft.deck_id = study_data.decks.first().map(|d| d.id);
+                }
+                let selected_name = study_data
+                    .decks
+                    .iter()
+                    .find(|d| Some(d.id) == draft.deck_id)
+                    .map(|d| d.name.clone())
+                    .unwrap_or_default();
+
+                ui.horizontal(|ui| {
+                    ui.label("Deck:");
+                    egui::ComboBox::from_id_source("extract_flashcard_deck")
+                        .selected_text(selected_name)
+                        .show_ui(ui, |ui| {
+                            for deck in &study_data.decks {
+                                ui.selectable_value(&mut draft.deck_id, Some(deck.id), &deck.name);
+                            }
+                        });
+                });
+
+                ui.label("Front (Question):");
+                ui.add(
+                    egui::TextEdit::multiline(&mut draft.front)
+                        .desired_rows(2)
+                        .hint_text("What should this card ask?"),
+                );
+                ui.label("Back (Answer):");
+                ui.add(egui::TextEdit::multiline(&mut draft.back).desired_rows(4));
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let ready = !draft.front.trim().is_empty() && !draft.back.trim().is_empty();
+                    if ui.add_enabled(ready, egui::Button::new("Create")).clicked() {
+                        create = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+    }
+
+    if create {
+        if let Some(draft) = editor.flashcard_draft.take() {
+            if let Some(deck) = study_data
+                .decks
+                .iter_mut()
+                .find(|d| Some(d.id) == draft.deck_id)
+            {
+                let deck_name = deck.name.clone();
+                let mut card = Card::new(
+                    deck.id,
+                    draft.front.trim().to_string(),
+                    draft.back.trim().to_string(),
+                );
+                card.source = draft.source;
+                deck.add_card(card);
+                editor.last_flashcard_deck = draft.deck_id;
+                match study_data.save() {
+                    Ok(()) => status.show(&format!("Flashcard added to {}", deck_name)),
+                    Err(e) => status.error(&format!("Error saving: {}", e)),
+                }
+            }
+        }
+    } else if cancel || !open {
+        editor.flashcard_draft = None;
+    }
+}
@@ -1,16 +1,52 @@
+pub mod agenda_widget;
+pub mod archive_ui;
+pub mod assignments_tab;
 pub mod calculator_tab;
+pub mod card_history;
+pub mod card_suggestions;
+pub mod day_planner;
+pub mod deck_merge;
+pub mod deck_sync_ui;
+pub mod duplicate_finder;
+pub mod eisenhower_matrix;
+pub mod epub_reader_tab;
 pub mod file_browser;
+pub mod find_bar;
 pub mod flashcard;
 pub mod flashcard_tab_ui;
 pub mod flashcard_ui;
+pub mod grade_calculator;
 pub mod graph_tab;
+pub mod habit_review;
+pub mod habit_stats;
+pub mod home_tab;
+pub mod image_viewer_tab;
+pub mod journal_tab;
+pub mod lan_share_ui;
 pub mod markdown_editor;
 pub mod markdown_renderer;
 pub mod markdown_tab_ui;
+pub mod note_outline;
+pub mod planner_tab;
+pub mod profile_ui;
+pub mod project_replace;
+pub mod quiz_ui;
 pub mod record_tab;
+pub mod recorder_tab;
+pub mod references_ui;
 pub mod reminder_tab;
+pub mod review_heatmap;
+pub mod rich_text;
 pub mod settings_tab_ui;
+pub mod snippets;
+pub mod spell_check_ui;
+pub mod startup_summary;
 pub mod stats_tab;
 pub mod terminal_tab_ui;
+pub mod timer_recovery_ui;
 pub mod timer_tab;
 pub mod todo_tab;
+pub mod trash_ui;
+pub mod video_tab;
+pub mod whiteboard_tab;
+pub mod world_clock;
@@ -0,0 +1,118 @@
+use eframe::egui;
+
+const WORDS_PER_MINUTE: usize = 200;
+
+pub struct Heading {
+    pub level: usize,
+    pub title: String,
+    pub line: usize,
+}
+
+pub struct NoteStats {
+    pub words: usize,
+    pub characters: usize,
+    pub headings: Vec<Heading>,
+}
+
+impl NoteStats {
+    pub fn of(content: &str) -> Self {
+        Self {
+            // Markdown markers like "#" or "-" on their own aren't words
+            words: content
+                .split_whitespace()
+                .filter(|word| word.chars().any(char::is_alphanumeric))
+                .count(),
+            characters: content.chars().filter(|c| !c.is_whitespace()).count(),
+            headings: headings(content),
+        }
+    }
+
+    pub fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(WORDS_PER_MINUTE)
+    }
+
+    pub fn summary(&self) -> String {
+        let minutes = self.reading_minutes();
+        format!(
+            "{} words · {} characters · {}",
+            self.words,
+            self.characters,
+            if minutes == 0 {
+                "no reading time".to_string()
+            } else {
+                format!("{} min read", minutes)
+            }
+        )
+    }
+}
+
+// "#" to "######" headings, ignoring lines inside code fences
+pub fn headings(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut in_code = false;
+    for (line_index, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code || !trimmed.starts_with('#') {
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        let title = trimmed[level..].trim();
+        if level <= 6 && !title.is_empty() {
+            headings.push(Heading {
+                level,
+                title: title.to_string(),
+                line: line_index,
+            });
+        }
+    }
+    headings
+}
+
+// Character offset of the start of a line, as used by TextEdit cursors
+pub fn line_start(content: &str, line: usize) -> usize {
+    content
+        .split('\n')
+        .take(line)
+        .map(|l| l.chars().count() + 1)
+        .sum()
+}
+
+// Lists the headings; returns the line of the one clicked
+pub fn outline_panel(ui: &mut egui::Ui, stats: &NoteStats) -> Option<usize> {
+    let mut clicked = None;
+    ui.strong("Outline");
+    ui.separator();
+    if stats.headings.is_empty() {
+        ui.label(egui::RichText::new("Add # headings to build an outline").italics());
+        return None;
+    }
+
+    egui::ScrollArea::vertical()
+        .id_source("note_outline_scroll")
+        .show(ui, |ui| {
+            let min_level = stats.headings.iter().map(|h| h.level).min().unwrap_or(1);
+            for heading in &stats.headings {
+                ui.horizontal(|ui| {
+                    ui.add_space((heading.level - min_level) as f32 * 12.0);
+                    let text = if heading.level == min_level {
+                        egui::RichText::new(&heading.title).strong()
+                    } else {
+                        egui::RichText::new(&heading.title)
+                    };
+                    if ui
+                        .add(egui::Label::new(text).sense(egui::Sense::click()))
+                        .on_hover_cursor(egui::CursorIcon::PointingHand)
+                        .clicked()
+                    {
+                        clicked = Some(heading.line);
+                    }
+                });
+            }
+        });
+    clicked
+}
@@ -0,0 +1,265 @@
+use crate::app::StatusMessage;
+use crate::data::{BoardColumn, StudyData, Todo};
+use crate::settings::{AppSettings, ColorTheme};
+use crate::timer::Timer;
+use crate::ui::day_planner;
+use eframe::egui;
+use std::cell::RefCell;
+
+#[derive(Default, PartialEq)]
+enum PlannerView {
+    #[default]
+    Board,
+    Day,
+}
+
+#[derive(Default)]
+struct PlannerState {
+    view: PlannerView,
+    dragged: Option<u64>,  // Id of the todo being dragged
+    new_todo: [String; 3], // Add fields for every column but Done
+}
+
+thread_local! {
+    static PLANNER_STATE: RefCell<PlannerState> = RefCell::new(PlannerState::default());
+}
+
+// Returns true when a time block started the timer, so the Timer tab can be shown
+pub fn display(
+    ui: &mut egui::Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    settings: &AppSettings,
+    timer: &mut Timer,
+) -> bool {
+    let colors = settings.get_current_colors();
+
+    ui.horizontal(|ui| {
+        ui.heading(egui::RichText::new("Planner").color(colors.text_primary_color32()));
+        ui.add_space(10.0);
+        PLANNER_STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            ui.selectable_value(&mut state.view, PlannerView::Board, "🗂 Week Board");
+            ui.selectable_value(&mut state.view, PlannerView::Day, "🕘 Day Blocks");
+        });
+    });
+
+    if PLANNER_STATE.with(|state| state.borrow().view == PlannerView::Day) {
+        return day_planner::display(ui, study_data, status, &colors, timer);
+    }
+    display_board(ui, study_data, status, &colors);
+    false
+}
+
+fn display_board(
+    ui: &mut egui::Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    colors: &ColorTheme,
+) {
+    ui.label(
+        egui::RichText::new(
+            "Drag ☰ to move a todo between columns. Dropping on Done completes it.",
+        )
+        .small()
+        .color(colors.text_secondary_color32()),
+    );
+    ui.add_space(10.0);
+
+    let mut moves: Vec<(u64, BoardColumn)> = Vec::new();
+    let mut added: Vec<(String, BoardColumn)> = Vec::new();
+    let mut column_rects = Vec::new();
+
+    PLANNER_STATE.with(|state| {
+        let mut state = state.borrow_mut();
+
+        ui.columns(BoardColumn::ALL.len(), |columns| {
+            for (index, column) in BoardColumn::ALL.iter().enumerate() {
+                let ui = &mut columns[index];
+                let todos: Vec<_> = study_data
+                    .todos
+                    .iter()
+                    .filter(|t| t.column() == *column)
+                    .collect();
+
+                let frame = egui::Frame::group(ui.style()).show(ui, |ui| {
+                    ui.set_min_height(300.0);
+                    ui.horizontal(|ui| {
+                        ui.strong(column.title());
+                        ui.label(
+                            egui::RichText::new(format!("{}", todos.len()))
+                                .color(colors.text_secondary_color32()),
+                        );
+                    });
+                    ui.separator();
+
+                    if *column != BoardColumn::Done {
+                        let field = &mut state.new_todo[index];
+                        let response = ui.add(
+                            egui::TextEdit::singleline(field)
+                                .hint_text("+ Add todo")
+                                .desired_width(f32::INFINITY),
+                        );
+                        if response.lost_focus()
+                            && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                            && !field.trim().is_empty()
+                        {
+                            added.push((field.trim().to_string(), *column));
+                            field.clear();
+                        }
+                        ui.add_space(4.0);
+                    }
+
+                    egui::ScrollArea::vertical()
+                        .id_source(("planner_column", index))
+                        .show(ui, |ui| {
+                            for todo in &todos {
+                                todo_card(ui, todo, index, colors, &mut state.dragged, &mut moves);
+                                ui.add_space(4.0);
+                            }
+                        });
+                });
+                column_rects.push((*column, frame.response.rect));
+            }
+        });
+
+        // Drop the dragged todo onto the column under the pointer
+        if let Some(id) = state.dragged {
+            ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grabbing);
+            let pointer = ui.ctx().pointer_interact_pos();
+            let target = pointer.and_then(|pointer| {
+                column_rects
+                    .iter()
+                    .find(|(_, rect)| rect.contains(pointer))
+                    .copied()
+            });
+
+            if let Some((_, rect)) = target {
+                ui.painter().rect_stroke(
+                    rect.expand(2.0),
+                    egui::Rounding::same(4.0),
+                    egui::Stroke::new(1.5, colors.accent_color32()),
+                );
+            }
+            if let (Some(pointer), Some(todo)) =
+                (pointer, study_data.todos.iter().find(|t| t.id == id))
+            {
+                let painter = ui.ctx().layer_painter(egui::LayerId::new(
+                    egui::Order::Tooltip,
+                    egui::Id::new("planner_drag"),
+                ));
+                painter.text(
+                    pointer + egui::vec2(12.0, 0.0),
+                    egui::Align2::LEFT_CENTER,
+                    &todo.text,
+                    egui::FontId::default(),
+                    colors.text_primary_color32(),
+                );
+            }
+
+            if ui.input(|i| i.pointer.any_released()) {
+                if let Some((column, _)) = target {
+                    moves.push((id, column));
+                }
+                state.dragged = None;
+            }
+        }
+    });
+
+    for (id, column) in moves {
+        let unchanged = study_data
+            .todos
+            .iter()
+            .any(|t| t.id == id && t.column() == column);
+        if unchanged {
+            continue;
+        }
+        if let Err(e) = study_data.move_todo_to_column(id, column) {
+            status.error(&format!("Error moving todo: {}", e));
+        }
+    }
+
+    for (text, column) in added {
+        let result =
+            study_data
+                .add_todo(text)
+                .and_then(|()| match study_data.todos.last().map(|t| t.id) {
+                    Some(id) if column != BoardColumn::default() => {
+                        study_data.move_todo_to_column(id, column)
+                    }
+                    _ => Ok(()),
+                });
+        if let Err(e) = result {
+            status.error(&format!("Error adding todo: {}", e));
+        }
+    }
+}
+
+fn todo_card(
+    ui: &mut egui::Ui,
+    todo: &Todo,
+    index: usize,
+    colors: &ColorTheme,
+    dragged: &mut Option<u64>,
+    moves: &mut Vec<(u64, BoardColumn)>,
+) {
+    let card = egui::Frame::none()
+        .fill(colors.background_color32())
+        .rounding(egui::Rounding::same(4.0))
+        .inner_margin(egui::Margin::same(6.0))
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.horizontal(|ui| {
+                let handle = ui
+                    .add(egui::Label::new("☰").sense(egui::Sense::drag()))
+                    .on_hover_text("Drag to another column");
+                if handle.hovered() {
+                    ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grab);
+                }
+                if handle.drag_started() {
+                    *dragged = Some(todo.id);
+                }
+
+                let mut text = egui::RichText::new(&todo.text).color(colors.text_primary_color32());
+                if todo.completed {
+                    text = text.strikethrough();
+                }
+                ui.add(egui::Label::new(text).wrap(true));
+            });
+
+            ui.horizontal(|ui| {
+                if let Some(project) = &todo.project {
+                    ui.label(
+                        egui::RichText::new(project)
+                            .small()
+                            .color(colors.text_secondary_color32()),
+                    );
+                }
+                // Buttons as an alternative to dragging
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if let Some(next) = BoardColumn::ALL.get(index + 1) {
+                        if ui.small_button("▶").on_hover_text(next.title()).clicked() {
+                            moves.push((todo.id, *next));
+                        }
+                    }
+                    if let Some(previous) = index.checked_sub(1).map(|i| BoardColumn::ALL[i]) {
+                        if ui
+                            .small_button("◀")
+                            .on_hover_text(previous.title())
+                            .clicked()
+                        {
+                            moves.push((todo.id, previous));
+                        }
+                    }
+                });
+            });
+        });
+
+    if *dragged == Some(todo.id) {
+        ui.painter().rect_stroke(
+            card.response.rect,
+            egui::Rounding::same(4.0),
+            egui::Stroke::new(1.0, colors.accent_color32()),
+        );
+    }
+}
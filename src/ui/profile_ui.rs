@@ -0,0 +1,120 @@
+use crate::app::StatusMessage;
+use crate::profile::{self, ProfileList};
+use eframe::egui;
+use std::cell::RefCell;
+
+thread_local! {
+    static NEW_PROFILE: RefCell<String> = RefCell::new(String::new());
+    // Loaded once rather than read from disk every frame
+    static PROFILES: RefCell<Option<ProfileList>> = RefCell::new(None);
+}
+
+fn with_profiles<R>(f: impl FnOnce(&mut ProfileList) -> R) -> R {
+    PROFILES.with(|profiles| f(profiles.borrow_mut().get_or_insert_with(ProfileList::load)))
+}
+
+// Opens the profile in a new window and closes this one, which keeps every loaded
+// file tied to a single profile
+fn switch_to(ctx: &egui::Context, name: &str, status: &mut StatusMessage) {
+    if name == profile::active_name() {
+        return;
+    }
+    match profile::relaunch_as(name) {
+        Ok(()) => ctx.send_viewport_cmd(egui::ViewportCommand::Close),
+        Err(e) => status.error(&format!("Could not switch to {}: {}", name, e)),
+    }
+}
+
+// Startup prompt asking whose data to open
+pub fn picker(ctx: &egui::Context, open: &mut bool, status: &mut StatusMessage) {
+    let names = with_profiles(|list| list.names());
+    let mut chosen = None;
+
+    egui::Window::new("👤 Who's studying?")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label("Pick a profile. You can switch later in Settings.");
+            ui.add_space(10.0);
+            for name in &names {
+                let label = if name == profile::active_name() {
+                    format!("{} (last used)", name)
+                } else {
+                    name.clone()
+                };
+                if ui
+                    .add_sized([220.0, 28.0], egui::Button::new(label))
+                    .clicked()
+                {
+                    chosen = Some(name.clone());
+                }
+            }
+        });
+
+    if let Some(name) = chosen {
+        switch_to(ctx, &name, status);
+        *open = false;
+    }
+}
+
+pub fn settings_section(ui: &mut egui::Ui, status: &mut StatusMessage) {
+    with_profiles(|list| profile_list(ui, list, status));
+}
+
+fn profile_list(ui: &mut egui::Ui, list: &mut ProfileList, status: &mut StatusMessage) {
+    let active = profile::active_name();
+
+    ui.label(format!("Current profile: {}", active));
+    ui.label(
+        egui::RichText::new(
+            "Each profile has its own sessions, todos, decks and settings. Switching reopens the app.",
+        )
+        .small(),
+    );
+    ui.add_space(10.0);
+
+    for name in list.names() {
+        ui.horizontal(|ui| {
+            ui.label(&name);
+            if name == active {
+                ui.label(egui::RichText::new("active").small());
+            } else if ui.button("Switch").clicked() {
+                switch_to(ui.ctx(), &name, status);
+            }
+        });
+    }
+
+    ui.add_space(5.0);
+    NEW_PROFILE.with(|new_profile| {
+        let mut new_profile = new_profile.borrow_mut();
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut *new_profile)
+                    .hint_text("New profile name")
+                    .desired_width(160.0),
+            );
+            if ui.button("➕ Create").clicked() {
+                match list.create(&new_profile) {
+                    Ok(()) => {
+                        status.show(&format!("Created profile {}", new_profile.trim()));
+                        new_profile.clear();
+                    }
+                    Err(e) => status.error(&e.to_string()),
+                }
+            }
+        });
+    });
+
+    if ui
+        .checkbox(
+            &mut list.ask_on_startup,
+            "Ask which profile to use when the app starts",
+        )
+        .changed()
+    {
+        if let Err(e) = list.save() {
+            status.error(&format!("Failed to save profiles: {}", e));
+        }
+    }
+}
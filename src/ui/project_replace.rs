@@ -0,0 +1,430 @@
+use crate::app::StatusMessage;
+use crate::ui::find_bar;
+use crate::ui::markdown_editor::{MarkdownEditor, FILES_DIR};
+use eframe::egui::{self, Color32, RichText};
+use regex::Regex;
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Lines longer than this are shortened in the preview
+const PREVIEW_CHARS: usize = 160;
+
+// A line, or lines, that a match touches
+struct LineChange {
+    line: usize,
+    before: String,
+    after: String,
+}
+
+struct FileChanges {
+    path: PathBuf,
+    original: String, // Checked again before writing, in case the note changed meanwhile
+    replaced: String,
+    matches: usize,
+    lines: Vec<LineChange>,
+    include: bool,
+}
+
+struct ReplaceWindow {
+    query: String,
+    replacement: String,
+    regex: bool,
+    case_sensitive: bool,
+    results: Option<Vec<FileChanges>>, // None until searched, and again after any change
+    error: Option<String>,
+}
+
+thread_local! {
+    static WINDOW: RefCell<Option<ReplaceWindow>> = RefCell::new(None);
+}
+
+pub fn open() {
+    WINDOW.with(|window| {
+        window.borrow_mut().get_or_insert(ReplaceWindow {
+            query: String::new(),
+            replacement: String::new(),
+            regex: false,
+            case_sensitive: false,
+            results: None,
+            error: None,
+        });
+    });
+}
+
+pub fn is_open() -> bool {
+    WINDOW.with(|window| window.borrow().is_some())
+}
+
+fn collect_notes(dir: &Path, notes: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_notes(&path, notes);
+        } else if path.extension().map_or(false, |ext| ext == "md") {
+            notes.push(path);
+        }
+    }
+}
+
+fn line_start(text: &str, at: usize) -> usize {
+    text[..at].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn line_end(text: &str, at: usize) -> usize {
+    text[at..].find('\n').map_or(text.len(), |i| at + i)
+}
+
+fn shorten(line: &str) -> String {
+    if line.chars().count() > PREVIEW_CHARS {
+        let short: String = line.chars().take(PREVIEW_CHARS).collect();
+        format!("{}…", short)
+    } else {
+        line.to_string()
+    }
+}
+
+// The new text along with every changed line before and after
+fn plan_file(
+    path: PathBuf,
+    original: String,
+    re: &Regex,
+    replacement: &str,
+    regex: bool,
+) -> Option<FileChanges> {
+    // Each match with what it becomes, so the preview shows exactly what gets written
+    let edits: Vec<(std::ops::Range<usize>, String)> = re
+        .captures_iter(&original)
+        .map(|caps| {
+            let found = caps.get(0).unwrap();
+            let mut with = String::new();
+            if regex {
+                caps.expand(replacement, &mut with);
+            } else {
+                with.push_str(replacement);
+            }
+            (found.range(), with)
+        })
+        .collect();
+    if edits.is_empty() {
+        return None;
+    }
+
+    let mut replaced = String::with_capacity(original.len());
+    let mut copied = 0;
+    for (range, with) in &edits {
+        replaced.push_str(&original[copied..range.start]);
+        replaced.push_str(with);
+        copied = range.end;
+    }
+    replaced.push_str(&original[copied..]);
+
+    // Matches on the same lines are shown together
+    let mut lines = Vec::new();
+    let mut index = 0;
+    while index < edits.len() {
+        let start = line_start(&original, edits[index].0.start);
+        let mut end = line_end(&original, edits[index].0.end);
+        let mut last = index + 1;
+        while last < edits.len() && edits[last].0.start <= end {
+            end = line_end(&original, edits[last].0.end.max(end));
+            last += 1;
+        }
+
+        let mut after = String::new();
+        let mut copied = start;
+        for (range, with) in &edits[index..last] {
+            after.push_str(&original[copied..range.start]);
+            after.push_str(with);
+            copied = range.end;
+        }
+        after.push_str(&original[copied..end]);
+
+        lines.push(LineChange {
+            line: original[..start].matches('\n').count() + 1,
+            before: shorten(&original[start..end]),
+            after: shorten(&after),
+        });
+        index = last;
+    }
+
+    Some(FileChanges {
+        path,
+        matches: edits.len(),
+        original,
+        replaced,
+        lines,
+        include: true,
+    })
+}
+
+fn search(state: &mut ReplaceWindow, editors: &mut [&mut MarkdownEditor]) {
+    state.results = None;
+    state.error = None;
+    let re = match find_bar::build_pattern(&state.query, state.regex, state.case_sensitive) {
+        Some(Ok(re)) => re,
+        Some(Err(e)) => {
+            state.error = Some(e.to_string());
+            return;
+        }
+        None => return,
+    };
+
+    let mut notes = Vec::new();
+    collect_notes(Path::new(FILES_DIR), &mut notes);
+    notes.sort();
+    state.results = Some(
+        notes
+            .into_iter()
+            .filter_map(|path| {
+                let text = current_text(editors, &path)?;
+                plan_file(path, text, &re, &state.replacement, state.regex)
+            })
+            .collect(),
+    );
+}
+
+// Notes open in a tab are searched as typed, without saving them first
+fn current_text(editors: &[&mut MarkdownEditor], path: &Path) -> Option<String> {
+    match editors.iter().find(|editor| open_in(editor, path)) {
+        Some(editor) => Some(editor.current_content.clone()),
+        None => fs::read_to_string(path).ok(),
+    }
+}
+
+fn open_in(editor: &MarkdownEditor, path: &Path) -> bool {
+    editor
+        .current_file
+        .as_deref()
+        .is_some_and(|open| same_file(open, path))
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn apply(results: &[FileChanges], editors: &mut [&mut MarkdownEditor], status: &mut StatusMessage) {
+    let mut written = 0;
+    let mut matches = 0;
+    let mut skipped = Vec::new();
+    for file in results.iter().filter(|file| file.include) {
+        let current = current_text(editors, &file.path);
+        if current.as_deref() != Some(file.original.as_str()) {
+            skipped.push(file.path.display().to_string());
+            continue;
+        }
+        if let Err(e) = fs::write(&file.path, &file.replaced) {
+            status.error(&format!("Failed to write {}: {}", file.path.display(), e));
+            return;
+        }
+        written += 1;
+        matches += file.matches;
+
+        // Open tabs show the new text rather than saving the old text back over it
+        for editor in editors.iter_mut() {
+            if open_in(editor, &file.path) {
+                let _ = editor.open_file(&file.path);
+            }
+        }
+    }
+
+    if skipped.is_empty() {
+        status.show(&format!(
+            "Replaced {} match(es) in {} note(s)",
+            matches, written
+        ));
+    } else {
+        status.error(&format!(
+            "Replaced {} match(es) in {} note(s); skipped notes changed since the search: {}",
+            matches,
+            written,
+            skipped.join(", ")
+        ));
+    }
+}
+
+pub fn display(
+    ctx: &egui::Context,
+    status: &mut StatusMessage,
+    editors: &mut [&mut MarkdownEditor],
+) {
+    WINDOW.with(|window| {
+        let mut window = window.borrow_mut();
+        let Some(state) = window.as_mut() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut applied = false;
+        egui::Window::new("🔎 Replace in Notes")
+            .open(&mut open)
+            .default_width(600.0)
+            .default_height(480.0)
+            .show(ctx, |ui| {
+                let mut changed = false;
+                let mut submitted = false;
+                egui::Grid::new("project_replace_fields")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Find:");
+                        let response = ui.add(
+                            egui::TextEdit::singleline(&mut state.query)
+                                .hint_text(if state.regex { "Pattern" } else { "Text" })
+                                .desired_width(320.0),
+                        );
+                        changed |= response.changed();
+                        submitted |=
+                            response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                        ui.end_row();
+
+                        ui.label("Replace:");
+                        let response = ui.add(
+                            egui::TextEdit::singleline(&mut state.replacement)
+                                .hint_text(if state.regex {
+                                    "Replace with ($1 for groups)"
+                                } else {
+                                    "Replace with"
+                                })
+                                .desired_width(320.0),
+                        );
+                        changed |= response.changed();
+                        submitted |=
+                            response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                        ui.end_row();
+                    });
+
+                ui.horizontal(|ui| {
+                    changed |= ui
+                        .toggle_value(&mut state.case_sensitive, "Aa")
+                        .on_hover_text("Match case")
+                        .changed();
+                    changed |= ui
+                        .toggle_value(&mut state.regex, ".*")
+                        .on_hover_text("Regular expression")
+                        .changed();
+                    if ui
+                        .add_enabled(!state.query.is_empty(), egui::Button::new("🔍 Preview"))
+                        .on_hover_text(format!("Search every note in {}/", FILES_DIR))
+                        .clicked()
+                    {
+                        submitted = true;
+                    }
+                });
+                // A preview only stands for the search it was made from
+                if changed {
+                    state.results = None;
+                    state.error = None;
+                }
+                if submitted && !state.query.is_empty() {
+                    search(state, editors);
+                }
+
+                if let Some(error) = &state.error {
+                    ui.label(
+                        RichText::new(format!("Invalid pattern: {}", error))
+                            .color(Color32::from_rgb(213, 94, 0)),
+                    );
+                }
+                let Some(results) = state.results.as_mut() else {
+                    return;
+                };
+                ui.separator();
+                if results.is_empty() {
+                    ui.label("No matches in any note");
+                    return;
+                }
+
+                let included: Vec<&FileChanges> = results.iter().filter(|f| f.include).collect();
+                let total: usize = included.iter().map(|f| f.matches).sum();
+                let summary = format!(
+                    "{} match(es) in {} of {} note(s) will be replaced",
+                    total,
+                    included.len(),
+                    results.len()
+                );
+                ui.horizontal(|ui| {
+                    ui.label(summary);
+                    if ui.small_button("All").clicked() {
+                        results.iter_mut().for_each(|f| f.include = true);
+                    }
+                    if ui.small_button("None").clicked() {
+                        results.iter_mut().for_each(|f| f.include = false);
+                    }
+                });
+
+                egui::ScrollArea::vertical()
+                    .max_height(ui.available_height() - 40.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for (index, file) in results.iter_mut().enumerate() {
+                            let name = file
+                                .path
+                                .strip_prefix(FILES_DIR)
+                                .unwrap_or(&file.path)
+                                .display()
+                                .to_string();
+                            ui.push_id(index, |ui| {
+                                ui.horizontal(|ui| {
+                                    ui.checkbox(&mut file.include, RichText::new(name).strong());
+                                    ui.label(
+                                        RichText::new(format!("{} match(es)", file.matches))
+                                            .small()
+                                            .weak(),
+                                    );
+                                });
+                                ui.add_enabled_ui(file.include, |ui| {
+                                    for change in &file.lines {
+                                        preview_line(ui, change);
+                                    }
+                                });
+                            });
+                            ui.add_space(6.0);
+                        }
+                    });
+
+                ui.separator();
+                if ui
+                    .add_enabled(total > 0, egui::Button::new("✔ Replace in Selected Notes"))
+                    .clicked()
+                {
+                    apply(results, editors, status);
+                    applied = true;
+                }
+            });
+
+        if applied {
+            state.results = None;
+        }
+        if !open {
+            *window = None;
+        }
+    });
+}
+
+fn preview_line(ui: &mut egui::Ui, change: &LineChange) {
+    ui.horizontal_top(|ui| {
+        ui.label(
+            RichText::new(format!("{:>5}", change.line))
+                .monospace()
+                .weak(),
+        );
+        ui.vertical(|ui| {
+            ui.label(
+                RichText::new(format!("- {}", change.before))
+                    .monospace()
+                    .color(Color32::from_rgb(213, 94, 0)),
+            );
+            ui.label(
+                RichText::new(format!("+ {}", change.after))
+                    .monospace()
+                    .color(Color32::from_rgb(0, 158, 115)),
+            );
+        });
+    });
+}
@@ -0,0 +1,238 @@
+use crate::data::QuizResult;
+use crate::ui::flashcard::Deck;
+use chrono::Local;
+use eframe::egui;
+use rand::prelude::SliceRandom;
+
+const QUIZ_LENGTH: usize = 10;
+const OPTIONS_PER_QUESTION: usize = 4;
+
+pub struct QuizQuestion {
+    pub prompt: String,
+    pub options: Vec<String>,
+    pub answer_index: usize,
+}
+
+pub struct QuizSession {
+    deck_id: u64,
+    deck_name: String,
+    cards: Vec<(String, String)>, // Kept so the quiz can be retried with new questions
+    questions: Vec<QuizQuestion>,
+    current: usize,
+    selected: Option<usize>,
+    correct: u32,
+    finished: bool,
+}
+
+pub enum QuizAction {
+    None,
+    Finished(QuizResult),
+    Close,
+}
+
+impl QuizSession {
+    pub fn new(deck: &Deck) -> Result<Self, String> {
+        let cards: Vec<(String, String)> = deck
+            .cards
+            .iter()
+            .filter(|c| !c.back.trim().is_empty())
+            .map(|c| (c.front.clone(), c.back.clone()))
+            .collect();
+
+        let mut session = Self {
+            deck_id: deck.id,
+            deck_name: deck.name.clone(),
+            cards,
+            questions: Vec::new(),
+            current: 0,
+            selected: None,
+            correct: 0,
+            finished: false,
+        };
+        session.restart()?;
+        Ok(session)
+    }
+
+    fn restart(&mut self) -> Result<(), String> {
+        self.questions = generate_questions(&self.cards)?;
+        self.current = 0;
+        self.selected = None;
+        self.correct = 0;
+        self.finished = false;
+        Ok(())
+    }
+}
+
+// Other cards' answers are used as the wrong options
+fn generate_questions(cards: &[(String, String)]) -> Result<Vec<QuizQuestion>, String> {
+    let mut answers: Vec<&str> = cards.iter().map(|(_, back)| back.trim()).collect();
+    answers.sort();
+    answers.dedup();
+    if answers.len() < 2 {
+        return Err("A quiz needs at least two cards with different answers".to_string());
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut picked: Vec<&(String, String)> = cards.iter().collect();
+    picked.shuffle(&mut rng);
+    picked.truncate(QUIZ_LENGTH);
+
+    let questions = picked
+        .into_iter()
+        .map(|(front, back)| {
+            let answer = back.trim();
+            let mut distractors: Vec<&str> =
+                answers.iter().copied().filter(|a| *a != answer).collect();
+            distractors.shuffle(&mut rng);
+            distractors.truncate(OPTIONS_PER_QUESTION - 1);
+
+            let mut options: Vec<String> = distractors.iter().map(|d| d.to_string()).collect();
+            options.push(answer.to_string());
+            options.shuffle(&mut rng);
+            let answer_index = options.iter().position(|o| o == answer).unwrap_or(0);
+
+            QuizQuestion {
+                prompt: front.clone(),
+                options,
+                answer_index,
+            }
+        })
+        .collect();
+
+    Ok(questions)
+}
+
+pub fn display(ui: &mut egui::Ui, quiz: &mut QuizSession) -> QuizAction {
+    let mut action = QuizAction::None;
+    let total = quiz.questions.len();
+
+    ui.horizontal(|ui| {
+        ui.heading(format!("📝 Quiz: {}", quiz.deck_name));
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if ui.button("✖ Quit").clicked() {
+                action = QuizAction::Close;
+            }
+            if !quiz.finished {
+                ui.label(format!("Score: {}", quiz.correct));
+                ui.separator();
+                ui.label(format!("Question {} of {}", quiz.current + 1, total));
+            }
+        });
+    });
+    ui.separator();
+
+    if quiz.finished {
+        let percent = if total > 0 {
+            quiz.correct as f32 / total as f32 * 100.0
+        } else {
+            0.0
+        };
+        ui.vertical_centered(|ui| {
+            ui.add_space(30.0);
+            ui.label(egui::RichText::new("Quiz complete!").size(24.0).strong());
+            ui.add_space(10.0);
+            ui.label(
+                egui::RichText::new(format!(
+                    "{} of {} correct ({:.0}%)",
+                    quiz.correct, total, percent
+                ))
+                .size(18.0),
+            );
+            ui.add_space(20.0);
+            ui.horizontal(|ui| {
+                if ui.button("🔁 New Quiz").clicked() {
+                    // Can't fail here, the same cards produced a quiz before
+                    let _ = quiz.restart();
+                }
+                if ui.button("← Back to Decks").clicked() {
+                    action = QuizAction::Close;
+                }
+            });
+        });
+        return action;
+    }
+
+    let question = &quiz.questions[quiz.current];
+    ui.add_space(10.0);
+    ui.group(|ui| {
+        ui.set_width(ui.available_width());
+        ui.label(egui::RichText::new("Question:").size(16.0).strong());
+        ui.add_space(5.0);
+        ui.label(egui::RichText::new(&question.prompt).size(18.0));
+    });
+    ui.add_space(15.0);
+
+    let mut clicked = None;
+    for (i, option) in question.options.iter().enumerate() {
+        // Mark right and wrong answers with a symbol too, not only red/green
+        let mut marker = "";
+        let mut button_fill = None;
+        if let Some(selected) = quiz.selected {
+            if i == question.answer_index {
+                marker = "✔ ";
+                button_fill = Some(egui::Color32::from_rgb(40, 167, 69));
+            } else if i == selected {
+                marker = "✖ ";
+                button_fill = Some(egui::Color32::from_rgb(220, 53, 69));
+            }
+        }
+        let mut text = egui::RichText::new(format!(
+            "{}{}. {}",
+            marker,
+            (b'A' + i as u8) as char,
+            option
+        ))
+        .size(15.0);
+        if button_fill.is_some() {
+            text = text.color(egui::Color32::WHITE);
+        }
+
+        let mut button = egui::Button::new(text).min_size(egui::vec2(ui.available_width(), 36.0));
+        if let Some(fill) = button_fill {
+            button = button.fill(fill);
+        }
+        if ui.add_enabled(quiz.selected.is_none() || button_fill.is_some(), button).clicked()
+            && quiz.selected.is_none()
+        {
+            clicked = Some(i);
+        }
+        ui.add_space(4.0);
+    }
+
+    if let Some(i) = clicked {
+        quiz.selected = Some(i);
+        if i == question.answer_index {
+            quiz.correct += 1;
+        }
+    }
+
+    if let Some(selected) = quiz.selected {
+        ui.add_space(10.0);
+        let (message, color) = if selected == question.answer_index {
+            ("✔ Correct!", egui::Color32::from_rgb(40, 167, 69))
+        } else {
+            ("✖ Not quite.", egui::Color32::from_rgb(220, 53, 69))
+        };
+        ui.label(egui::RichText::new(message).size(16.0).color(color));
+
+        let last = quiz.current + 1 >= total;
+        let next_label = if last { "See Results" } else { "Next →" };
+        if ui.button(next_label).clicked() {
+            quiz.selected = None;
+            if last {
+                quiz.finished = true;
+                action = QuizAction::Finished(QuizResult {
+                    date: Local::now().format("%Y-%m-%d").to_string(),
+                    deck_id: quiz.deck_id,
+                    deck_name: quiz.deck_name.clone(),
+                    correct: quiz.correct,
+                    total: total as u32,
+                });
+            } else {
+                quiz.current += 1;
+            }
+        }
+    }
+
+    action
+}
@@ -1,15 +1,21 @@
 use crate::app::StatusMessage;
-use crate::data::StudyData;
+use crate::data::{self, SessionEdit, SessionTotals, StudyData, TimeSpan};
 use crate::timer::Timer; // Import Timer
-use chrono::{Local, NaiveDate};
+use chrono::{Local, NaiveDate, NaiveTime};
 use eframe::egui;
 use eframe::egui::Ui;
+use std::collections::HashSet;
 
 pub struct RecordState {
     pub date: String,
     pub hours: String,
     pub minutes: String,
+    pub start_time: String, // HH:MM, optional
     pub description: String,
+    pub show_all: bool,
+    pub selected: HashSet<usize>, // Indices into StudyData::sessions
+    pub subject: String,
+    pub pending_edit: Option<SessionEdit>, // Awaiting confirmation
 }
 
 impl Default for RecordState {
@@ -18,7 +24,12 @@ impl Default for RecordState {
             date: Local::now().date_naive().format("%Y-%m-%d").to_string(),
             hours: "0".to_string(),
             minutes: "0".to_string(),
+            start_time: String::new(),
             description: String::new(),
+            show_all: false,
+            selected: HashSet::new(),
+            subject: String::new(),
+            pending_edit: None,
         }
     }
 }
@@ -83,6 +94,21 @@ pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessa
             }
         });
 
+        // Start time puts the session on the Graph tab's timeline
+        ui.horizontal(|ui| {
+            ui.label("Started at (optional):");
+            ui.add(
+                egui::TextEdit::singleline(&mut state.start_time)
+                    .hint_text("HH:MM")
+                    .desired_width(60.0),
+            );
+        });
+        let start_valid = state.start_time.trim().is_empty()
+            || NaiveTime::parse_from_str(state.start_time.trim(), "%H:%M").is_ok();
+        if !start_valid {
+            ui.colored_label(egui::Color32::RED, "Invalid start time. Use HH:MM.");
+        }
+
         // Description
         ui.horizontal(|ui| {
             ui.label("Description (optional):");
@@ -97,7 +123,7 @@ pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessa
 
         if ui
             .add_enabled(
-                date_valid && hours_valid && minutes_valid,
+                date_valid && hours_valid && minutes_valid && start_valid,
                 egui::Button::new("Save Session"),
             )
             .clicked()
@@ -112,11 +138,21 @@ pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessa
                 Some(state.description.clone())
             };
 
+            let span = (!state.start_time.trim().is_empty()).then(|| TimeSpan {
+                start: state.start_time.trim().to_string(),
+                minutes: total_minutes,
+            });
+
             if total_minutes > 0.0 {
-                if let Err(e) =
-                    study_data.add_session(state.date.clone(), total_minutes, description)
-                {
-                    status.show(&format!("Error saving: {}", e));
+                if let Err(e) = study_data.add_session_with_span(
+                    state.date.clone(),
+                    total_minutes,
+                    description,
+                    span,
+                    Vec::new(),
+                    Vec::new(),
+                ) {
+                    status.error(&format!("Error saving: {}", e));
                 } else {
                     status.show(&format!(
                         "Saved {:.1} minutes ({:.1} hours) of study time",
@@ -127,52 +163,278 @@ pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessa
                     // Reset fields except date
                     state.hours = "0".to_string();
                     state.minutes = "0".to_string();
+                    state.start_time.clear();
                     state.description.clear();
                 }
             } else {
-                status.show("Study time must be greater than zero");
+                status.warn("Study time must be greater than zero");
             }
         }
-
-        // Status message
-        status.render(ui);
     });
 
-    // Display recent sessions
     ui.add_space(20.0);
-    ui.heading("Recent Sessions");
+    RECORD_STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        sessions_list(ui, study_data, &mut state);
+        confirm_edit(ui.ctx(), study_data, status, &mut state);
+    });
+}
+
+fn sessions_list(ui: &mut Ui, study_data: &StudyData, state: &mut RecordState) {
+    // Sessions may have been removed elsewhere (trash, sync) since they were selected
+    let count = study_data.sessions.len();
+    state.selected.retain(|&i| i < count);
+
+    ui.horizontal(|ui| {
+        ui.heading(if state.show_all {
+            "All Sessions"
+        } else {
+            "Recent Sessions"
+        });
+        ui.checkbox(&mut state.show_all, "Show all");
+    });
     ui.add_space(10.0);
 
     // Sort sessions by date (newest first)
-    let mut sessions = study_data.sessions.clone();
-    sessions.sort_by(|a, b| b.date.cmp(&a.date));
-
-    // Take only the last 5 sessions
-    let recent_sessions = sessions.into_iter().take(5).collect::<Vec<_>>();
+    let mut order: Vec<usize> = (0..count).collect();
+    order.sort_by(|&a, &b| {
+        study_data.sessions[b]
+            .date
+            .cmp(&study_data.sessions[a].date)
+    });
+    if !state.show_all {
+        order.truncate(5);
+    }
 
-    if recent_sessions.is_empty() {
+    if order.is_empty() {
         ui.label("No sessions recorded yet.");
-    } else {
-        egui::Grid::new("recent_sessions_grid")
-            .num_columns(4)
-            .spacing([20.0, 4.0])
-            .striped(true)
-            .show(ui, |ui| {
-                // Header
-                ui.strong("Date");
-                ui.strong("Minutes");
-                ui.strong("Hours");
-                ui.strong("Description");
-                ui.end_row();
-
-                for session in recent_sessions {
-                    ui.label(session.date);
-                    ui.label(format!("{:.1}", session.minutes));
-                    ui.label(format!("{:.1}", session.minutes / 60.0));
-                    ui.label(session.description.unwrap_or_else(|| "-".to_string()));
+        return;
+    }
+
+    if !state.selected.is_empty() {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(format!("{} selected", state.selected.len()));
+            if ui.button("🗑 Delete").clicked() {
+                state.pending_edit = Some(SessionEdit::Delete);
+            }
+            ui.separator();
+            ui.add(
+                egui::TextEdit::singleline(&mut state.subject)
+                    .hint_text("Subject")
+                    .desired_width(120.0),
+            );
+            if ui.button("🏷 Set Subject").clicked() {
+                let subject = state.subject.trim();
+                let subject = (!subject.is_empty()).then(|| subject.to_string());
+                state.pending_edit = Some(SessionEdit::SetSubject(subject));
+            }
+            ui.separator();
+            let mut days = HashSet::new();
+            let mergeable = state
+                .selected
+                .iter()
+                .any(|&i| !days.insert(&study_data.sessions[i].date));
+            if ui
+                .add_enabled(mergeable, egui::Button::new("🔗 Merge Same-Day"))
+                .on_disabled_hover_text("Select two or more records from the same day")
+                .clicked()
+            {
+                state.pending_edit = Some(SessionEdit::Merge);
+            }
+            if ui.button("Clear Selection").clicked() {
+                state.selected.clear();
+            }
+        });
+        ui.add_space(5.0);
+    }
+
+    let scroll_height = if state.show_all { 300.0 } else { f32::INFINITY };
+    egui::ScrollArea::vertical()
+        .max_height(scroll_height)
+        .show(ui, |ui| {
+            egui::Grid::new("recent_sessions_grid")
+                .num_columns(5)
+                .spacing([20.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    // Header
+                    let mut all_selected = order.iter().all(|i| state.selected.contains(i));
+                    if ui.checkbox(&mut all_selected, "").changed() {
+                        for &i in &order {
+                            if all_selected {
+                                state.selected.insert(i);
+                            } else {
+                                state.selected.remove(&i);
+                            }
+                        }
+                    }
+                    ui.strong("Date");
+                    ui.strong("Minutes");
+                    ui.strong("Hours");
+                    ui.strong("Description");
                     ui.end_row();
+
+                    for &i in &order {
+                        let session = &study_data.sessions[i];
+                        let mut selected = state.selected.contains(&i);
+                        if ui.checkbox(&mut selected, "").changed() {
+                            if selected {
+                                state.selected.insert(i);
+                            } else {
+                                state.selected.remove(&i);
+                            }
+                        }
+                        ui.label(&session.date);
+                        ui.label(format!("{:.1}", session.minutes));
+                        ui.label(format!("{:.1}", session.minutes / 60.0));
+                        ui.vertical(|ui| {
+                            ui.label(session.description.as_deref().unwrap_or("-"));
+                            if !session.notes.is_empty() {
+                                let names: Vec<String> =
+                                    session.notes.iter().map(|note| note_name(note)).collect();
+                                ui.label(
+                                    egui::RichText::new(format!("worked on: {}", names.join(", ")))
+                                        .small()
+                                        .weak(),
+                                )
+                                .on_hover_text(session.notes.join("\n"));
+                            }
+                            if !session.laps.is_empty() {
+                                let laps: Vec<String> = session
+                                    .laps
+                                    .iter()
+                                    .map(|lap| format!("{} {:.0}m", lap.label, lap.minutes))
+                                    .collect();
+                                ui.label(egui::RichText::new(laps.join(" · ")).small().weak());
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+fn note_name(path: &str) -> String {
+    std::path::Path::new(path)
+        .file_name()
+        .map_or(path.to_string(), |name| name.to_string_lossy().to_string())
+}
+
+// Shows how the pending edit changes the study stats before applying it
+fn confirm_edit(
+    ctx: &egui::Context,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    state: &mut RecordState,
+) {
+    let edit = match &state.pending_edit {
+        Some(edit) => edit.clone(),
+        None => return,
+    };
+
+    let before = SessionTotals::of(&study_data.sessions);
+    let after = SessionTotals::of(&data::edit_sessions(
+        &study_data.sessions,
+        &state.selected,
+        &edit,
+    ));
+    let title = match &edit {
+        SessionEdit::Delete => "Delete Sessions",
+        SessionEdit::SetSubject(_) => "Set Subject",
+        SessionEdit::Merge => "Merge Sessions",
+    };
+
+    let mut confirmed = false;
+    let mut cancelled = false;
+    egui::Window::new(title)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(match &edit {
+                SessionEdit::Delete => {
+                    format!("Move {} record(s) to the trash?", state.selected.len())
+                }
+                SessionEdit::SetSubject(Some(subject)) => format!(
+                    "Set the subject of {} record(s) to \"{}\"?",
+                    state.selected.len(),
+                    subject
+                ),
+                SessionEdit::SetSubject(None) => {
+                    format!("Clear the subject of {} record(s)?", state.selected.len())
+                }
+                SessionEdit::Merge => {
+                    format!("Merge {} record(s) into one per day?", state.selected.len())
+                }
+            });
+            ui.add_space(10.0);
+
+            egui::Grid::new("session_edit_effect")
+                .num_columns(3)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("");
+                    ui.strong("Before");
+                    ui.strong("After");
+                    ui.end_row();
+
+                    ui.label("Records");
+                    ui.label(before.records.to_string());
+                    ui.label(after.records.to_string());
+                    ui.end_row();
+
+                    ui.label("Total hours");
+                    ui.label(format!("{:.1}", before.minutes / 60.0));
+                    ui.label(format!("{:.1}", after.minutes / 60.0));
+                    ui.end_row();
+
+                    ui.label("Days studied");
+                    ui.label(before.days.to_string());
+                    ui.label(after.days.to_string());
+                    ui.end_row();
+
+                    // Only the subjects whose totals move
+                    let subjects: std::collections::BTreeSet<&String> = before
+                        .by_subject
+                        .keys()
+                        .chain(after.by_subject.keys())
+                        .collect();
+                    for subject in subjects {
+                        let old = before.by_subject.get(subject).copied().unwrap_or(0.0);
+                        let new = after.by_subject.get(subject).copied().unwrap_or(0.0);
+                        if (old - new).abs() < 0.05 {
+                            continue;
+                        }
+                        ui.label(format!("{} (hours)", subject));
+                        ui.label(format!("{:.1}", old / 60.0));
+                        ui.label(format!("{:.1}", new / 60.0));
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button("Apply").clicked() {
+                    confirmed = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    cancelled = true;
                 }
             });
+        });
+
+    if confirmed {
+        match study_data.apply_session_edit(&state.selected, &edit) {
+            // Deletes are announced by the trash with an undo hint
+            Ok(()) if edit != SessionEdit::Delete => {
+                status.show(&format!("Updated {} record(s)", state.selected.len()))
+            }
+            Ok(()) => {}
+            Err(e) => status.error(&format!("Error saving: {}", e)),
+        }
+        state.selected.clear();
+        state.pending_edit = None;
+    } else if cancelled {
+        state.pending_edit = None;
     }
 }
-
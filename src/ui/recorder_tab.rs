@@ -0,0 +1,357 @@
+use crate::app::StatusMessage;
+use crate::audio_recorder::{self, Marker, Player, Recorder, RECORDINGS_DIR};
+use eframe::egui::{self, Color32, RichText};
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Default)]
+struct RecorderTab {
+    recorder: Option<Recorder>,
+    markers: Vec<Marker>, // Of the recording in progress
+    marker_label: String,
+    name: String,
+    player: Option<Player>,
+    player_markers: Vec<Marker>,
+    seek_preview: Option<f64>, // Slider position while it's being dragged
+    recordings: Option<Vec<PathBuf>>, // None until the folder is listed
+}
+
+thread_local! {
+    static RECORDER: RefCell<RecorderTab> = RefCell::new(RecorderTab::default());
+}
+
+pub fn display(ui: &mut egui::Ui, status: &mut StatusMessage) {
+    RECORDER.with(|tab| {
+        let mut tab = tab.borrow_mut();
+        egui::ScrollArea::vertical()
+            .id_source("recorder_scroll")
+            .show(ui, |ui| {
+                record_section(ui, &mut tab, status);
+                ui.add_space(8.0);
+                ui.separator();
+                recordings_section(ui, &mut tab, status);
+                if tab.player.is_some() {
+                    ui.separator();
+                    player_section(ui, &mut tab, status);
+                }
+            });
+
+        let busy = tab.recorder.is_some() || tab.player.as_ref().is_some_and(|p| p.is_playing());
+        if busy {
+            ui.ctx().request_repaint_after(Duration::from_millis(100));
+        }
+    });
+}
+
+fn record_section(ui: &mut egui::Ui, tab: &mut RecorderTab, status: &mut StatusMessage) {
+    ui.strong("Record");
+    let Some(recorder) = tab.recorder.as_ref() else {
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.add(
+                egui::TextEdit::singleline(&mut tab.name)
+                    .hint_text("lecture")
+                    .desired_width(180.0),
+            );
+            if ui
+                .button("⏺ Record")
+                .on_hover_text(format!("Records the microphone into {}", RECORDINGS_DIR))
+                .clicked()
+            {
+                let path = Path::new(RECORDINGS_DIR).join(file_name(&tab.name));
+                match Recorder::start(path) {
+                    Ok(recorder) => {
+                        tab.recorder = Some(recorder);
+                        tab.markers.clear();
+                        tab.marker_label.clear();
+                    }
+                    Err(e) => status.error(&format!("Could not start recording: {}", e)),
+                }
+            }
+        });
+        return;
+    };
+
+    if let Some(e) = recorder.error() {
+        status.error(&format!("Recording stopped: {}", e));
+        stop_recording(tab, status);
+        return;
+    }
+
+    let elapsed = recorder.elapsed().as_secs_f64();
+    let paused = recorder.is_paused();
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(audio_recorder::format_time(elapsed))
+                .monospace()
+                .size(28.0),
+        );
+        if paused {
+            ui.label(RichText::new("⏸ Paused").color(Color32::GRAY));
+        } else {
+            ui.label(RichText::new("● REC").color(Color32::from_rgb(220, 50, 50)));
+        }
+        ui.add(
+            egui::ProgressBar::new(recorder.level().min(1.0))
+                .desired_width(160.0)
+                .text("Level"),
+        );
+    });
+
+    let mut stop = false;
+    ui.horizontal(|ui| {
+        if paused {
+            if ui.button("▶ Resume").clicked() {
+                recorder.set_paused(false);
+            }
+        } else if ui.button("⏸ Pause").clicked() {
+            recorder.set_paused(true);
+        }
+        if ui.button("⏹ Stop").clicked() {
+            stop = true;
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut tab.marker_label)
+                .hint_text("What's happening (optional)")
+                .desired_width(240.0),
+        );
+        let entered = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        if ui
+            .button("📍 Marker")
+            .on_hover_text("Marks this moment so playback can jump back to it")
+            .clicked()
+            || entered
+        {
+            tab.markers.push(Marker {
+                seconds: elapsed,
+                label: tab.marker_label.trim().to_string(),
+            });
+            tab.marker_label.clear();
+            // Saved right away so a crash mid-lecture keeps them
+            if let Err(e) = audio_recorder::save_markers(&recorder.path, &tab.markers) {
+                status.warn(&format!("Could not save markers: {}", e));
+            }
+        }
+    });
+    for marker in tab.markers.iter().rev() {
+        ui.label(format!(
+            "📍 {}  {}",
+            audio_recorder::format_time(marker.seconds),
+            marker.label
+        ));
+    }
+
+    if stop {
+        stop_recording(tab, status);
+    }
+}
+
+fn stop_recording(tab: &mut RecorderTab, status: &mut StatusMessage) {
+    let Some(recorder) = tab.recorder.take() else {
+        return;
+    };
+    match recorder.stop() {
+        Ok(path) => {
+            status.show(&format!("Saved {}", path.display()));
+            tab.recordings = None;
+            open_player(tab, path, status);
+        }
+        Err(e) => status.error(&format!("Could not finish the recording: {}", e)),
+    }
+}
+
+fn recordings_section(ui: &mut egui::Ui, tab: &mut RecorderTab, status: &mut StatusMessage) {
+    ui.horizontal(|ui| {
+        ui.strong("Recordings");
+        if ui.small_button("🔄").on_hover_text("Refresh").clicked() {
+            tab.recordings = None;
+        }
+    });
+    let recordings = tab
+        .recordings
+        .get_or_insert_with(audio_recorder::list_recordings)
+        .clone();
+    let recording_now = tab.recorder.as_ref().map(|r| r.path.clone());
+
+    let mut opened = None;
+    egui::ScrollArea::vertical()
+        .id_source("recordings_list")
+        .max_height(180.0)
+        .show(ui, |ui| {
+            let mut any = false;
+            for path in recordings
+                .iter()
+                .filter(|p| Some(*p) != recording_now.as_ref())
+            {
+                any = true;
+                let selected = tab.player.as_ref().is_some_and(|p| &p.path == path);
+                let name = path
+                    .file_name()
+                    .map_or(String::new(), |n| n.to_string_lossy().to_string());
+                if ui.selectable_label(selected, name).clicked() && !selected {
+                    opened = Some(path.clone());
+                }
+            }
+            if !any {
+                ui.label("No recordings yet.");
+            }
+        });
+    if let Some(path) = opened {
+        open_player(tab, path, status);
+    }
+}
+
+fn open_player(tab: &mut RecorderTab, path: PathBuf, status: &mut StatusMessage) {
+    tab.player = None; // Releases the audio output before opening a new one
+    tab.seek_preview = None;
+    match Player::open(path.clone()) {
+        Ok(player) => {
+            tab.player_markers = audio_recorder::load_markers(&path);
+            tab.player = Some(player);
+        }
+        Err(e) => status.error(&format!("Could not open {}: {}", path.display(), e)),
+    }
+}
+
+fn player_section(ui: &mut egui::Ui, tab: &mut RecorderTab, status: &mut StatusMessage) {
+    let Some(player) = tab.player.as_mut() else {
+        return;
+    };
+    player.update();
+    let length = player.length.as_secs_f64();
+    let position = player.position().as_secs_f64();
+
+    let mut result = Ok(());
+    ui.horizontal(|ui| {
+        if player.is_playing() {
+            if ui.button("⏸ Pause").clicked() {
+                player.pause();
+            }
+        } else if ui.button("▶ Play").clicked() {
+            result = player.resume();
+        }
+        if ui.button("⏹ Stop").clicked() {
+            player.stop();
+        }
+        let shown = tab.seek_preview.unwrap_or(position);
+        ui.label(
+            RichText::new(format!(
+                "{} / {}",
+                audio_recorder::format_time(shown),
+                audio_recorder::format_time(length)
+            ))
+            .monospace(),
+        );
+    });
+
+    let mut slider_value = tab.seek_preview.unwrap_or(position);
+    ui.spacing_mut().slider_width = ui.available_width().min(600.0);
+    let response =
+        ui.add(egui::Slider::new(&mut slider_value, 0.0..=length.max(0.1)).show_value(false));
+    if response.dragged() {
+        tab.seek_preview = Some(slider_value);
+    } else if response.drag_released() || response.changed() {
+        tab.seek_preview = None;
+        result = player.seek(Duration::from_secs_f64(slider_value));
+    }
+
+    ui.add_space(4.0);
+    ui.horizontal(|ui| {
+        ui.strong("Markers");
+        if ui
+            .small_button("📍 Mark here")
+            .on_hover_text("Adds a marker at the playback position")
+            .clicked()
+        {
+            let seconds = player.position().as_secs_f64();
+            let index = tab
+                .player_markers
+                .partition_point(|marker| marker.seconds <= seconds);
+            tab.player_markers.insert(
+                index,
+                Marker {
+                    seconds,
+                    label: String::new(),
+                },
+            );
+            save_player_markers(&player.path, &tab.player_markers, status);
+        }
+    });
+    if tab.player_markers.is_empty() {
+        ui.label("No markers in this recording.");
+    }
+
+    let mut removed = None;
+    let mut edited = false;
+    for (i, marker) in tab.player_markers.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            if ui
+                .button(audio_recorder::format_time(marker.seconds))
+                .on_hover_text("Jump here")
+                .clicked()
+            {
+                result = player.play_from(Duration::from_secs_f64(marker.seconds));
+            }
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut marker.label)
+                    .hint_text("Label")
+                    .desired_width(240.0),
+            );
+            edited |= response.lost_focus();
+            if ui
+                .small_button("🗑")
+                .on_hover_text("Remove marker")
+                .clicked()
+            {
+                removed = Some(i);
+            }
+        });
+    }
+    if let Some(i) = removed {
+        tab.player_markers.remove(i);
+        edited = true;
+    }
+    if edited {
+        save_player_markers(&player.path, &tab.player_markers, status);
+    }
+
+    if let Err(e) = result {
+        status.error(&format!("Playback failed: {}", e));
+    }
+}
+
+fn save_player_markers(path: &Path, markers: &[Marker], status: &mut StatusMessage) {
+    if let Err(e) = audio_recorder::save_markers(path, markers) {
+        status.warn(&format!("Could not save markers: {}", e));
+    }
+}
+
+// A new name each time unless one is given, and never over an existing recording
+fn file_name(name: &str) -> String {
+    let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() {
+        format!("lecture_{}.wav", stamp)
+    } else if Path::new(RECORDINGS_DIR)
+        .join(format!("{}.wav", name))
+        .exists()
+    {
+        format!("{}_{}.wav", name, stamp)
+    } else {
+        format!("{}.wav", name)
+    }
+}
@@ -0,0 +1,305 @@
+use crate::app::StatusMessage;
+use crate::bibtex;
+use crate::data::{Reference, StudyData};
+use crate::ui::markdown_editor::MarkdownEditor;
+use eframe::egui;
+use std::cell::RefCell;
+use std::fs;
+
+struct ReferencesWindow {
+    search: String,
+    editing: Option<Reference>, // Form contents; id 0 for a new reference
+}
+
+thread_local! {
+    static WINDOW: RefCell<Option<ReferencesWindow>> = RefCell::new(None);
+}
+
+pub fn open() {
+    WINDOW.with(|window| {
+        window.borrow_mut().get_or_insert(ReferencesWindow {
+            search: String::new(),
+            editing: None,
+        });
+    });
+}
+
+fn matches(reference: &Reference, search: &str) -> bool {
+    let search = search.trim().to_lowercase();
+    search.is_empty()
+        || [
+            &reference.key,
+            &reference.title,
+            &reference.authors,
+            &reference.year,
+        ]
+        .iter()
+        .any(|field| field.to_lowercase().contains(&search))
+}
+
+pub fn display(
+    ctx: &egui::Context,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    mut editor: Option<&mut MarkdownEditor>,
+) {
+    WINDOW.with(|window| {
+        let mut window = window.borrow_mut();
+        let Some(state) = window.as_mut() else {
+            return;
+        };
+
+        let mut open = true;
+        egui::Window::new("📑 References")
+            .open(&mut open)
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("➕ New").clicked() {
+                        state.editing = Some(Reference {
+                            entry_type: "article".to_string(),
+                            ..Default::default()
+                        });
+                    }
+                    if ui.button("📥 Import BibTeX").clicked() {
+                        import(study_data, status);
+                    }
+                    if ui
+                        .add_enabled(
+                            !study_data.references.is_empty(),
+                            egui::Button::new("📤 Export BibTeX"),
+                        )
+                        .clicked()
+                    {
+                        export(study_data, status);
+                    }
+                });
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.search)
+                        .hint_text("Search by key, title, author or year")
+                        .desired_width(f32::INFINITY),
+                );
+                ui.separator();
+
+                if let Some(reference) = state.editing.as_mut() {
+                    let mut close = false;
+                    reference_form(ui, reference, study_data, status, &mut close);
+                    if close {
+                        state.editing = None;
+                    }
+                    ui.separator();
+                }
+
+                if study_data.references.is_empty() {
+                    ui.label("No references yet. Add one or import a .bib file.");
+                    return;
+                }
+
+                let can_insert = editor.as_ref().is_some_and(|e| e.current_file.is_some());
+                let mut deleted = None;
+                egui::ScrollArea::vertical()
+                    .id_source("references_scroll")
+                    .max_height(360.0)
+                    .show(ui, |ui| {
+                        for reference in study_data
+                            .references
+                            .iter()
+                            .filter(|r| matches(r, &state.search))
+                        {
+                            ui.horizontal(|ui| {
+                                ui.strong(format!("@{}", reference.key));
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |ui| {
+                                        if ui.small_button("🗑").on_hover_text("Delete").clicked()
+                                        {
+                                            deleted = Some(reference.id);
+                                        }
+                                        if ui.small_button("✏").on_hover_text("Edit").clicked() {
+                                            state.editing = Some(reference.clone());
+                                        }
+                                        if ui
+                                            .add_enabled(
+                                                can_insert,
+                                                egui::Button::new("Cite").small(),
+                                            )
+                                            .on_hover_text("Insert [@key] into the open note")
+                                            .on_disabled_hover_text("Open a note to cite into")
+                                            .clicked()
+                                        {
+                                            if let Some(editor) = editor.as_mut() {
+                                                editor.insert_citation(&reference.key);
+                                            }
+                                        }
+                                    },
+                                );
+                            });
+                            ui.label(&reference.title);
+                            let mut byline = reference.authors.replace(" and ", "; ");
+                            if !reference.year.is_empty() {
+                                byline.push_str(&format!(" ({})", reference.year));
+                            }
+                            ui.label(egui::RichText::new(byline).small());
+                            if !reference.doi.is_empty() {
+                                ui.hyperlink_to(
+                                    format!("doi:{}", reference.doi),
+                                    format!("https://doi.org/{}", reference.doi),
+                                );
+                            } else if !reference.url.is_empty() {
+                                ui.hyperlink(&reference.url);
+                            }
+                            if !reference.notes.is_empty() {
+                                ui.label(egui::RichText::new(&reference.notes).italics());
+                            }
+                            ui.separator();
+                        }
+                    });
+
+                if let Some(id) = deleted {
+                    match study_data.delete_reference(id) {
+                        Ok(()) => status.show("Reference moved to trash"),
+                        Err(e) => status.error(&format!("Failed to delete reference: {}", e)),
+                    }
+                }
+            });
+
+        if !open {
+            *window = None;
+        }
+    });
+}
+
+fn reference_form(
+    ui: &mut egui::Ui,
+    reference: &mut Reference,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    close: &mut bool,
+) {
+    egui::Grid::new("reference_form")
+        .num_columns(2)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            let fields = [
+                ("Title:", &mut reference.title, "Title of the work"),
+                (
+                    "Authors:",
+                    &mut reference.authors,
+                    "Last, First and Last, First",
+                ),
+                ("Year:", &mut reference.year, "e.g. 2021"),
+                ("DOI:", &mut reference.doi, "10.1000/xyz123"),
+                ("URL:", &mut reference.url, "https://"),
+                (
+                    "Type:",
+                    &mut reference.entry_type,
+                    "article, book, misc, ...",
+                ),
+            ];
+            for (label, value, hint) in fields {
+                ui.label(label);
+                ui.add(
+                    egui::TextEdit::singleline(value)
+                        .hint_text(hint)
+                        .desired_width(320.0),
+                );
+                ui.end_row();
+            }
+            ui.label("Key:");
+            let suggested = reference.suggested_key();
+            ui.add(
+                egui::TextEdit::singleline(&mut reference.key)
+                    .hint_text(suggested)
+                    .desired_width(320.0),
+            );
+            ui.end_row();
+            ui.label("Notes:");
+            ui.add(
+                egui::TextEdit::multiline(&mut reference.notes)
+                    .desired_width(320.0)
+                    .desired_rows(2),
+            );
+            ui.end_row();
+        });
+
+    ui.horizontal(|ui| {
+        if ui.button("Save").clicked() {
+            if reference.title.trim().is_empty() {
+                status.warn("A reference needs a title");
+                return;
+            }
+            let result = if reference.id == 0 {
+                study_data
+                    .add_reference(reference.clone())
+                    .map(|key| status.show(&format!("Added reference @{}", key)))
+            } else {
+                if reference.key.trim().is_empty() {
+                    reference.key = reference.suggested_key();
+                }
+                let key = reference.key.trim().to_string();
+                if study_data
+                    .references
+                    .iter()
+                    .any(|r| r.key == key && r.id != reference.id)
+                {
+                    status.warn(&format!("The key @{} is already in use", key));
+                    return;
+                }
+                reference.key = key;
+                study_data.update_reference(reference.clone())
+            };
+            match result {
+                Ok(()) => *close = true,
+                Err(e) => status.error(&format!("Failed to save reference: {}", e)),
+            }
+        }
+        if ui.button("Cancel").clicked() {
+            *close = true;
+        }
+    });
+}
+
+fn import(study_data: &mut StudyData, status: &mut StatusMessage) {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("BibTeX", &["bib", "bibtex"])
+        .pick_file()
+    else {
+        return;
+    };
+    let result = fs::read_to_string(&path)
+        .map_err(|e| e.into())
+        .and_then(|text| bibtex::parse(&text))
+        .and_then(|references| {
+            let total = references.len();
+            study_data
+                .import_references(references)
+                .map(|added| (added, total))
+        });
+    match result {
+        Ok((added, total)) if added < total => status.show(&format!(
+            "Imported {} references, skipped {} already in the collection",
+            added,
+            total - added
+        )),
+        Ok((added, _)) => status.show(&format!("Imported {} references", added)),
+        Err(e) => status.error(&format!("BibTeX import failed: {}", e)),
+    }
+}
+
+fn export(study_data: &StudyData, status: &mut StatusMessage) {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("BibTeX", &["bib"])
+        .set_file_name("references.bib")
+        .save_file()
+    else {
+        return;
+    };
+    match fs::write(&path, bibtex::export(&study_data.references)) {
+        Ok(()) => status.show(&format!(
+            "Exported {} references to {}",
+            study_data.references.len(),
+            path.display()
+        )),
+        Err(e) => status.error(&format!("BibTeX export failed: {}", e)),
+    }
+}
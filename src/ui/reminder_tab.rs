@@ -1,5 +1,8 @@
 use crate::app::StatusMessage;
-use crate::data::{NotificationPeriod, Reminder, StudyData};
+use crate::data::{NotificationPeriod, OverdueEscalation, Reminder, StudyData};
+use crate::quick_add;
+use crate::settings::AppSettings;
+use crate::ui::rich_text::linkified_label;
 use chrono::{Local, NaiveDate};
 use egui::{ ScrollArea, TextEdit};
 use std::cell::RefCell;
@@ -9,6 +12,7 @@ thread_local! {
     static NEW_REMINDER_TITLE: RefCell<String> = RefCell::new(String::new());
     static NEW_REMINDER_DESC: RefCell<String> = RefCell::new(String::new());
     static NEW_REMINDER_DATE: RefCell<String> = RefCell::new(String::new());
+    static NEW_REMINDER_ESCALATION: RefCell<OverdueEscalation> = RefCell::new(OverdueEscalation::default());
     static EDITING_MAP: RefCell<HashMap<u64, EditingReminder>> = RefCell::new(HashMap::new());
     static CUSTOM_DAYS: RefCell<String> = RefCell::new(String::from("5"));
 }
@@ -18,9 +22,17 @@ struct EditingReminder {
     description: String,
     due_date: String,
     notification_periods: Vec<NotificationPeriod>,
+    escalation: OverdueEscalation,
 }
 
-pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut StatusMessage) {
+pub fn display(
+    ui: &mut egui::Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    settings: &AppSettings,
+) {
+    let date_format = settings.date_format;
+    let clock_format = settings.clock_format;
     ui.heading("Reminders");
 
     // Auto-fill due date with today's date if empty
@@ -31,9 +43,6 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
             *due_date = today.format("%Y-%m-%d").to_string();
         }
     });
-    // Check for due reminders
-    check_due_reminders(study_data, status);
-
     // Add new reminder section
     ui.collapsing("Add New Reminder", |ui| {
         NEW_REMINDER_TITLE.with(|title_ref| {
@@ -47,11 +56,16 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
                         ui.label("Title:");
                         ui.add(
                             TextEdit::singleline(&mut *title)
-                                .hint_text("Enter reminder title")
+                                .hint_text("Enter reminder title (e.g. \"exam fri 9am\")")
                                 .desired_width(280.0),
                         );
                     });
 
+                    // A date or time typed into the title replaces the Due Date field
+                    let parsed = quick_add::parse_dates(&title);
+                    let chip_color = ui.visuals().hyperlink_color;
+                    parsed.preview(ui, chip_color);
+
                     ui.horizontal(|ui| {
                         ui.label("Description:");
                         ui.add(
@@ -112,20 +126,30 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
                                         });
                                     });
 
+                                    let escalation = NEW_REMINDER_ESCALATION.with(|escalation| {
+                                        let mut escalation = escalation.borrow_mut();
+                                        escalation_combo(ui, "new_reminder_escalation", &mut escalation);
+                                        *escalation
+                                    });
+
                                     if ui.button("Add Reminder").clicked() {
-                                        if title.is_empty() {
-                                            status.show("Reminder title cannot be empty!");
+                                        if parsed.title.is_empty() {
+                                            status.warn("Reminder title cannot be empty!");
                                             return;
                                         }
 
+                                        if let Some(date) = parsed.due_date_string() {
+                                            *due_date = date;
+                                        }
+
                                         if due_date.is_empty() {
-                                            status.show("Due date cannot be empty!");
+                                            status.warn("Due date cannot be empty!");
                                             return;
                                         }
 
                                         // Validate date format
                                         if NaiveDate::parse_from_str(&due_date, "%Y-%m-%d").is_err() {
-                                            status.show("Invalid date format! Use YYYY-MM-DD");
+                                            status.warn("Invalid date format! Use YYYY-MM-DD");
                                             return;
                                         }
 
@@ -158,12 +182,14 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
                                         };
 
                                         if let Err(e) = study_data.add_reminder(
-                                            title.clone(),
+                                            parsed.title.clone(),
                                             description,
                                             due_date.clone(),
+                                            parsed.due_time_string(),
                                             periods,
+                                            escalation,
                                         ) {
-                                            status.show(&format!("Error adding reminder: {}", e));
+                                            status.error(&format!("Error adding reminder: {}", e));
                                         } else {
                                             status.show("Reminder added successfully!");
                                             title.clear();
@@ -173,6 +199,9 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
                                             *three_days_val = false;
                                             *one_week_val = false;
                                             *custom_val = false;
+                                            NEW_REMINDER_ESCALATION.with(|escalation| {
+                                                *escalation.borrow_mut() = OverdueEscalation::default();
+                                            });
                                         }
                                     }
                                 });
@@ -190,17 +219,21 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
     ui.horizontal(|ui| {
         if ui.button("Clear Completed").clicked() {
             if let Err(e) = study_data.clear_completed_reminders() {
-                status.show(&format!("Error clearing completed reminders: {}", e));
-            } else {
-                status.show("Completed reminders cleared!");
+                status.error(&format!("Error clearing completed reminders: {}", e));
+            }
+        }
+
+        if ui.button("📦 Archive Completed").clicked() {
+            match study_data.archive_completed_reminders() {
+                Ok(0) => status.show("No completed reminders to archive"),
+                Ok(count) => status.show(&format!("Archived {} reminder(s)", count)),
+                Err(e) => status.error(&format!("Error archiving reminders: {}", e)),
             }
         }
 
         if ui.button("Clear All").clicked() {
             if let Err(e) = study_data.clear_reminders() {
-                status.show(&format!("Error clearing reminders: {}", e));
-            } else {
-                status.show("All reminders cleared!");
+                status.error(&format!("Error clearing reminders: {}", e));
             }
         }
     });
@@ -434,15 +467,21 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
                                 ui.label("days before");
                             });
 
+                            escalation_combo(
+                                ui,
+                                ("edit_reminder_escalation", reminder.id),
+                                &mut editing_reminder.escalation,
+                            );
+
                             ui.horizontal(|ui| {
                                 if ui.button("Save").clicked() {
                                     if editing_reminder.title.is_empty() {
-                                        status.show("Reminder title cannot be empty!");
+                                        status.warn("Reminder title cannot be empty!");
                                         return;
                                     }
 
                                     if editing_reminder.due_date.is_empty() {
-                                        status.show("Due date cannot be empty!");
+                                        status.warn("Due date cannot be empty!");
                                         return;
                                     }
 
@@ -453,7 +492,7 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
                                     )
                                     .is_err()
                                     {
-                                        status.show("Invalid date format! Use YYYY-MM-DD");
+                                        status.warn("Invalid date format! Use YYYY-MM-DD");
                                         return;
                                     }
 
@@ -544,14 +583,51 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
                             };
 
                             ui.label(title_text);
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "📅 {}",
+                                    date_format.format_str(&reminder.due_date)
+                                ))
+                                .small(),
+                            );
+                            if let Some(time) = &reminder.due_time {
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "🕔 {}",
+                                        clock_format.format_str(time)
+                                    ))
+                                    .small(),
+                                );
+                            }
                             ui.label(egui::RichText::new(&due_text).small());
+                            if let Some(until) = reminder
+                                .snoozed_until
+                                .as_ref()
+                                .filter(|_| reminder.is_snoozed())
+                            {
+                                let until = match until.split_once(' ') {
+                                    Some((date, time)) => {
+                                        format!(
+                                            "{} {}",
+                                            date_format.format_str(date),
+                                            clock_format.format_str(time)
+                                        )
+                                    }
+                                    None => until.clone(),
+                                };
+                                ui.label(
+                                    egui::RichText::new(format!("💤 Snoozed until {}", until))
+                                        .small()
+                                        .italics(),
+                                );
+                            }
                         });
 
                         // Display description if available
                         if let Some(desc) = &reminder.description {
                             if !desc.is_empty() {
                                 ui.indent("desc_indent", |ui| {
-                                    ui.label(desc);
+                                    linkified_label(ui, desc);
                                 });
                             }
                         }
@@ -587,15 +663,13 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
     // Process the collected actions
     for id in toggle_reminders {
         if let Err(e) = study_data.toggle_reminder(id) {
-            status.show(&format!("Error toggling reminder: {}", e));
+            status.error(&format!("Error toggling reminder: {}", e));
         }
     }
 
     for id in delete_reminders {
         if let Err(e) = study_data.delete_reminder(id) {
-            status.show(&format!("Error deleting reminder: {}", e));
-        } else {
-            status.show("Reminder deleted successfully!");
+            status.error(&format!("Error deleting reminder: {}", e));
         }
     }
 
@@ -612,8 +686,9 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
             description,
             editing_reminder.due_date,
             editing_reminder.notification_periods,
+            editing_reminder.escalation,
         ) {
-            status.show(&format!("Error updating reminder: {}", e));
+            status.error(&format!("Error updating reminder: {}", e));
         } else {
             status.show("Reminder updated successfully!");
         }
@@ -630,6 +705,7 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
                 description,
                 due_date: reminder.due_date,
                 notification_periods: reminder.notification_periods,
+                escalation: reminder.escalation,
             };
             editing_map.insert(id, editing_reminder);
         }
@@ -638,9 +714,6 @@ pub fn display(ui: &mut egui::Ui, study_data: &mut StudyData, status: &mut Statu
             editing_map.remove(&id);
         }
     });
-
-    // Show status message
-    status.render(ui);
 }
 
 
@@ -667,6 +740,23 @@ fn format_notification_periods(periods: &[NotificationPeriod]) -> String {
     result
 }
 
+fn escalation_combo(
+    ui: &mut egui::Ui,
+    id_source: impl std::hash::Hash,
+    escalation: &mut OverdueEscalation,
+) {
+    ui.horizontal(|ui| {
+        ui.label("When overdue:");
+        egui::ComboBox::from_id_source(id_source)
+            .selected_text(escalation.label())
+            .show_ui(ui, |ui| {
+                for option in OverdueEscalation::ALL {
+                    ui.selectable_value(escalation, option, option.label());
+                }
+            });
+    });
+}
+
 fn days_until_due(due_date: &str) -> Option<i64> {
     if let Ok(date) = NaiveDate::parse_from_str(due_date, "%Y-%m-%d") {
         let today = Local::now().date_naive();
@@ -675,72 +765,3 @@ fn days_until_due(due_date: &str) -> Option<i64> {
         None
     }
 }
-
-fn check_due_reminders(study_data: &StudyData, status: &mut StatusMessage) {
-    let today = Local::now().date_naive();
-    let mut notifications = Vec::new();
-
-    for reminder in &study_data.reminders {
-        if reminder.is_completed {
-            continue;
-        }
-
-        if let Ok(due_date) = NaiveDate::parse_from_str(&reminder.due_date, "%Y-%m-%d") {
-            let days_until = (due_date - today).num_days();
-
-            for period in &reminder.notification_periods {
-                match period {
-                    NotificationPeriod::OneDay if days_until == 1 => {
-                        notifications.push(format!("\"{}\" is due tomorrow!", reminder.title));
-                    }
-                    NotificationPeriod::ThreeDays if days_until == 3 => {
-                        notifications.push(format!("\"{}\" is due in 3 days!", reminder.title));
-                    }
-                    NotificationPeriod::OneWeek if days_until == 7 => {
-                        notifications.push(format!("\"{}\" is due in a week!", reminder.title));
-                    }
-                    NotificationPeriod::Custom(custom_days)
-                        if days_until == *custom_days as i64 =>
-                    {
-                        notifications.push(format!(
-                            "\"{}\" is due in {} days!",
-                            reminder.title, custom_days
-                        ));
-                    }
-                    _ => {}
-                }
-            }
-
-            // Always notify if due today
-            if days_until == 0 {
-                notifications.push(format!("\"{}\" is due today!", reminder.title));
-            }
-
-            // Always notify if overdue
-            if days_until < 0 {
-                notifications.push(format!(
-                    "\"{}\" is overdue by {} days!",
-                    reminder.title, -days_until
-                ));
-            }
-        }
-    }
-
-    // Display notifications (up to 3)
-    if !notifications.is_empty() {
-        let mut message = "REMINDER: ".to_string();
-        let display_count = notifications.len().min(3);
-
-        for i in 0..display_count {
-            message.push_str(&notifications[i]);
-            if i < display_count - 1 {
-                message.push_str(" | ");
-            }
-        }
-        if notifications.len() > 3 {
-            message.push_str(" (and more...)");
-        }
-
-        status.show(&message);
-    }
-}
@@ -0,0 +1,219 @@
+use crate::data::StudyData;
+use crate::settings::AppSettings;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use eframe::egui;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    // Year shown while the window is open
+    static OPEN_YEAR: RefCell<Option<i32>> = RefCell::new(None);
+}
+
+const CELL: f32 = 11.0;
+const GAP: f32 = 3.0;
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+pub fn open() {
+    let year = Local::now().year();
+    OPEN_YEAR.with(|open_year| *open_year.borrow_mut() = Some(year));
+}
+
+pub fn display(ctx: &egui::Context, study_data: &StudyData, settings: &AppSettings) {
+    let Some(mut year) = OPEN_YEAR.with(|open_year| *open_year.borrow()) else {
+        return;
+    };
+    let today = Local::now().date_naive();
+    let per_day = study_data.reviews_per_day();
+
+    let mut open = true;
+    egui::Window::new("📊 Review Heatmap")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("◀").on_hover_text("Previous year").clicked() {
+                    year -= 1;
+                }
+                ui.strong(year.to_string());
+                if ui
+                    .add_enabled(year < today.year(), egui::Button::new("▶"))
+                    .on_hover_text("Next year")
+                    .clicked()
+                {
+                    year += 1;
+                }
+            });
+            calendar(ui, &per_day, year, today, settings);
+            ui.separator();
+            summary(ui, &per_day, year, today, settings);
+        });
+
+    OPEN_YEAR.with(|open_year| *open_year.borrow_mut() = open.then_some(year));
+}
+
+// One column per week, like the study heatmap in the graphs tab
+fn calendar(
+    ui: &mut egui::Ui,
+    per_day: &HashMap<NaiveDate, usize>,
+    year: i32,
+    today: NaiveDate,
+    settings: &AppSettings,
+) {
+    let (Some(start), Some(end)) = (
+        NaiveDate::from_ymd_opt(year, 1, 1),
+        NaiveDate::from_ymd_opt(year, 12, 31),
+    ) else {
+        return;
+    };
+    let colors = settings.get_current_colors();
+    let offset = settings.week_start.offset(start) as i64;
+    let weeks = ((end - start).num_days() + offset) / 7 + 1;
+    let (label_width, header) = (32.0, 16.0);
+    let size = egui::vec2(
+        label_width + weeks as f32 * (CELL + GAP),
+        header + 7.0 * (CELL + GAP),
+    );
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+
+    let cell_rect = |date: NaiveDate| {
+        let index = (date - start).num_days() + offset;
+        let corner = egui::vec2(
+            label_width + (index / 7) as f32 * (CELL + GAP),
+            header + (index % 7) as f32 * (CELL + GAP),
+        );
+        egui::Rect::from_min_size(rect.min + corner, egui::vec2(CELL, CELL))
+    };
+
+    let painter = ui.painter();
+    let text_color = colors.text_secondary_color32();
+    let font = egui::FontId::proportional(10.0);
+    for (month, name) in MONTHS.iter().enumerate() {
+        if let Some(first) = NaiveDate::from_ymd_opt(year, month as u32 + 1, 1) {
+            let x = cell_rect(first).left();
+            painter.text(
+                egui::pos2(x, rect.top()),
+                egui::Align2::LEFT_TOP,
+                name,
+                font.clone(),
+                text_color,
+            );
+        }
+    }
+    for (day, name) in settings.week_start.day_names().iter().enumerate() {
+        let y = rect.top() + header + day as f32 * (CELL + GAP) + CELL / 2.0;
+        painter.text(
+            egui::pos2(rect.left(), y),
+            egui::Align2::LEFT_CENTER,
+            name,
+            font.clone(),
+            text_color,
+        );
+    }
+
+    // Shades are relative to the busiest day of the year
+    let max = per_day
+        .iter()
+        .filter(|(date, _)| date.year() == year)
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let empty = text_color.gamma_multiply(0.15);
+    let mut hovered = None;
+    let mut date = start;
+    while date <= end {
+        let count = per_day.get(&date).copied().unwrap_or(0);
+        let color = if count == 0 {
+            empty
+        } else {
+            let level = (count * 4).div_ceil(max);
+            colors.accent_color32().gamma_multiply(level as f32 / 4.0)
+        };
+        let cell = cell_rect(date);
+        painter.rect_filled(cell, 2.0, color);
+        if date == today {
+            painter.rect_stroke(cell, 2.0, egui::Stroke::new(1.0, text_color));
+        }
+        if response.hover_pos().is_some_and(|pos| cell.contains(pos)) {
+            hovered = Some((date, count));
+        }
+        date += Duration::days(1);
+    }
+
+    if let Some((date, count)) = hovered {
+        response.on_hover_text_at_pointer(format!(
+            "{}: {} reviews",
+            settings.date_format.format(date),
+            count
+        ));
+    }
+}
+
+fn summary(
+    ui: &mut egui::Ui,
+    per_day: &HashMap<NaiveDate, usize>,
+    year: i32,
+    today: NaiveDate,
+    settings: &AppSettings,
+) {
+    let counts: Vec<usize> = per_day
+        .iter()
+        .filter(|(date, _)| date.year() == year)
+        .map(|(_, count)| *count)
+        .collect();
+    let total: usize = counts.iter().sum();
+    let days_in_year = if year == today.year() {
+        today.ordinal()
+    } else {
+        NaiveDate::from_ymd_opt(year, 12, 31).map_or(365, |d| d.ordinal())
+    };
+
+    // Counts back from today, or from yesterday when nothing is reviewed yet today
+    let mut day = if per_day.contains_key(&today) {
+        today
+    } else {
+        today - Duration::days(1)
+    };
+    let mut streak = 0;
+    while per_day.contains_key(&day) {
+        streak += 1;
+        day -= Duration::days(1);
+    }
+
+    let numbers = &settings.number_format;
+    egui::Grid::new("review_heatmap_summary")
+        .num_columns(2)
+        .spacing([20.0, 4.0])
+        .show(ui, |ui| {
+            let rows = [
+                ("Reviews", numbers.format(total as f64, 0)),
+                (
+                    "Days reviewed",
+                    format!(
+                        "{} of {} ({:.0}%)",
+                        counts.len(),
+                        days_in_year,
+                        counts.len() as f64 * 100.0 / days_in_year as f64
+                    ),
+                ),
+                (
+                    "Daily average",
+                    numbers.format(total as f64 / days_in_year as f64, 1),
+                ),
+                ("Current streak", format!("🔥 {} days", streak)),
+                (
+                    "Today",
+                    per_day.get(&today).copied().unwrap_or(0).to_string(),
+                ),
+            ];
+            for (label, value) in rows {
+                ui.label(label);
+                ui.label(egui::RichText::new(value).strong());
+                ui.end_row();
+            }
+        });
+}
@@ -0,0 +1,109 @@
+use eframe::egui::{self, Align, FontSelection, RichText};
+use regex::Regex;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+// Brackets and quotes usually wrap a link rather than belong to it
+static URL: OnceLock<Regex> = OnceLock::new();
+
+// Byte ranges of the http(s) links in some text
+pub fn find_urls(text: &str) -> Vec<Range<usize>> {
+    let url = URL.get_or_init(|| Regex::new(r#"https?://[^\s<>()\[\]{}"'`]+"#).unwrap());
+    url.find_iter(text)
+        .filter_map(|found| {
+            // "see https://example.com." ends the sentence, not the link
+            let trimmed = found
+                .as_str()
+                .trim_end_matches(['.', ',', ';', ':', '!', '?']);
+            (trimmed.len() > "https://".len()).then(|| found.start()..found.start() + trimmed.len())
+        })
+        .collect()
+}
+
+// A label whose links open in the browser when clicked and are copied on middle-click.
+// Text without links is a plain label.
+pub fn linkified_label(ui: &mut egui::Ui, text: impl Into<RichText>) -> egui::Response {
+    let text = text.into();
+    let urls = find_urls(text.text());
+    if urls.is_empty() {
+        return ui.label(text);
+    }
+
+    let mut job = egui::text::LayoutJob::default();
+    text.append_to(&mut job, ui.style(), FontSelection::Default, Align::Center);
+    let format = job.sections[0].format.clone();
+    let mut link = format.clone();
+    link.color = ui.visuals().hyperlink_color;
+    link.underline = egui::Stroke::new(1.0, link.color);
+
+    // Restyle the single section the text came in as, link by link
+    job.sections.clear();
+    let mut at = 0;
+    for url in &urls {
+        if url.start > at {
+            job.sections.push(section(at..url.start, &format));
+        }
+        job.sections.push(section(url.clone(), &link));
+        at = url.end;
+    }
+    if at < job.text.len() {
+        job.sections.push(section(at..job.text.len(), &format));
+    }
+    job.wrap.max_width = if ui.wrap_text() {
+        ui.available_width()
+    } else {
+        f32::INFINITY
+    };
+
+    let galley = ui.fonts(|fonts| fonts.layout_job(job));
+    let (rect, response) = ui.allocate_exact_size(galley.size(), egui::Sense::click());
+    ui.painter()
+        .galley_with_color(rect.min, galley.clone(), ui.visuals().text_color());
+
+    // The link under the pointer, if any
+    let url_at = |pos: Option<egui::Pos2>| {
+        let relative = pos? - rect.min;
+        if !galley
+            .rows
+            .iter()
+            .any(|row| row.rect.contains(relative.to_pos2()))
+        {
+            return None;
+        }
+        // The nearest gap between characters, which may be just after the one pointed at
+        let cursor = galley.cursor_from_pos(relative);
+        let mut index = cursor.ccursor.index;
+        if index > 0 && galley.pos_from_cursor(&cursor).min.x > relative.x {
+            index -= 1;
+        }
+        let byte = galley
+            .text()
+            .char_indices()
+            .nth(index)
+            .map_or(galley.text().len(), |(i, _)| i);
+        let url = urls
+            .iter()
+            .find(|url| url.start <= byte && byte < url.end)?;
+        Some(galley.text()[url.clone()].to_string())
+    };
+
+    if let Some(url) = url_at(response.hover_pos()) {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+        if response.clicked() {
+            ui.ctx().open_url(egui::OpenUrl::new_tab(&url));
+        }
+        if response.middle_clicked() {
+            ui.output_mut(|output| output.copied_text = url.clone());
+        }
+        return response.on_hover_text(format!("{}\nClick to open, middle-click to copy", url));
+    }
+    response
+}
+
+fn section(byte_range: Range<usize>, format: &egui::TextFormat) -> egui::text::LayoutSection {
+    egui::text::LayoutSection {
+        leading_space: 0.0,
+        byte_range,
+        format: format.clone(),
+    }
+}
@@ -1,6 +1,21 @@
 use crate::app::{StatusMessage, Tab};
-use crate::settings::{AppSettings, ColorTheme, PresetTheme};
+use crate::hooks::{self, EventHook, HookAction, HookEvent};
+use crate::settings::{
+    AppSettings, ClockFormat, ColorTheme, DateFormat, NumberFormat, PresetTheme, UiDensity,
+    WeekStart,
+};
 use eframe::egui::{self};
+use std::cell::RefCell;
+
+thread_local! {
+    static NEW_HOOK_EVENT: RefCell<HookEvent> = RefCell::new(HookEvent::SessionFinished);
+    static NEW_HOOK_ACTION: RefCell<HookAction> = RefCell::new(HookAction::Command);
+    static NEW_HOOK_TARGET: RefCell<String> = RefCell::new(String::new());
+    // Index in tab_configs of the row being dragged in Tab Management
+    static DRAGGED_TAB: RefCell<Option<usize>> = RefCell::new(None);
+    // Settings waiting for confirmation, with where they came from
+    static PENDING_SETTINGS: RefCell<Option<(String, AppSettings)>> = RefCell::new(None);
+}
 
 pub fn display(
     ui: &mut egui::Ui,
@@ -20,11 +35,12 @@ pub fn display(
             let mut theme_changed = false;
             let old_preset = settings.theme_preset.clone();
 
-            // Display themes in 2 rows with 6 themes each
+            // Display themes in rows of 6
             let all_presets = PresetTheme::all_presets();
             let themes_per_row = 6;
+            let row_count = (all_presets.len() + themes_per_row - 1) / themes_per_row;
 
-            for row in 0..2 {
+            for row in 0..row_count {
                 ui.horizontal_wrapped(|ui| {
                     let start_idx = row * themes_per_row;
                     let end_idx = (start_idx + themes_per_row).min(all_presets.len());
@@ -56,7 +72,7 @@ pub fn display(
                     }
                 });
 
-                if row == 0 {
+                if row + 1 < row_count {
                     ui.add_space(5.0);
                 }
             }
@@ -176,7 +192,7 @@ pub fn display(
 
             if theme_changed {
                 if let Err(e) = settings.save() {
-                    status.show(&format!("Failed to save theme: {}", e));
+                    status.error(&format!("Failed to save theme: {}", e));
                 } else {
                     status.show("Theme saved successfully!");
                 }
@@ -190,19 +206,31 @@ pub fn display(
             ui.heading("📑 Tab Management");
             ui.add_space(10.0);
 
-            ui.label("Configure tabs visibility, names, and order:");
+            ui.label("Configure tabs visibility, names, and order (drag ☰ to reorder):");
             ui.add_space(10.0);
 
             let mut any_changed = false;
             let mut move_up_index = None;
             let mut move_down_index = None;
+            let mut row_rects = Vec::new();
 
             for (index, config) in settings.tab_configs.clone().iter().enumerate() {
                 if config.tab_type == Tab::Settings {
                     continue; // Skip settings tab as it's always enabled
                 }
 
-                ui.horizontal(|ui| {
+                let row = ui.horizontal(|ui| {
+                    // Drag handle
+                    let handle = ui
+                        .add(egui::Label::new("☰").sense(egui::Sense::drag()))
+                        .on_hover_text("Drag to reorder");
+                    if handle.hovered() {
+                        ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grab);
+                    }
+                    if handle.drag_started() {
+                        DRAGGED_TAB.with(|dragged| *dragged.borrow_mut() = Some(index));
+                    }
+
                     // Move up/down buttons
                     if index > 0 && ui.button("⬆").clicked() {
                         move_up_index = Some(index);
@@ -245,10 +273,40 @@ pub fn display(
                         any_changed = true;
                     }
                 });
+                row_rects.push((index, row.response.rect));
 
                 ui.add_space(5.0);
             }
 
+            // Drop the dragged row onto the row under the pointer
+            if let Some(from) = DRAGGED_TAB.with(|dragged| *dragged.borrow()) {
+                ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grabbing);
+
+                let target = ui.ctx().pointer_interact_pos().and_then(|pointer| {
+                    row_rects
+                        .iter()
+                        .find(|(_, rect)| rect.expand2(egui::vec2(0.0, 3.0)).contains(pointer))
+                        .copied()
+                });
+                if let Some((_, rect)) = target {
+                    ui.painter().rect_stroke(
+                        rect.expand(2.0),
+                        egui::Rounding::same(4.0),
+                        egui::Stroke::new(1.5, settings.get_current_colors().accent_color32()),
+                    );
+                }
+
+                if ui.input(|i| i.pointer.any_released()) {
+                    if let Some((to, _)) = target {
+                        if to != from {
+                            settings.move_tab(from, to);
+                            any_changed = true;
+                        }
+                    }
+                    DRAGGED_TAB.with(|dragged| *dragged.borrow_mut() = None);
+                }
+            }
+
             // Handle tab reordering
             if let Some(index) = move_up_index {
                 settings.move_tab_up(index);
@@ -261,7 +319,7 @@ pub fn display(
 
             if any_changed {
                 if let Err(e) = settings.save() {
-                    status.show(&format!("Failed to save tab settings: {}", e));
+                    status.error(&format!("Failed to save tab settings: {}", e));
                 } else {
                     status.show("Tab settings saved successfully!");
                 }
@@ -275,6 +333,701 @@ pub fn display(
 
         ui.add_space(20.0);
 
+        // Event Hooks Section
+        ui.group(|ui| {
+            ui.heading("🔗 Event Hooks");
+            ui.add_space(10.0);
+
+            ui.label("Run a shell command or POST to a webhook when something happens.");
+            ui.label(
+                egui::RichText::new(
+                    "Commands get FOCUSPAD_EVENT and details like FOCUSPAD_SUBJECT in the environment; webhooks receive the same as JSON.",
+                )
+                .small(),
+            );
+            ui.add_space(10.0);
+
+            let mut hooks_changed = false;
+
+            ui.horizontal(|ui| {
+                ui.label("Daily goal (minutes, 0 = off):");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut settings.daily_goal_minutes)
+                            .clamp_range(0..=1440)
+                            .speed(5),
+                    )
+                    .changed()
+                {
+                    hooks_changed = true;
+                }
+            });
+            ui.add_space(5.0);
+
+            let mut delete_index = None;
+            let mut test_index = None;
+
+            for (index, hook) in settings.hooks.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    if ui.checkbox(&mut hook.enabled, "").changed() {
+                        hooks_changed = true;
+                    }
+                    ui.label(format!("{} → {}:", hook.event.name(), hook.action.name()));
+                    if ui
+                        .add(egui::TextEdit::singleline(&mut hook.target).desired_width(250.0))
+                        .lost_focus()
+                    {
+                        hooks_changed = true;
+                    }
+                    if ui.button("▶ Test").clicked() {
+                        test_index = Some(index);
+                    }
+                    if ui.button("🗑").clicked() {
+                        delete_index = Some(index);
+                    }
+                });
+            }
+
+            if let Some(index) = test_index {
+                let hook = &settings.hooks[index];
+                let details = vec![("test".to_string(), "true".to_string())];
+                match hooks::run_hook(hook, &hook.event, &details) {
+                    Ok(()) => status.show("Hook started"),
+                    Err(e) => status.error(&format!("Hook failed: {}", e)),
+                }
+            }
+
+            if let Some(index) = delete_index {
+                settings.hooks.remove(index);
+                hooks_changed = true;
+            }
+
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                NEW_HOOK_EVENT.with(|event| {
+                    let mut event = event.borrow_mut();
+                    egui::ComboBox::from_id_source("new_hook_event")
+                        .selected_text(event.name())
+                        .show_ui(ui, |ui| {
+                            for option in HookEvent::all() {
+                                let name = option.name();
+                                ui.selectable_value(&mut *event, option, name);
+                            }
+                        });
+                });
+
+                NEW_HOOK_ACTION.with(|action| {
+                    let mut action = action.borrow_mut();
+                    egui::ComboBox::from_id_source("new_hook_action")
+                        .selected_text(action.name())
+                        .show_ui(ui, |ui| {
+                            for option in [HookAction::Command, HookAction::Webhook] {
+                                let name = option.name();
+                                ui.selectable_value(&mut *action, option, name);
+                            }
+                        });
+                });
+
+                NEW_HOOK_TARGET.with(|target| {
+                    let mut target = target.borrow_mut();
+                    ui.add(
+                        egui::TextEdit::singleline(&mut *target)
+                            .hint_text("Command or https:// URL")
+                            .desired_width(200.0),
+                    );
+
+                    if ui.button("➕ Add Hook").clicked() {
+                        if target.trim().is_empty() {
+                            status.show("Please enter a command or URL for the hook");
+                        } else {
+                            settings.hooks.push(EventHook {
+                                event: NEW_HOOK_EVENT.with(|e| e.borrow().clone()),
+                                action: NEW_HOOK_ACTION.with(|a| a.borrow().clone()),
+                                target: target.trim().to_string(),
+                                enabled: true,
+                            });
+                            target.clear();
+                            hooks_changed = true;
+                        }
+                    }
+                });
+            });
+
+            if hooks_changed {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save hooks: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Discord Section
+        ui.group(|ui| {
+            ui.heading("🎮 Discord Rich Presence");
+            ui.add_space(10.0);
+
+            let mut discord_changed = false;
+
+            if ui
+                .checkbox(
+                    &mut settings.discord_presence_enabled,
+                    "Show \"Studying ...\" on Discord while the timer runs",
+                )
+                .changed()
+            {
+                discord_changed = true;
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("Application ID:");
+                if ui
+                    .add(
+                        egui::TextEdit::singleline(&mut settings.discord_client_id)
+                            .hint_text("From the Discord developer portal")
+                            .desired_width(200.0),
+                    )
+                    .lost_focus()
+                {
+                    discord_changed = true;
+                }
+            });
+
+            ui.label(
+                egui::RichText::new("The timer description is shown as the subject.").small(),
+            );
+
+            if discord_changed {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save Discord settings: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Language Section
+        ui.group(|ui| {
+            ui.heading("🌐 Language & Fonts");
+            ui.add_space(10.0);
+
+            if ui
+                .checkbox(
+                    &mut settings.rtl_layout,
+                    "Right-to-left layout (mirror navigation, right-align notes)",
+                )
+                .changed()
+            {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save language settings: {}", e));
+                }
+            }
+
+            ui.add_space(10.0);
+
+            let previous = settings.clone();
+            let mut font_changed = false;
+
+            ui.horizontal(|ui| {
+                ui.label("Density:");
+                for density in UiDensity::ALL {
+                    font_changed |= ui
+                        .radio_value(&mut settings.ui_density, density, density.label())
+                        .changed();
+                }
+            })
+            .response
+            .on_hover_text("Compact shrinks spacing, tabs and fonts for small screens");
+
+            ui.add_space(6.0);
+
+            egui::Grid::new("font_settings_grid")
+                .num_columns(3)
+                .spacing([10.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label("Interface font:");
+                    font_changed |= font_picker(ui, &mut settings.ui_font_path, "ui_font");
+                    font_changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut settings.ui_font_size)
+                                .clamp_range(8.0..=32.0)
+                                .speed(0.5)
+                                .suffix(" pt"),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Editor & terminal font:");
+                    font_changed |= font_picker(ui, &mut settings.editor_font_path, "editor_font");
+                    font_changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut settings.editor_font_size)
+                                .clamp_range(8.0..=40.0)
+                                .speed(0.5)
+                                .suffix(" pt"),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Fallback font:")
+                        .on_hover_text("Used for scripts the other fonts can't show, e.g. Chinese, Japanese, Arabic");
+                    font_changed |=
+                        font_picker(ui, &mut settings.fallback_font_path, "fallback_font");
+                    ui.end_row();
+                });
+
+            if font_changed {
+                match crate::fonts::apply(ui.ctx(), settings) {
+                    Ok(()) => {
+                        if let Err(e) = settings.save() {
+                            status.error(&format!("Failed to save font settings: {}", e));
+                        }
+                    }
+                    Err(e) => {
+                        *settings = previous;
+                        let _ = crate::fonts::apply(ui.ctx(), settings);
+                        status.error(&format!("Failed to load font: {}", e));
+                    }
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Zen Mode Section
+        ui.group(|ui| {
+            ui.heading("🧘 Zen Mode");
+            ui.add_space(10.0);
+            ui.label("Press F11 in a Markdown tab to write with nothing else on screen.");
+
+            let mut changed = false;
+            ui.horizontal(|ui| {
+                ui.label("Line width:");
+                changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut settings.zen_line_width)
+                            .clamp_range(400.0..=1400.0)
+                            .speed(10.0)
+                            .suffix(" pt"),
+                    )
+                    .changed();
+            });
+            changed |= ui
+                .checkbox(
+                    &mut settings.zen_focus_paragraph,
+                    "Dim everything but the current paragraph",
+                )
+                .changed();
+            if changed {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save zen mode settings: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Flashcard Review Section
+        ui.group(|ui| {
+            ui.heading("🎯 Flashcard Review");
+            ui.add_space(10.0);
+            ui.label("Fullscreen review hides the tabs and status bar; Esc leaves it.");
+            if ui
+                .checkbox(
+                    &mut settings.review_window_fullscreen,
+                    "Also make the window fullscreen",
+                )
+                .changed()
+            {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save review settings: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Snippets Section
+        ui.group(|ui| {
+            ui.heading("✂ Snippets");
+            ui.add_space(10.0);
+            ui.label("Typing a trigger in a note or card replaces it with its text.");
+            crate::ui::snippets::settings_section(ui, settings, status);
+        });
+
+        ui.add_space(20.0);
+
+        // Startup Section
+        ui.group(|ui| {
+            ui.heading("🌅 Startup");
+            ui.add_space(10.0);
+
+            if ui
+                .checkbox(
+                    &mut settings.show_startup_summary,
+                    "Show a summary of reminders, tasks, due cards and yesterday's study time on launch",
+                )
+                .changed()
+            {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save startup settings: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Pomodoro Section
+        ui.group(|ui| {
+            ui.heading("🍅 Pomodoro");
+            ui.add_space(10.0);
+
+            let mut changed = false;
+            ui.horizontal(|ui| {
+                ui.label("Work interval (minutes, 0 = off):");
+                changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut settings.work_interval_minutes)
+                            .clamp_range(0..=240)
+                            .speed(1),
+                    )
+                    .changed();
+            });
+            ui.horizontal(|ui| {
+                ui.label("Break length (minutes):");
+                changed |= ui
+                    .add(
+                        egui::DragValue::new(&mut settings.break_minutes)
+                            .clamp_range(1..=120)
+                            .speed(1),
+                    )
+                    .changed();
+            });
+            changed |= ui
+                .checkbox(
+                    &mut settings.auto_start_breaks,
+                    "Start a break automatically when a work interval ends",
+                )
+                .changed();
+            changed |= ui
+                .checkbox(
+                    &mut settings.auto_start_work,
+                    "Start the next work interval automatically when a break ends",
+                )
+                .changed();
+            ui.label(
+                egui::RichText::new(
+                    "A short countdown comes first, so you can skip the break or start right away.",
+                )
+                .small(),
+            );
+
+            if changed {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save pomodoro settings: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Break Reminder Section
+        ui.group(|ui| {
+            ui.heading("👀 Break Reminders");
+            ui.add_space(10.0);
+
+            let mut changed = ui
+                .checkbox(
+                    &mut settings.break_reminders,
+                    "Remind me to rest my eyes after continuous screen time",
+                )
+                .changed();
+            ui.add_enabled_ui(settings.break_reminders, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Remind after (minutes):");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut settings.break_reminder_minutes)
+                                .clamp_range(5..=180)
+                                .speed(1),
+                        )
+                        .changed();
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Snooze for (minutes):");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut settings.break_snooze_minutes)
+                                .clamp_range(1..=60)
+                                .speed(1),
+                        )
+                        .changed();
+                });
+            });
+            ui.label(
+                egui::RichText::new(
+                    "Follows the 20-20-20 rule and runs whether or not the timer does. Five minutes away from FocusPad counts as a break.",
+                )
+                .small(),
+            );
+
+            if changed {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save break reminder settings: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Quiet Hours Section
+        ui.group(|ui| {
+            ui.heading("🌙 Quiet Hours");
+            ui.add_space(10.0);
+
+            let mut changed = ui
+                .checkbox(
+                    &mut settings.quiet_hours,
+                    "Hold reminder notifications during quiet hours",
+                )
+                .changed();
+            ui.add_enabled_ui(settings.quiet_hours, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("From");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut settings.quiet_hours_start)
+                                .clamp_range(0..=23)
+                                .suffix(":00"),
+                        )
+                        .changed();
+                    ui.label("to");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut settings.quiet_hours_end)
+                                .clamp_range(0..=23)
+                                .suffix(":00"),
+                        )
+                        .changed();
+                });
+            });
+            ui.label(
+                egui::RichText::new(
+                    "Reminders that come due in the window are shown together once it ends.",
+                )
+                .small(),
+            );
+
+            if changed {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save quiet hours: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Night Light Section
+        ui.group(|ui| {
+            ui.heading("🌇 Night Light");
+            ui.add_space(10.0);
+
+            let mut changed = ui
+                .checkbox(&mut settings.night_light, "Warm the colors in the evening")
+                .changed();
+            ui.add_enabled_ui(settings.night_light, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("From");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut settings.night_light_start)
+                                .clamp_range(0..=23)
+                                .suffix(":00"),
+                        )
+                        .changed();
+                    ui.label("to");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut settings.night_light_end)
+                                .clamp_range(0..=23)
+                                .suffix(":00"),
+                        )
+                        .changed();
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Strength:");
+                    changed |= ui
+                        .add(
+                            egui::Slider::new(&mut settings.night_light_strength, 5..=80)
+                                .suffix("%"),
+                        )
+                        .changed();
+                });
+            });
+            ui.label(
+                egui::RichText::new(
+                    "Tints the whole window amber to go easier on the eyes during late sessions.",
+                )
+                .small(),
+            );
+
+            if changed {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save night light settings: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Date & Week Section
+        ui.group(|ui| {
+            ui.heading("📅 Date, Time & Numbers");
+            ui.add_space(10.0);
+
+            let mut changed = false;
+            ui.horizontal(|ui| {
+                ui.label("Weeks start on");
+                for week_start in WeekStart::ALL {
+                    changed |= ui
+                        .radio_value(&mut settings.week_start, week_start, week_start.label())
+                        .changed();
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Date format");
+                let today = chrono::Local::now().date_naive();
+                egui::ComboBox::from_id_source("date_format")
+                    .selected_text(settings.date_format.format(today))
+                    .show_ui(ui, |ui| {
+                        for format in DateFormat::ALL {
+                            changed |= ui
+                                .selectable_value(
+                                    &mut settings.date_format,
+                                    format,
+                                    format.format(today),
+                                )
+                                .changed();
+                        }
+                    });
+            });
+            ui.horizontal(|ui| {
+                ui.label("Clock");
+                for clock_format in ClockFormat::ALL {
+                    changed |= ui
+                        .radio_value(
+                            &mut settings.clock_format,
+                            clock_format,
+                            clock_format.label(),
+                        )
+                        .changed();
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label("Numbers");
+                egui::ComboBox::from_id_source("number_format")
+                    .selected_text(settings.number_format.format(1234.5, 1))
+                    .show_ui(ui, |ui| {
+                        for format in NumberFormat::ALL {
+                            changed |= ui
+                                .selectable_value(
+                                    &mut settings.number_format,
+                                    format,
+                                    format.format(1234.5, 1),
+                                )
+                                .changed();
+                        }
+                    });
+            });
+            ui.label(
+                egui::RichText::new(
+                    "Used by the timer, statistics, graphs, the habit calendar and reminders.",
+                )
+                .small(),
+            );
+
+            if changed {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save date settings: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // World Clock Section
+        ui.group(|ui| {
+            ui.heading("🕐 World Clock");
+            ui.add_space(10.0);
+            ui.label("Show the time in up to three other time zones next to the weather.");
+            ui.add_space(5.0);
+            crate::ui::world_clock::settings_section(ui, settings, status);
+            ui.add_space(10.0);
+            if ui
+                .checkbox(
+                    &mut settings.agenda_widget,
+                    "Also cycle through the top todo, next reminder and habit progress",
+                )
+                .changed()
+            {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save agenda setting: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
+        // Profiles Section
+        ui.group(|ui| {
+            ui.heading("👤 Profiles");
+            ui.add_space(10.0);
+            crate::ui::profile_ui::settings_section(ui, status);
+        });
+
+        ui.add_space(20.0);
+
+        // Card Suggestions Section
+        ui.group(|ui| {
+            ui.heading("✨ Card Suggestions");
+            ui.add_space(10.0);
+            crate::ui::card_suggestions::settings_section(ui, status);
+        });
+
+        ui.add_space(20.0);
+
+        // Spell Check Section
+        ui.group(|ui| {
+            ui.heading("🔤 Spell Check");
+            ui.add_space(10.0);
+            crate::ui::spell_check_ui::settings_section(ui, status);
+        });
+
+        ui.add_space(20.0);
+
+        // Power Section
+        ui.group(|ui| {
+            ui.heading("🔋 Battery Saver");
+            ui.add_space(10.0);
+
+            if ui
+                .checkbox(
+                    &mut settings.battery_saver,
+                    "Reduce motion and save battery",
+                )
+                .on_hover_text(
+                    "Turns off animations, redraws the running timer once a second instead of every frame, and pauses weather updates while on battery",
+                )
+                .changed()
+            {
+                if let Err(e) = settings.save() {
+                    status.error(&format!("Failed to save battery saver setting: {}", e));
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+
         // Reset Section
         ui.group(|ui| {
             ui.heading("🔧 Reset Options");
@@ -286,7 +1039,7 @@ pub fn display(
                         config.custom_name = None;
                     }
                     if let Err(e) = settings.save() {
-                        status.show(&format!("Failed to reset names: {}", e));
+                        status.error(&format!("Failed to reset names: {}", e));
                     } else {
                         status.show("All tab names reset to defaults!");
                     }
@@ -295,7 +1048,7 @@ pub fn display(
                 if ui.button("🔄 Reset Tab Order").clicked() {
                     settings.reset_tab_order();
                     if let Err(e) = settings.save() {
-                        status.show(&format!("Failed to reset tab order: {}", e));
+                        status.error(&format!("Failed to reset tab order: {}", e));
                     } else {
                         status.show("Tab order reset to default!");
                     }
@@ -305,24 +1058,66 @@ pub fn display(
                     settings.theme_preset = PresetTheme::Default;
                     settings.custom_colors = ColorTheme::default();
                     if let Err(e) = settings.save() {
-                        status.show(&format!("Failed to reset theme: {}", e));
+                        status.error(&format!("Failed to reset theme: {}", e));
                     } else {
                         status.show("Theme reset to default!");
                     }
                 }
 
                 if ui.button("🔄 Reset All Settings").clicked() {
-                    *settings = AppSettings::default();
-                    if let Err(e) = settings.save() {
-                        status.show(&format!("Failed to reset all settings: {}", e));
-                    } else {
-                        status.show("All settings reset to defaults!");
+                    PENDING_SETTINGS.with(|pending| {
+                        *pending.borrow_mut() =
+                            Some(("Reset to defaults".to_string(), AppSettings::default()));
+                    });
+                }
+            });
+        });
+
+        ui.add_space(20.0);
+
+        // Backup Section
+        ui.group(|ui| {
+            ui.heading("💾 Backup & Restore");
+            ui.add_space(10.0);
+
+            ui.label("Move your tabs, theme, hooks and other preferences to another machine.");
+            ui.add_space(5.0);
+
+            ui.horizontal(|ui| {
+                if ui.button("📤 Export Settings").clicked() {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .add_filter("JSON", &["json"])
+                        .set_file_name("focuspad_settings.json")
+                        .save_file()
+                    {
+                        match settings.export_to(&path) {
+                            Ok(()) => {
+                                status.show(&format!("Settings exported to {}", path.display()))
+                            }
+                            Err(e) => status.error(&format!("Failed to export settings: {}", e)),
+                        }
+                    }
+                }
+
+                if ui.button("📥 Import Settings").clicked() {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .add_filter("JSON", &["json"])
+                        .pick_file()
+                    {
+                        match AppSettings::import_from(&path) {
+                            Ok(imported) => PENDING_SETTINGS.with(|pending| {
+                                *pending.borrow_mut() =
+                                    Some((format!("Import {}", path.display()), imported));
+                            }),
+                            Err(e) => status.error(&format!("Failed to import settings: {}", e)),
+                        }
                     }
-                    *current_tab = settings.get_first_enabled_tab();
                 }
             });
         });
 
+        render_pending_settings(ui.ctx(), settings, status, current_tab);
+
         ui.add_space(20.0);
 
         // Information Section
@@ -340,3 +1135,100 @@ pub fn display(
         });
     });
 }
+
+// Shows what an import or reset would change and applies it once confirmed
+fn render_pending_settings(
+    ctx: &egui::Context,
+    settings: &mut AppSettings,
+    status: &mut StatusMessage,
+    current_tab: &mut Tab,
+) {
+    let pending = PENDING_SETTINGS.with(|pending| pending.borrow().clone());
+    let (title, incoming) = match pending {
+        Some(pending) => pending,
+        None => return,
+    };
+
+    let changes = settings.describe_changes(&incoming);
+    let mut apply = false;
+    let mut close = false;
+
+    egui::Window::new("Review Settings Changes")
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.strong(&title);
+            ui.add_space(5.0);
+
+            if changes.is_empty() {
+                ui.label("Nothing would change.");
+            } else {
+                ui.label("These settings will change:");
+                for change in &changes {
+                    ui.label(format!("• {}", change));
+                }
+            }
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!changes.is_empty(), egui::Button::new("Apply"))
+                    .clicked()
+                {
+                    apply = true;
+                }
+                if ui.button("Cancel").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+    if apply {
+        *settings = incoming;
+        if let Err(e) = settings.save() {
+            status.error(&format!("Failed to save settings: {}", e));
+        } else {
+            status.show("Settings applied!");
+        }
+        if let Err(e) = crate::fonts::apply(ctx, settings) {
+            status.error(&format!("Failed to load font: {}", e));
+        }
+        if !settings.is_tab_enabled(current_tab) {
+            *current_tab = settings.get_first_enabled_tab();
+        }
+    }
+    if apply || close {
+        PENDING_SETTINGS.with(|pending| *pending.borrow_mut() = None);
+    }
+}
+
+// Path label with choose/clear buttons; returns true when the path changed
+fn font_picker(ui: &mut egui::Ui, path: &mut Option<String>, id: &str) -> bool {
+    let mut changed = false;
+    ui.push_id(id, |ui| {
+        ui.horizontal(|ui| {
+            let current = path
+                .as_deref()
+                .and_then(|p| std::path::Path::new(p).file_name())
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| "Default".to_string());
+            ui.label(egui::RichText::new(current).monospace());
+
+            if ui.button("📂 Choose…").clicked() {
+                if let Some(picked) = rfd::FileDialog::new()
+                    .add_filter("Fonts", &["ttf", "otf", "ttc"])
+                    .pick_file()
+                {
+                    *path = Some(picked.to_string_lossy().to_string());
+                    changed = true;
+                }
+            }
+
+            if path.is_some() && ui.button("✖").on_hover_text("Use the default").clicked() {
+                *path = None;
+                changed = true;
+            }
+        });
+    });
+    changed
+}
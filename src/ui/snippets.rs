@@ -0,0 +1,127 @@
+use crate::app::StatusMessage;
+use crate::settings::AppSettings;
+use eframe::egui;
+use egui::text::{CCursor, CCursorRange};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+// Typing a trigger, e.g. ";thm", replaces it with the expansion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snippet {
+    pub trigger: String,
+    pub expansion: String,
+}
+
+const PLACEHOLDERS: &str = "{cursor} is where the cursor ends up; {date} and {time} are filled in";
+
+thread_local! {
+    // A copy of the settings' snippets, for text fields that have no settings at hand
+    static SNIPPETS: RefCell<Vec<Snippet>> = RefCell::new(Vec::new());
+}
+
+pub fn install(snippets: &[Snippet]) {
+    SNIPPETS.with(|installed| {
+        let mut installed = installed.borrow_mut();
+        if installed.as_slice() != snippets {
+            *installed = snippets.to_vec();
+        }
+    });
+}
+
+// Call after a text field changed. Expands a trigger that ends right at the cursor and
+// moves the cursor to the expansion's {cursor}, or its end.
+pub fn expand(ctx: &egui::Context, id: egui::Id, text: &mut String) -> bool {
+    let Some(mut state) = egui::TextEdit::load_state(ctx, id) else {
+        return false;
+    };
+    let Some(range) = state.ccursor_range() else {
+        return false;
+    };
+    let cursor = text
+        .char_indices()
+        .nth(range.primary.index)
+        .map_or(text.len(), |(i, _)| i);
+
+    // When one trigger ends another, e.g. "thm" and ";thm", the longer one wins
+    let snippet = SNIPPETS.with(|snippets| {
+        snippets
+            .borrow()
+            .iter()
+            .filter(|s| !s.trigger.is_empty() && text[..cursor].ends_with(s.trigger.as_str()))
+            .max_by_key(|s| s.trigger.len())
+            .cloned()
+    });
+    let Some(snippet) = snippet else {
+        return false;
+    };
+
+    let now = chrono::Local::now();
+    let filled = snippet
+        .expansion
+        .replace("{date}", &now.format("%Y-%m-%d").to_string())
+        .replace("{time}", &now.format("%H:%M").to_string());
+    let (before, after) = filled.split_once("{cursor}").unwrap_or((&filled, ""));
+
+    let start = cursor - snippet.trigger.len();
+    let caret = text[..start].chars().count() + before.chars().count();
+    text.replace_range(start..cursor, &format!("{}{}", before, after));
+
+    state.set_ccursor_range(Some(CCursorRange::one(CCursor::new(caret))));
+    state.store(ctx, id);
+    ctx.request_repaint();
+    true
+}
+
+pub fn settings_section(ui: &mut egui::Ui, settings: &mut AppSettings, status: &mut StatusMessage) {
+    ui.label(egui::RichText::new(PLACEHOLDERS).small());
+    ui.add_space(5.0);
+
+    let mut changed = false;
+    let mut removed = None;
+    for (index, snippet) in settings.snippets.iter_mut().enumerate() {
+        ui.push_id(index, |ui| {
+            ui.horizontal(|ui| {
+                changed |= ui
+                    .add(
+                        egui::TextEdit::singleline(&mut snippet.trigger)
+                            .hint_text(";thm")
+                            .desired_width(80.0),
+                    )
+                    .lost_focus();
+                ui.label("→");
+                changed |= ui
+                    .add(
+                        egui::TextEdit::multiline(&mut snippet.expansion)
+                            .hint_text("**Theorem.** {cursor}")
+                            .desired_rows(2)
+                            .desired_width(320.0),
+                    )
+                    .lost_focus();
+                if ui
+                    .small_button("✖")
+                    .on_hover_text("Remove snippet")
+                    .clicked()
+                {
+                    removed = Some(index);
+                }
+            });
+        });
+    }
+
+    if let Some(index) = removed {
+        settings.snippets.remove(index);
+        changed = true;
+    }
+    if ui.button("➕ Add Snippet").clicked() {
+        settings.snippets.push(Snippet {
+            trigger: String::new(),
+            expansion: String::new(),
+        });
+    }
+
+    if changed {
+        if let Err(e) = settings.save() {
+            status.error(&format!("Failed to save snippets: {}", e));
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::app::StatusMessage;
+use crate::spell_check::{self, SpellChecker};
+use eframe::egui;
+use egui::text::{LayoutJob, TextFormat};
+use std::cell::RefCell;
+use std::sync::Arc;
+
+const MENU_WORDS: usize = 8; // Misspellings offered in the right-click menu
+
+thread_local! {
+    static CHECKER: RefCell<SpellChecker> = RefCell::new(SpellChecker::new());
+    static LANGUAGES: RefCell<Option<Vec<String>>> = RefCell::new(None);
+}
+
+fn with_checker<R>(f: impl FnOnce(&mut SpellChecker) -> R) -> R {
+    CHECKER.with(|checker| f(&mut checker.borrow_mut()))
+}
+
+// Lays out editor text with misspelled words underlined, for TextEdit::layouter
+pub fn highlight(
+    ui: &egui::Ui,
+    text: &str,
+    style: egui::TextStyle,
+    wrap_width: f32,
+) -> Arc<egui::Galley> {
+    let job = highlight_job(ui, text, style, wrap_width);
+    ui.fonts(|fonts| fonts.layout_job(job))
+}
+
+// The layout behind `highlight`, for callers that style the text further
+pub fn highlight_job(
+    ui: &egui::Ui,
+    text: &str,
+    style: egui::TextStyle,
+    wrap_width: f32,
+) -> LayoutJob {
+    let color = ui
+        .visuals()
+        .override_text_color
+        .unwrap_or_else(|| ui.visuals().widgets.inactive.text_color());
+    let plain = TextFormat::simple(style.resolve(ui.style()), color);
+    let underlined = TextFormat {
+        underline: egui::Stroke::new(1.5, egui::Color32::from_rgb(213, 94, 0)),
+        ..plain.clone()
+    };
+
+    let mut job = LayoutJob::default();
+    let mut last = 0;
+    for range in with_checker(|checker| checker.misspelled(text)) {
+        job.append(&text[last..range.start], 0.0, plain.clone());
+        job.append(&text[range.clone()], 0.0, underlined.clone());
+        last = range.end;
+    }
+    job.append(&text[last..], 0.0, plain);
+    job.wrap.max_width = wrap_width;
+    job
+}
+
+// Multiline field with spell checking, snippets and a Spelling context menu, for card text
+pub fn multiline(ui: &mut egui::Ui, text: &mut String, rows: usize) -> egui::Response {
+    let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+        highlight(ui, text, egui::TextStyle::Body, wrap_width)
+    };
+    let response = ui.add(
+        egui::TextEdit::multiline(text)
+            .desired_rows(rows)
+            .layouter(&mut layouter),
+    );
+    if response.changed() {
+        crate::ui::snippets::expand(ui.ctx(), response.id, text);
+    }
+    response.clone().context_menu(|ui| {
+        if !spelling_menu(ui, text) {
+            ui.close_menu();
+        }
+    });
+    response
+}
+
+// Adds a Spelling submenu to a context menu; false when there is nothing to offer
+pub fn spelling_menu(ui: &mut egui::Ui, text: &mut String) -> bool {
+    let mut words: Vec<String> = Vec::new();
+    for range in with_checker(|checker| checker.misspelled(text)) {
+        let word = text[range].to_string();
+        if !words.contains(&word) {
+            words.push(word);
+        }
+        if words.len() == MENU_WORDS {
+            break;
+        }
+    }
+    if words.is_empty() {
+        return false;
+    }
+
+    ui.menu_button("🔤 Spelling", |ui| {
+        for word in &words {
+            ui.menu_button(word, |ui| {
+                let suggestions = with_checker(|checker| checker.suggestions(word));
+                if suggestions.is_empty() {
+                    ui.label(egui::RichText::new("No suggestions").italics());
+                }
+                for suggestion in suggestions {
+                    if ui.button(&suggestion).clicked() {
+                        replace_word(text, word, &suggestion);
+                        ui.close_menu();
+                    }
+                }
+                ui.separator();
+                if ui.button("Ignore this word").clicked() {
+                    if let Err(e) = with_checker(|checker| checker.ignore(word)) {
+                        crate::error::report("Could not save the ignore list", e);
+                    }
+                    ui.close_menu();
+                }
+            });
+        }
+    });
+    true
+}
+
+// Replaces every flagged occurrence, leaving the word alone inside longer ones
+fn replace_word(text: &mut String, word: &str, replacement: &str) {
+    let ranges = with_checker(|checker| checker.misspelled(text));
+    for range in ranges.into_iter().rev() {
+        if &text[range.clone()] == word {
+            text.replace_range(range, replacement);
+        }
+    }
+}
+
+pub fn settings_section(ui: &mut egui::Ui, status: &mut StatusMessage) {
+    with_checker(|checker| {
+        let mut changed = ui
+            .checkbox(
+                &mut checker.config.enabled,
+                "Underline misspelled words in notes and cards",
+            )
+            .changed();
+
+        let languages = LANGUAGES.with(|l| {
+            l.borrow_mut()
+                .get_or_insert_with(spell_check::available_languages)
+                .clone()
+        });
+        ui.horizontal(|ui| {
+            ui.label("Dictionary:");
+            egui::ComboBox::from_id_source("spell_check_language")
+                .selected_text(&checker.config.language)
+                .show_ui(ui, |ui| {
+                    for language in &languages {
+                        changed |= ui
+                            .selectable_value(
+                                &mut checker.config.language,
+                                language.clone(),
+                                language,
+                            )
+                            .changed();
+                    }
+                });
+            if ui
+                .small_button("⟳")
+                .on_hover_text("Look for new dictionaries")
+                .clicked()
+            {
+                LANGUAGES.with(|l| *l.borrow_mut() = None);
+            }
+        });
+
+        if let Some(dir) = spell_check::user_dictionary_dir() {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Add Hunspell dictionaries (.aff and .dic) to {}",
+                    dir.display()
+                ))
+                .small(),
+            );
+        }
+        if checker.config.enabled && !checker.is_active() {
+            if let Some(error) = &checker.load_error {
+                ui.colored_label(egui::Color32::from_rgb(213, 94, 0), error);
+            }
+        }
+
+        if !checker.config.ignored.is_empty() {
+            ui.add_space(5.0);
+            let mut removed = None;
+            ui.collapsing(
+                format!("Ignored words ({})", checker.config.ignored.len()),
+                |ui| {
+                    for word in &checker.config.ignored {
+                        ui.horizontal(|ui| {
+                            ui.label(word);
+                            if ui.small_button("✖").clicked() {
+                                removed = Some(word.clone());
+                            }
+                        });
+                    }
+                },
+            );
+            if let Some(word) = removed {
+                checker.config.ignored.remove(&word);
+                changed = true;
+            }
+        }
+
+        if changed {
+            if let Err(e) = checker.config.save() {
+                status.error(&format!("Failed to save spell check settings: {}", e));
+            }
+        }
+    });
+}
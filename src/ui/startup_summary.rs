@@ -0,0 +1,131 @@
+use crate::app::Tab;
+use crate::data::StudyData;
+use chrono::{Duration, Local};
+use eframe::egui;
+
+// Snapshot of what needs attention, taken once at launch
+pub struct StartupSummary {
+    pub open: bool,
+    overdue_reminders: Vec<(String, String)>, // (title, due date)
+    open_todos: Vec<String>,
+    due_cards: usize,
+    yesterday_minutes: f64,
+}
+
+impl StartupSummary {
+    pub fn new(study_data: &StudyData) -> Self {
+        let today = Local::now().date_naive();
+        let today_str = today.format("%Y-%m-%d").to_string();
+        let yesterday = (today - Duration::days(1)).format("%Y-%m-%d").to_string();
+
+        let mut overdue_reminders: Vec<(String, String)> = study_data
+            .reminders
+            .iter()
+            .filter(|r| !r.is_completed && r.due_date <= today_str)
+            .map(|r| (r.title.clone(), r.due_date.clone()))
+            .collect();
+        overdue_reminders.sort_by(|a, b| a.1.cmp(&b.1));
+
+        Self {
+            open: true,
+            overdue_reminders,
+            open_todos: study_data
+                .todos
+                .iter()
+                .filter(|t| !t.completed)
+                .map(|t| t.text.clone())
+                .collect(),
+            due_cards: study_data.get_due_cards_count(),
+            yesterday_minutes: study_data
+                .sessions
+                .iter()
+                .filter(|s| s.date == yesterday)
+                .map(|s| s.minutes)
+                .sum(),
+        }
+    }
+
+    // Returns the tab to jump to when one of the quick-jump buttons is clicked
+    pub fn display(&mut self, ctx: &egui::Context) -> Option<Tab> {
+        if !self.open {
+            return None;
+        }
+
+        let mut jump_to = None;
+        let mut open = self.open;
+
+        egui::Window::new("🌅 Today at a Glance")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.set_min_width(320.0);
+
+                ui.horizontal(|ui| {
+                    ui.strong(format!(
+                        "🔔 Reminders due ({})",
+                        self.overdue_reminders.len()
+                    ));
+                    if ui.small_button("Open →").clicked() {
+                        jump_to = Some(Tab::Reminder);
+                    }
+                });
+                if self.overdue_reminders.is_empty() {
+                    ui.label("Nothing overdue.");
+                }
+                for (title, due_date) in self.overdue_reminders.iter().take(5) {
+                    ui.label(format!("• {} ({})", title, due_date));
+                }
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    ui.strong(format!("✅ Open tasks ({})", self.open_todos.len()));
+                    if ui.small_button("Open →").clicked() {
+                        jump_to = Some(Tab::Todo);
+                    }
+                });
+                if self.open_todos.is_empty() {
+                    ui.label("All tasks done.");
+                }
+                for todo in self.open_todos.iter().take(5) {
+                    ui.label(format!("• {}", todo));
+                }
+                if self.open_todos.len() > 5 {
+                    ui.label(format!("…and {} more", self.open_todos.len() - 5));
+                }
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    ui.strong(format!("🃏 Cards due: {}", self.due_cards));
+                    if ui.small_button("Review →").clicked() {
+                        jump_to = Some(Tab::Flashcards);
+                    }
+                });
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    ui.strong(format!(
+                        "⏱ Studied yesterday: {:.0} min ({:.1} h)",
+                        self.yesterday_minutes,
+                        self.yesterday_minutes / 60.0
+                    ));
+                    if ui.small_button("Stats →").clicked() {
+                        jump_to = Some(Tab::Stats);
+                    }
+                });
+
+                ui.separator();
+                ui.vertical_centered(|ui| {
+                    if ui.button("Let's go").clicked() {
+                        self.open = false;
+                    }
+                });
+            });
+
+        if !open || jump_to.is_some() {
+            self.open = false;
+        }
+        jump_to
+    }
+}
@@ -1,8 +1,13 @@
 use crate::app::StatusMessage;
 use crate::data::{StudyData, StudySession};
+use crate::settings::AppSettings;
+use crate::task_sync::SyncStatus;
+use crate::time_export::{ProjectMapping, TimeExport, TimeProvider};
+use crate::year_review;
+use chrono::{Datelike, Local};
 use eframe::egui;
 use eframe::egui::Ui;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 // Add state for session editing
 pub struct EditSessionState {
@@ -27,9 +32,16 @@ impl Default for EditSessionState {
 
 thread_local! {
     static EDIT_STATE: RefCell<EditSessionState> = RefCell::new(EditSessionState::default());
+    static REVIEW_YEAR: Cell<i32> = Cell::new(Local::now().year());
 }
 
-pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessage) {
+pub fn display(
+    ui: &mut Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    time_export: &mut TimeExport,
+    settings: &AppSettings,
+) {
     if study_data.sessions.is_empty() {
         ui.vertical_centered(|ui| {
             ui.add_space(50.0);
@@ -53,32 +65,179 @@ pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessa
         .striped(true)
         .show(ui, |ui| {
             ui.label("Today:");
-            ui.label(format!(
-                "{:.1} minutes ({:.1} hours)",
-                today_minutes,
-                today_minutes / 60.0
-            ));
+            ui.label(settings.number_format.minutes_and_hours(today_minutes));
             ui.end_row();
 
             ui.label("Last 7 days:");
-            ui.label(format!(
-                "{:.1} minutes ({:.1} hours)",
-                last_week_minutes,
-                last_week_minutes / 60.0
-            ));
+            ui.label(settings.number_format.minutes_and_hours(last_week_minutes));
             ui.end_row();
 
             ui.label("Total:");
-            ui.label(format!(
-                "{:.1} minutes ({:.1} hours)",
-                total_minutes,
-                total_minutes / 60.0
-            ));
+            ui.label(settings.number_format.minutes_and_hours(total_minutes));
             ui.end_row();
         });
 
+    ui.add_space(10.0);
+
+    ui.horizontal(|ui| {
+        if ui.button("📊 Export to Excel").clicked() {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("Excel workbook", &["xlsx"])
+                .set_file_name("study_report.xlsx")
+                .save_file()
+            {
+                match crate::xlsx_export::export_stats(study_data, &path) {
+                    Ok(()) => status.show(&format!("Exported report to {}", path.display())),
+                    Err(e) => status.error(&format!("Export failed: {}", e)),
+                }
+            }
+        }
+
+        if ui.button("🖨 Weekly PDF Report").clicked() {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("PDF", &["pdf"])
+                .set_file_name("weekly_report.pdf")
+                .save_file()
+            {
+                match crate::pdf_export::export_weekly_report(study_data, &path) {
+                    Ok(()) => status.show(&format!("Exported report to {}", path.display())),
+                    Err(e) => status.error(&format!("Export failed: {}", e)),
+                }
+            }
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let mut year = REVIEW_YEAR.with(Cell::get);
+        ui.label("Year in review:");
+        egui::ComboBox::from_id_source("review_year")
+            .selected_text(year.to_string())
+            .show_ui(ui, |ui| {
+                for option in year_review::available_years(study_data) {
+                    ui.selectable_value(&mut year, option, option.to_string());
+                }
+            });
+        REVIEW_YEAR.with(|cell| cell.set(year));
+
+        if ui.button("📝 Markdown").clicked() {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("Markdown", &["md"])
+                .set_file_name(&format!("year_in_review_{}.md", year))
+                .save_file()
+            {
+                let markdown = year_review::compute(study_data, year).to_markdown();
+                match std::fs::write(&path, markdown) {
+                    Ok(()) => status.show(&format!("Exported report to {}", path.display())),
+                    Err(e) => status.error(&format!("Export failed: {}", e)),
+                }
+            }
+        }
+
+        if ui.button("🖨 PDF").clicked() {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("PDF", &["pdf"])
+                .set_file_name(&format!("year_in_review_{}.pdf", year))
+                .save_file()
+            {
+                let review = year_review::compute(study_data, year);
+                match crate::pdf_export::export_year_review(&review, &path) {
+                    Ok(()) => status.show(&format!("Exported report to {}", path.display())),
+                    Err(e) => status.error(&format!("Export failed: {}", e)),
+                }
+            }
+        }
+    });
+
+    time_export_section(ui, study_data, status, time_export);
+
     ui.add_space(20.0);
 
+    if !study_data.quiz_results.is_empty() {
+        ui.heading("Quiz Results");
+        ui.add_space(10.0);
+
+        egui::Grid::new("quiz_results_grid")
+            .num_columns(3)
+            .spacing([40.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                // Most recent first
+                for result in study_data.quiz_results.iter().rev().take(10) {
+                    ui.label(&result.date);
+                    ui.label(&result.deck_name);
+                    let percent = if result.total > 0 {
+                        result.correct as f32 / result.total as f32 * 100.0
+                    } else {
+                        0.0
+                    };
+                    ui.label(format!(
+                        "{} / {} ({}%)",
+                        result.correct,
+                        result.total,
+                        settings.number_format.format(percent as f64, 0)
+                    ));
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(20.0);
+    }
+
+    let answer_times = study_data.answer_times();
+    if !answer_times.is_empty() {
+        ui.heading("Flashcard Answer Times");
+        ui.add_space(10.0);
+
+        let seconds = |value: f32| format!("{} s", settings.number_format.format(value as f64, 1));
+        egui::Grid::new("answer_times_grid")
+            .num_columns(5)
+            .spacing([40.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for header in ["Deck", "Answers", "Average", "Median", "90th percentile"] {
+                    ui.label(egui::RichText::new(header).strong());
+                }
+                ui.end_row();
+                for deck in &answer_times {
+                    ui.label(&deck.deck_name);
+                    ui.label(settings.number_format.format(deck.answers as f64, 0));
+                    ui.label(seconds(deck.average));
+                    ui.label(seconds(deck.median));
+                    ui.label(seconds(deck.p90));
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(20.0);
+    }
+
+    let lap_totals = study_data.lap_totals();
+    if !lap_totals.is_empty() {
+        ui.heading("Time by Segment");
+        ui.add_space(10.0);
+
+        egui::Grid::new("lap_totals_grid")
+            .num_columns(2)
+            .spacing([40.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for (label, minutes) in &lap_totals {
+                    ui.label(label);
+                    ui.label(settings.number_format.minutes_and_hours(*minutes));
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(20.0);
+    }
+
+    if !study_data.assignments.is_empty() {
+        ui.heading("Course Grades");
+        ui.add_space(10.0);
+        crate::ui::assignments_tab::course_grade_grid(ui, study_data);
+        ui.add_space(20.0);
+    }
+
     // Show edit dialog if needed
     EDIT_STATE.with(|state| {
         if state.borrow().show_dialog {
@@ -119,8 +278,8 @@ pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessa
 
                 for (idx, session) in sessions.iter().enumerate() {
                     ui.label(&session.date);
-                    ui.label(format!("{:.1}", session.minutes));
-                    ui.label(format!("{:.1}", session.minutes / 60.0));
+                    ui.label(settings.number_format.format(session.minutes, 1));
+                    ui.label(settings.number_format.format(session.minutes / 60.0, 1));
                     ui.label(
                         session
                             .description
@@ -145,7 +304,7 @@ pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessa
                             if let Some(real_idx) = find_session_index(study_data, idx, &sessions) {
                                 study_data.sessions.remove(real_idx);
                                 if let Err(e) = study_data.save() {
-                                    status.show(&format!("Error saving: {}", e));
+                                    status.error(&format!("Error saving: {}", e));
                                 } else {
                                     status.show("Session deleted");
                                 }
@@ -157,9 +316,133 @@ pub fn display(ui: &mut Ui, study_data: &mut StudyData, status: &mut StatusMessa
                 }
             });
     });
+}
+
+fn time_export_section(
+    ui: &mut Ui,
+    study_data: &StudyData,
+    status: &mut StatusMessage,
+    time_export: &mut TimeExport,
+) {
+    ui.collapsing("⏱ Send sessions to Toggl / Clockify", |ui| {
+        ui.horizontal(|ui| {
+            let text = egui::RichText::new(time_export.status_text());
+            match time_export.status {
+                SyncStatus::Failed(_) => {
+                    ui.label(text.color(egui::Color32::from_rgb(220, 90, 90)));
+                }
+                _ => {
+                    ui.label(text);
+                }
+            }
+
+            let pending = time_export.pending_count(&study_data.sessions);
+            if ui
+                .add_enabled(
+                    !time_export.is_pushing() && pending > 0,
+                    egui::Button::new(format!("⇪ Send {} now", pending)),
+                )
+                .clicked()
+            {
+                time_export.start(&study_data.sessions);
+            }
+        });
+
+        let config = &mut time_export.config;
+        if ui
+            .checkbox(
+                &mut config.enabled,
+                "Send finished sessions automatically every 5 minutes",
+            )
+            .changed()
+            && config.since.is_empty()
+        {
+            // Only sessions from now on, so turning this on doesn't upload the whole history
+            config.since = Local::now().format("%Y-%m-%d").to_string();
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Service:");
+            egui::ComboBox::from_id_source("time_export_provider")
+                .selected_text(config.provider.name())
+                .show_ui(ui, |ui| {
+                    for provider in [TimeProvider::Toggl, TimeProvider::Clockify] {
+                        let name = provider.name();
+                        ui.selectable_value(&mut config.provider, provider, name);
+                    }
+                });
+        });
+
+        egui::Grid::new("time_export_grid")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("API token:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut config.api_token)
+                        .password(true)
+                        .desired_width(250.0),
+                );
+                ui.end_row();
+                ui.label("Workspace ID:");
+                ui.add(egui::TextEdit::singleline(&mut config.workspace_id).desired_width(150.0));
+                ui.end_row();
+                ui.label("Default project ID:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut config.default_project_id)
+                        .hint_text("Optional")
+                        .desired_width(150.0),
+                );
+                ui.end_row();
+                ui.label("Send sessions from:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut config.since)
+                        .hint_text("YYYY-MM-DD")
+                        .desired_width(100.0),
+                );
+                ui.end_row();
+            });
+
+        ui.add_space(5.0);
+        ui.label("Subjects → project IDs:");
+        let mut remove_mapping = None;
+        for (index, mapping) in config.mappings.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.add(egui::TextEdit::singleline(&mut mapping.subject).desired_width(120.0));
+                ui.label("→");
+                ui.add(egui::TextEdit::singleline(&mut mapping.project_id).desired_width(150.0));
+                if ui.button("🗑").clicked() {
+                    remove_mapping = Some(index);
+                }
+            });
+        }
+        if let Some(index) = remove_mapping {
+            config.mappings.remove(index);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("➕ Add mapping").clicked() {
+                config.mappings.push(ProjectMapping {
+                    subject: String::new(),
+                    project_id: String::new(),
+                });
+            }
 
-    // Status message
-    status.render(ui);
+            if ui.button("💾 Save export settings").clicked() {
+                if let Err(e) = config.save() {
+                    status.error(&format!("Error saving export settings: {}", e));
+                } else {
+                    status.show("Export settings saved");
+                }
+            }
+        });
+
+        ui.label(
+            egui::RichText::new(
+                "The session description is matched against the subjects. Each timer run becomes one time entry; sessions entered by hand start at 09:00.",
+            )
+            .small(),
+        );
+    });
 }
 
 fn find_session_index(
@@ -250,15 +533,15 @@ fn render_edit_dialog(ui: &mut Ui, study_data: &mut StudyData, status: &mut Stat
 
                                     // Save data
                                     if let Err(e) = study_data.save() {
-                                        status.show(&format!("Error saving: {}", e));
+                                        status.error(&format!("Error saving: {}", e));
                                     } else {
                                         status.show("Session updated successfully");
                                     }
                                 } else {
-                                    status.show("Minutes must be greater than zero");
+                                    status.warn("Minutes must be greater than zero");
                                 }
                             } else {
-                                status.show("Invalid minutes value");
+                                status.warn("Invalid minutes value");
                             }
                         }
                     }
@@ -291,4 +574,3 @@ fn render_edit_dialog(ui: &mut Ui, study_data: &mut StudyData, status: &mut Stat
         });
     }
 }
-
@@ -5,8 +5,16 @@ pub fn display(
     ui: &mut egui::Ui,
     terminal: &mut TerminalEmulator,
     _status: &mut crate::app::StatusMessage,
+    font_size: f32,
 ) {
     ui.vertical(|ui| {
+        // Follows the editor & terminal font from settings
+        ui.style_mut().text_styles.insert(
+            egui::TextStyle::Monospace,
+            egui::FontId::monospace(font_size),
+        );
+        ui.style_mut().override_text_style = Some(egui::TextStyle::Monospace);
+
         // Directory header
         ui.horizontal(|ui| {
             ui.label(RichText::new("Current directory: ").strong());
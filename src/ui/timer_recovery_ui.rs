@@ -0,0 +1,112 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use crate::timer::Timer;
+use crate::timer_recovery::TimerRecovery;
+use crate::ui::timer_tab;
+use eframe::egui;
+
+// Offers to resume or log a session the app closed in the middle of
+pub fn display(
+    ctx: &egui::Context,
+    recovery: &mut TimerRecovery,
+    timer: &mut Timer,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+) {
+    let Some(snapshot) = recovery.recovered.clone() else {
+        return;
+    };
+    let mut done = false;
+
+    egui::Window::new("⏱ Unfinished Session")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.set_min_width(320.0);
+            if snapshot.was_running() {
+                ui.label("FocusPad closed while the timer was running.");
+            } else {
+                ui.label("FocusPad closed with a paused session that wasn't saved.");
+            }
+            ui.add_space(6.0);
+
+            if !snapshot.description.is_empty() {
+                ui.label(format!("Subject: {}", snapshot.description));
+            }
+            if let Some(started) = snapshot.started_at() {
+                ui.label(format!(
+                    "Running since {}",
+                    started.format("%Y-%m-%d %H:%M")
+                ));
+            }
+            ui.label(format!(
+                "Time on the timer: {:.0} minutes",
+                snapshot.elapsed_minutes()
+            ));
+            ui.add_space(6.0);
+
+            ui.horizontal(|ui| {
+                ui.label("Minutes to log:");
+                ui.add(
+                    egui::DragValue::new(&mut recovery.log_minutes)
+                        .clamp_range(0.0..=1440.0)
+                        .speed(1.0),
+                );
+            });
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui
+                    .button("▶ Resume")
+                    .on_hover_text("Carry on with the timer where it left off")
+                    .clicked()
+                {
+                    timer_tab::resume(timer, &snapshot);
+                    status.show("Timer resumed");
+                    done = true;
+                }
+                if ui
+                    .add_enabled(
+                        recovery.log_minutes > 0.0,
+                        egui::Button::new(format!("💾 Log {:.0} min", recovery.log_minutes)),
+                    )
+                    .clicked()
+                {
+                    let description = Some(snapshot.description.clone()).filter(|d| !d.is_empty());
+                    let minutes = recovery.log_minutes;
+                    let notes = snapshot.notes.clone();
+                    let laps = snapshot.laps.clone();
+                    // Booked on the day it started, unless a paused session has no start left
+                    let saved = match snapshot.session_start() {
+                        Some(start) => study_data.add_timed_session_from(
+                            start.naive_local(),
+                            minutes,
+                            description.clone(),
+                            notes,
+                            laps,
+                        ),
+                        None => {
+                            study_data.add_timed_session(minutes, description.clone(), notes, laps)
+                        }
+                    };
+                    match saved {
+                        Ok(()) => {
+                            timer_tab::emit_session_finished(minutes, description);
+                            status.show(&format!("Saved {:.0} minutes", minutes));
+                        }
+                        Err(e) => status.error(&format!("Error saving: {}", e)),
+                    }
+                    done = true;
+                }
+                if ui.button("🗑 Discard").clicked() {
+                    status.show("Unfinished session discarded");
+                    done = true;
+                }
+            });
+        });
+
+    if done {
+        recovery.dismiss();
+    }
+}
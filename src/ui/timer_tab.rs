@@ -1,8 +1,10 @@
 use crate::app::StatusMessage;
-use crate::data::StudyData;
+use crate::data::{Lap, StudyData};
 use crate::debug::DebugTools;
+use crate::hooks::{self, HookEvent};
+use crate::settings::AppSettings;
 use crate::timer::Timer;
-use chrono::Local;
+use crate::timer_recovery::TimerSnapshot;
 use eframe::egui::{self, Ui};
 use std::process::{Child, Command};
 use std::time::{Duration, Instant};
@@ -19,6 +21,23 @@ thread_local! {
     static AUDIO_PROCESS: std::cell::RefCell<Option<Child>> = std::cell::RefCell::new(None);
     // Flag to indicate if alarm is currently playing
     static ALARM_PLAYING: std::cell::RefCell<bool> = std::cell::RefCell::new(false);
+    // Whether the "target reached" toast for the current time block has been shown
+    static TARGET_ANNOUNCED: std::cell::RefCell<bool> = std::cell::RefCell::new(false);
+    // Notes edited since the timer started, saved with the session
+    static EDITED_NOTES: std::cell::RefCell<Vec<String>> = std::cell::RefCell::new(Vec::new());
+    // Laps marked so far in the current session, and the label for the next one
+    static LAPS: std::cell::RefCell<Vec<Lap>> = std::cell::RefCell::new(Vec::new());
+    static LAP_LABEL: std::cell::RefCell<String> = std::cell::RefCell::new(String::new());
+    // Interval that auto-starts once the countdown overlay runs out
+    static PENDING_START: std::cell::RefCell<Option<(NextInterval, Instant)>> = std::cell::RefCell::new(None);
+}
+
+const AUTO_START_COUNTDOWN: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, PartialEq)]
+enum NextInterval {
+    Break,
+    Work,
 }
 
 pub fn display(
@@ -27,42 +46,16 @@ pub fn display(
     study_data: &mut StudyData,
     debug_tools: &mut DebugTools,
     status: &mut StatusMessage,
+    settings: &AppSettings,
 ) {
     let elapsed_minutes = timer.get_elapsed_minutes();
     let hours = (elapsed_minutes as i32) / 60;
     let minutes = (elapsed_minutes as i32) % 60;
     let seconds = ((elapsed_minutes * 60.0) as i32) % 60;
 
-    // Check if break timer has ended
-    let break_ended = BREAK_END_TIME.with(|break_end_time| {
-        let mut break_end_cell = break_end_time.borrow_mut();
-        if let Some(end_time) = *break_end_cell {
-            if end_time <= Instant::now() {
-                // Break has ended
-                *break_end_cell = None;
-                return true;
-            }
-        }
-        false
-    });
-
-    if break_ended {
-        // Play alarm sound
-        if play_alarm_sound() {
-            status.show("🔔 Break ended! Time to study again!");
-        } else {
-            status.show("🔔 Break ended! (Failed to play alarm)");
-        }
-
-        // Auto-start the timer again if it was paused
-        if !timer.is_running {
-            timer.start();
-        }
-    }
-
     // Request frequent repaint if on break to update the timer display
     if BREAK_END_TIME.with(|break_end_time| break_end_time.borrow().is_some()) {
-        ui.ctx().request_repaint();
+        settings.request_clock_repaint(ui.ctx());
     }
 
     // Display the timer in large font
@@ -77,6 +70,12 @@ pub fn display(
                 let secs = remaining.as_secs() % 60;
 
                 ui.heading(format!("BREAK: {:02}:{:02} remaining", mins, secs));
+                let back_at =
+                    chrono::Local::now() + chrono::Duration::seconds(remaining.as_secs() as i64);
+                ui.label(format!(
+                    "Back to work at {}",
+                    settings.clock_format.format(back_at.time())
+                ));
                 true
             } else {
                 ui.heading(format!("{:02}:{:02}:{:02}", hours, minutes, seconds));
@@ -84,6 +83,22 @@ pub fn display(
             }
         });
 
+        if let Some(target) = timer.target {
+            let progress = timer.get_elapsed_time().as_secs_f32() / target.as_secs_f32().max(1.0);
+            ui.add(
+                egui::ProgressBar::new(progress.min(1.0))
+                    .desired_width(240.0)
+                    .text(format!(
+                        "Target {}h {:02}m",
+                        target.as_secs() / 3600,
+                        (target.as_secs() % 3600) / 60
+                    )),
+            );
+            if progress >= 1.0 && !TARGET_ANNOUNCED.with(|a| a.replace(true)) {
+                status.show("🎯 Planned block complete");
+            }
+        }
+
         ui.add_space(30.0);
     });
 
@@ -108,6 +123,45 @@ pub fn display(
         });
     });
 
+    // Laps split the session into labeled segments
+    ui.horizontal(|ui| {
+        ui.label("Segment:");
+        LAP_LABEL.with(|label| {
+            ui.add(
+                egui::TextEdit::singleline(&mut *label.borrow_mut())
+                    .hint_text("e.g. reading")
+                    .desired_width(140.0),
+            );
+        });
+        let lap_minutes = timer.get_elapsed_minutes() - lapped_minutes();
+        if ui
+            .add_enabled(lap_minutes > 0.0, egui::Button::new("🏁 Lap"))
+            .on_hover_text("End this segment and start the next")
+            .clicked()
+        {
+            let lap = close_lap(lap_minutes, LAPS.with(|laps| laps.borrow().len()) + 1);
+            status.show(&format!(
+                "{}: {} minutes",
+                lap.label,
+                settings.number_format.format(lap.minutes, 1)
+            ));
+            LAPS.with(|laps| laps.borrow_mut().push(lap));
+        }
+    });
+    LAPS.with(|laps| {
+        for (index, lap) in laps.borrow().iter().enumerate() {
+            ui.label(
+                egui::RichText::new(format!(
+                    "{}. {} — {} min",
+                    index + 1,
+                    lap.label,
+                    settings.number_format.format(lap.minutes, 1)
+                ))
+                .small(),
+            );
+        }
+    });
+
     ui.add_space(10.0);
 
     // Control buttons
@@ -122,74 +176,40 @@ pub fn display(
                     }
                 } else {
                     if ui.button("▶ Start").clicked() {
-                        timer.start();
+                        start_work(timer, settings);
                         status.show("Timer started");
                     }
                 }
 
                 if ui.button("💾 Save").clicked() {
-                    let minutes = timer.get_elapsed_minutes();
-                    if minutes > 0.0 {
-                        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
-
-                        // Get the description
-                        let description = DESCRIPTION.with(|desc| {
-                            let desc = desc.borrow();
-                            if desc.is_empty() {
-                                None
-                            } else {
-                                Some(desc.clone())
-                            }
-                        });
-
-                        if let Err(e) = study_data.add_session(today, minutes, description) {
-                            status.show(&format!("Error saving: {}", e));
-                        } else {
-                            status.show(&format!("Saved {:.1} minutes to today's total", minutes));
-                            // Reset accumulated time but keep running if it was running
-                            let was_running = timer.is_running;
-                            timer.reset();
-                            if was_running {
-                                timer.start();
-                            }
-
-                            // Clear description
-                            DESCRIPTION.with(|desc| {
-                                *desc.borrow_mut() = String::new();
-                            });
-                        }
-                    } else {
+                    if timer.get_elapsed_minutes() <= 0.0 {
                         status.show("No time to save");
+                    } else if save_elapsed(timer, study_data, status) {
+                        // Reset accumulated time but keep running if it was running
+                        let was_running = timer.is_running;
+                        timer.reset();
+                        if was_running {
+                            timer.start();
+                        }
+
+                        // Clear description
+                        DESCRIPTION.with(|desc| {
+                            *desc.borrow_mut() = String::new();
+                        });
                     }
                 }
 
                 if ui.button("⏹ Stop").clicked() {
-                    let minutes = timer.get_elapsed_minutes();
-                    if minutes > 0.0 {
-                        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
-
-                        // Get the description
-                        let description = DESCRIPTION.with(|desc| {
-                            let desc = desc.borrow();
-                            if desc.is_empty() {
-                                None
-                            } else {
-                                Some(desc.clone())
-                            }
+                    if save_elapsed(timer, study_data, status) {
+                        // Clear description
+                        DESCRIPTION.with(|desc| {
+                            *desc.borrow_mut() = String::new();
                         });
-
-                        if let Err(e) = study_data.add_session(today, minutes, description) {
-                            status.show(&format!("Error saving: {}", e));
-                        } else {
-                            status.show(&format!("Saved {:.1} minutes to today's total", minutes));
-
-                            // Clear description
-                            DESCRIPTION.with(|desc| {
-                                *desc.borrow_mut() = String::new();
-                            });
-                        }
                     }
                     timer.reset();
+                    timer.target = None;
+                    take_edited_notes();
+                    take_laps();
                     status.show("Timer stopped and reset");
                 }
             },
@@ -204,13 +224,26 @@ pub fn display(
     let on_break = BREAK_END_TIME.with(|break_end_time| break_end_time.borrow().is_some());
 
     if on_break {
-        // Show cancel button if on break
-        if ui.button("⏹ Cancel Break").clicked() {
-            BREAK_END_TIME.with(|break_end_time| {
-                *break_end_time.borrow_mut() = None;
-            });
-            status.show("Break cancelled");
-        }
+        ui.horizontal(|ui| {
+            // Show cancel button if on break
+            if ui.button("⏹ Cancel Break").clicked() {
+                BREAK_END_TIME.with(|break_end_time| {
+                    *break_end_time.borrow_mut() = None;
+                });
+                status.show("Break cancelled");
+            }
+            if ui
+                .button("⏭ Skip Break")
+                .on_hover_text("End the break and start studying")
+                .clicked()
+            {
+                BREAK_END_TIME.with(|break_end_time| {
+                    *break_end_time.borrow_mut() = None;
+                });
+                start_work(timer, settings);
+                status.show("Break skipped");
+            }
+        });
     } else {
         // Break buttons row
         ui.horizontal(|ui| {
@@ -281,7 +314,7 @@ pub fn display(
                     if play_alarm_sound() {
                         status.show("🔔 Testing alarm sound!");
                     } else {
-                        status.show("⚠️ Failed to play alarm sound!");
+                        status.error("⚠️ Failed to play alarm sound!");
                     }
                 }
 
@@ -305,11 +338,7 @@ pub fn display(
 
     ui.vertical_centered(|ui| {
         ui.label("Today's total study time:");
-        ui.label(format!(
-            "{:.1} minutes ({:.1} hours)",
-            today_minutes,
-            today_minutes / 60.0
-        ));
+        ui.label(settings.number_format.minutes_and_hours(today_minutes));
     });
 
     ui.add_space(20.0);
@@ -318,9 +347,247 @@ pub fn display(
     if let Some(debug_message) = debug_tools.ui(ui, timer) {
         status.show(&debug_message);
     }
+}
+
+// Subject typed into the description field, shared with the Discord presence
+pub fn current_description() -> String {
+    DESCRIPTION.with(|desc| desc.borrow().clone())
+}
+
+// Runs every frame so breaks and work intervals roll over whichever tab is open
+pub fn update_intervals(
+    ctx: &egui::Context,
+    timer: &mut Timer,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    settings: &AppSettings,
+) {
+    // Check if break timer has ended
+    let break_ended = BREAK_END_TIME.with(|break_end_time| {
+        let mut break_end_cell = break_end_time.borrow_mut();
+        if let Some(end_time) = *break_end_cell {
+            if end_time <= Instant::now() {
+                // Break has ended
+                *break_end_cell = None;
+                return true;
+            }
+        }
+        false
+    });
+
+    if break_ended {
+        // Play alarm sound
+        if play_alarm_sound() {
+            status.show("🔔 Break ended! Time to study again!");
+        } else {
+            status.error("🔔 Break ended! (Failed to play alarm)");
+        }
+
+        if settings.auto_start_work && !timer.is_running {
+            schedule_start(NextInterval::Work);
+        }
+    }
+
+    let on_break = BREAK_END_TIME.with(|break_end_time| break_end_time.borrow().is_some());
+    if on_break {
+        settings.request_clock_repaint(ctx);
+    }
+
+    let interval_done = timer.is_running
+        && timer
+            .target
+            .map_or(false, |target| timer.get_elapsed_time() >= target);
+    let pending = PENDING_START.with(|p| *p.borrow());
+    if interval_done && settings.auto_start_breaks && !on_break && pending.is_none() {
+        schedule_start(NextInterval::Break);
+    }
+
+    let Some((next, at)) = PENDING_START.with(|p| *p.borrow()) else {
+        return;
+    };
+    // Drop the countdown if the timer was paused or started by hand meanwhile
+    if (next == NextInterval::Break) != timer.is_running {
+        PENDING_START.with(|p| *p.borrow_mut() = None);
+        return;
+    }
+    ctx.request_repaint_after(Duration::from_millis(250));
+
+    let remaining = at.saturating_duration_since(Instant::now());
+    let mut start_now = remaining.is_zero();
+    let mut skip = false;
+    egui::Area::new("auto_start_countdown")
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 60.0])
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                let seconds = remaining.as_secs() + 1;
+                ui.heading(match next {
+                    NextInterval::Break => format!("☕ Break starts in {}", seconds),
+                    NextInterval::Work => format!("📚 Next session starts in {}", seconds),
+                });
+                ui.horizontal(|ui| {
+                    if ui.button("▶ Start Now").clicked() {
+                        start_now = true;
+                    }
+                    let skip_label = match next {
+                        NextInterval::Break => "⏭ Skip Break",
+                        NextInterval::Work => "✖ Not Yet",
+                    };
+                    if ui.button(skip_label).clicked() {
+                        skip = true;
+                    }
+                });
+            });
+        });
+    if !start_now && !skip {
+        return;
+    }
+
+    PENDING_START.with(|p| *p.borrow_mut() = None);
+    match (next, skip) {
+        (NextInterval::Break, false) => {
+            save_elapsed(timer, study_data, status);
+            timer.reset();
+            start_break(settings.break_minutes.max(1) as u64, status);
+        }
+        (NextInterval::Break, true) => {
+            save_elapsed(timer, study_data, status);
+            timer.reset();
+            start_work(timer, settings);
+            status.show("Break skipped");
+        }
+        (NextInterval::Work, false) => {
+            start_work(timer, settings);
+            status.show("Timer started");
+        }
+        (NextInterval::Work, true) => status.show("Start the timer when you're ready"),
+    }
+}
+
+fn schedule_start(next: NextInterval) {
+    PENDING_START.with(|p| *p.borrow_mut() = Some((next, Instant::now() + AUTO_START_COUNTDOWN)));
+}
+
+// Starts or resumes studying, as one work interval when those are set up
+fn start_work(timer: &mut Timer, settings: &AppSettings) {
+    if timer.target.is_none() && settings.work_interval_minutes > 0 {
+        timer.target = Some(Duration::from_secs(
+            settings.work_interval_minutes as u64 * 60,
+        ));
+        TARGET_ANNOUNCED.with(|a| *a.borrow_mut() = false);
+    }
+    timer.start();
+    emit_session_started();
+}
+
+// Logs the timer's time under the current description; false if nothing was saved
+fn save_elapsed(timer: &Timer, study_data: &mut StudyData, status: &mut StatusMessage) -> bool {
+    let minutes = timer.get_elapsed_minutes();
+    if minutes <= 0.0 {
+        return false;
+    }
+    let description = DESCRIPTION.with(|desc| {
+        let desc = desc.borrow();
+        if desc.is_empty() {
+            None
+        } else {
+            Some(desc.clone())
+        }
+    });
+
+    let notes = take_edited_notes();
+    let mut laps = take_laps();
+    let lapped: f64 = laps.iter().map(|lap| lap.minutes).sum();
+    if !laps.is_empty() && minutes > lapped {
+        // The time since the last lap is a segment of its own
+        laps.push(close_lap(minutes - lapped, laps.len() + 1));
+    }
+    if let Err(e) = study_data.add_timed_session(minutes, description.clone(), notes, laps) {
+        status.error(&format!("Error saving: {}", e));
+        return false;
+    }
+    emit_session_finished(minutes, description);
+    status.show(&format!("Saved {:.1} minutes to today's total", minutes));
+    true
+}
+
+// Links a note to the running session
+pub fn note_edited(path: String) {
+    EDITED_NOTES.with(|notes| {
+        let mut notes = notes.borrow_mut();
+        if !notes.contains(&path) {
+            notes.push(path);
+        }
+    });
+}
+
+fn lapped_minutes() -> f64 {
+    LAPS.with(|laps| laps.borrow().iter().map(|lap| lap.minutes).sum())
+}
+
+// Ends the current segment under the typed label, or "Lap <number>"
+fn close_lap(minutes: f64, number: usize) -> Lap {
+    let typed = LAP_LABEL.with(|label| std::mem::take(&mut *label.borrow_mut()));
+    let label = if typed.trim().is_empty() {
+        format!("Lap {}", number)
+    } else {
+        typed.trim().to_string()
+    };
+    Lap { label, minutes }
+}
+
+pub fn laps() -> Vec<Lap> {
+    LAPS.with(|laps| laps.borrow().clone())
+}
+
+fn take_laps() -> Vec<Lap> {
+    LAPS.with(|laps| std::mem::take(&mut *laps.borrow_mut()))
+}
+
+pub fn edited_notes() -> Vec<String> {
+    EDITED_NOTES.with(|notes| notes.borrow().clone())
+}
+
+// Picks up a session left over from before the app closed
+pub fn resume(timer: &mut Timer, snapshot: &TimerSnapshot) {
+    DESCRIPTION.with(|desc| *desc.borrow_mut() = snapshot.description.clone());
+    EDITED_NOTES.with(|notes| *notes.borrow_mut() = snapshot.notes.clone());
+    LAPS.with(|laps| *laps.borrow_mut() = snapshot.laps.clone());
+    timer.reset();
+    timer.accumulated_time = Duration::from_secs_f64(snapshot.elapsed_minutes() * 60.0);
+    timer.target = snapshot.target_secs.map(Duration::from_secs);
+    if snapshot.was_running() {
+        timer.start();
+        emit_session_started();
+    }
+}
+
+fn take_edited_notes() -> Vec<String> {
+    EDITED_NOTES.with(|notes| std::mem::take(&mut *notes.borrow_mut()))
+}
+
+// Starts the timer for a planned time block with its subject and length filled in
+pub fn start_planned(timer: &mut Timer, subject: &str, minutes: f64) {
+    DESCRIPTION.with(|desc| *desc.borrow_mut() = subject.to_string());
+    TARGET_ANNOUNCED.with(|a| *a.borrow_mut() = false);
+    timer.target = Some(Duration::from_secs_f64(minutes * 60.0));
+    timer.start();
+    emit_session_started();
+}
+
+fn emit_session_started() {
+    let subject = DESCRIPTION.with(|desc| desc.borrow().clone());
+    hooks::emit(HookEvent::SessionStarted, &[("subject", subject)]);
+}
 
-    // Status message
-    status.render(ui);
+pub fn emit_session_finished(minutes: f64, description: Option<String>) {
+    hooks::emit(
+        HookEvent::SessionFinished,
+        &[
+            ("subject", description.unwrap_or_default()),
+            ("minutes", format!("{:.1}", minutes)),
+        ],
+    );
 }
 
 // Helper function to start a break
@@ -1,6 +1,12 @@
 use crate::app::StatusMessage;
-use crate::data::StudyData;
-use crate::settings::AppSettings;
+use crate::data::{HabitPause, StudyData, TodoPriority};
+use crate::quick_add;
+use crate::settings::{AppSettings, WeekStart};
+use crate::task_sync::{ConflictPolicy, ListMapping, SyncProvider, SyncStatus, TaskSync};
+use crate::ui::eisenhower_matrix;
+use crate::ui::habit_review;
+use crate::ui::habit_stats;
+use crate::ui::rich_text::linkified_label;
 use chrono::{Datelike, Duration, Local, NaiveDate};
 use egui::{ComboBox, ScrollArea, TextEdit, Window};
 use std::cell::RefCell;
@@ -9,6 +15,7 @@ use std::collections::HashMap;
 // We'll use thread-local storage instead of once_cell
 thread_local! {
     static NEW_TODO: RefCell<String> = RefCell::new(String::new());
+    static NEW_TODO_PROJECT: RefCell<String> = RefCell::new(String::new());
     static NEW_HABIT: RefCell<String> = RefCell::new(String::new());
     static NEW_HABIT_CATEGORY: RefCell<String> = RefCell::new(String::from("General"));
     static EDITING_MAP: RefCell<HashMap<u64, String>> = RefCell::new(HashMap::new());
@@ -16,11 +23,15 @@ thread_local! {
     static SELECTED_CATEGORY_FILTER: RefCell<String> = RefCell::new(String::from("All"));
     static MONTHLY_VIEW_HABIT: RefCell<Option<u64>> = RefCell::new(None);
     static MONTHLY_VIEW_DATE: RefCell<NaiveDate> = RefCell::new(Local::now().date_naive());
+    static PAUSE_START: RefCell<String> = RefCell::new(String::new());
+    static PAUSE_END: RefCell<String> = RefCell::new(String::new());
+    static DRAGGED_HABIT: RefCell<Option<u64>> = RefCell::new(None);
 }
 
 #[derive(Debug, Clone, PartialEq)]
 enum HabitTab {
     Todos,
+    Matrix,
     Habits,
 }
 
@@ -28,16 +39,27 @@ impl HabitTab {
     fn as_str(&self) -> &str {
         match self {
             HabitTab::Todos => "Todos",
+            HabitTab::Matrix => "Matrix",
             HabitTab::Habits => "Habits",
         }
     }
 }
 
+// Used by the tab bar agenda to land on the right section
+pub fn show_todos() {
+    SELECTED_TAB.with(|tab| *tab.borrow_mut() = HabitTab::Todos);
+}
+
+pub fn show_habits() {
+    SELECTED_TAB.with(|tab| *tab.borrow_mut() = HabitTab::Habits);
+}
+
 pub fn display(
     ui: &mut egui::Ui,
     study_data: &mut StudyData,
     status: &mut StatusMessage,
     settings: &AppSettings,
+    task_sync: &mut TaskSync,
 ) {
     let colors = settings.get_current_colors();
 
@@ -48,7 +70,7 @@ pub fn display(
         SELECTED_TAB.with(|tab_ref| {
             let mut current_tab = tab_ref.borrow_mut();
 
-            for tab in [HabitTab::Todos, HabitTab::Habits].iter() {
+            for tab in [HabitTab::Todos, HabitTab::Matrix, HabitTab::Habits].iter() {
                 let is_selected = *current_tab == *tab;
                 let button_color = if is_selected {
                     colors.active_tab_color32()
@@ -74,16 +96,18 @@ pub fn display(
     SELECTED_TAB.with(|tab_ref| {
         let current_tab = tab_ref.borrow();
         match *current_tab {
-            HabitTab::Todos => display_todos(ui, study_data, status, settings),
+            HabitTab::Todos => display_todos(ui, study_data, status, settings, task_sync),
+            HabitTab::Matrix => {
+                eisenhower_matrix::display(ui, study_data, status, &settings.get_current_colors())
+            }
             HabitTab::Habits => display_habits(ui, study_data, status, settings),
         }
     });
 
     // Show monthly view popup if a habit is selected
-    display_monthly_view_popup(ui, study_data, settings);
-
-    // Show status message
-    status.render(ui);
+    display_monthly_view_popup(ui, study_data, status, settings);
+    habit_stats::display(ui.ctx(), study_data, &settings.get_current_colors());
+    habit_review::display(ui.ctx(), study_data, status, &settings.get_current_colors());
 }
 
 fn display_todos(
@@ -91,52 +115,76 @@ fn display_todos(
     study_data: &mut StudyData,
     status: &mut StatusMessage,
     settings: &AppSettings,
+    task_sync: &mut TaskSync,
 ) {
     let colors = settings.get_current_colors();
 
-    // Add new todo section with themed colors
+    // Add new todo section with themed colors. The text understands quick-add syntax,
+    // e.g. "submit lab report !high #physics fri 5pm"
     ui.horizontal(|ui| {
         ui.label(egui::RichText::new("New Task:").color(colors.text_secondary_color32()));
 
         // Use thread_local with with() to access the value
         NEW_TODO.with(|todo_ref| {
-            let mut new_todo = todo_ref.borrow_mut();
-
-            let text_edit = ui.add(
-                TextEdit::singleline(&mut *new_todo)
-                    .hint_text("Enter a new task...")
-                    .desired_width(280.0)
-                    .text_color(colors.text_primary_color32()),
-            );
-
-            if text_edit.lost_focus()
-                && ui.input(|i| i.key_pressed(egui::Key::Enter))
-                && !new_todo.is_empty()
-            {
-                if let Err(e) = study_data.add_todo(new_todo.clone()) {
-                    status.show(&format!("Error adding todo: {}", e));
-                } else {
-                    status.show("Todo added successfully!");
-                    new_todo.clear();
-                }
-            }
-
-            let add_button =
-                egui::Button::new(egui::RichText::new("Add").color(colors.text_primary_color32()))
-                    .fill(colors.accent_color32())
-                    .stroke(egui::Stroke::new(1.0, colors.active_tab_color32()));
-
-            if ui.add(add_button).clicked() && !new_todo.is_empty() {
-                if let Err(e) = study_data.add_todo(new_todo.clone()) {
-                    status.show(&format!("Error adding todo: {}", e));
-                } else {
-                    status.show("Todo added successfully!");
-                    new_todo.clear();
+            NEW_TODO_PROJECT.with(|project_ref| {
+                let mut new_todo = todo_ref.borrow_mut();
+                let mut new_project = project_ref.borrow_mut();
+
+                let text_edit = ui.add(
+                    TextEdit::singleline(&mut *new_todo)
+                        .hint_text("Enter a new task... (!high #project fri 5pm)")
+                        .desired_width(280.0)
+                        .text_color(colors.text_primary_color32()),
+                );
+
+                ui.add(
+                    TextEdit::singleline(&mut *new_project)
+                        .hint_text("Project")
+                        .desired_width(90.0)
+                        .text_color(colors.text_primary_color32()),
+                );
+
+                let add_button = egui::Button::new(
+                    egui::RichText::new("Add").color(colors.text_primary_color32()),
+                )
+                .fill(colors.accent_color32())
+                .stroke(egui::Stroke::new(1.0, colors.active_tab_color32()));
+
+                let submitted =
+                    text_edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                let add_clicked = ui.add(add_button).clicked();
+                if (submitted || add_clicked) && !new_todo.is_empty() {
+                    let parsed = quick_add::parse(&new_todo);
+                    // The project field wins over a #tag in the text
+                    let project = if new_project.trim().is_empty() {
+                        parsed.project.clone()
+                    } else {
+                        Some(new_project.trim().to_string())
+                    };
+
+                    if parsed.title.is_empty() {
+                        status.warn("The task needs a title besides its date and tags");
+                    } else if let Err(e) = study_data.add_todo_with_details(
+                        parsed.title.clone(),
+                        project,
+                        parsed.priority.unwrap_or_default(),
+                        parsed.due_date_string(),
+                        parsed.due_time_string(),
+                    ) {
+                        status.error(&format!("Error adding todo: {}", e));
+                    } else {
+                        status.show("Todo added successfully!");
+                        new_todo.clear();
+                    }
                 }
-            }
+            });
         });
     });
 
+    NEW_TODO.with(|todo_ref| {
+        quick_add::parse(&todo_ref.borrow()).preview(ui, colors.accent_color32());
+    });
+
     ui.separator();
 
     // Buttons for clearing todos with themed colors
@@ -149,9 +197,25 @@ fn display_todos(
 
         if ui.add(clear_completed_button).clicked() {
             if let Err(e) = study_data.clear_completed_todos() {
-                status.show(&format!("Error clearing completed todos: {}", e));
-            } else {
-                status.show("Completed todos cleared!");
+                status.error(&format!("Error clearing completed todos: {}", e));
+            }
+        }
+
+        let archive_button = egui::Button::new(
+            egui::RichText::new("📦 Archive Completed").color(colors.text_primary_color32()),
+        )
+        .fill(colors.inactive_tab_color32())
+        .stroke(egui::Stroke::new(1.0, colors.accent_color32()));
+
+        if ui
+            .add(archive_button)
+            .on_hover_text("Keep them out of the list without deleting them")
+            .clicked()
+        {
+            match study_data.archive_completed_todos() {
+                Ok(0) => status.show("No completed todos to archive"),
+                Ok(count) => status.show(&format!("Archived {} todo(s)", count)),
+                Err(e) => status.error(&format!("Error archiving todos: {}", e)),
             }
         }
 
@@ -163,18 +227,159 @@ fn display_todos(
 
         if ui.add(clear_all_button).clicked() {
             if let Err(e) = study_data.clear_todos() {
-                status.show(&format!("Error clearing todos: {}", e));
-            } else {
-                status.show("All todos cleared!");
+                status.error(&format!("Error clearing todos: {}", e));
             }
         }
     });
 
+    display_sync_bar(ui, study_data, status, &colors, task_sync);
+
     ui.separator();
 
     display_todo_list(ui, study_data, status, &colors);
 }
 
+fn display_sync_bar(
+    ui: &mut egui::Ui,
+    study_data: &StudyData,
+    status: &mut StatusMessage,
+    colors: &crate::settings::ColorTheme,
+    task_sync: &mut TaskSync,
+) {
+    ui.horizontal(|ui| {
+        let status_color = match task_sync.status {
+            SyncStatus::Failed(_) => egui::Color32::from_rgb(220, 90, 90),
+            _ => colors.text_secondary_color32(),
+        };
+        ui.label(egui::RichText::new(task_sync.status_text()).color(status_color));
+
+        let sync_button = egui::Button::new(
+            egui::RichText::new("⟳ Sync now").color(colors.text_primary_color32()),
+        )
+        .fill(colors.inactive_tab_color32())
+        .stroke(egui::Stroke::new(1.0, colors.accent_color32()));
+
+        if ui
+            .add_enabled(!task_sync.is_syncing(), sync_button)
+            .clicked()
+        {
+            task_sync.start(&study_data.todos);
+        }
+    });
+
+    ui.collapsing("☁ Sync settings", |ui| {
+        let config = &mut task_sync.config;
+
+        ui.checkbox(&mut config.enabled, "Sync automatically every 15 minutes");
+
+        ui.horizontal(|ui| {
+            ui.label("Provider:");
+            ComboBox::from_id_source("sync_provider")
+                .selected_text(config.provider.name())
+                .show_ui(ui, |ui| {
+                    for provider in [SyncProvider::GoogleTasks, SyncProvider::CalDav] {
+                        let name = provider.name();
+                        ui.selectable_value(&mut config.provider, provider, name);
+                    }
+                });
+        });
+
+        match config.provider {
+            SyncProvider::GoogleTasks => {
+                ui.horizontal(|ui| {
+                    ui.label("Access token:");
+                    ui.add(
+                        TextEdit::singleline(&mut config.google_access_token)
+                            .password(true)
+                            .desired_width(250.0),
+                    );
+                });
+                ui.label(
+                    egui::RichText::new(
+                        "OAuth token with the tasks scope. Use \"@default\" as the list for your main list.",
+                    )
+                    .small()
+                    .color(colors.text_secondary_color32()),
+                );
+            }
+            SyncProvider::CalDav => {
+                egui::Grid::new("caldav_grid").num_columns(2).show(ui, |ui| {
+                    ui.label("Calendar home URL:");
+                    ui.add(TextEdit::singleline(&mut config.caldav_url).desired_width(250.0));
+                    ui.end_row();
+                    ui.label("Username:");
+                    ui.add(
+                        TextEdit::singleline(&mut config.caldav_username).desired_width(150.0),
+                    );
+                    ui.end_row();
+                    ui.label("Password:");
+                    ui.add(
+                        TextEdit::singleline(&mut config.caldav_password)
+                            .password(true)
+                            .desired_width(150.0),
+                    );
+                    ui.end_row();
+                });
+            }
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("On conflict:");
+            ComboBox::from_id_source("sync_conflict_policy")
+                .selected_text(config.conflict_policy.name())
+                .show_ui(ui, |ui| {
+                    for policy in ConflictPolicy::all() {
+                        let name = policy.name();
+                        ui.selectable_value(&mut config.conflict_policy, policy, name);
+                    }
+                });
+        });
+
+        ui.add_space(5.0);
+        ui.label("Projects → remote lists:");
+        let mut remove_mapping = None;
+        for (index, mapping) in config.mappings.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.add(TextEdit::singleline(&mut mapping.project).desired_width(100.0));
+                ui.label("→");
+                ui.add(TextEdit::singleline(&mut mapping.remote_list).desired_width(150.0));
+                if ui.button("🗑").clicked() {
+                    remove_mapping = Some(index);
+                }
+            });
+        }
+        if let Some(index) = remove_mapping {
+            config.mappings.remove(index);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("➕ Add mapping").clicked() {
+                config.mappings.push(ListMapping {
+                    project: String::new(),
+                    remote_list: String::new(),
+                });
+            }
+
+            if ui.button("💾 Save sync settings").clicked() {
+                if let Err(e) = config.save() {
+                    status.error(&format!("Error saving sync settings: {}", e));
+                } else {
+                    status.show("Sync settings saved");
+                }
+            }
+        });
+
+        ui.label(
+            egui::RichText::new(format!(
+                "Todos without a project use the \"{}\" mapping.",
+                crate::task_sync::DEFAULT_PROJECT
+            ))
+            .small()
+            .color(colors.text_secondary_color32()),
+        );
+    });
+}
+
 fn display_habits(
     ui: &mut egui::Ui,
     study_data: &mut StudyData,
@@ -223,7 +428,7 @@ fn display_habits(
                     || ui.button("Add Habit").clicked() && !new_habit.is_empty()
                 {
                     if let Err(e) = study_data.add_habit(new_habit.clone(), category.clone()) {
-                        status.show(&format!("Error adding habit: {}", e));
+                        status.error(&format!("Error adding habit: {}", e));
                     } else {
                         status.show("Habit added successfully!");
                         new_habit.clear();
@@ -264,11 +469,23 @@ fn display_habits(
 
         if ui.add(clear_completed_button).clicked() {
             if let Err(e) = study_data.clear_completed_habits() {
-                status.show(&format!("Error clearing completed habits: {}", e));
-            } else {
-                status.show("Completed habits cleared!");
+                status.error(&format!("Error clearing completed habits: {}", e));
             }
         }
+
+        let review_button = egui::Button::new(
+            egui::RichText::new("🌙 Evening Review").color(colors.text_primary_color32()),
+        )
+        .fill(colors.inactive_tab_color32())
+        .stroke(egui::Stroke::new(1.0, colors.accent_color32()));
+
+        if ui
+            .add(review_button)
+            .on_hover_text("Check off the rest of today's habits in one go")
+            .clicked()
+        {
+            habit_review::open();
+        }
     });
 
     ui.separator();
@@ -390,7 +607,32 @@ fn display_todo_list(
                             } else {
                                 egui::RichText::new(&todo.text).color(text_color)
                             };
-                            ui.label(text);
+                            linkified_label(ui, text);
+
+                            if let Some(project) = &todo.project {
+                                ui.label(
+                                    egui::RichText::new(format!("#{}", project))
+                                        .small()
+                                        .color(colors.accent_color32()),
+                                );
+                            }
+
+                            if todo.priority != TodoPriority::Normal {
+                                ui.label(
+                                    egui::RichText::new(format!("❗ {}", todo.priority.as_str()))
+                                        .small()
+                                        .color(colors.text_secondary_color32()),
+                                );
+                            }
+
+                            if let Some(due) = &todo.due_date {
+                                let time = todo.due_time.as_deref().unwrap_or_default();
+                                ui.label(
+                                    egui::RichText::new(format!("📅 {} {}", due, time).trim_end())
+                                        .small()
+                                        .color(colors.text_secondary_color32()),
+                                );
+                            }
 
                             ui.with_layout(
                                 egui::Layout::right_to_left(egui::Align::Center),
@@ -433,21 +675,19 @@ fn display_todo_list(
     // Process the collected actions
     for id in toggle_todos {
         if let Err(e) = study_data.toggle_todo(id) {
-            status.show(&format!("Error toggling todo: {}", e));
+            status.error(&format!("Error toggling todo: {}", e));
         }
     }
 
     for id in delete_todos {
         if let Err(e) = study_data.delete_todo(id) {
-            status.show(&format!("Error deleting todo: {}", e));
-        } else {
-            status.show("Todo deleted successfully!");
+            status.error(&format!("Error deleting todo: {}", e));
         }
     }
 
     for (id, text) in edit_todos {
         if let Err(e) = study_data.update_todo_text(id, text) {
-            status.show(&format!("Error updating todo: {}", e));
+            status.error(&format!("Error updating todo: {}", e));
         } else {
             status.show("Todo updated successfully!");
         }
@@ -475,7 +715,10 @@ fn display_habit_list(
 ) {
     let mut mark_habit_complete: Vec<u64> = Vec::new();
     let mut delete_habits: Vec<u64> = Vec::new();
+    let mut archive_habits: Vec<u64> = Vec::new();
     let mut show_monthly_view: Option<u64> = None;
+    let mut toggle_category: Option<String> = None;
+    let mut row_rects: Vec<(u64, egui::Rect)> = Vec::new();
 
     // Get filtered habits
     let filtered_habits = SELECTED_CATEGORY_FILTER.with(|filter_ref| {
@@ -501,9 +744,9 @@ fn display_habit_list(
             return;
         }
 
-        // Group habits by category and sort categories to prevent glitching
-        let mut categories: std::collections::HashMap<String, Vec<_>> =
-            std::collections::HashMap::new();
+        // Categories alphabetically, habits in their saved order within each
+        let mut categories: std::collections::BTreeMap<String, Vec<_>> =
+            std::collections::BTreeMap::new();
         for habit in &filtered_habits {
             categories
                 .entry(habit.category.clone())
@@ -511,18 +754,33 @@ fn display_habit_list(
                 .push(habit);
         }
 
-        // Sort categories alphabetically to prevent UI glitching
-        let mut sorted_categories: Vec<(String, Vec<_>)> = categories.into_iter().collect();
-        sorted_categories.sort_by(|a, b| a.0.cmp(&b.0));
-
-        for (category, habits) in sorted_categories {
-            if SELECTED_CATEGORY_FILTER.with(|f| f.borrow().as_str() == "All") {
+        let grouped = SELECTED_CATEGORY_FILTER.with(|f| f.borrow().as_str() == "All");
+        for (category, habits) in categories {
+            if grouped {
                 ui.group(|ui| {
-                    ui.label(
-                        egui::RichText::new(&category)
-                            .heading()
-                            .color(colors.accent_color32()),
-                    );
+                    let collapsed = study_data.is_habit_category_collapsed(&category);
+                    let header = if collapsed {
+                        format!("▶ {} ({})", category, habits.len())
+                    } else {
+                        format!("▼ {}", category)
+                    };
+                    if ui
+                        .add(
+                            egui::Label::new(
+                                egui::RichText::new(header)
+                                    .heading()
+                                    .color(colors.accent_color32()),
+                            )
+                            .sense(egui::Sense::click()),
+                        )
+                        .on_hover_text(if collapsed { "Expand" } else { "Collapse" })
+                        .clicked()
+                    {
+                        toggle_category = Some(category.clone());
+                    }
+                    if collapsed {
+                        return;
+                    }
 
                     for habit in habits {
                         if let Some(habit_id) = display_habit_item(
@@ -531,6 +789,8 @@ fn display_habit_list(
                             colors,
                             &mut mark_habit_complete,
                             &mut delete_habits,
+                            &mut archive_habits,
+                            &mut row_rects,
                         ) {
                             show_monthly_view = Some(habit_id);
                         }
@@ -545,6 +805,8 @@ fn display_habit_list(
                         colors,
                         &mut mark_habit_complete,
                         &mut delete_habits,
+                        &mut archive_habits,
+                        &mut row_rects,
                     ) {
                         show_monthly_view = Some(habit_id);
                     }
@@ -553,10 +815,51 @@ fn display_habit_list(
         }
     });
 
+    // Drop the dragged habit onto the habit under the pointer
+    let mut moved = None;
+    if let Some(id) = DRAGGED_HABIT.with(|d| *d.borrow()) {
+        ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grabbing);
+
+        let target = ui.ctx().pointer_interact_pos().and_then(|pointer| {
+            row_rects
+                .iter()
+                .find(|(_, rect)| rect.expand2(egui::vec2(0.0, 2.0)).contains(pointer))
+                .copied()
+        });
+        if let Some((_, rect)) = target {
+            ui.painter().rect_stroke(
+                rect.expand(2.0),
+                egui::Rounding::same(4.0),
+                egui::Stroke::new(1.5, colors.accent_color32()),
+            );
+        }
+
+        if ui.input(|i| i.pointer.any_released()) {
+            if let Some((to, _)) = target {
+                if to != id {
+                    moved = Some((id, to));
+                }
+            }
+            DRAGGED_HABIT.with(|d| *d.borrow_mut() = None);
+        }
+    }
+
     // Process actions
+    if let Some((id, target)) = moved {
+        if let Err(e) = study_data.move_habit(id, target) {
+            status.error(&format!("Error moving habit: {}", e));
+        }
+    }
+
+    if let Some(category) = toggle_category {
+        if let Err(e) = study_data.toggle_habit_category(&category) {
+            status.error(&format!("Error saving: {}", e));
+        }
+    }
+
     for id in mark_habit_complete {
         if let Err(e) = study_data.mark_habit_complete_today(id) {
-            status.show(&format!("Error marking habit complete: {}", e));
+            status.error(&format!("Error marking habit complete: {}", e));
         } else {
             status.show("Habit marked complete for today!");
         }
@@ -564,9 +867,14 @@ fn display_habit_list(
 
     for id in delete_habits {
         if let Err(e) = study_data.delete_habit(id) {
-            status.show(&format!("Error deleting habit: {}", e));
-        } else {
-            status.show("Habit deleted successfully!");
+            status.error(&format!("Error deleting habit: {}", e));
+        }
+    }
+
+    for id in archive_habits {
+        match study_data.archive_habit(id) {
+            Ok(()) => status.show("Habit archived; its history still counts in stats"),
+            Err(e) => status.error(&format!("Error archiving habit: {}", e)),
         }
     }
 
@@ -587,6 +895,8 @@ fn display_habit_item(
     colors: &crate::settings::ColorTheme,
     mark_complete: &mut Vec<u64>,
     delete_habits: &mut Vec<u64>,
+    archive_habits: &mut Vec<u64>,
+    row_rects: &mut Vec<(u64, egui::Rect)>,
 ) -> Option<u64> {
     let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
     let is_complete_today = habit.completion_dates.contains(&today);
@@ -611,8 +921,18 @@ fn display_habit_item(
             },
         ));
 
-    habit_frame.show(ui, |ui| {
+    let frame = habit_frame.show(ui, |ui| {
         ui.horizontal(|ui| {
+            let handle = ui
+                .add(egui::Label::new("☰").sense(egui::Sense::drag()))
+                .on_hover_text("Drag to reorder");
+            if handle.hovered() {
+                ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grab);
+            }
+            if handle.drag_started() {
+                DRAGGED_HABIT.with(|d| *d.borrow_mut() = Some(habit.id));
+            }
+
             // Completion checkbox
             let mut completed = is_complete_today;
             if ui.checkbox(&mut completed, "").clicked() {
@@ -651,6 +971,14 @@ fn display_habit_item(
                         egui::RichText::new(&format!("✅ {} total", total_completions))
                             .color(colors.text_secondary_color32()),
                     );
+
+                    if let Some(pause) = habit.current_pause() {
+                        ui.separator();
+                        ui.label(
+                            egui::RichText::new(format!("⏸ Paused until {}", pause.end))
+                                .color(colors.text_secondary_color32()),
+                        );
+                    }
                 });
             });
 
@@ -666,6 +994,16 @@ fn display_habit_item(
                     show_monthly = true;
                 }
 
+                let stats_button = egui::Button::new(
+                    egui::RichText::new("📊").color(colors.text_primary_color32()),
+                )
+                .fill(colors.inactive_tab_color32())
+                .stroke(egui::Stroke::new(1.0, colors.accent_color32()));
+
+                if ui.add(stats_button).on_hover_text("Statistics").clicked() {
+                    habit_stats::open(habit.id);
+                }
+
                 ui.separator();
                 // Delete button
                 let delete_button = egui::Button::new(
@@ -681,6 +1019,20 @@ fn display_habit_item(
                     delete_habits.push(habit.id);
                 }
 
+                let archive_button = egui::Button::new(
+                    egui::RichText::new("📦").color(colors.text_primary_color32()),
+                )
+                .fill(colors.inactive_tab_color32())
+                .stroke(egui::Stroke::new(1.0, colors.accent_color32()));
+
+                if ui
+                    .add(archive_button)
+                    .on_hover_text("Archive finished habit")
+                    .clicked()
+                {
+                    archive_habits.push(habit.id);
+                }
+
                 ui.separator();
 
                 // Last 7 days visual indicator
@@ -691,24 +1043,31 @@ fn display_habit_item(
                         let date_str = date.format("%Y-%m-%d").to_string();
                         let completed_on_date = habit.completion_dates.contains(&date_str);
 
-                        let color = if completed_on_date {
-                            egui::Color32::from_rgb(50, 200, 50)
+                        // Filled vs hollow dot so the state doesn't rely on green alone
+                        let (symbol, color, state) = if completed_on_date {
+                            ("●", egui::Color32::from_rgb(50, 200, 50), "done")
                         } else {
-                            egui::Color32::from_rgb(100, 100, 100)
+                            ("○", egui::Color32::from_rgb(100, 100, 100), "missed")
                         };
 
                         ui.add(
-                            egui::widgets::Button::new("●")
+                            egui::widgets::Button::new(symbol)
                                 .fill(color)
                                 .stroke(egui::Stroke::NONE),
                         )
-                        .on_hover_text(&format!("{}", date.format("%m/%d")));
+                        .on_hover_text(&format!(
+                            "{} - {}",
+                            date.format("%m/%d"),
+                            state
+                        ));
                     }
                 });
             });
         });
     });
 
+    row_rects.push((habit.id, frame.response.rect));
+
     if show_monthly {
         Some(habit.id)
     } else {
@@ -716,8 +1075,19 @@ fn display_habit_item(
     }
 }
 
-fn display_monthly_view_popup(ui: &mut egui::Ui, study_data: &StudyData, settings: &AppSettings) {
+enum PauseChange {
+    Add(u64, NaiveDate, NaiveDate),
+    Remove(u64, HabitPause),
+}
+
+fn display_monthly_view_popup(
+    ui: &mut egui::Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    settings: &AppSettings,
+) {
     let colors = settings.get_current_colors();
+    let mut pause_change = None;
 
     MONTHLY_VIEW_HABIT.with(|habit_ref| {
         let mut habit_id_opt = habit_ref.borrow_mut();
@@ -792,7 +1162,13 @@ fn display_monthly_view_popup(ui: &mut egui::Ui, study_data: &StudyData, setting
                             ui.separator();
 
                             // Calendar grid
-                            display_monthly_calendar(ui, habit, *current_date, &colors);
+                            display_monthly_calendar(
+                                ui,
+                                habit,
+                                *current_date,
+                                settings.week_start,
+                                &colors,
+                            );
 
                             ui.separator();
 
@@ -816,6 +1192,9 @@ fn display_monthly_view_popup(ui: &mut egui::Ui, study_data: &StudyData, setting
                                 );
                             });
                         });
+
+                        ui.separator();
+                        pause_change = display_pauses(ui, habit, &colors);
                     });
 
                 if !open {
@@ -827,12 +1206,96 @@ fn display_monthly_view_popup(ui: &mut egui::Ui, study_data: &StudyData, setting
             }
         }
     });
+
+    let result = match pause_change {
+        Some(PauseChange::Add(id, start, end)) => study_data.add_habit_pause(id, start, end),
+        Some(PauseChange::Remove(id, pause)) => study_data.remove_habit_pause(id, &pause),
+        None => return,
+    };
+    if let Err(e) = result {
+        status.error(&format!("Error updating habit pause: {}", e));
+    }
+}
+
+// Pausing for illness or holidays keeps the streak and leaves the days out of the success rate
+fn display_pauses(
+    ui: &mut egui::Ui,
+    habit: &crate::data::Habit,
+    colors: &crate::settings::ColorTheme,
+) -> Option<PauseChange> {
+    let mut change = None;
+
+    ui.collapsing("⏸ Pauses", |ui| {
+        for pause in &habit.pauses {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(format!("{} – {}", pause.start, pause.end))
+                        .color(colors.text_primary_color32()),
+                );
+                if ui.small_button("✖").on_hover_text("Remove pause").clicked() {
+                    change = Some(PauseChange::Remove(habit.id, pause.clone()));
+                }
+            });
+        }
+
+        let today = Local::now().date_naive();
+        PAUSE_START.with(|start_ref| {
+            PAUSE_END.with(|end_ref| {
+                let mut start = start_ref.borrow_mut();
+                let mut end = end_ref.borrow_mut();
+                if start.is_empty() {
+                    *start = today.format("%Y-%m-%d").to_string();
+                }
+                if end.is_empty() {
+                    *end = (today + Duration::days(6)).format("%Y-%m-%d").to_string();
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("From");
+                    ui.add(TextEdit::singleline(&mut *start).desired_width(85.0));
+                    ui.label("to");
+                    ui.add(TextEdit::singleline(&mut *end).desired_width(85.0));
+                    if ui.button("Add pause").clicked() {
+                        let parse = |date: &str| NaiveDate::parse_from_str(date, "%Y-%m-%d");
+                        if let (Ok(start), Ok(end)) = (parse(start.as_str()), parse(end.as_str())) {
+                            change = Some(PauseChange::Add(habit.id, start, end));
+                        }
+                    }
+                });
+            });
+        });
+        ui.label(
+            egui::RichText::new("Dates are YYYY-MM-DD. Paused days show hatched in the calendar.")
+                .small()
+                .color(colors.text_secondary_color32()),
+        );
+    });
+
+    change
+}
+
+// Diagonal lines over a paused day
+fn hatch(ui: &egui::Ui, rect: egui::Rect, color: egui::Color32) {
+    let painter = ui.painter().with_clip_rect(rect);
+    let stroke = egui::Stroke::new(1.0, color);
+    let mut x = rect.left() - rect.height();
+    while x < rect.right() {
+        painter.line_segment(
+            [
+                egui::pos2(x, rect.bottom()),
+                egui::pos2(x + rect.height(), rect.top()),
+            ],
+            stroke,
+        );
+        x += 6.0;
+    }
 }
 
 fn display_monthly_calendar(
     ui: &mut egui::Ui,
     habit: &crate::data::Habit,
     current_date: NaiveDate,
+    week_start: WeekStart,
     colors: &crate::settings::ColorTheme,
 ) {
     // Get the first day of the month
@@ -851,12 +1314,12 @@ fn display_monthly_calendar(
             .num_days()
     };
 
-    // Get the day of week for the first day (0 = Sunday, 6 = Saturday)
-    let first_day_weekday = first_day.weekday().num_days_from_sunday() as i32;
+    // Column of the first day, counted from the configured start of the week
+    let first_day_weekday = week_start.offset(first_day) as i32;
 
     // Day headers
     ui.horizontal(|ui| {
-        for day in ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"] {
+        for day in week_start.day_names() {
             ui.allocate_ui_with_layout(
                 egui::Vec2::new(40.0, 20.0),
                 egui::Layout::centered_and_justified(egui::Direction::TopDown),
@@ -909,6 +1372,7 @@ fn display_monthly_calendar(
                         let date = first_day.with_day(day_number as u32).unwrap();
                         let date_str = date.format("%Y-%m-%d").to_string();
                         let is_completed = habit.completion_dates.contains(&date_str);
+                        let is_paused = !is_completed && habit.is_paused_on(&date_str);
                         let is_today = date == Local::now().date_naive();
 
                         let (bg_color, text_color, border_color) = if is_completed {
@@ -940,13 +1404,20 @@ fn display_monthly_calendar(
                                     .stroke(egui::Stroke::new(1.0, border_color))
                                     .inner_margin(egui::Margin::same(4.0));
 
-                                frame.show(ui, |ui| {
+                                let cell = frame.show(ui, |ui| {
+                                    let day_text = if is_completed {
+                                        format!("✔{}", day_number)
+                                    } else {
+                                        day_number.to_string()
+                                    };
                                     ui.label(
-                                        egui::RichText::new(&day_number.to_string())
-                                            .color(text_color)
-                                            .size(12.0),
+                                        egui::RichText::new(day_text).color(text_color).size(12.0),
                                     );
                                 });
+                                if is_paused {
+                                    hatch(ui, cell.response.rect, colors.text_secondary_color32());
+                                    cell.response.on_hover_text("Paused");
+                                }
                             },
                         );
                     }
@@ -990,11 +1461,13 @@ fn calculate_month_stats(habit: &crate::data::Habit, current_date: NaiveDate) ->
         if let Some(date) = first_day.with_day(day) {
             // Only count days up to today (don't count future days)
             if date <= today {
-                valid_days += 1;
                 let date_str = date.format("%Y-%m-%d").to_string();
                 if habit.completion_dates.contains(&date_str) {
                     completed_days += 1;
+                } else if habit.is_paused_on(&date_str) {
+                    continue;
                 }
+                valid_days += 1;
             }
         }
     }
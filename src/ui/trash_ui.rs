@@ -0,0 +1,86 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use eframe::egui;
+
+pub fn display(
+    ctx: &egui::Context,
+    study_data: &mut StudyData,
+    open: &mut bool,
+    status: &mut StatusMessage,
+) {
+    let mut restore = None;
+    let mut delete = None;
+    let mut empty = false;
+
+    egui::Window::new("🗑 Trash")
+        .open(open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size([480.0, 400.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{} item(s). Items are deleted for good after 30 days.",
+                    study_data.trash.len()
+                ));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add_enabled(
+                            !study_data.trash.is_empty(),
+                            egui::Button::new("Empty Trash"),
+                        )
+                        .clicked()
+                    {
+                        empty = true;
+                    }
+                });
+            });
+            ui.label(egui::RichText::new("Ctrl+Z restores the most recent delete.").small());
+            ui.separator();
+
+            if study_data.trash.is_empty() {
+                ui.label("The trash is empty.");
+                return;
+            }
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("trash_entries")
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        // Newest first
+                        for entry in study_data.trash.iter().rev() {
+                            ui.label(egui::RichText::new(entry.item.kind()).strong());
+                            ui.label(entry.item.label());
+                            ui.label(egui::RichText::new(&entry.deleted_at).small());
+                            ui.horizontal(|ui| {
+                                if ui.button("↩ Restore").clicked() {
+                                    restore = Some(entry.id);
+                                }
+                                if ui.button("✖").on_hover_text("Delete forever").clicked() {
+                                    delete = Some(entry.id);
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+
+    if let Some(id) = restore {
+        match study_data.restore_from_trash(id) {
+            Ok(()) => status.show("Item restored"),
+            Err(e) => status.error(&format!("Could not restore: {}", e)),
+        }
+    }
+    if let Some(id) = delete {
+        if let Err(e) = study_data.delete_from_trash(id) {
+            status.error(&format!("Error saving: {}", e));
+        }
+    }
+    if empty {
+        if let Err(e) = study_data.empty_trash() {
+            status.error(&format!("Error saving: {}", e));
+        }
+    }
+}
@@ -0,0 +1,388 @@
+use crate::app::StatusMessage;
+use crate::audio_recorder::format_time;
+use crate::tab_manager::TabInstance;
+use crate::ui::markdown_editor::{MarkdownEditor, FILES_DIR};
+use crate::video_player::{self, Playback, VideoInfo};
+use eframe::egui::{self, Color32, RichText};
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const NOTES_DIR: &str = "video_notes"; // Under files/
+const VIDEO_EXTENSIONS: [&str; 7] = ["mp4", "mkv", "webm", "mov", "avi", "m4v", "ogv"];
+
+struct VideoState {
+    info: VideoInfo,
+    playback: Option<Playback>,
+    position: f64, // Where a paused video stands
+    texture: Option<egui::TextureHandle>,
+    seek_preview: Option<f64>,
+    note_path: PathBuf,
+    notes: String,
+    note_text: String,
+    note_time: Option<f64>, // Taken when typing starts, so the note points at what prompted it
+}
+
+thread_local! {
+    // Keyed by file path, so each video keeps its place
+    static VIDEOS: RefCell<HashMap<String, VideoState>> = RefCell::new(HashMap::new());
+}
+
+impl VideoState {
+    fn open(ctx: &egui::Context, path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let info = video_player::probe(Path::new(path))?;
+        let note_path = note_path(Path::new(path));
+        let mut state = Self {
+            info,
+            playback: None,
+            position: 0.0,
+            texture: None,
+            seek_preview: None,
+            notes: fs::read_to_string(&note_path).unwrap_or_default(),
+            note_path,
+            note_text: String::new(),
+            note_time: None,
+        };
+        state.show_still(ctx, path);
+        Ok(state)
+    }
+
+    fn position(&self) -> f64 {
+        self.playback
+            .as_ref()
+            .map_or(self.position, |playback| playback.position())
+            .min(self.info.duration)
+    }
+
+    fn show_still(&mut self, ctx: &egui::Context, path: &str) {
+        if let Ok(image) = video_player::frame_at(Path::new(path), &self.info, self.position) {
+            self.set_frame(ctx, path, image);
+        }
+    }
+
+    fn set_frame(&mut self, ctx: &egui::Context, path: &str, image: egui::ColorImage) {
+        match self.texture.as_mut() {
+            Some(texture) => texture.set(image, egui::TextureOptions::LINEAR),
+            None => {
+                self.texture = Some(ctx.load_texture(
+                    format!("video_{}", path),
+                    image,
+                    egui::TextureOptions::LINEAR,
+                ))
+            }
+        }
+    }
+
+    fn play(&mut self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if self.position >= self.info.duration - 0.1 {
+            self.position = 0.0;
+        }
+        self.playback = Some(Playback::start(Path::new(path), &self.info, self.position)?);
+        Ok(())
+    }
+
+    fn pause(&mut self) {
+        self.position = self.position();
+        self.playback = None;
+    }
+
+    // Keeps playing from the new spot, or shows its frame while paused
+    fn seek(
+        &mut self,
+        ctx: &egui::Context,
+        path: &str,
+        time: f64,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.position = time.clamp(0.0, self.info.duration);
+        if self.playback.is_some() {
+            self.play(path)
+        } else {
+            self.show_still(ctx, path);
+            Ok(())
+        }
+    }
+}
+
+// Each video gets its own note, named after it
+fn note_path(video: &Path) -> PathBuf {
+    let stem = video
+        .file_stem()
+        .map_or("video".to_string(), |s| s.to_string_lossy().to_string());
+    Path::new(FILES_DIR)
+        .join(NOTES_DIR)
+        .join(format!("{}.md", stem))
+}
+
+// Returns a note to open in the editor when asked for
+pub fn display(
+    ui: &mut egui::Ui,
+    tab: Option<&mut TabInstance>,
+    status: &mut StatusMessage,
+    editor: Option<&mut MarkdownEditor>,
+) -> Option<PathBuf> {
+    let tab = tab?;
+    let Some(path) = tab.file_path.clone() else {
+        ui.vertical_centered(|ui| {
+            ui.add_space(40.0);
+            ui.heading("Video Player");
+            ui.label("Drop a video on the window, or pick a lecture recording to watch.");
+            ui.label(RichText::new("Playback uses FFmpeg, which needs to be installed.").small());
+            if ui.button("📂 Open Video…").clicked() {
+                if let Some(picked) = rfd::FileDialog::new()
+                    .add_filter("Video", &VIDEO_EXTENSIONS)
+                    .pick_file()
+                {
+                    tab.title = picked.file_name().map_or("Video".to_string(), |name| {
+                        name.to_string_lossy().to_string()
+                    });
+                    tab.file_path = Some(picked.to_string_lossy().to_string());
+                }
+            }
+        });
+        return None;
+    };
+
+    VIDEOS.with(|videos| {
+        let mut videos = videos.borrow_mut();
+        if !videos.contains_key(&path) {
+            match VideoState::open(ui.ctx(), &path) {
+                Ok(state) => {
+                    videos.insert(path.clone(), state);
+                }
+                Err(e) => {
+                    ui.colored_label(
+                        Color32::from_rgb(213, 94, 0),
+                        format!("Could not open {}: {}", path, e),
+                    );
+                    return None;
+                }
+            }
+        }
+        let state = videos.get_mut(&path)?;
+
+        // Only the newest due frame is uploaded, late ones are skipped
+        if let Some(frame) = state.playback.as_mut().and_then(|p| p.due_frame()) {
+            state.set_frame(ui.ctx(), &path, frame.image);
+        }
+        if state.playback.is_some() {
+            if state.position() >= state.info.duration {
+                state.pause();
+            } else {
+                ui.ctx()
+                    .request_repaint_after(Duration::from_secs_f64(1.0 / state.info.fps));
+            }
+        }
+
+        let mut open_note = None;
+        egui::SidePanel::right(egui::Id::new(("video_notes", &path)))
+            .resizable(true)
+            .default_width(300.0)
+            .show_inside(ui, |ui| {
+                open_note = notes_panel(ui, state, &path, status, editor);
+            });
+        controls(ui, state, &path, status);
+        ui.separator();
+        picture(ui, state);
+        open_note
+    })
+}
+
+fn controls(ui: &mut egui::Ui, state: &mut VideoState, path: &str, status: &mut StatusMessage) {
+    let position = state.position();
+    let mut result = Ok(());
+    ui.horizontal(|ui| {
+        if state.playback.is_some() {
+            if ui.button("⏸ Pause").clicked() {
+                state.pause();
+            }
+        } else if ui.button("▶ Play").clicked() {
+            result = state.play(path);
+        }
+        for (label, step) in [("⏪ 10s", -10.0), ("10s ⏩", 10.0)] {
+            if ui.button(label).clicked() {
+                result = state.seek(ui.ctx(), path, position + step);
+            }
+        }
+        ui.label(
+            RichText::new(format!(
+                "{} / {}",
+                format_time(state.seek_preview.unwrap_or(position)),
+                format_time(state.info.duration)
+            ))
+            .monospace(),
+        );
+    });
+
+    let mut value = state.seek_preview.unwrap_or(position);
+    ui.spacing_mut().slider_width = ui.available_width();
+    let response =
+        ui.add(egui::Slider::new(&mut value, 0.0..=state.info.duration.max(0.1)).show_value(false));
+    if response.dragged() {
+        state.seek_preview = Some(value);
+    } else if response.drag_released() || response.changed() {
+        state.seek_preview = None;
+        result = state.seek(ui.ctx(), path, value);
+    }
+
+    if let Err(e) = result {
+        state.playback = None;
+        status.error(&format!("Playback failed: {}", e));
+    }
+}
+
+fn picture(ui: &mut egui::Ui, state: &VideoState) {
+    let Some(texture) = &state.texture else {
+        ui.label("No picture yet.");
+        return;
+    };
+    let available = ui.available_size();
+    let size = texture.size_vec2();
+    let scale = (available.x / size.x).min(available.y / size.y).max(0.05);
+    ui.centered_and_justified(|ui| {
+        ui.image((texture.id(), size * scale));
+    });
+}
+
+fn notes_panel(
+    ui: &mut egui::Ui,
+    state: &mut VideoState,
+    path: &str,
+    status: &mut StatusMessage,
+    editor: Option<&mut MarkdownEditor>,
+) -> Option<PathBuf> {
+    let mut open_note = None;
+    ui.horizontal(|ui| {
+        ui.strong("Notes");
+        if ui
+            .small_button("🔄")
+            .on_hover_text("Reload the note")
+            .clicked()
+        {
+            state.notes = fs::read_to_string(&state.note_path).unwrap_or_default();
+        }
+        if ui
+            .small_button("📝 Open in Editor")
+            .on_hover_text(state.note_path.display().to_string())
+            .clicked()
+        {
+            if let Err(e) = ensure_note(&state.note_path, path) {
+                status.error(&format!("Could not create the note: {}", e));
+            } else {
+                open_note = Some(state.note_path.clone());
+            }
+        }
+    });
+
+    let position = state.position();
+    let stamp = state.note_time.unwrap_or(position);
+    let response = ui.add(
+        egui::TextEdit::multiline(&mut state.note_text)
+            .hint_text(format!("Note at {} (Enter to add)", format_time(stamp)))
+            .desired_rows(2)
+            .desired_width(f32::INFINITY),
+    );
+    if state.note_text.trim().is_empty() {
+        state.note_time = None;
+    } else if state.note_time.is_none() {
+        state.note_time = Some(position);
+    }
+    let entered =
+        response.has_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter) && !i.modifiers.shift);
+    if (ui.button("➕ Add Note").clicked() || entered) && !state.note_text.trim().is_empty() {
+        let line = format!(
+            "- [{}] {}\n",
+            format_time(stamp),
+            state.note_text.trim().replace('\n', " ")
+        );
+        match add_note(&state.note_path, path, &line, editor) {
+            Ok(()) => {
+                state.note_text.clear();
+                state.note_time = None;
+                state.notes = fs::read_to_string(&state.note_path).unwrap_or_default();
+            }
+            Err(e) => status.error(&format!("Could not save the note: {}", e)),
+        }
+    }
+    ui.separator();
+
+    let timestamp = Regex::new(r"\[((?:\d+:)?\d{1,2}:\d{2})\]").unwrap();
+    let mut seek_to = None;
+    egui::ScrollArea::vertical()
+        .id_source("video_notes_scroll")
+        .show(ui, |ui| {
+            if state.notes.trim().is_empty() {
+                ui.label("No notes yet. Timestamps like [12:34] in the note jump the video.");
+            }
+            for line in state.notes.lines().filter(|l| !l.trim().is_empty()) {
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing.x = 2.0;
+                    let mut rest = 0;
+                    for caps in timestamp.captures_iter(line) {
+                        let whole = caps.get(0).unwrap();
+                        ui.label(&line[rest..whole.start()]);
+                        if ui.link(&caps[1]).on_hover_text("Jump here").clicked() {
+                            seek_to = parse_time(&caps[1]);
+                        }
+                        rest = whole.end();
+                    }
+                    ui.label(&line[rest..]);
+                });
+            }
+        });
+
+    if let Some(time) = seek_to {
+        state.position = time.clamp(0.0, state.info.duration);
+        if let Err(e) = state.play(path) {
+            status.error(&format!("Playback failed: {}", e));
+        }
+    }
+    open_note
+}
+
+fn parse_time(text: &str) -> Option<f64> {
+    text.split(':').try_fold(0.0, |total, part| {
+        Some(total * 60.0 + part.parse::<f64>().ok()?)
+    })
+}
+
+fn ensure_note(note: &Path, video: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if note.exists() {
+        return Ok(());
+    }
+    if let Some(dir) = note.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let title = note
+        .file_stem()
+        .map_or(String::new(), |s| s.to_string_lossy().to_string());
+    fs::write(note, format!("# {}\n\nVideo: {}\n\n", title, video))?;
+    Ok(())
+}
+
+// Goes through the editor when it has the note open, so its copy doesn't overwrite the line
+fn add_note(
+    note: &Path,
+    video: &str,
+    line: &str,
+    editor: Option<&mut MarkdownEditor>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    ensure_note(note, video)?;
+    if let Some(editor) = editor.filter(|e| e.current_file.as_deref() == Some(note)) {
+        if !editor.current_content.is_empty() && !editor.current_content.ends_with('\n') {
+            editor.current_content.push('\n');
+        }
+        editor.current_content.push_str(line);
+        editor.save_file()?;
+        return Ok(());
+    }
+    let mut content = fs::read_to_string(note)?;
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(line);
+    fs::write(note, content)?;
+    Ok(())
+}
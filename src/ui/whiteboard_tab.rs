@@ -0,0 +1,323 @@
+use crate::app::StatusMessage;
+use crate::data::StudyData;
+use crate::image_handler::CardImage;
+use crate::ui::image_viewer_tab::{self, CardDraft};
+use crate::ui::markdown_editor::{MarkdownEditor, FILES_DIR};
+use eframe::egui::{self, Color32, Pos2, Stroke, Vec2};
+use image::{Rgba, RgbaImage};
+use std::cell::RefCell;
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+const SKETCHES_DIR: &str = "sketches"; // Under files/
+const CANVAS_SIZE: Vec2 = Vec2::new(1600.0, 1000.0); // Drawing space, in PNG pixels
+const BACKGROUND: Color32 = Color32::WHITE;
+const COLORS: [Color32; 6] = [
+    Color32::BLACK,
+    Color32::from_rgb(220, 50, 50),
+    Color32::from_rgb(40, 150, 70),
+    Color32::from_rgb(40, 100, 220),
+    Color32::from_rgb(240, 150, 20),
+    Color32::from_rgb(140, 70, 190),
+];
+
+struct PenStroke {
+    points: Vec<Pos2>, // In canvas pixels
+    color: Color32,
+    width: f32,
+}
+
+struct Whiteboard {
+    strokes: Vec<PenStroke>,
+    undone: Vec<PenStroke>,
+    current: Option<PenStroke>,
+    color: Color32,
+    width: f32,
+    eraser: bool,
+    name: String,
+    saved: Option<PathBuf>, // Last PNG written, until the drawing changes
+    card: Option<CardDraft>,
+}
+
+impl Default for Whiteboard {
+    fn default() -> Self {
+        Self {
+            strokes: Vec::new(),
+            undone: Vec::new(),
+            current: None,
+            color: COLORS[0],
+            width: 4.0,
+            eraser: false,
+            name: String::new(),
+            saved: None,
+            card: None,
+        }
+    }
+}
+
+thread_local! {
+    static WHITEBOARD: RefCell<Whiteboard> = RefCell::new(Whiteboard::default());
+}
+
+pub fn display(
+    ui: &mut egui::Ui,
+    study_data: &mut StudyData,
+    status: &mut StatusMessage,
+    editor: Option<&mut MarkdownEditor>,
+) {
+    WHITEBOARD.with(|board| {
+        let mut board = board.borrow_mut();
+        toolbar(ui, &mut board, status, editor);
+        ui.separator();
+        canvas(ui, &mut board);
+
+        if let Some(draft) = image_viewer_tab::card_dialog(
+            ui.ctx(),
+            "whiteboard_card",
+            "The sketch goes on the front.",
+            &mut board.card,
+            study_data,
+        ) {
+            let image = png(&board).and_then(|data| CardImage::new(file_name(&board), data));
+            image_viewer_tab::add_image_card(draft, image, study_data, status);
+        }
+    });
+}
+
+fn toolbar(
+    ui: &mut egui::Ui,
+    board: &mut Whiteboard,
+    status: &mut StatusMessage,
+    editor: Option<&mut MarkdownEditor>,
+) {
+    ui.horizontal_wrapped(|ui| {
+        ui.selectable_value(&mut board.eraser, false, "✏ Pen");
+        ui.selectable_value(&mut board.eraser, true, "🧽 Eraser");
+        for color in COLORS {
+            let (rect, response) = ui.allocate_exact_size(Vec2::splat(16.0), egui::Sense::click());
+            ui.painter().rect_filled(rect, 3.0, color);
+            if board.color == color && !board.eraser {
+                ui.painter().rect_stroke(
+                    rect.expand(2.0),
+                    3.0,
+                    Stroke::new(2.0, ui.visuals().text_color()),
+                );
+            }
+            if response.clicked() {
+                board.color = color;
+                board.eraser = false;
+            }
+        }
+        ui.add(egui::Slider::new(&mut board.width, 1.0..=40.0).text("Size"));
+
+        ui.separator();
+        if ui
+            .add_enabled(!board.strokes.is_empty(), egui::Button::new("↶ Undo"))
+            .clicked()
+        {
+            if let Some(stroke) = board.strokes.pop() {
+                board.undone.push(stroke);
+                board.saved = None;
+            }
+        }
+        if ui
+            .add_enabled(!board.undone.is_empty(), egui::Button::new("↷ Redo"))
+            .clicked()
+        {
+            if let Some(stroke) = board.undone.pop() {
+                board.strokes.push(stroke);
+                board.saved = None;
+            }
+        }
+        if ui
+            .add_enabled(!board.strokes.is_empty(), egui::Button::new("🗑 Clear"))
+            .clicked()
+        {
+            // Kept in the redo list so an accidental clear can be taken back one stroke at a time
+            while let Some(stroke) = board.strokes.pop() {
+                board.undone.push(stroke);
+            }
+            board.saved = None;
+        }
+    });
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label("Name:");
+        ui.add(
+            egui::TextEdit::singleline(&mut board.name)
+                .hint_text("sketch")
+                .desired_width(140.0),
+        );
+        let has_drawing = !board.strokes.is_empty();
+        if ui
+            .add_enabled(has_drawing, egui::Button::new("💾 Save PNG"))
+            .on_hover_text(format!("Saves into {}/{}", FILES_DIR, SKETCHES_DIR))
+            .clicked()
+        {
+            match save(board) {
+                Ok(path) => status.show(&format!("Saved {}", path.display())),
+                Err(e) => status.error(&format!("Could not save the sketch: {}", e)),
+            }
+        }
+
+        let note_open = editor.as_ref().is_some_and(|e| e.current_file.is_some());
+        if ui
+            .add_enabled(
+                has_drawing && note_open,
+                egui::Button::new("📝 Insert into Note"),
+            )
+            .on_hover_text("Saves the sketch and adds it to the note open in the editor")
+            .on_disabled_hover_text("Open a note in a Markdown tab first")
+            .clicked()
+        {
+            match save(board) {
+                Ok(path) => {
+                    if let Some(editor) = editor {
+                        editor.insert_image(&path);
+                        if let Err(e) = editor.save_file() {
+                            status.error(&format!("Error saving note: {}", e));
+                        } else {
+                            status.show("Sketch added to the note");
+                        }
+                    }
+                }
+                Err(e) => status.error(&format!("Could not save the sketch: {}", e)),
+            }
+        }
+        if ui
+            .add_enabled(has_drawing, egui::Button::new("🃏 Attach to Card"))
+            .clicked()
+        {
+            board.card = Some(CardDraft::default());
+        }
+    });
+}
+
+fn canvas(ui: &mut egui::Ui, board: &mut Whiteboard) {
+    let available = ui.available_size();
+    let scale = (available.x / CANVAS_SIZE.x)
+        .min(available.y / CANVAS_SIZE.y)
+        .max(0.1);
+    let (response, painter) = ui.allocate_painter(CANVAS_SIZE * scale, egui::Sense::drag());
+    let area = response.rect;
+    painter.rect_filled(area, 0.0, BACKGROUND);
+    painter.rect_stroke(
+        area,
+        0.0,
+        Stroke::new(1.0, ui.visuals().widgets.noninteractive.bg_stroke.color),
+    );
+
+    let to_canvas = |p: Pos2| ((p - area.min) / scale).to_pos2();
+    let to_screen = |p: Pos2| area.min + p.to_vec2() * scale;
+
+    if let Some(pointer) = response.interact_pointer_pos() {
+        let point = to_canvas(pointer);
+        if response.drag_started() || board.current.is_none() {
+            board.current = Some(PenStroke {
+                points: vec![point],
+                color: if board.eraser {
+                    BACKGROUND
+                } else {
+                    board.color
+                },
+                width: if board.eraser {
+                    board.width * 4.0
+                } else {
+                    board.width
+                },
+            });
+        } else if let Some(stroke) = board.current.as_mut() {
+            if stroke.points.last() != Some(&point) {
+                stroke.points.push(point);
+            }
+        }
+    }
+    if response.drag_released() {
+        if let Some(stroke) = board.current.take() {
+            board.strokes.push(stroke);
+            board.undone.clear();
+            board.saved = None;
+        }
+    }
+
+    let painter = painter.with_clip_rect(area);
+    for stroke in board.strokes.iter().chain(board.current.iter()) {
+        let width = stroke.width * scale;
+        if stroke.points.len() == 1 {
+            painter.circle_filled(to_screen(stroke.points[0]), width / 2.0, stroke.color);
+            continue;
+        }
+        let points: Vec<Pos2> = stroke.points.iter().map(|p| to_screen(*p)).collect();
+        painter.add(egui::Shape::line(points, Stroke::new(width, stroke.color)));
+    }
+
+    if response.hovered() {
+        if let Some(pointer) = response.hover_pos() {
+            let radius = if board.eraser {
+                board.width * 2.0
+            } else {
+                board.width / 2.0
+            } * scale;
+            painter.circle_stroke(pointer, radius.max(2.0), Stroke::new(1.0, Color32::GRAY));
+        }
+    }
+}
+
+fn render(board: &Whiteboard) -> RgbaImage {
+    let mut image = RgbaImage::from_pixel(
+        CANVAS_SIZE.x as u32,
+        CANVAS_SIZE.y as u32,
+        Rgba(BACKGROUND.to_array()),
+    );
+    for stroke in &board.strokes {
+        let first = stroke.points[0];
+        image_viewer_tab::draw_line(&mut image, first, first, stroke.width, stroke.color);
+        for pair in stroke.points.windows(2) {
+            image_viewer_tab::draw_line(&mut image, pair[0], pair[1], stroke.width, stroke.color);
+        }
+    }
+    image
+}
+
+fn png(board: &Whiteboard) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut data = Vec::new();
+    render(board).write_to(&mut Cursor::new(&mut data), image::ImageOutputFormat::Png)?;
+    Ok(data)
+}
+
+fn file_name(board: &Whiteboard) -> String {
+    let name: String = board
+        .name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() {
+        format!(
+            "sketch_{}.png",
+            chrono::Local::now().format("%Y%m%d_%H%M%S")
+        )
+    } else {
+        format!("{}.png", name)
+    }
+}
+
+// Saving again without changes reuses the file instead of writing a copy
+fn save(board: &mut Whiteboard) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if let Some(path) = board.saved.as_ref().filter(|path| path.exists()) {
+        return Ok(path.clone());
+    }
+    let dir = Path::new(FILES_DIR).join(SKETCHES_DIR);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(file_name(board));
+    fs::write(&path, png(board)?)?;
+    board.saved = Some(path.clone());
+    Ok(path)
+}
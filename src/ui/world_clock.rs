@@ -0,0 +1,130 @@
+use crate::app::StatusMessage;
+use crate::settings::AppSettings;
+use chrono::{Timelike, Utc};
+use chrono_tz::Tz;
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub const MAX_CLOCKS: usize = 3;
+const SUGGESTIONS: usize = 6;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorldClockZone {
+    pub label: String,     // Short name for the tab bar; the city is used when empty
+    pub time_zone: String, // IANA name, e.g. "America/New_York"
+}
+
+impl WorldClockZone {
+    pub fn tz(&self) -> Option<Tz> {
+        self.time_zone.trim().parse().ok()
+    }
+
+    pub fn display_label(&self) -> String {
+        if !self.label.trim().is_empty() {
+            return self.label.trim().to_string();
+        }
+        let city = self.time_zone.rsplit('/').next().unwrap_or(&self.time_zone);
+        city.replace('_', " ")
+    }
+}
+
+// Compact clocks for the tab bar, next to the weather
+pub fn render(ui: &mut egui::Ui, zones: &[WorldClockZone]) {
+    let now = Utc::now();
+    let mut shown = false;
+    for zone in zones {
+        let Some(tz) = zone.tz() else {
+            continue;
+        };
+        let time = now.with_timezone(&tz);
+        ui.label(format!("{} {}", zone.display_label(), time.format("%H:%M")))
+            .on_hover_text(format!(
+                "{}\n{}",
+                zone.time_zone,
+                time.format("%A %-d %B, UTC%:z")
+            ));
+        shown = true;
+    }
+    if shown {
+        // Only the minutes change, so wake up once they roll over
+        let seconds = 60 - now.second() as u64;
+        ui.ctx().request_repaint_after(Duration::from_secs(seconds));
+    }
+}
+
+fn matching_zones(query: &str) -> Vec<&'static str> {
+    let query = query.trim().to_lowercase().replace(' ', "_");
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|tz| tz.name())
+        .filter(|name| name.to_lowercase().contains(&query))
+        .take(SUGGESTIONS)
+        .collect()
+}
+
+pub fn settings_section(ui: &mut egui::Ui, settings: &mut AppSettings, status: &mut StatusMessage) {
+    let mut changed = false;
+    let mut removed = None;
+
+    for (index, zone) in settings.world_clocks.iter_mut().enumerate() {
+        ui.push_id(index, |ui| {
+            ui.horizontal(|ui| {
+                changed |= ui
+                    .add(
+                        egui::TextEdit::singleline(&mut zone.label)
+                            .hint_text("Label")
+                            .desired_width(80.0),
+                    )
+                    .lost_focus();
+                let valid = zone.tz().is_some();
+                let mut edit = egui::TextEdit::singleline(&mut zone.time_zone)
+                    .hint_text("Europe/Berlin")
+                    .desired_width(200.0);
+                if !valid {
+                    edit = edit.text_color(egui::Color32::from_rgb(213, 94, 0));
+                }
+                let response = ui.add(edit);
+                changed |= response.lost_focus();
+                if let Some(tz) = zone.tz() {
+                    ui.label(Utc::now().with_timezone(&tz).format("%H:%M").to_string());
+                }
+                if ui.small_button("✖").on_hover_text("Remove clock").clicked() {
+                    removed = Some(index);
+                }
+
+                if !valid && !zone.time_zone.trim().is_empty() {
+                    response.on_hover_text("Unknown time zone");
+                }
+            });
+
+            if zone.tz().is_none() && !zone.time_zone.trim().is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    for name in matching_zones(&zone.time_zone) {
+                        if ui.small_button(name).clicked() {
+                            zone.time_zone = name.to_string();
+                            changed = true;
+                        }
+                    }
+                });
+            }
+        });
+    }
+
+    if let Some(index) = removed {
+        settings.world_clocks.remove(index);
+        changed = true;
+    }
+    if settings.world_clocks.len() < MAX_CLOCKS && ui.button("➕ Add Clock").clicked() {
+        settings.world_clocks.push(WorldClockZone {
+            label: String::new(),
+            time_zone: String::new(),
+        });
+    }
+
+    if changed {
+        if let Err(e) = settings.save() {
+            status.error(&format!("Failed to save world clock settings: {}", e));
+        }
+    }
+}
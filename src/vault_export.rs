@@ -0,0 +1,450 @@
+use crate::data::{StudyData, Todo};
+use crate::error;
+use crate::image_handler::CardImage;
+use crate::ui::flashcard::Deck;
+use regex::{Captures, Regex};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const NOTES_DIR: &str = "files";
+const ATTACHMENTS_DIR: &str = "attachments";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VaultFormat {
+    Obsidian,
+    Notion,
+}
+
+impl VaultFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VaultFormat::Obsidian => "Obsidian vault",
+            VaultFormat::Notion => "Notion (Markdown + CSV)",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ExportSummary {
+    pub notes: usize,
+    pub attachments: usize,
+    pub todos: usize,
+    pub decks: usize,
+}
+
+pub fn export_vault(
+    study_data: &StudyData,
+    target: &Path,
+    format: VaultFormat,
+) -> Result<ExportSummary, Box<dyn std::error::Error>> {
+    // Writing into a folder with files in it could replace them, notes included
+    if target
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some())
+    {
+        return Err(format!(
+            "{} isn't empty, export into a new or empty folder",
+            target.display()
+        )
+        .into());
+    }
+    fs::create_dir_all(target)?;
+    let mut summary = ExportSummary::default();
+
+    let notes_source = Path::new(NOTES_DIR);
+    if let (Ok(notes), Ok(vault)) = (notes_source.canonicalize(), target.canonicalize()) {
+        if vault.starts_with(&notes) {
+            return Err("The vault can't go inside the notes folder it exports".into());
+        }
+    }
+    if notes_source.is_dir() {
+        export_notes(notes_source, notes_source, target, format, &mut summary)?;
+    }
+
+    export_todos(&study_data.todos, target, format)?;
+    summary.todos = study_data.todos.len();
+
+    if !study_data.decks.is_empty() {
+        export_decks(&study_data.decks, target, format, &mut summary)?;
+    }
+
+    Ok(summary)
+}
+
+fn export_notes(
+    root: &Path,
+    dir: &Path,
+    target: &Path,
+    format: VaultFormat,
+    summary: &mut ExportSummary,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let relative = path.strip_prefix(root)?;
+        let destination = target.join(relative);
+
+        if path.is_dir() {
+            fs::create_dir_all(&destination)?;
+            export_notes(root, &path, target, format, summary)?;
+        } else if path.extension().map_or(false, |ext| ext == "md") {
+            let content = fs::read_to_string(&path)?;
+            let depth = relative.components().count() - 1;
+            let content = copy_external_images(&content, &path, target, depth, summary)?;
+            let content = match format {
+                // Obsidian understands [[wiki-links]] natively
+                VaultFormat::Obsidian => content,
+                VaultFormat::Notion => wiki_links_to_markdown(&content),
+            };
+            fs::write(&destination, content)?;
+            summary.notes += 1;
+        } else {
+            // Images and other attachments keep their place so relative links still resolve
+            fs::copy(&path, &destination)?;
+            summary.attachments += 1;
+        }
+    }
+    Ok(())
+}
+
+// Images inserted from outside the notes folder are stored with absolute paths,
+// copy those into the vault so the export is self-contained
+fn copy_external_images(
+    content: &str,
+    note_path: &Path,
+    target: &Path,
+    depth: usize,
+    summary: &mut ExportSummary,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let image_regex = Regex::new(r"!\[([^\]]*)\]\(([^)]+)\)")?;
+
+    let rewritten = image_regex.replace_all(content, |caps: &Captures| {
+        let source = PathBuf::from(caps[2].trim());
+        let file_name = match source.file_name() {
+            Some(name) if source.is_absolute() => name.to_owned(),
+            _ => return caps[0].to_string(),
+        };
+
+        let attachments = target.join(ATTACHMENTS_DIR);
+        let file_name = free_name(&attachments, &file_name.to_string_lossy());
+        let result = fs::create_dir_all(&attachments)
+            .and_then(|_| fs::copy(&source, attachments.join(&file_name)));
+        match result {
+            Ok(_) => {
+                summary.attachments += 1;
+                format!(
+                    "![{}]({}{}/{})",
+                    &caps[1],
+                    "../".repeat(depth),
+                    ATTACHMENTS_DIR,
+                    encode_link(&file_name)
+                )
+            }
+            Err(e) => {
+                error::report(
+                    &format!(
+                        "Could not copy image {} from {}",
+                        source.display(),
+                        note_path.display()
+                    ),
+                    e,
+                );
+                caps[0].to_string()
+            }
+        }
+    });
+
+    Ok(rewritten.into_owned())
+}
+
+// Notion's markdown import doesn't know about [[wiki-links]], turn them into relative links
+fn wiki_links_to_markdown(content: &str) -> String {
+    let wiki_regex = Regex::new(r"(!?)\[\[([^\]|#]+)(#[^\]|]*)?(?:\|([^\]]*))?\]\]").unwrap();
+
+    wiki_regex
+        .replace_all(content, |caps: &Captures| {
+            let embed = !caps[1].is_empty();
+            let page = caps[2].trim();
+            let heading = caps.get(3).map_or("", |m| m.as_str());
+            let label = caps
+                .get(4)
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_else(|| page.to_string());
+
+            let file = if Path::new(page).extension().is_some() {
+                page.to_string()
+            } else {
+                format!("{}.md", page)
+            };
+
+            if embed {
+                format!("![{}]({})", label, encode_link(&file))
+            } else {
+                format!("[{}]({}{})", label, encode_link(&file), heading)
+            }
+        })
+        .into_owned()
+}
+
+fn export_todos(
+    todos: &[Todo],
+    target: &Path,
+    format: VaultFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut by_project: BTreeMap<String, Vec<&Todo>> = BTreeMap::new();
+    for todo in todos {
+        let project = todo.project.clone().unwrap_or_else(|| "Inbox".to_string());
+        by_project.entry(project).or_default().push(todo);
+    }
+
+    let mut markdown = String::from("# Todos\n");
+    for (project, todos) in &by_project {
+        markdown.push_str(&format!("\n## {}\n\n", project));
+        for todo in todos {
+            let mark = if todo.completed { "x" } else { " " };
+            markdown.push_str(&format!("- [{}] {}\n", mark, todo.text));
+        }
+    }
+    fs::write(target.join(free_name(target, "Todos.md")), markdown)?;
+
+    if format == VaultFormat::Notion {
+        let mut csv = String::from("Name,Done,Project,Created\n");
+        for todo in todos {
+            csv.push_str(&csv_row(&[
+                &todo.text,
+                if todo.completed { "Yes" } else { "No" },
+                todo.project.as_deref().unwrap_or("Inbox"),
+                &todo.created_at,
+            ]));
+        }
+        fs::write(target.join(free_name(target, "Todos.csv")), csv)?;
+    }
+
+    Ok(())
+}
+
+fn export_decks(
+    decks: &[Deck],
+    target: &Path,
+    format: VaultFormat,
+    summary: &mut ExportSummary,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let decks_dir = target.join("Flashcards");
+    fs::create_dir_all(&decks_dir)?;
+    let attachments = target.join(ATTACHMENTS_DIR);
+
+    let mut index =
+        String::from("# Flashcard Decks\n\n| Deck | Cards | Due |\n| --- | --- | --- |\n");
+
+    for deck in decks {
+        // Names like "A/B" and "A-B" clean up the same, so later ones get a number
+        let name = sanitize_file_name(&deck.name);
+        let mut file_stem = name.clone();
+        for n in 2.. {
+            let taken = ["md", "csv"]
+                .iter()
+                .any(|ext| decks_dir.join(format!("{}.{}", file_stem, ext)).exists());
+            if !taken {
+                break;
+            }
+            file_stem = format!("{} ({})", name, n);
+        }
+        let due = deck.get_due_cards(true).len();
+        let deck_link = match format {
+            VaultFormat::Obsidian => format!("[[{}]]", file_stem),
+            VaultFormat::Notion => format!("[{}]({}.md)", deck.name, encode_link(&file_stem)),
+        };
+        index.push_str(&format!(
+            "| {} | {} | {} |\n",
+            deck_link,
+            deck.cards.len(),
+            due
+        ));
+
+        let mut markdown = format!("# {}\n\n", deck.name);
+        if let Some(description) = &deck.description {
+            markdown.push_str(&format!("{}\n\n", description));
+        }
+        markdown.push_str(&format!("Cards: {} · Due: {}\n", deck.cards.len(), due));
+
+        for card in &deck.cards {
+            markdown.push_str(&format!("\n## {}\n\n", card.front.replace('\n', " ")));
+            if let Some(image) = &card.front_image {
+                let name = save_card_image(image, &attachments)?;
+                markdown.push_str(&format!("{}\n\n", image_embed(&name, format)));
+                summary.attachments += 1;
+            }
+            markdown.push_str(&format!("{}\n", card.back));
+            if let Some(image) = &card.back_image {
+                let name = save_card_image(image, &attachments)?;
+                markdown.push_str(&format!("\n{}\n", image_embed(&name, format)));
+                summary.attachments += 1;
+            }
+            if !card.notes.trim().is_empty() {
+                markdown.push_str(&format!(
+                    "\n> {}\n",
+                    card.notes.trim().replace('\n', "\n> ")
+                ));
+            }
+            if !card.tags.is_empty() {
+                let mut tags: Vec<&String> = card.tags.iter().collect();
+                tags.sort();
+                let tags: Vec<String> = tags.iter().map(|t| format!("#{}", t)).collect();
+                markdown.push_str(&format!("\n{}\n", tags.join(" ")));
+            }
+        }
+        fs::write(decks_dir.join(format!("{}.md", file_stem)), markdown)?;
+
+        if format == VaultFormat::Notion {
+            let mut csv = String::from("Front,Back,Notes,Tags,Due,Reviews\n");
+            for card in &deck.cards {
+                let mut tags: Vec<&str> = card.tags.iter().map(|t| t.as_str()).collect();
+                tags.sort();
+                csv.push_str(&csv_row(&[
+                    &card.front,
+                    &card.back,
+                    &card.notes,
+                    &tags.join(", "),
+                    &card.due_date,
+                    &card.reviews.len().to_string(),
+                ]));
+            }
+            fs::write(decks_dir.join(format!("{}.csv", file_stem)), csv)?;
+        }
+
+        summary.decks += 1;
+    }
+
+    fs::write(decks_dir.join(free_name(&decks_dir, "Decks.md")), index)?;
+    Ok(())
+}
+
+// A single deck as a Q&A document, with card images written next to it in an assets folder
+pub fn export_deck_markdown(deck: &Deck, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let assets = path.parent().unwrap_or(Path::new(".")).join("assets");
+
+    let mut markdown = format!(
+        "---\ndeck: \"{}\"\ncards: {}\ncreated: {}\n---\n\n# {}\n",
+        deck.name.replace('"', "\\\""),
+        deck.cards.len(),
+        deck.created_at,
+        deck.name
+    );
+    if let Some(description) = &deck.description {
+        markdown.push_str(&format!("\n{}\n", description));
+    }
+
+    for (i, card) in deck.cards.iter().enumerate() {
+        markdown.push_str(&format!(
+            "\n## Card {}\n\n**Question**\n\n{}\n",
+            i + 1,
+            card.front
+        ));
+        if let Some(image) = &card.front_image {
+            let name = save_card_image(image, &assets)?;
+            markdown.push_str(&format!(
+                "\n![Question image](assets/{})\n",
+                encode_link(&name)
+            ));
+        }
+
+        markdown.push_str(&format!("\n**Answer**\n\n{}\n", card.back));
+        if let Some(image) = &card.back_image {
+            let name = save_card_image(image, &assets)?;
+            markdown.push_str(&format!(
+                "\n![Answer image](assets/{})\n",
+                encode_link(&name)
+            ));
+        }
+
+        if !card.notes.trim().is_empty() {
+            markdown.push_str(&format!("\n**Notes**\n\n{}\n", card.notes.trim()));
+        }
+
+        if !card.tags.is_empty() {
+            let mut tags: Vec<&String> = card.tags.iter().collect();
+            tags.sort();
+            let tags: Vec<String> = tags.iter().map(|t| format!("`{}`", t)).collect();
+            markdown.push_str(&format!("\nTags: {}\n", tags.join(" ")));
+        }
+    }
+
+    fs::write(path, markdown)?;
+    Ok(())
+}
+
+// Clipboard images all share the same filename, so prefix them with the image id
+fn save_card_image(
+    image: &CardImage,
+    attachments: &Path,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut unique = image.clone();
+    let name = sanitize_file_name(&format!("{}-{}", image.id, image.filename));
+    unique.filename = free_name(attachments, &name);
+    unique.save_to_disk(attachments)?;
+    Ok(unique.filename)
+}
+
+fn image_embed(file_name: &str, format: VaultFormat) -> String {
+    match format {
+        VaultFormat::Obsidian => format!("![[{}]]", file_name),
+        VaultFormat::Notion => format!("![](../{}/{})", ATTACHMENTS_DIR, encode_link(file_name)),
+    }
+}
+
+// Nothing already in the folder is replaced; a clash gets a number, like notes received
+// over Share Nearby
+fn free_name(dir: &Path, file_name: &str) -> String {
+    if !dir.join(file_name).exists() {
+        return file_name.to_string();
+    }
+    let path = Path::new(file_name);
+    let stem = path
+        .file_stem()
+        .map_or(file_name.to_string(), |s| s.to_string_lossy().to_string());
+    let extension = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+    (2..)
+        .map(|n| format!("{} ({}){}", stem, n, extension))
+        .find(|name| !dir.join(name).exists())
+        .unwrap_or_else(|| file_name.to_string())
+}
+
+fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => '-',
+            c => c,
+        })
+        .collect();
+    let cleaned = cleaned.trim().to_string();
+    if cleaned.is_empty() {
+        "Untitled".to_string()
+    } else {
+        cleaned
+    }
+}
+
+fn encode_link(path: &str) -> String {
+    path.replace('%', "%25")
+        .replace(' ', "%20")
+        .replace('(', "%28")
+        .replace(')', "%29")
+}
+
+fn csv_row(fields: &[&str]) -> String {
+    let escaped: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect();
+    format!("{}\n", escaped.join(","))
+}
@@ -0,0 +1,289 @@
+use eframe::egui::ColorImage;
+use rodio::{OutputStream, Sink, Source};
+use std::io::{BufReader, ErrorKind, Read};
+use std::path::Path;
+use std::process::{Child, ChildStdout, Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Decoding is done by ffmpeg, like downloads are done by curl
+const MAX_WIDTH: u32 = 1280;
+const MAX_FPS: f64 = 30.0;
+const AUDIO_RATE: u32 = 44100;
+
+#[derive(Debug, Clone)]
+pub struct VideoInfo {
+    pub duration: f64,
+    pub width: u32, // Of the decoded frames, which may be scaled down
+    pub height: u32,
+    pub fps: f64,
+    pub has_audio: bool,
+}
+
+pub struct Frame {
+    pub time: f64,
+    pub image: ColorImage,
+}
+
+fn missing_tool(e: std::io::Error, tool: &str) -> Box<dyn std::error::Error> {
+    if e.kind() == ErrorKind::NotFound {
+        format!(
+            "Video playback needs {} from FFmpeg installed and on the PATH",
+            tool
+        )
+        .into()
+    } else {
+        e.into()
+    }
+}
+
+pub fn probe(path: &Path) -> Result<VideoInfo, Box<dyn std::error::Error>> {
+    let output = Command::new("ffprobe")
+        .args([
+            "-v",
+            "error",
+            "-show_entries",
+            "stream=codec_type,width,height,avg_frame_rate:format=duration",
+            "-of",
+            "json",
+        ])
+        .arg(path)
+        .output()
+        .map_err(|e| missing_tool(e, "ffprobe"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr)
+            .trim()
+            .to_string()
+            .into());
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let streams = json["streams"].as_array().cloned().unwrap_or_default();
+    let video = streams
+        .iter()
+        .find(|s| s["codec_type"] == "video")
+        .ok_or("The file has no video stream")?;
+    let source_width = video["width"].as_u64().unwrap_or(0) as u32;
+    let source_height = video["height"].as_u64().unwrap_or(0) as u32;
+    if source_width == 0 || source_height == 0 {
+        return Err("Could not read the video size".into());
+    }
+
+    // "30000/1001" style rates
+    let fps = video["avg_frame_rate"]
+        .as_str()
+        .and_then(|rate| {
+            let (num, den) = rate.split_once('/')?;
+            let (num, den): (f64, f64) = (num.parse().ok()?, den.parse().ok()?);
+            (den > 0.0 && num > 0.0).then(|| num / den)
+        })
+        .unwrap_or(25.0)
+        .min(MAX_FPS);
+    let duration = json["format"]["duration"]
+        .as_str()
+        .and_then(|d| d.parse().ok())
+        .unwrap_or(0.0);
+    if duration <= 0.0 {
+        return Err("Could not read the video length".into());
+    }
+
+    // Even sizes, which every pixel format accepts
+    let width = source_width.min(MAX_WIDTH) & !1;
+    let height =
+        ((source_height as f64 * width as f64 / source_width as f64).round() as u32 & !1).max(2);
+
+    Ok(VideoInfo {
+        duration,
+        width,
+        height,
+        fps,
+        has_audio: streams.iter().any(|s| s["codec_type"] == "audio"),
+    })
+}
+
+fn frame_command(path: &Path, info: &VideoInfo, from: f64) -> Command {
+    let mut command = Command::new("ffmpeg");
+    command
+        .args(["-v", "error", "-ss", &format!("{:.3}", from), "-i"])
+        .arg(path)
+        .args([
+            "-an",
+            "-vf",
+            &format!("fps={},scale={}:{}", info.fps, info.width, info.height),
+            "-f",
+            "rawvideo",
+            "-pix_fmt",
+            "rgba",
+            "pipe:1",
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+    command
+}
+
+// A single frame, for showing where a paused video is
+pub fn frame_at(
+    path: &Path,
+    info: &VideoInfo,
+    time: f64,
+) -> Result<ColorImage, Box<dyn std::error::Error>> {
+    let mut command = frame_command(path, info, time);
+    command.args(["-frames:v", "1"]);
+    let output = command.output().map_err(|e| missing_tool(e, "ffmpeg"))?;
+    let size = [info.width as usize, info.height as usize];
+    if output.stdout.len() < size[0] * size[1] * 4 {
+        return Err("No frame at that time".into());
+    }
+    Ok(ColorImage::from_rgba_unmultiplied(
+        size,
+        &output.stdout[..size[0] * size[1] * 4],
+    ))
+}
+
+// 16-bit stereo PCM straight from ffmpeg's stdout
+struct PcmSource {
+    reader: BufReader<ChildStdout>,
+}
+
+impl Iterator for PcmSource {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        let mut bytes = [0u8; 2];
+        self.reader.read_exact(&mut bytes).ok()?;
+        Some(i16::from_le_bytes(bytes))
+    }
+}
+
+impl Source for PcmSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        2
+    }
+
+    fn sample_rate(&self) -> u32 {
+        AUDIO_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+// Plays from one spot until dropped; pausing and seeking start a new one
+pub struct Playback {
+    processes: Vec<Child>,
+    frames: Receiver<Frame>,
+    next: Option<Frame>,
+    _audio: Option<(OutputStream, Sink)>,
+    started: Instant,
+    from: f64,
+}
+
+impl Playback {
+    pub fn start(
+        path: &Path,
+        info: &VideoInfo,
+        from: f64,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut video = frame_command(path, info, from)
+            .spawn()
+            .map_err(|e| missing_tool(e, "ffmpeg"))?;
+        let mut stdout = video.stdout.take().ok_or("No video output")?;
+        let frame_bytes = info.width as usize * info.height as usize * 4;
+        let size = [info.width as usize, info.height as usize];
+        let fps = info.fps;
+        // A few frames of buffer, so decoding stays just ahead of playback
+        let (sender, frames) = mpsc::sync_channel(4);
+        thread::spawn(move || {
+            let mut buffer = vec![0u8; frame_bytes];
+            let mut index = 0u64;
+            while stdout.read_exact(&mut buffer).is_ok() {
+                let frame = Frame {
+                    time: from + index as f64 / fps,
+                    image: ColorImage::from_rgba_unmultiplied(size, &buffer),
+                };
+                if sender.send(frame).is_err() {
+                    break;
+                }
+                index += 1;
+            }
+        });
+
+        let mut processes = vec![video];
+        let mut audio = None;
+        if info.has_audio {
+            // Playback goes on silently if there's no audio device
+            if let Ok((stream, handle)) = OutputStream::try_default() {
+                let child = Command::new("ffmpeg")
+                    .args(["-v", "error", "-ss", &format!("{:.3}", from), "-i"])
+                    .arg(path)
+                    .args(["-vn", "-f", "s16le", "-ac", "2", "-ar"])
+                    .arg(AUDIO_RATE.to_string())
+                    .arg("pipe:1")
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::null())
+                    .spawn();
+                if let Ok(mut child) = child {
+                    if let (Some(stdout), Ok(sink)) = (child.stdout.take(), Sink::try_new(&handle))
+                    {
+                        sink.append(PcmSource {
+                            reader: BufReader::new(stdout),
+                        });
+                        audio = Some((stream, sink));
+                    }
+                    processes.push(child);
+                }
+            }
+        }
+
+        Ok(Self {
+            processes,
+            frames,
+            next: None,
+            _audio: audio,
+            started: Instant::now(),
+            from,
+        })
+    }
+
+    pub fn position(&self) -> f64 {
+        self.from + self.started.elapsed().as_secs_f64()
+    }
+
+    // The newest frame that is due, skipping any that are already late
+    pub fn due_frame(&mut self) -> Option<Frame> {
+        let now = self.position();
+        let mut due = None;
+        loop {
+            let frame = match self.next.take() {
+                Some(frame) => frame,
+                None => match self.frames.try_recv() {
+                    Ok(frame) => frame,
+                    Err(_) => break,
+                },
+            };
+            if frame.time > now {
+                self.next = Some(frame);
+                break;
+            }
+            due = Some(frame);
+        }
+        due
+    }
+}
+
+impl Drop for Playback {
+    fn drop(&mut self) {
+        for process in &mut self.processes {
+            let _ = process.kill();
+            let _ = process.wait();
+        }
+    }
+}
@@ -169,8 +169,13 @@ impl WeatherWidget {
         city_changed
     }
 
-    pub fn update(&mut self) {
+    pub fn update(&mut self, battery_saver: bool) {
         if self.should_update() {
+            if battery_saver && crate::power::on_battery() {
+                // Skip this round and look again after the next interval
+                self.last_update = Some(Instant::now());
+                return;
+            }
             self.fetch_weather();
         }
     }
@@ -221,11 +226,7 @@ impl WeatherWidget {
     }
 
     fn get_settings_dir_static() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-        let mut path = dirs::config_dir()
-            .or_else(|| dirs::home_dir())
-            .ok_or("Could not find config directory")?;
-
-        path.push("study_timer");
+        let path = crate::profile::config_dir().ok_or("Could not find config directory")?;
         Ok(path)
     }
 }
@@ -48,10 +48,6 @@ impl WeatherWidget {
 }
 
 fn get_settings_dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let mut path = dirs::config_dir()
-        .or_else(|| dirs::home_dir())
-        .ok_or("Could not find config directory")?;
-
-    path.push("study_timer");
+    let path = crate::profile::config_dir().ok_or("Could not find config directory")?;
     Ok(path)
 }
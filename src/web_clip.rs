@@ -0,0 +1,684 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+pub const CLIPS_DIR: &str = "files/clips";
+
+// Never part of the article text
+const SKIPPED_TAGS: [&str; 12] = [
+    "script", "style", "noscript", "svg", "nav", "footer", "aside", "form", "iframe", "button",
+    "select", "template",
+];
+// Class or id fragments that mark page furniture rather than content
+const BOILERPLATE: [&str; 10] = [
+    "comment",
+    "sidebar",
+    "share",
+    "social",
+    "cookie",
+    "newsletter",
+    "related",
+    "promo",
+    "advert",
+    "breadcrumb",
+];
+const VOID_TAGS: [&str; 9] = [
+    "br", "img", "hr", "meta", "link", "input", "source", "wbr", "area",
+];
+// A container is the article when it holds this share of all paragraph text
+const CONTENT_SHARE: f64 = 0.7;
+
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Open {
+        name: String,
+        attrs: HashMap<String, String>,
+    },
+    Close(String),
+}
+
+// Downloads, converts and saves on a background thread; the result is the saved note
+pub fn start(url: String) -> Receiver<Result<PathBuf, String>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = clip(&url).map_err(|e| e.to_string());
+        let _ = sender.send(result);
+    });
+    receiver
+}
+
+fn clip(url: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let url = if url.contains("://") {
+        url.trim().to_string()
+    } else {
+        format!("https://{}", url.trim())
+    };
+    let html = fetch(&url)?;
+    let (title, body) = to_markdown(&html, &url);
+    if body.trim().is_empty() {
+        return Err("Could not find any text on the page".into());
+    }
+
+    fs::create_dir_all(CLIPS_DIR)?;
+    let path = free_path(&slug(&title));
+    let date = chrono::Local::now().format("%Y-%m-%d");
+    fs::write(
+        &path,
+        format!(
+            "# {}\n\n> Clipped from <{}> on {}\n\n{}\n",
+            title, url, date, body
+        ),
+    )?;
+    Ok(path)
+}
+
+fn fetch(url: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let output = Command::new("curl")
+        .args([
+            "-sL",
+            "--fail",
+            "--max-time",
+            "30",
+            "-A",
+            "Mozilla/5.0 (FocusPad)",
+        ])
+        .arg(url)
+        .output()?;
+    if !output.status.success() {
+        return Err(format!("Could not download {}", url).into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn slug(title: &str) -> String {
+    let slug: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let slug = slug
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let slug: String = slug.chars().take(60).collect();
+    if slug.is_empty() {
+        "clip".to_string()
+    } else {
+        slug
+    }
+}
+
+fn free_path(slug: &str) -> PathBuf {
+    let dir = Path::new(CLIPS_DIR);
+    let mut path = dir.join(format!("{}.md", slug));
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("{}-{}.md", slug, n));
+        n += 1;
+    }
+    path
+}
+
+// Returns the title and the markdown of the main content
+pub fn to_markdown(html: &str, url: &str) -> (String, String) {
+    let tokens = tokenize(html);
+    let (start, end) = content_range(&tokens);
+
+    let heading = first_text_in(&tokens[start..end], "h1");
+    let title = heading
+        .or_else(|| first_text_in(&tokens, "title"))
+        .unwrap_or_else(|| url.to_string());
+
+    let mut writer = Writer::new(url);
+    writer.write(&tokens[start..end]);
+    let body = writer.finish();
+    // The note starts with the title already
+    let body = match body.strip_prefix(&format!("# {}", title)) {
+        Some(rest) => rest.trim_start().to_string(),
+        None => body,
+    };
+    (title, body)
+}
+
+// The whole body, for pages that are all content like EPUB chapters
+pub fn body_to_markdown(html: &str) -> String {
+    let tokens = tokenize(html);
+    let start = tokens
+        .iter()
+        .position(|t| matches!(t, Token::Open { name, .. } if name == "body"))
+        .map_or(0, |i| i + 1);
+    let end = tokens
+        .iter()
+        .rposition(|t| matches!(t, Token::Close(name) if name == "body"))
+        .unwrap_or(tokens.len())
+        .max(start);
+    let mut writer = Writer::new("");
+    writer.write(&tokens[start..end]);
+    writer.finish()
+}
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            tokens.push(Token::Text(rest.to_string()));
+            break;
+        };
+        // A "<" that doesn't start a tag is just text
+        let starts_tag = rest[lt + 1..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!' || c == '?');
+        let text_end = if starts_tag { lt } else { lt + 1 };
+        if text_end > 0 {
+            tokens.push(Token::Text(rest[..text_end].to_string()));
+        }
+        rest = &rest[text_end..];
+        if !starts_tag {
+            continue;
+        }
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+        let Some(gt) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..gt];
+        rest = &rest[gt + 1..];
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+            continue;
+        }
+        let tag = tag.trim_end_matches('/');
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let name = tag[..name_end].to_lowercase();
+        if name.is_empty() {
+            continue;
+        }
+        let attrs = parse_attrs(&tag[name_end..]);
+
+        // Keep the raw text of script and style blocks out of the token stream
+        if name == "script" || name == "style" {
+            rest = rest
+                .match_indices("</")
+                .find(|(i, _)| {
+                    rest.get(i + 2..i + 2 + name.len())
+                        .is_some_and(|tag| tag.eq_ignore_ascii_case(&name))
+                })
+                .map_or("", |(i, _)| &rest[i..]);
+        }
+        tokens.push(Token::Open { name, attrs });
+    }
+    tokens
+}
+
+fn parse_attrs(text: &str) -> HashMap<String, String> {
+    let mut attrs = HashMap::new();
+    let mut rest = text.trim();
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_lowercase();
+        rest = rest[name_end..].trim_start();
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (quoted, remaining) = match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let inner = &after[1..];
+                    let end = inner.find(quote).unwrap_or(inner.len());
+                    (&inner[..end], inner.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                }
+            };
+            value = decode_entities(quoted);
+            rest = remaining.trim_start();
+        }
+        if !name.is_empty() {
+            attrs.insert(name, value);
+        }
+    }
+    attrs
+}
+
+// The tightest container that still holds most of the paragraph text, like readability
+fn content_range(tokens: &[Token]) -> (usize, usize) {
+    let mut paragraph_text = vec![0usize; tokens.len() + 1];
+    let mut in_paragraph = 0usize;
+    let mut stacks: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut ranges = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        let mut text = 0;
+        match token {
+            Token::Open { name, .. } => {
+                if name == "p" {
+                    in_paragraph += 1;
+                }
+                if !VOID_TAGS.contains(&name.as_str()) {
+                    stacks.entry(name.as_str()).or_default().push(index);
+                }
+            }
+            Token::Close(name) => {
+                if name == "p" {
+                    in_paragraph = in_paragraph.saturating_sub(1);
+                }
+                if let Some(open) = stacks.get_mut(name.as_str()).and_then(|s| s.pop()) {
+                    ranges.push((name.as_str(), open, index));
+                }
+            }
+            Token::Text(t) if in_paragraph > 0 => text = t.trim().len(),
+            Token::Text(_) => {}
+        }
+        paragraph_text[index + 1] = paragraph_text[index] + text;
+    }
+
+    for preferred in ["article", "main"] {
+        if let Some(&(_, open, close)) = ranges
+            .iter()
+            .filter(|(name, _, _)| *name == preferred)
+            .filter(|(_, open, close)| paragraph_text[*close] > paragraph_text[*open])
+            .max_by_key(|(_, open, close)| paragraph_text[*close] - paragraph_text[*open])
+        {
+            return (open + 1, close);
+        }
+    }
+
+    let total = paragraph_text[tokens.len()];
+    let best = ranges
+        .iter()
+        .filter(|(name, _, _)| matches!(*name, "div" | "section" | "body"))
+        .filter(|(_, open, close)| {
+            total > 0
+                && (paragraph_text[*close] - paragraph_text[*open]) as f64
+                    >= total as f64 * CONTENT_SHARE
+        })
+        .min_by_key(|(_, open, close)| close - open);
+    match best {
+        Some(&(_, open, close)) => (open + 1, close),
+        None => (0, tokens.len()),
+    }
+}
+
+fn first_text_in(tokens: &[Token], tag: &str) -> Option<String> {
+    let start = tokens
+        .iter()
+        .position(|t| matches!(t, Token::Open { name, .. } if name == tag))?;
+    let mut text = String::new();
+    for token in &tokens[start + 1..] {
+        match token {
+            Token::Text(t) => text.push_str(t),
+            Token::Close(name) if name == tag => break,
+            _ => {}
+        }
+    }
+    let text = collapse_whitespace(&decode_entities(&text));
+    (!text.trim().is_empty()).then(|| text.trim().to_string())
+}
+
+fn is_boilerplate(attrs: &HashMap<String, String>) -> bool {
+    ["class", "id"].iter().any(|key| {
+        attrs.get(*key).is_some_and(|value| {
+            let value = value.to_lowercase();
+            BOILERPLATE.iter().any(|word| value.contains(word))
+        })
+    })
+}
+
+struct Writer {
+    out: String,
+    base: String,
+    lists: Vec<Option<usize>>, // None for bullets, the next number for ordered lists
+    links: Vec<String>,
+    quote_depth: usize,
+    in_pre: bool,
+    skipping: Option<(String, usize)>, // Tag being skipped and how deep inside it we are
+}
+
+impl Writer {
+    fn new(base: &str) -> Self {
+        Self {
+            out: String::new(),
+            base: base.to_string(),
+            lists: Vec::new(),
+            links: Vec::new(),
+            quote_depth: 0,
+            in_pre: false,
+            skipping: None,
+        }
+    }
+
+    fn write(&mut self, tokens: &[Token]) {
+        for token in tokens {
+            if let Some((skipped, depth)) = self.skipping.as_mut() {
+                match token {
+                    Token::Open { name, .. } if name == skipped => *depth += 1,
+                    Token::Close(name) if name == skipped => {
+                        *depth -= 1;
+                        if *depth == 0 {
+                            self.skipping = None;
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            match token {
+                Token::Text(text) => self.text(text),
+                Token::Open { name, attrs } => {
+                    let void = VOID_TAGS.contains(&name.as_str());
+                    if !void && (SKIPPED_TAGS.contains(&name.as_str()) || is_boilerplate(attrs)) {
+                        self.skipping = Some((name.clone(), 1));
+                        continue;
+                    }
+                    self.open(name, attrs);
+                }
+                Token::Close(name) => self.close(name),
+            }
+        }
+    }
+
+    fn open(&mut self, name: &str, attrs: &HashMap<String, String>) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.block();
+                let level = name[1..].parse().unwrap_or(1);
+                self.out.push_str(&"#".repeat(level));
+                self.out.push(' ');
+            }
+            "p" | "div" | "section" | "table" | "figure" | "figcaption" | "dl" | "dd" | "dt" => {
+                self.block()
+            }
+            "tr" => self.line(),
+            "td" | "th" => self.out.push_str(" | "),
+            "br" => self.out.push_str("  \n"),
+            "hr" => {
+                self.block();
+                self.out.push_str("---");
+                self.block();
+            }
+            "ul" => {
+                self.list_start();
+                self.lists.push(None);
+            }
+            "ol" => {
+                self.list_start();
+                self.lists.push(Some(1));
+            }
+            "li" => {
+                self.line();
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "- ".to_string(),
+                };
+                self.out.push_str(&indent);
+                self.out.push_str(&marker);
+            }
+            "blockquote" => {
+                self.quote_depth += 1;
+                self.block();
+            }
+            "pre" => {
+                self.block();
+                self.out.push_str("```\n");
+                self.in_pre = true;
+            }
+            "code" if !self.in_pre => self.out.push('`'),
+            "strong" | "b" => self.out.push_str("**"),
+            "em" | "i" => self.out.push('*'),
+            "a" => {
+                let href = attrs
+                    .get("href")
+                    .filter(|href| !href.starts_with('#') && !href.starts_with("javascript:"))
+                    .map(|href| resolve(&self.base, href))
+                    .unwrap_or_default();
+                if !href.is_empty() {
+                    self.out.push('[');
+                }
+                self.links.push(href);
+            }
+            "img" => {
+                if let Some(src) = attrs.get("src").filter(|src| !src.starts_with("data:")) {
+                    let alt = attrs.get("alt").map_or("", |alt| alt.as_str());
+                    self.out
+                        .push_str(&format!("![{}]({})", alt.trim(), resolve(&self.base, src)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "p" | "div" | "section" | "table"
+            | "figure" | "dl" => self.block(),
+            "ul" | "ol" => {
+                self.lists.pop();
+                self.line();
+                if self.lists.is_empty() {
+                    self.block();
+                }
+            }
+            "blockquote" => {
+                self.block();
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+            }
+            "pre" => {
+                self.in_pre = false;
+                if !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push_str("```");
+                self.block();
+            }
+            "code" if !self.in_pre => self.out.push('`'),
+            "strong" | "b" => self.out.push_str("**"),
+            "em" | "i" => self.out.push('*'),
+            "a" => {
+                if let Some(href) = self.links.pop().filter(|href| !href.is_empty()) {
+                    if self.out.ends_with('[') {
+                        self.out.pop(); // A link without text, e.g. around an icon
+                    } else {
+                        self.out.push_str(&format!("]({})", href));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = decode_entities(text);
+        if self.in_pre {
+            self.out.push_str(&text);
+            return;
+        }
+        let text = collapse_whitespace(&text);
+        let at_line_start =
+            self.out.is_empty() || self.out.ends_with('\n') || self.out.ends_with("> ");
+        let text = if at_line_start {
+            text.trim_start()
+        } else {
+            &text
+        };
+        if !text.is_empty() {
+            self.out.push_str(text);
+        }
+    }
+
+    // Top-level lists are their own block, nested ones continue the item
+    fn list_start(&mut self) {
+        if self.lists.is_empty() {
+            self.block();
+        } else {
+            self.line();
+        }
+    }
+
+    // Starts a new paragraph
+    fn block(&mut self) {
+        self.trim_end();
+        if !self.out.is_empty() {
+            self.out.push_str("\n\n");
+        }
+        self.out.push_str(&"> ".repeat(self.quote_depth));
+    }
+
+    // Starts a new line within the current block
+    fn line(&mut self) {
+        self.trim_end();
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out.push_str(&"> ".repeat(self.quote_depth));
+    }
+
+    // Also drops quote markers left on otherwise empty lines
+    fn trim_end(&mut self) {
+        loop {
+            let trimmed = self.out.trim_end_matches([' ', '\n']).len();
+            self.out.truncate(trimmed);
+            let line_start = self.out.rfind('\n').map_or(0, |i| i + 1);
+            let line = &self.out[line_start..];
+            if line.is_empty() || !line.chars().all(|c| c == '>' || c == ' ') {
+                break;
+            }
+            self.out.truncate(line_start);
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.trim_end();
+        // Drop the leftovers of elements that had no text, and repeated blank lines
+        let mut lines: Vec<&str> = Vec::new();
+        for line in self.out.lines().map(str::trim_end) {
+            if matches!(line.trim(), "-" | "**" | "*" | "|") {
+                continue;
+            }
+            if line.is_empty() && lines.last().map_or(true, |last| last.is_empty()) {
+                continue;
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_space {
+                out.push(' ');
+            }
+            last_space = true;
+        } else {
+            out.push(c);
+            last_space = false;
+        }
+    }
+    out
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let Some(semi) = rest
+            .char_indices()
+            .take(12)
+            .find(|&(_, c)| c == ';')
+            .map(|(i, _)| i)
+        else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..semi];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            "mdash" => Some('—'),
+            "ndash" => Some('–'),
+            "hellip" => Some('…'),
+            "lsquo" => Some('‘'),
+            "rsquo" => Some('’'),
+            "ldquo" => Some('“'),
+            "rdquo" => Some('”'),
+            "copy" => Some('©'),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// Makes links and image sources absolute so they work from the saved note
+fn resolve(base: &str, link: &str) -> String {
+    let link = link.trim();
+    if link.contains("://") || link.starts_with("mailto:") {
+        return link.to_string();
+    }
+    let scheme_end = base.find("://").map_or(0, |i| i + 3);
+    let scheme = &base[..scheme_end.saturating_sub(3)];
+    if let Some(rest) = link.strip_prefix("//") {
+        return format!("{}://{}", scheme, rest);
+    }
+    let host_end = base[scheme_end..]
+        .find('/')
+        .map_or(base.len(), |i| scheme_end + i);
+    if link.starts_with('/') {
+        return format!("{}{}", &base[..host_end], link);
+    }
+    let path = base[..base.find(['?', '#']).unwrap_or(base.len())].to_string();
+    let dir_end = path[host_end.min(path.len())..]
+        .rfind('/')
+        .map_or(path.len(), |i| host_end + i);
+    format!("{}/{}", &path[..dir_end], link)
+}
@@ -0,0 +1,273 @@
+use crate::data::{HabitFrequency, StudyData};
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use rust_xlsxwriter::{Chart, ChartType, Format, Workbook, Worksheet};
+use std::path::Path;
+
+const CHART_DAYS: i64 = 14;
+const REPORT_WEEKS: i64 = 8;
+
+pub fn export_stats(study_data: &StudyData, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let mut workbook = Workbook::new();
+    let header = Format::new().set_bold();
+    let hours = Format::new().set_num_format("0.00");
+
+    // The summary goes first so it's the sheet people see when opening the file
+    write_summary(workbook.add_worksheet(), study_data, &header, &hours)?;
+    write_sessions(workbook.add_worksheet(), study_data, &header, &hours)?;
+    write_habits(workbook.add_worksheet(), study_data, &header)?;
+    write_todos(workbook.add_worksheet(), study_data, &header)?;
+    write_reviews(workbook.add_worksheet(), study_data, &header)?;
+
+    workbook.save(path)?;
+    Ok(())
+}
+
+fn write_headers(
+    sheet: &mut Worksheet,
+    headers: &[(&str, f64)],
+    format: &Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for (col, (title, width)) in headers.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, *title, format)?;
+        sheet.set_column_width(col as u16, *width)?;
+    }
+    sheet.set_freeze_panes(1, 0)?;
+    Ok(())
+}
+
+fn write_summary(
+    sheet: &mut Worksheet,
+    study_data: &StudyData,
+    header: &Format,
+    hours: &Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    sheet.set_name("Summary")?;
+    sheet.set_column_width(0, 24)?;
+    sheet.set_column_width(1, 14)?;
+
+    let today = Local::now().date_naive();
+    sheet.write_string_with_format(0, 0, "Study report", header)?;
+    sheet.write_string(0, 1, today.format("%Y-%m-%d").to_string())?;
+
+    // Formulas keep the summary correct if sessions are edited in the spreadsheet
+    let totals = [
+        ("Total hours", "=SUM(Sessions!B:B)/60"),
+        ("Sessions", "=COUNTA(Sessions!A:A)-1"),
+        (
+            "Average session (minutes)",
+            "=IFERROR(AVERAGE(Sessions!B:B),0)",
+        ),
+        ("Todos completed", "=COUNTIF(Todos!D:D,\"Yes\")"),
+        ("Todos open", "=COUNTIF(Todos!D:D,\"No\")"),
+        ("Card reviews", "=COUNTA(Reviews!A:A)-1"),
+    ];
+    for (i, (label, formula)) in totals.iter().enumerate() {
+        let row = 2 + i as u32;
+        sheet.write_string(row, 0, *label)?;
+        sheet.write_formula_with_format(row, 1, *formula, hours)?;
+    }
+
+    // Daily hours for the last two weeks, feeding the chart
+    let daily_start = 10;
+    sheet.write_string_with_format(daily_start - 1, 0, "Date", header)?;
+    sheet.write_string_with_format(daily_start - 1, 1, "Hours", header)?;
+    for i in 0..CHART_DAYS {
+        let date = today - Duration::days(CHART_DAYS - 1 - i);
+        let row = daily_start + i as u32;
+        sheet.write_string(row, 0, date.format("%Y-%m-%d").to_string())?;
+        sheet.write_formula_with_format(
+            row,
+            1,
+            format!("=SUMIF(Sessions!A:A,A{},Sessions!B:B)/60", row + 1).as_str(),
+            hours,
+        )?;
+    }
+    let daily_end = daily_start + CHART_DAYS as u32 - 1;
+
+    let mut chart = Chart::new(ChartType::Column);
+    chart.title().set_name("Hours per day");
+    chart.legend().set_hidden();
+    chart
+        .add_series()
+        .set_name("Hours")
+        .set_categories(("Summary", daily_start, 0, daily_end, 0))
+        .set_values(("Summary", daily_start, 1, daily_end, 1));
+    sheet.insert_chart(1, 3, &chart)?;
+
+    // Weekly totals are what advisors usually ask for. Dates are stored as text,
+    // so these are computed here rather than with date-range formulas
+    let weekly_start = daily_end + 3;
+    sheet.write_string_with_format(weekly_start - 1, 0, "Week starting", header)?;
+    sheet.write_string_with_format(weekly_start - 1, 1, "Hours", header)?;
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    for i in 0..REPORT_WEEKS {
+        let week_start = this_monday - Duration::weeks(REPORT_WEEKS - 1 - i);
+        let week_end = week_start + Duration::days(6);
+        let minutes: f64 = study_data
+            .sessions
+            .iter()
+            .filter(|s| {
+                NaiveDate::parse_from_str(&s.date, "%Y-%m-%d")
+                    .map_or(false, |d| d >= week_start && d <= week_end)
+            })
+            .map(|s| s.minutes)
+            .sum();
+
+        let row = weekly_start + i as u32;
+        sheet.write_string(row, 0, week_start.format("%Y-%m-%d").to_string())?;
+        sheet.write_number_with_format(row, 1, minutes / 60.0, hours)?;
+    }
+    let weekly_end = weekly_start + REPORT_WEEKS as u32 - 1;
+
+    let mut weekly_chart = Chart::new(ChartType::Line);
+    weekly_chart.title().set_name("Hours per week");
+    weekly_chart.legend().set_hidden();
+    weekly_chart
+        .add_series()
+        .set_name("Hours")
+        .set_categories(("Summary", weekly_start, 0, weekly_end, 0))
+        .set_values(("Summary", weekly_start, 1, weekly_end, 1));
+    sheet.insert_chart(17, 3, &weekly_chart)?;
+
+    Ok(())
+}
+
+fn write_sessions(
+    sheet: &mut Worksheet,
+    study_data: &StudyData,
+    header: &Format,
+    hours: &Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    sheet.set_name("Sessions")?;
+    write_headers(
+        sheet,
+        &[
+            ("Date", 12.0),
+            ("Minutes", 10.0),
+            ("Hours", 10.0),
+            ("Description", 40.0),
+        ],
+        header,
+    )?;
+
+    let mut sessions: Vec<_> = study_data.sessions.iter().collect();
+    sessions.sort_by(|a, b| a.date.cmp(&b.date));
+
+    for (i, session) in sessions.iter().enumerate() {
+        let row = i as u32 + 1;
+        sheet.write_string(row, 0, &session.date)?;
+        sheet.write_number_with_format(row, 1, session.minutes, hours)?;
+        sheet.write_formula_with_format(row, 2, format!("=B{}/60", row + 1).as_str(), hours)?;
+        if let Some(description) = &session.description {
+            sheet.write_string(row, 3, description)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_habits(
+    sheet: &mut Worksheet,
+    study_data: &StudyData,
+    header: &Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    sheet.set_name("Habits")?;
+    write_headers(
+        sheet,
+        &[
+            ("Habit", 24.0),
+            ("Category", 14.0),
+            ("Frequency", 14.0),
+            ("Created", 12.0),
+            ("Completions", 12.0),
+            ("Current streak", 14.0),
+            ("30 day rate", 12.0),
+        ],
+        header,
+    )?;
+    let percent = Format::new().set_num_format("0%");
+
+    for (i, habit) in study_data.habit_history().enumerate() {
+        let row = i as u32 + 1;
+        let frequency = match habit.target_frequency {
+            HabitFrequency::Daily => "Daily".to_string(),
+            HabitFrequency::Weekly => "Weekly".to_string(),
+            HabitFrequency::Custom(days) => format!("Every {} days", days),
+        };
+        sheet.write_string(row, 0, &habit.name)?;
+        sheet.write_string(row, 1, &habit.category)?;
+        sheet.write_string(row, 2, frequency)?;
+        sheet.write_string(row, 3, &habit.created_at)?;
+        sheet.write_number(row, 4, habit.completion_dates.len() as f64)?;
+        sheet.write_number(row, 5, habit.calculate_current_streak() as f64)?;
+        sheet.write_number_with_format(
+            row,
+            6,
+            habit.get_completion_rate_last_n_days(30) as f64,
+            &percent,
+        )?;
+    }
+    Ok(())
+}
+
+fn write_todos(
+    sheet: &mut Worksheet,
+    study_data: &StudyData,
+    header: &Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    sheet.set_name("Todos")?;
+    write_headers(
+        sheet,
+        &[
+            ("Todo", 40.0),
+            ("Project", 14.0),
+            ("Created", 12.0),
+            ("Completed", 10.0),
+        ],
+        header,
+    )?;
+
+    for (i, todo) in study_data.todo_history().enumerate() {
+        let row = i as u32 + 1;
+        sheet.write_string(row, 0, &todo.text)?;
+        sheet.write_string(row, 1, todo.project.as_deref().unwrap_or("Inbox"))?;
+        sheet.write_string(row, 2, &todo.created_at)?;
+        sheet.write_string(row, 3, if todo.completed { "Yes" } else { "No" })?;
+    }
+    Ok(())
+}
+
+fn write_reviews(
+    sheet: &mut Worksheet,
+    study_data: &StudyData,
+    header: &Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    sheet.set_name("Reviews")?;
+    write_headers(
+        sheet,
+        &[
+            ("Date", 12.0),
+            ("Deck", 20.0),
+            ("Card", 40.0),
+            ("Grade", 10.0),
+            ("Interval (days)", 14.0),
+            ("Ease", 8.0),
+        ],
+        header,
+    )?;
+
+    let mut row = 1;
+    for deck in &study_data.decks {
+        for card in &deck.cards {
+            for review in &card.reviews {
+                sheet.write_string(row, 0, &review.date)?;
+                sheet.write_string(row, 1, &deck.name)?;
+                sheet.write_string(row, 2, &card.front)?;
+                sheet.write_string(row, 3, format!("{:?}", review.grade))?;
+                sheet.write_number(row, 4, review.interval as f64)?;
+                sheet.write_number(row, 5, review.ease_factor as f64)?;
+                row += 1;
+            }
+        }
+    }
+    Ok(())
+}
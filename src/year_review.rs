@@ -0,0 +1,275 @@
+use crate::data::{HabitFrequency, StudyData};
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use std::collections::{BTreeMap, HashSet};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+pub struct HabitSummary {
+    pub name: String,
+    pub completions: usize,
+    pub expected: usize, // Completions the habit's frequency asked for while it existed this year
+    pub longest_streak: u32,
+}
+
+impl HabitSummary {
+    pub fn success_rate(&self) -> f64 {
+        if self.expected == 0 {
+            return 0.0;
+        }
+        (self.completions as f64 / self.expected as f64).min(1.0) * 100.0
+    }
+}
+
+pub struct YearReview {
+    pub year: i32,
+    pub total_minutes: f64,
+    pub sessions: usize,
+    pub study_days: usize,
+    pub longest_streak: u32,
+    pub busiest_week: Option<(NaiveDate, f64)>, // Monday of the week and its minutes
+    pub busiest_day: Option<(NaiveDate, f64)>,
+    pub monthly_minutes: [f64; 12],
+    pub reviews: usize,
+    pub cards_added: usize,
+    pub most_reviewed_deck: Option<(String, usize)>,
+    pub habits: Vec<HabitSummary>,
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.get(..10)?, "%Y-%m-%d").ok()
+}
+
+// Longest run of consecutive days in the set
+fn longest_run(days: &HashSet<NaiveDate>) -> u32 {
+    let mut longest = 0;
+    for day in days {
+        if days.contains(&(*day - Duration::days(1))) {
+            continue;
+        }
+        let mut length = 1;
+        while days.contains(&(*day + Duration::days(length))) {
+            length += 1;
+        }
+        longest = longest.max(length as u32);
+    }
+    longest
+}
+
+// Years with any recorded sessions, newest first, always including the current one
+pub fn available_years(study_data: &StudyData) -> Vec<i32> {
+    let mut years: Vec<i32> = study_data
+        .sessions
+        .iter()
+        .filter_map(|s| parse_date(&s.date))
+        .map(|d| d.year())
+        .chain(std::iter::once(Local::now().year()))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years
+}
+
+pub fn compute(study_data: &StudyData, year: i32) -> YearReview {
+    let in_year = |date: &NaiveDate| date.year() == year;
+
+    let mut daily: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+    let mut sessions = 0;
+    for session in &study_data.sessions {
+        if let Some(date) = parse_date(&session.date).filter(in_year) {
+            *daily.entry(date).or_insert(0.0) += session.minutes;
+            sessions += 1;
+        }
+    }
+
+    let mut weekly: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+    let mut monthly_minutes = [0.0; 12];
+    for (date, minutes) in &daily {
+        let monday = *date - Duration::days(date.weekday().num_days_from_monday() as i64);
+        *weekly.entry(monday).or_insert(0.0) += minutes;
+        monthly_minutes[date.month0() as usize] += minutes;
+    }
+
+    let busiest = |totals: &BTreeMap<NaiveDate, f64>| {
+        totals
+            .iter()
+            .filter(|(_, minutes)| **minutes > 0.0)
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(date, minutes)| (*date, *minutes))
+    };
+    let study_days: HashSet<NaiveDate> = daily.keys().copied().collect();
+
+    let mut reviews = 0;
+    let mut cards_added = 0;
+    let mut most_reviewed_deck: Option<(String, usize)> = None;
+    for deck in &study_data.decks {
+        let deck_reviews = deck
+            .cards
+            .iter()
+            .flat_map(|c| c.reviews.iter())
+            .filter(|r| parse_date(&r.date).is_some_and(|d| in_year(&d)))
+            .count();
+        reviews += deck_reviews;
+        cards_added += deck
+            .cards
+            .iter()
+            .filter(|c| parse_date(&c.created_at).is_some_and(|d| in_year(&d)))
+            .count();
+        if deck_reviews > most_reviewed_deck.as_ref().map_or(0, |(_, n)| *n) {
+            most_reviewed_deck = Some((deck.name.clone(), deck_reviews));
+        }
+    }
+
+    let today = Local::now().date_naive();
+    let year_start = NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(today);
+    let year_end = NaiveDate::from_ymd_opt(year, 12, 31)
+        .unwrap_or(today)
+        .min(today);
+    // Archived habits still count for the year they were active
+    let habits = study_data
+        .habit_history()
+        .filter_map(|habit| {
+            let start = parse_date(&habit.created_at)
+                .unwrap_or(year_start)
+                .max(year_start);
+            if start > year_end {
+                return None;
+            }
+            // Paused days don't count against the habit
+            let days = (year_end - start).num_days() as usize + 1
+                - habit.paused_days_between(start, year_end);
+            let expected = match habit.target_frequency {
+                HabitFrequency::Daily => days,
+                HabitFrequency::Weekly => days.div_ceil(7),
+                HabitFrequency::Custom(n) => days.div_ceil(n.max(1) as usize),
+            };
+            let done: HashSet<NaiveDate> = habit
+                .completion_dates
+                .iter()
+                .filter_map(|d| parse_date(d))
+                .filter(in_year)
+                .collect();
+            Some(HabitSummary {
+                name: habit.name.clone(),
+                completions: done.len(),
+                expected,
+                longest_streak: longest_run(&done),
+            })
+        })
+        .collect();
+
+    YearReview {
+        year,
+        total_minutes: daily.values().sum(),
+        sessions,
+        study_days: study_days.len(),
+        longest_streak: longest_run(&study_days),
+        busiest_week: busiest(&weekly),
+        busiest_day: busiest(&daily),
+        monthly_minutes,
+        reviews,
+        cards_added,
+        most_reviewed_deck,
+        habits,
+    }
+}
+
+impl YearReview {
+    pub fn busiest_week_label(&self) -> Option<String> {
+        self.busiest_week.map(|(monday, minutes)| {
+            format!(
+                "Week of {} ({:.1} hours)",
+                monday.format("%B %-d"),
+                minutes / 60.0
+            )
+        })
+    }
+
+    pub fn busiest_day_label(&self) -> Option<String> {
+        self.busiest_day.map(|(date, minutes)| {
+            format!(
+                "{} ({:.1} hours)",
+                date.format("%A, %B %-d"),
+                minutes / 60.0
+            )
+        })
+    }
+
+    // (month name, minutes) for the months that have any study time
+    pub fn active_months(&self) -> Vec<(&'static str, f64)> {
+        MONTHS
+            .iter()
+            .zip(self.monthly_minutes.iter())
+            .filter(|(_, minutes)| **minutes > 0.0)
+            .map(|(name, minutes)| (*name, *minutes))
+            .collect()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# {} Year in Review\n\n## Study time\n\n", self.year);
+        md.push_str(&format!(
+            "- **Total:** {:.1} hours across {} sessions\n",
+            self.total_minutes / 60.0,
+            self.sessions
+        ));
+        md.push_str(&format!("- **Days studied:** {}\n", self.study_days));
+        md.push_str(&format!(
+            "- **Longest streak:** {} days\n",
+            self.longest_streak
+        ));
+        if let Some(week) = self.busiest_week_label() {
+            md.push_str(&format!("- **Busiest week:** {}\n", week));
+        }
+        if let Some(day) = self.busiest_day_label() {
+            md.push_str(&format!("- **Busiest day:** {}\n", day));
+        }
+
+        let months = self.active_months();
+        if !months.is_empty() {
+            md.push_str("\n| Month | Hours |\n| --- | ---: |\n");
+            for (month, minutes) in months {
+                md.push_str(&format!("| {} | {:.1} |\n", month, minutes / 60.0));
+            }
+        }
+
+        md.push_str("\n## Flashcards\n\n");
+        md.push_str(&format!("- **Reviews:** {}\n", self.reviews));
+        md.push_str(&format!("- **Cards added:** {}\n", self.cards_added));
+        if let Some((deck, count)) = &self.most_reviewed_deck {
+            md.push_str(&format!(
+                "- **Most reviewed deck:** {} ({} reviews)\n",
+                deck, count
+            ));
+        }
+
+        if !self.habits.is_empty() {
+            md.push_str("\n## Habits\n\n");
+            md.push_str("| Habit | Completions | Success | Longest streak |\n");
+            md.push_str("| --- | ---: | ---: | ---: |\n");
+            for habit in &self.habits {
+                md.push_str(&format!(
+                    "| {} | {} | {:.0}% | {} days |\n",
+                    habit.name.replace('|', "\\|"),
+                    habit.completions,
+                    habit.success_rate(),
+                    habit.longest_streak
+                ));
+            }
+        }
+
+        md
+    }
+}